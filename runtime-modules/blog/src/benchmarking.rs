@@ -140,6 +140,36 @@ fn generate_reply<T: Trait<I>, I: Instance>(
     T::ReplyId::zero()
 }
 
+fn generate_author<T: Trait<I> + membership::Trait + balances::Trait, I: Instance>(
+    name: &'static str,
+    id: u32,
+) -> (T::AccountId, ParticipantId<T>) {
+    let (account_id, participant_id) = member_funded_account::<T, I>(name, id);
+
+    Blog::<T, I>::update_author(RawOrigin::Root.into(), participant_id, true).unwrap();
+
+    (account_id, participant_id)
+}
+
+fn generate_draft<T: Trait<I>, I: Instance>(
+    author_id: T::AccountId,
+    participant_id: ParticipantId<T>,
+) -> DraftId {
+    let draft_id = Blog::<T, I>::draft_count();
+
+    Blog::<T, I>::submit_draft(
+        RawOrigin::Signed(author_id).into(),
+        participant_id,
+        vec![0u8],
+        vec![0u8],
+    )
+    .unwrap();
+
+    assert!(<DraftById<T, I>>::contains_key(draft_id));
+
+    draft_id
+}
+
 benchmarks_instance! {
     where_clause { where T: balances::Trait, T: membership::Trait }
 
@@ -332,6 +362,153 @@ benchmarks_instance! {
                 ).into());
         }
     }
+
+    update_author {
+        let (_, participant_id) = member_funded_account::<T, I>("author", 0);
+    }: _(RawOrigin::Root, participant_id, true)
+    verify {
+        assert!(<AuthorById<T, I>>::contains_key(participant_id));
+        assert_last_event::<T, I>(RawEvent::AuthorUpdated(participant_id, true).into());
+    }
+
+    submit_draft {
+        let t in 0 .. MAX_BYTES;
+        let b in 0 .. MAX_BYTES;
+
+        let (account_id, participant_id) = generate_author::<T, I>("author", 0);
+        let title = vec![0u8; t.try_into().unwrap()];
+        let body = vec![0u8; b.try_into().unwrap()];
+        let draft_id = Blog::<T, I>::draft_count();
+    }: _(RawOrigin::Signed(account_id), participant_id, title.clone(), body.clone())
+    verify {
+        assert_eq!(Blog::<T, I>::draft_count(), draft_id + 1);
+        assert_eq!(
+            Blog::<T, I>::draft_by_id(draft_id),
+            Draft {
+                author: participant_id,
+                title_hash: T::Hashing::hash(&title),
+                body_hash: T::Hashing::hash(&body),
+            }
+        );
+        assert_last_event::<T, I>(
+            RawEvent::DraftSubmitted(participant_id, draft_id, title, body).into()
+        );
+    }
+
+    edit_draft {
+        let t in 0 .. MAX_BYTES;
+        let b in 0 .. MAX_BYTES;
+
+        let (account_id, participant_id) = generate_author::<T, I>("author", 0);
+        let draft_id = generate_draft::<T, I>(account_id.clone(), participant_id);
+        let title = Some(vec![1u8; t.try_into().unwrap()]);
+        let body = Some(vec![1u8; b.try_into().unwrap()]);
+    }: _(RawOrigin::Signed(account_id), participant_id, draft_id, title.clone(), body.clone())
+    verify {
+        assert_eq!(
+            Blog::<T, I>::draft_by_id(draft_id),
+            Draft {
+                author: participant_id,
+                title_hash: T::Hashing::hash(&vec![1u8; t.try_into().unwrap()]),
+                body_hash: T::Hashing::hash(&vec![1u8; b.try_into().unwrap()]),
+            }
+        );
+        assert_last_event::<T, I>(
+            RawEvent::DraftEdited(participant_id, draft_id, title, body).into()
+        );
+    }
+
+    withdraw_draft {
+        let (account_id, participant_id) = generate_author::<T, I>("author", 0);
+        let draft_id = generate_draft::<T, I>(account_id.clone(), participant_id);
+    }: _(RawOrigin::Signed(account_id), participant_id, draft_id)
+    verify {
+        assert!(!<DraftById<T, I>>::contains_key(draft_id));
+        assert_last_event::<T, I>(RawEvent::DraftWithdrawn(participant_id, draft_id).into());
+    }
+
+    review_drafts {
+        let i in 1 .. T::PostsMaxNumber::get().try_into().unwrap();
+
+        let (account_id, participant_id) = generate_author::<T, I>("author", 0);
+        let decisions: BTreeMap<DraftId, bool> = (0..i)
+            .map(|_| (generate_draft::<T, I>(account_id.clone(), participant_id), true))
+            .collect();
+    }: _(RawOrigin::Root, decisions.clone())
+    verify {
+        assert_eq!(Blog::<T, I>::post_count(), i as PostId);
+
+        for (post_id, draft_id) in decisions.keys().enumerate() {
+            assert!(!<DraftById<T, I>>::contains_key(draft_id));
+
+            let mut expected_post = Post::<T, I>::new(&vec![0u8], &vec![0u8]);
+            expected_post.author = Some(participant_id);
+            assert_eq!(Blog::<T, I>::post_by_id(post_id as PostId), expected_post);
+
+            assert_in_events::<T, I>(
+                RawEvent::DraftApproved(*draft_id, post_id as PostId).into()
+            );
+        }
+    }
+
+    react_to_post {
+        let post_id = generate_post::<T, I>(0);
+        let (account_id, participant_id) = member_funded_account::<T, I>("caller", 0);
+        let index = T::ReactionsMaxNumber::get() - 1;
+    }: react(RawOrigin::Signed(account_id), participant_id, post_id, None, index)
+    verify {
+        assert_last_event::<T, I>(RawEvent::PostReacted(participant_id, post_id, index).into());
+    }
+
+    react_to_reply {
+        let post_id = generate_post::<T, I>(0);
+        let (account_id, participant_id) = member_funded_account::<T, I>("caller", 0);
+        let reply_id = generate_reply::<T, I>(account_id.clone(), participant_id, post_id);
+        let index = T::ReactionsMaxNumber::get() - 1;
+    }: react(RawOrigin::Signed(account_id), participant_id, post_id, Some(reply_id), index)
+    verify {
+        assert_last_event::<T, I>(
+            RawEvent::ReplyReacted(participant_id, post_id, reply_id, index).into()
+        );
+    }
+
+    tip_post {
+        let (author_account_id, author_id) = generate_author::<T, I>("author", 0);
+        let draft_id = generate_draft::<T, I>(author_account_id.clone(), author_id);
+        Blog::<T, I>::review_drafts(
+            RawOrigin::Root.into(),
+            vec![(draft_id, true)].into_iter().collect()
+        ).unwrap();
+        let post_id = Blog::<T, I>::post_count() - 1;
+
+        // Leave room for the tip in the author account
+        Balances::<T>::make_free_balance_be(&author_account_id, Zero::zero());
+
+        let (account_id, participant_id) = member_funded_account::<T, I>("tipper", 1);
+        let amount = T::ReplyDeposit::get();
+    }: tip(RawOrigin::Signed(account_id), participant_id, post_id, None, amount)
+    verify {
+        assert_last_event::<T, I>(
+            RawEvent::PostTipped(participant_id, post_id, author_id, amount).into()
+        );
+    }
+
+    tip_reply {
+        let post_id = generate_post::<T, I>(0);
+        let (owner_account_id, owner_id) = member_funded_account::<T, I>("owner", 0);
+        let reply_id = generate_reply::<T, I>(owner_account_id.clone(), owner_id, post_id);
+
+        // Leave room for the tip in the reply owner account
+        Balances::<T>::make_free_balance_be(&owner_account_id, Zero::zero());
+
+        let (account_id, participant_id) = member_funded_account::<T, I>("tipper", 1);
+        let amount = T::ReplyDeposit::get();
+    }: tip(RawOrigin::Signed(account_id), participant_id, post_id, Some(reply_id), amount)
+    verify {
+        assert_last_event::<T, I>(
+            RawEvent::ReplyTipped(participant_id, post_id, reply_id, owner_id, amount).into()
+        );
+    }
 }
 
 #[cfg(test)]
@@ -395,4 +572,67 @@ mod tests {
             assert_ok!(test_benchmark_delete_replies::<Runtime>());
         })
     }
+
+    #[test]
+    fn test_update_author() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_update_author::<Runtime>());
+        })
+    }
+
+    #[test]
+    fn test_submit_draft() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_submit_draft::<Runtime>());
+        })
+    }
+
+    #[test]
+    fn test_edit_draft() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_edit_draft::<Runtime>());
+        })
+    }
+
+    #[test]
+    fn test_withdraw_draft() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_withdraw_draft::<Runtime>());
+        })
+    }
+
+    #[test]
+    fn test_review_drafts() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_review_drafts::<Runtime>());
+        })
+    }
+
+    #[test]
+    fn test_react_to_post() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_react_to_post::<Runtime>());
+        })
+    }
+
+    #[test]
+    fn test_react_to_reply() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_react_to_reply::<Runtime>());
+        })
+    }
+
+    #[test]
+    fn test_tip_post() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_tip_post::<Runtime>());
+        })
+    }
+
+    #[test]
+    fn test_tip_reply() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_tip_reply::<Runtime>());
+        })
+    }
 }
@@ -4,7 +4,7 @@ use frame_support::decl_error;
 decl_error! {
     /// Blog module predefined errors
     pub enum Error for Module<T: Trait<I>, I: Instance> {
-        /// A non-owner is trying to do a privilegeded action.
+        /// A non-owner (or non-editor, where allowed) is trying to do a privilegeded action.
         BlogOwnershipError,
 
         /// A non-member is trying to participate
@@ -34,5 +34,29 @@ decl_error! {
         /// This error represent the invalid state where there is not enough funds in a post
         /// account to pay off its delete
         InsufficientBalanceInPostAccount,
+
+        /// A non-author is trying to submit or edit a draft.
+        AuthorshipError,
+
+        /// Author authorization is not changed.
+        AuthorNotChanged,
+
+        /// Draft do not exists.
+        DraftNotFound,
+
+        /// A non-author of a draft is trying to modify it.
+        DraftOwnershipError,
+
+        /// Post has no author to be tipped.
+        PostAuthorNotFound,
+
+        /// Tip amount should be greater than zero.
+        ZeroTipAmount,
+
+        /// Insufficient balance for tip.
+        InsufficientBalanceForTip,
+
+        /// Participant cannot tip themselves.
+        CannotTipSelf,
     }
 }
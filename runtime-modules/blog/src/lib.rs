@@ -14,14 +14,23 @@
 //! - Creation and editing of posts, associated with given blog
 //! - Posts locking/unlocking
 //! - Creation and editing of replies, associated with given post
+//! - Submission of post drafts by the authorized authors and their review by the blog owner
+//! - Reactions to posts and replies, and tips to their authors
 //!
 //! ### Terminology
 //!
 //! - **Lock:** A forbiddance of mutation of any associated information related to a given post.
+//! - **Author:** A member authorized by the blog owner to submit post drafts.
+//! - **Editor:** The lead of the editor working group, can manage the authors and review the
+//! drafts besides the blog owner.
+//! - **Draft:** A post submitted by an author, published only once approved by the blog owner
+//! or the editor.
+//! - **Tip:** A transfer of tokens from a member to the author of a post or a reply.
 //!
 //! ## Interface
 //! The posts creation/edition/locking/unlocking are done through proposals
-//! To reply to posts you need to be a member
+//! The authors management and drafts review are done through proposals or by the editor
+//! To reply, react or tip you need to be a member
 //!
 //! ## Supported extrinsics
 //!
@@ -32,24 +41,34 @@
 //! - [create_reply](./struct.Module.html#method.create_reply)
 //! - [edit_reply](./struct.Module.html#method.edit_reply)
 //! - [delete_replies](./struct.Module.html#method.delete_replies)
+//! - [update_author](./struct.Module.html#method.update_author)
+//! - [submit_draft](./struct.Module.html#method.submit_draft)
+//! - [edit_draft](./struct.Module.html#method.edit_draft)
+//! - [withdraw_draft](./struct.Module.html#method.withdraw_draft)
+//! - [review_drafts](./struct.Module.html#method.review_drafts)
+//! - [react](./struct.Module.html#method.react)
+//! - [tip](./struct.Module.html#method.tip)
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Codec, Decode, Encode};
-use common::membership::MemberOriginValidator;
+use common::membership::{MemberOriginValidator, MembershipInfoProvider};
+use common::working_group::WorkingGroupAuthenticator;
 use errors::Error;
 pub use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::traits::{Currency, ExistenceRequirement};
 use frame_support::weights::Weight;
 use frame_support::{
-    decl_event, decl_module, decl_storage, ensure, traits::Get, Parameter, StorageDoubleMap,
+    decl_event, decl_module, decl_storage, ensure, traits::Get, IterableStorageMap, Parameter,
+    StorageDoubleMap,
 };
 use sp_arithmetic::traits::{BaseArithmetic, One};
 use sp_runtime::SaturatedConversion;
 use sp_runtime::{
-    traits::{AccountIdConversion, Hash, MaybeSerialize, Member, Saturating},
+    traits::{AccountIdConversion, Hash, MaybeSerialize, Member, Saturating, Zero},
     ModuleId,
 };
+use sp_std::cell::Cell;
 use sp_std::collections::btree_map::BTreeMap;
 use sp_std::prelude::*;
 
@@ -64,6 +83,12 @@ type MaxNumber = u64;
 /// Type for post IDs
 pub type PostId = u64;
 
+/// Type for draft IDs
+pub type DraftId = u64;
+
+/// Type for reaction indices
+pub type ReactionsNumber = u64;
+
 /// Blogger participant ID alias for the member of the system.
 pub type ParticipantId<T> = common::MemberId<T>;
 
@@ -83,6 +108,15 @@ pub trait WeightInfo {
     fn create_reply_to_reply(t: u32) -> Weight;
     fn edit_reply(t: u32) -> Weight;
     fn delete_replies(i: u32) -> Weight;
+    fn update_author() -> Weight;
+    fn submit_draft(t: u32, b: u32) -> Weight;
+    fn edit_draft(t: u32, b: u32) -> Weight;
+    fn withdraw_draft() -> Weight;
+    fn review_drafts(i: u32) -> Weight;
+    fn react_to_post() -> Weight;
+    fn react_to_reply() -> Weight;
+    fn tip_post() -> Weight;
+    fn tip_reply() -> Weight;
 }
 
 type BlogWeightInfo<T, I> = <T as Trait<I>>::WeightInfo;
@@ -98,12 +132,18 @@ pub trait Trait<I: Instance = DefaultInstance>:
         Self::AccountId,
     >;
 
+    /// Provides the controller accounts of the tipped participants.
+    type ParticipantInfoProvider: MembershipInfoProvider<Self>;
+
     /// The overarching event type.
     type Event: From<Event<Self, I>> + Into<<Self as frame_system::Trait>::Event>;
 
     /// The maximum number of posts in a blog.
     type PostsMaxNumber: Get<MaxNumber>;
 
+    /// The number of available reactions.
+    type ReactionsMaxNumber: Get<MaxNumber>;
+
     /// Type of identifier for replies.
     type ReplyId: Parameter
         + Member
@@ -127,6 +167,10 @@ pub trait Trait<I: Instance = DefaultInstance>:
 
     /// Time a reply can live until it can be deleted by anyone
     type ReplyLifetime: Get<Self::BlockNumber>;
+
+    /// Working group whose lead is the blog editor: besides the blog owner, the editor can
+    /// manage the authors and review the drafts
+    type EditorWorkingGroup: WorkingGroupAuthenticator<Self>;
 }
 
 /// Type, representing blog related post structure
@@ -138,6 +182,8 @@ pub struct Post<T: Trait<I>, I: Instance> {
     body_hash: T::Hash,
     /// Overall replies counter, associated with post
     replies_count: T::ReplyId,
+    /// Author of the post, if it was published from an approved draft
+    author: Option<ParticipantId<T>>,
 }
 
 // Note: we derive it by hand because the derive isn't working because of a Rust problem
@@ -150,6 +196,7 @@ impl<T: Trait<I>, I: Instance> sp_std::fmt::Debug for Post<T, I> {
             .field("title_hash", &self.title_hash)
             .field("body_hash", &self.body_hash)
             .field("replies_count", &self.replies_count)
+            .field("author", &self.author)
             .finish()
     }
 }
@@ -163,6 +210,7 @@ impl<T: Trait<I>, I: Instance> PartialEq for Post<T, I> {
             && self.title_hash == other.title_hash
             && self.body_hash == other.body_hash
             && self.replies_count == other.replies_count
+            && self.author == other.author
     }
 }
 
@@ -177,6 +225,7 @@ impl<T: Trait<I>, I: Instance> Default for Post<T, I> {
             title_hash: Default::default(),
             body_hash: Default::default(),
             replies_count: Default::default(),
+            author: Default::default(),
         }
     }
 }
@@ -191,6 +240,18 @@ impl<T: Trait<I>, I: Instance> Post<T, I> {
             body_hash: T::Hashing::hash(body),
             // Set replies count of newly created post to zero
             replies_count: T::ReplyId::default(),
+            author: None,
+        }
+    }
+
+    /// Create a new post published from the approved draft
+    pub fn from_draft(draft: &Draft<ParticipantId<T>, T::Hash>) -> Self {
+        Self {
+            locked: false,
+            title_hash: draft.title_hash,
+            body_hash: draft.body_hash,
+            replies_count: T::ReplyId::default(),
+            author: Some(draft.author),
         }
     }
 
@@ -333,6 +394,34 @@ impl<T: Trait<I>, I: Instance> Reply<T, I> {
     }
 }
 
+/// Post in the format preceding the blog authors.
+/// Used by the stored posts migration only.
+#[derive(Encode, Decode, Default, Clone, PartialEq)]
+pub(crate) struct OldPost<Hash, ReplyId> {
+    /// Locking status
+    pub locked: bool,
+    pub title_hash: Hash,
+    pub body_hash: Hash,
+    /// Overall replies counter, associated with post
+    pub replies_count: ReplyId,
+}
+
+/// Type, representing a post draft submitted by an author
+#[derive(Encode, Decode, Clone, Debug, Default, PartialEq)]
+pub struct Draft<ParticipantId, Hash> {
+    /// Participant id of the draft author
+    author: ParticipantId,
+    title_hash: Hash,
+    body_hash: Hash,
+}
+
+impl<ParticipantId: PartialEq, Hash> Draft<ParticipantId, Hash> {
+    /// Check if participant_id is the draft author
+    fn is_author(&self, participant_id: &ParticipantId) -> bool {
+        self.author == *participant_id
+    }
+}
+
 /// Represents a single reply that will be deleted by `delete_replies`
 #[derive(Encode, Decode, Clone, Debug, Default, PartialEq)]
 pub struct ReplyToDelete<ReplyId> {
@@ -359,6 +448,15 @@ decl_storage! {
         /// Reply by unique blog, post and reply identificators
         ReplyById get (fn reply_by_id): double_map hasher(blake2_128_concat) PostId, hasher(blake2_128_concat) T::ReplyId => Reply<T, I>;
 
+        /// Members authorized to submit post drafts
+        AuthorById get(fn author_by_id): map hasher(blake2_128_concat) ParticipantId<T> => ();
+
+        /// Draft count
+        DraftCount get(fn draft_count): DraftId;
+
+        /// Draft by unique draft identificator
+        DraftById get(fn draft_by_id): map hasher(blake2_128_concat) DraftId => Draft<ParticipantId<T>, T::Hash>;
+
     }
 }
 
@@ -720,10 +818,362 @@ decl_module! {
             Ok(())
         }
 
+        /// Blog owner or editor can authorize members to submit post drafts or revoke their
+        /// authorization
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)` doesn't depends on the state or parameters
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = BlogWeightInfo::<T, I>::update_author()]
+        pub fn update_author(origin, participant_id: ParticipantId<T>, authorized: bool) -> DispatchResult {
+
+            // Ensure origin is the blog owner or editor
+            Self::ensure_blog_owner_or_editor(origin)?;
+
+            // No change, invalid transaction
+            ensure!(
+                authorized != <AuthorById<T, I>>::contains_key(participant_id),
+                Error::<T, I>::AuthorNotChanged
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            if authorized {
+                <AuthorById<T, I>>::insert(participant_id, ());
+            } else {
+                <AuthorById<T, I>>::remove(participant_id);
+            }
+
+            // Trigger event
+            Self::deposit_event(RawEvent::AuthorUpdated(participant_id, authorized));
+            Ok(())
+        }
+
+        /// Author can submit a post draft, to be published once approved by the blog owner
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (T + B)` where:
+        /// - `T` is the length of the title
+        /// - `B` is the length of the body
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = BlogWeightInfo::<T, I>::submit_draft(
+                title.len().saturated_into(),
+                body.len().saturated_into()
+        )]
+        pub fn submit_draft(
+            origin,
+            participant_id: ParticipantId<T>,
+            title: Vec<u8>,
+            body: Vec<u8>
+        ) -> DispatchResult {
+            Self::ensure_valid_participant(origin, participant_id)?;
+
+            // Ensure participant is an authorized author
+            Self::ensure_author(&participant_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let draft_id = <DraftCount<I>>::get();
+            <DraftCount<I>>::put(draft_id + 1);
+
+            // New draft creation
+            let draft = Draft {
+                author: participant_id,
+                title_hash: T::Hashing::hash(&title),
+                body_hash: T::Hashing::hash(&body),
+            };
+            <DraftById<T, I>>::insert(draft_id, draft);
+
+            // Trigger event
+            Self::deposit_event(RawEvent::DraftSubmitted(participant_id, draft_id, title, body));
+            Ok(())
+        }
+
+        /// Draft author can edit the draft with a new title and/or body
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (T + B)` where:
+        /// - `T` is the length of the `new_title`
+        /// - `B` is the length of the `new_body`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = Module::<T, I>::edit_draft_weight(&new_title, &new_body)]
+        pub fn edit_draft(
+            origin,
+            participant_id: ParticipantId<T>,
+            draft_id: DraftId,
+            new_title: Option<Vec<u8>>,
+            new_body: Option<Vec<u8>>
+        ) -> DispatchResult {
+            Self::ensure_valid_participant(origin, participant_id)?;
+
+            // Ensure participant is an authorized author
+            Self::ensure_author(&participant_id)?;
+
+            // Ensure draft with given id exists and belongs to the author
+            Self::ensure_draft_authorship(draft_id, &participant_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // Update draft with new text
+            <DraftById<T, I>>::mutate(draft_id, |draft| {
+                if let Some(ref new_title) = new_title {
+                    draft.title_hash = T::Hashing::hash(new_title)
+                }
+                if let Some(ref new_body) = new_body {
+                    draft.body_hash = T::Hashing::hash(new_body)
+                }
+            });
+
+            // Trigger event
+            Self::deposit_event(RawEvent::DraftEdited(participant_id, draft_id, new_title, new_body));
+            Ok(())
+        }
+
+        /// Draft author can withdraw the draft before it is reviewed
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)` doesn't depends on the state or parameters
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = BlogWeightInfo::<T, I>::withdraw_draft()]
+        pub fn withdraw_draft(
+            origin,
+            participant_id: ParticipantId<T>,
+            draft_id: DraftId
+        ) -> DispatchResult {
+            Self::ensure_valid_participant(origin, participant_id)?;
+
+            // Ensure draft with given id exists and belongs to the author
+            Self::ensure_draft_authorship(draft_id, &participant_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <DraftById<T, I>>::remove(draft_id);
+
+            // Trigger event
+            Self::deposit_event(RawEvent::DraftWithdrawn(participant_id, draft_id));
+            Ok(())
+        }
+
+        /// Blog owner or editor can review drafts: the approved drafts are published as posts,
+        /// the rejected ones are removed
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (D)` where:
+        /// - `D` is the number of reviewed drafts
+        /// - DB:
+        ///    - O(D)
+        /// # </weight>
+        #[weight = BlogWeightInfo::<T, I>::review_drafts(decisions.len().saturated_into())]
+        pub fn review_drafts(origin, decisions: BTreeMap<DraftId, bool>) -> DispatchResult {
+
+            // Ensure origin is the blog owner or editor
+            Self::ensure_blog_owner_or_editor(origin)?;
+
+            // Ensure drafts with given ids exist
+            for draft_id in decisions.keys() {
+                Self::ensure_draft_exists(*draft_id)?;
+            }
+
+            // Ensure the approved drafts fit into the posts limit
+            let approved_count = decisions.values().filter(|approved| **approved).count() as MaxNumber;
+            ensure!(
+                Self::post_count().saturating_add(approved_count) <= T::PostsMaxNumber::get(),
+                Error::<T, I>::PostLimitReached
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            for (draft_id, approved) in decisions {
+                let draft = <DraftById<T, I>>::take(draft_id);
+
+                if approved {
+                    let post_id = <PostCount<I>>::get();
+                    <PostCount<I>>::put(post_id + 1);
+
+                    <PostById<T, I>>::insert(post_id, Post::from_draft(&draft));
+
+                    // Trigger event
+                    Self::deposit_event(RawEvent::DraftApproved(draft_id, post_id));
+                } else {
+                    // Trigger event
+                    Self::deposit_event(RawEvent::DraftRejected(draft_id));
+                }
+            }
+            Ok(())
+        }
+
+        /// Participant can react to a post or to a reply
+        /// (Only accessible, if related post is unlocked)
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)` doesn't depends on the state or parameters
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = Module::<T, I>::react_weight(&reply_id)]
+        pub fn react(
+            origin,
+            participant_id: ParticipantId<T>,
+            post_id: PostId,
+            reply_id: Option<T::ReplyId>,
+            index: ReactionsNumber,
+        ) -> DispatchResult {
+            Self::ensure_valid_participant(origin, participant_id)?;
+
+            // Ensure index is valid & reaction under given index exists
+            ensure!(
+                index < T::ReactionsMaxNumber::get(),
+                Error::<T, I>::InvalidReactionIndex
+            );
+
+            // Ensure post with given id exists
+            let post = Self::ensure_post_exists(post_id)?;
+
+            // Ensure post unlocked, so mutations can be performed
+            Self::ensure_post_unlocked(&post)?;
+
+            if let Some(reply_id) = reply_id {
+                // Check reply existed at some point in time(whether it is in storage or not)
+                ensure!(reply_id < post.replies_count(), Error::<T, I>::ReplyNotFound);
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            if let Some(reply_id) = reply_id {
+                // Trigger event
+                Self::deposit_event(RawEvent::ReplyReacted(participant_id, post_id, reply_id, index));
+            } else {
+                // Trigger event
+                Self::deposit_event(RawEvent::PostReacted(participant_id, post_id, index));
+            }
+            Ok(())
+        }
+
+        /// Participant can tip the author of a post or a reply, except themselves.
+        /// Only the posts published from approved drafts have an author.
+        /// The tip can't reap the tipping participant account.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)` doesn't depends on the state or parameters
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = Module::<T, I>::tip_weight(&reply_id)]
+        pub fn tip(
+            origin,
+            participant_id: ParticipantId<T>,
+            post_id: PostId,
+            reply_id: Option<T::ReplyId>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let account_id = Self::ensure_valid_participant(origin, participant_id)?;
+
+            ensure!(!amount.is_zero(), Error::<T, I>::ZeroTipAmount);
+
+            // Ensure post with given id exists
+            let post = Self::ensure_post_exists(post_id)?;
+
+            let recipient_id = if let Some(reply_id) = reply_id {
+                // Ensure reply with given id exists
+                Self::ensure_reply_exists(post_id, reply_id)?.owner
+            } else {
+                post.author.ok_or(Error::<T, I>::PostAuthorNotFound)?
+            };
+
+            ensure!(recipient_id != participant_id, Error::<T, I>::CannotTipSelf);
+
+            let recipient_account_id = T::ParticipantInfoProvider::controller_account_id(recipient_id)?;
+
+            ensure!(
+                Balances::<T>::usable_balance(&account_id) >= amount &&
+                    Balances::<T>::total_balance(&account_id).saturating_sub(amount) >=
+                        <T as balances::Trait>::ExistentialDeposit::get(),
+                Error::<T, I>::InsufficientBalanceForTip
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <Balances<T> as Currency<T::AccountId>>::transfer(
+                &account_id,
+                &recipient_account_id,
+                amount,
+                ExistenceRequirement::KeepAlive,
+            )?;
+
+            if let Some(reply_id) = reply_id {
+                // Trigger event
+                Self::deposit_event(
+                    RawEvent::ReplyTipped(participant_id, post_id, reply_id, recipient_id, amount)
+                );
+            } else {
+                // Trigger event
+                Self::deposit_event(RawEvent::PostTipped(participant_id, post_id, recipient_id, amount));
+            }
+            Ok(())
+        }
+
     }
 }
 
 impl<T: Trait<I>, I: Instance> Module<T, I> {
+    /// Migrates the posts stored before the blog authors were introduced: the migrated posts
+    /// have no author. Possible application includes the runtime upgrade.
+    pub fn migrate_posts() -> Weight {
+        let migrated_posts_number = Cell::new(0 as Weight);
+
+        <PostById<T, I>>::translate(|_, old_post: OldPost<T::Hash, T::ReplyId>| {
+            migrated_posts_number.set(migrated_posts_number.get() + 1);
+
+            Some(Post {
+                locked: old_post.locked,
+                title_hash: old_post.title_hash,
+                body_hash: old_post.body_hash,
+                replies_count: old_post.replies_count,
+                author: None,
+            })
+        });
+
+        let migrated_posts_number = migrated_posts_number.get();
+
+        T::DbWeight::get().reads_writes(migrated_posts_number, migrated_posts_number)
+    }
+
     fn get_treasury_account(post_id: PostId) -> T::AccountId {
         T::ModuleId::get().into_sub_account(post_id)
     }
@@ -757,6 +1207,14 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         BlogWeightInfo::<T, I>::edit_post(title_len, body_len)
     }
 
+    // edit_draft_weight
+    fn edit_draft_weight(title: &Option<Vec<u8>>, body: &Option<Vec<u8>>) -> Weight {
+        let title_len: u32 = title.as_ref().map_or(0, |t| t.len().saturated_into());
+        let body_len: u32 = body.as_ref().map_or(0, |b| b.len().saturated_into());
+
+        BlogWeightInfo::<T, I>::edit_draft(title_len, body_len)
+    }
+
     // calculate create_reply weight
     fn create_reply_weight(text_len: usize) -> Weight {
         let text_len: u32 = text_len.saturated_into();
@@ -764,6 +1222,24 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
             .max(BlogWeightInfo::<T, I>::create_reply_to_reply(text_len))
     }
 
+    // calculate react weight
+    fn react_weight(reply_id: &Option<T::ReplyId>) -> Weight {
+        if reply_id.is_some() {
+            BlogWeightInfo::<T, I>::react_to_reply()
+        } else {
+            BlogWeightInfo::<T, I>::react_to_post()
+        }
+    }
+
+    // calculate tip weight
+    fn tip_weight(reply_id: &Option<T::ReplyId>) -> Weight {
+        if reply_id.is_some() {
+            BlogWeightInfo::<T, I>::tip_reply()
+        } else {
+            BlogWeightInfo::<T, I>::tip_post()
+        }
+    }
+
     // Get participant id from origin
     fn ensure_valid_participant(
         origin: T::Origin,
@@ -796,6 +1272,36 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         Ok(Self::reply_by_id(post_id, reply_id))
     }
 
+    fn ensure_author(participant_id: &ParticipantId<T>) -> Result<(), DispatchError> {
+        ensure!(
+            <AuthorById<T, I>>::contains_key(participant_id),
+            Error::<T, I>::AuthorshipError
+        );
+        Ok(())
+    }
+
+    fn ensure_draft_exists(
+        draft_id: DraftId,
+    ) -> Result<Draft<ParticipantId<T>, T::Hash>, DispatchError> {
+        ensure!(
+            <DraftById<T, I>>::contains_key(draft_id),
+            Error::<T, I>::DraftNotFound
+        );
+        Ok(Self::draft_by_id(draft_id))
+    }
+
+    fn ensure_draft_authorship(
+        draft_id: DraftId,
+        participant_id: &ParticipantId<T>,
+    ) -> Result<(), DispatchError> {
+        let draft = Self::ensure_draft_exists(draft_id)?;
+        ensure!(
+            draft.is_author(participant_id),
+            Error::<T, I>::DraftOwnershipError
+        );
+        Ok(())
+    }
+
     fn ensure_blog_ownership(blog_owner: T::Origin) -> Result<(), DispatchError> {
         ensure!(
             frame_system::ensure_root(blog_owner).is_ok(),
@@ -805,6 +1311,19 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         Ok(())
     }
 
+    fn ensure_blog_owner_or_editor(origin: T::Origin) -> Result<(), DispatchError> {
+        if frame_system::ensure_root(origin.clone()).is_ok() {
+            return Ok(());
+        }
+
+        ensure!(
+            T::EditorWorkingGroup::ensure_leader_origin(origin).is_ok(),
+            Error::<T, I>::BlogOwnershipError
+        );
+
+        Ok(())
+    }
+
     fn ensure_reply_ownership(
         reply: &Reply<T, I>,
         reply_owner: &ParticipantId<T>,
@@ -839,7 +1358,10 @@ decl_event!(
     where
         ParticipantId = ParticipantId<T>,
         PostId = PostId,
+        DraftId = DraftId,
         ReplyId = <T as Trait<I>>::ReplyId,
+        ReactionIndex = ReactionsNumber,
+        Balance = BalanceOf<T>,
         Title = Vec<u8>,
         Text = Vec<u8>,
         UpdatedTitle = Option<Vec<u8>>,
@@ -868,5 +1390,35 @@ decl_event!(
 
         /// A reply was edited
         ReplyEdited(ParticipantId, PostId, ReplyId, Text),
+
+        /// A member authorization to submit drafts was updated
+        AuthorUpdated(ParticipantId, bool),
+
+        /// A draft was submitted
+        DraftSubmitted(ParticipantId, DraftId, Title, Text),
+
+        /// A draft was edited
+        DraftEdited(ParticipantId, DraftId, UpdatedTitle, UpdatedBody),
+
+        /// A draft was withdrawn by its author
+        DraftWithdrawn(ParticipantId, DraftId),
+
+        /// A draft was approved and published as the post
+        DraftApproved(DraftId, PostId),
+
+        /// A draft was rejected
+        DraftRejected(DraftId),
+
+        /// A participant reacted to a post
+        PostReacted(ParticipantId, PostId, ReactionIndex),
+
+        /// A participant reacted to a reply
+        ReplyReacted(ParticipantId, PostId, ReplyId, ReactionIndex),
+
+        /// A participant tipped the post author: tipper, post, author, amount
+        PostTipped(ParticipantId, PostId, ParticipantId, Balance),
+
+        /// A participant tipped the reply owner: tipper, post, reply, owner, amount
+        ReplyTipped(ParticipantId, PostId, ReplyId, ParticipantId, Balance),
     }
 );
@@ -18,6 +18,7 @@ pub(crate) const FIRST_OWNER_PARTICIPANT_ID: u64 = 0;
 pub(crate) const SECOND_OWNER_ORIGIN: u128 = 2;
 pub(crate) const SECOND_OWNER_PARTICIPANT_ID: u64 = 2;
 pub(crate) const BAD_MEMBER_ID: u64 = 100000;
+pub(crate) const EDITOR_ORIGIN: u128 = 7;
 
 impl_outer_origin! {
     pub enum Origin for Runtime {}
@@ -93,6 +94,11 @@ parameter_types! {
     pub const MinimumPeriod: u64 = 5;
     pub const ReferralCutMaximumPercent: u8 = 50;
     pub const CandidateStake: u64 = 100;
+    pub const HandleDisputeLockId: [u8; 8] = [4; 8];
+    pub const HandleDisputeStake: u64 = 150;
+    pub const HandleDisputeGracePeriod: u64 = 10;
+    pub const MaxRecoveryGuardians: u32 = 5;
+    pub const RecoveryDelay: u64 = 10;
 }
 
 impl membership::Trait for Runtime {
@@ -106,6 +112,11 @@ impl membership::Trait for Runtime {
     type StakingCandidateStakingHandler =
         staking_handler::StakingManager<Self, StakingCandidateLockId>;
     type CandidateStake = CandidateStake;
+    type HandleDisputeStakingHandler = staking_handler::StakingManager<Self, HandleDisputeLockId>;
+    type HandleDisputeStake = HandleDisputeStake;
+    type HandleDisputeGracePeriod = HandleDisputeGracePeriod;
+    type MaxRecoveryGuardians = MaxRecoveryGuardians;
+    type RecoveryDelay = RecoveryDelay;
 }
 
 impl pallet_timestamp::Trait for Runtime {
@@ -230,10 +241,62 @@ impl membership::WeightInfo for Weights {
     fn member_remark() -> Weight {
         unimplemented!()
     }
+    fn reserve_handle(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn release_handle(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn open_handle_dispute(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn resolve_handle_dispute() -> Weight {
+        unimplemented!()
+    }
+    fn execute_handle_dispute_resolution() -> Weight {
+        unimplemented!()
+    }
+    fn withdraw_handle_dispute() -> Weight {
+        unimplemented!()
+    }
+    fn add_member_verification() -> Weight {
+        unimplemented!()
+    }
+    fn revoke_member_verification() -> Weight {
+        unimplemented!()
+    }
+    fn set_second_level_referral_cut() -> Weight {
+        unimplemented!()
+    }
+    fn start_referral_campaign() -> Weight {
+        unimplemented!()
+    }
+    fn end_referral_campaign() -> Weight {
+        unimplemented!()
+    }
+    fn set_recovery_guardians(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn initiate_recovery() -> Weight {
+        unimplemented!()
+    }
+    fn approve_recovery() -> Weight {
+        unimplemented!()
+    }
+    fn reject_recovery() -> Weight {
+        unimplemented!()
+    }
+    fn cancel_recovery() -> Weight {
+        unimplemented!()
+    }
+    fn execute_recovery() -> Weight {
+        unimplemented!()
+    }
 }
 
 parameter_types! {
     pub const PostsMaxNumber: u64 = 20;
+    pub const ReactionsMaxNumber: u64 = 5;
     pub const RepliesMaxNumber: u64 = 100;
     pub const ReplyDeposit: u64 = 500;
     pub const BlogModuleId: ModuleId = ModuleId(*b"m00:blog"); // module : blog
@@ -244,13 +307,16 @@ impl Trait for Runtime {
     type Event = TestEvent;
 
     type PostsMaxNumber = PostsMaxNumber;
+    type ReactionsMaxNumber = ReactionsMaxNumber;
     type ParticipantEnsureOrigin = MockEnsureParticipant;
+    type ParticipantInfoProvider = MockEnsureParticipant;
     type WeightInfo = ();
 
     type ReplyId = u64;
     type ReplyDeposit = ReplyDeposit;
     type ModuleId = BlogModuleId;
     type ReplyLifetime = ReplyLifetime;
+    type EditorWorkingGroup = MockEditorWorkingGroup;
 }
 
 impl WeightInfo for () {
@@ -278,6 +344,83 @@ impl WeightInfo for () {
     fn delete_replies(_: u32) -> Weight {
         unimplemented!()
     }
+    fn update_author() -> Weight {
+        unimplemented!()
+    }
+    fn submit_draft(_: u32, _: u32) -> Weight {
+        unimplemented!()
+    }
+    fn edit_draft(_: u32, _: u32) -> Weight {
+        unimplemented!()
+    }
+    fn withdraw_draft() -> Weight {
+        unimplemented!()
+    }
+    fn review_drafts(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn react_to_post() -> Weight {
+        unimplemented!()
+    }
+    fn react_to_reply() -> Weight {
+        unimplemented!()
+    }
+    fn tip_post() -> Weight {
+        unimplemented!()
+    }
+    fn tip_reply() -> Weight {
+        unimplemented!()
+    }
+}
+
+// The lead of the editor working group signs with the `EDITOR_ORIGIN` account
+pub struct MockEditorWorkingGroup;
+impl common::working_group::WorkingGroupAuthenticator<Runtime> for MockEditorWorkingGroup {
+    fn ensure_worker_origin(
+        _origin: Origin,
+        _worker_id: &<Runtime as common::membership::MembershipTypes>::ActorId,
+    ) -> DispatchResult {
+        unimplemented!()
+    }
+
+    fn ensure_leader_origin(origin: Origin) -> DispatchResult {
+        let account_id = frame_system::ensure_signed(origin)?;
+
+        ensure!(
+            Self::is_leader_account_id(&account_id),
+            DispatchError::BadOrigin
+        );
+
+        Ok(())
+    }
+
+    fn get_leader_member_id() -> Option<<Runtime as common::membership::MembershipTypes>::MemberId>
+    {
+        unimplemented!()
+    }
+
+    fn is_leader_account_id(account_id: &<Runtime as frame_system::Trait>::AccountId) -> bool {
+        *account_id == EDITOR_ORIGIN
+    }
+
+    fn is_worker_account_id(
+        _account_id: &<Runtime as frame_system::Trait>::AccountId,
+        _worker_id: &<Runtime as common::membership::MembershipTypes>::ActorId,
+    ) -> bool {
+        unimplemented!()
+    }
+
+    fn worker_exists(
+        _worker_id: &<Runtime as common::membership::MembershipTypes>::ActorId,
+    ) -> bool {
+        unimplemented!()
+    }
+
+    fn ensure_worker_exists(
+        _worker_id: &<Runtime as common::membership::MembershipTypes>::ActorId,
+    ) -> DispatchResult {
+        unimplemented!()
+    }
 }
 
 pub struct MockEnsureParticipant;
@@ -303,6 +446,15 @@ impl
     }
 }
 
+// Participant controller accounts are equal to their ids
+impl common::membership::MembershipInfoProvider<Runtime> for MockEnsureParticipant {
+    fn controller_account_id(
+        member_id: ParticipantId<Runtime>,
+    ) -> Result<<Runtime as frame_system::Trait>::AccountId, DispatchError> {
+        Ok(member_id.into())
+    }
+}
+
 impl common::membership::MembershipTypes for Runtime {
     type MemberId = u64;
     type ActorId = u64;
@@ -347,7 +499,10 @@ pub fn generate_text(len: usize) -> Vec<u8> {
 type RawTestEvent = RawEvent<
     ParticipantId<Runtime>,
     PostId,
+    DraftId,
     <Runtime as Trait>::ReplyId,
+    ReactionsNumber,
+    u64,
     Vec<u8>,
     Vec<u8>,
     Option<Vec<u8>>,
@@ -403,6 +558,55 @@ pub fn edit_post(origin: Origin, post_id: PostId) -> DispatchResult {
     TestBlogModule::edit_post(origin, post_id, Some(title), Some(body))
 }
 
+// Drafts
+pub fn draft_by_id(draft_id: DraftId) -> Option<Draft<ParticipantId<Runtime>, H256>> {
+    match TestBlogModule::draft_by_id(draft_id) {
+        draft if draft != Draft::default() => Some(draft),
+        _ => None,
+    }
+}
+
+pub fn get_draft(author: ParticipantId<Runtime>) -> Draft<ParticipantId<Runtime>, H256> {
+    let (title, body) = generate_post();
+    Draft {
+        author,
+        title_hash: <Runtime as frame_system::Trait>::Hashing::hash(&title),
+        body_hash: <Runtime as frame_system::Trait>::Hashing::hash(&body),
+    }
+}
+
+pub fn update_author(
+    origin: Origin,
+    participant_id: ParticipantId<Runtime>,
+    authorized: bool,
+) -> DispatchResult {
+    TestBlogModule::update_author(origin, participant_id, authorized)
+}
+
+pub fn submit_draft(origin_id: u128, participant_id: u64) -> DispatchResult {
+    let (title, body) = generate_post();
+    TestBlogModule::submit_draft(Origin::signed(origin_id), participant_id, title, body)
+}
+
+pub fn edit_draft(origin_id: u128, participant_id: u64, draft_id: DraftId) -> DispatchResult {
+    let (title, body) = generate_post();
+    TestBlogModule::edit_draft(
+        Origin::signed(origin_id),
+        participant_id,
+        draft_id,
+        Some(title),
+        Some(body),
+    )
+}
+
+pub fn withdraw_draft(origin_id: u128, participant_id: u64, draft_id: DraftId) -> DispatchResult {
+    TestBlogModule::withdraw_draft(Origin::signed(origin_id), participant_id, draft_id)
+}
+
+pub fn review_drafts(origin: Origin, decisions: Vec<(DraftId, bool)>) -> DispatchResult {
+    TestBlogModule::review_drafts(origin, decisions.into_iter().collect())
+}
+
 // Replies
 pub fn reply_by_id(
     post_id: PostId,
@@ -481,3 +685,36 @@ pub fn edit_reply(
         reply,
     )
 }
+
+// Reactions and tips
+pub fn react(
+    origin_id: u128,
+    participant_id: u64,
+    post_id: PostId,
+    reply_id: Option<<Runtime as Trait>::ReplyId>,
+    index: ReactionsNumber,
+) -> DispatchResult {
+    TestBlogModule::react(
+        Origin::signed(origin_id),
+        participant_id,
+        post_id,
+        reply_id,
+        index,
+    )
+}
+
+pub fn tip(
+    origin_id: u128,
+    participant_id: u64,
+    post_id: PostId,
+    reply_id: Option<<Runtime as Trait>::ReplyId>,
+    amount: u64,
+) -> DispatchResult {
+    TestBlogModule::tip(
+        Origin::signed(origin_id),
+        participant_id,
+        post_id,
+        reply_id,
+        amount,
+    )
+}
@@ -2,7 +2,7 @@
 
 use crate::mock::*;
 use crate::*;
-use frame_support::assert_ok;
+use frame_support::{assert_ok, StorageMap};
 
 //Blog, post or reply id
 const FIRST_ID: u64 = 0;
@@ -1226,6 +1226,565 @@ fn reply_delete_success_with_other_participant() {
     })
 }
 
+// Drafts
+#[test]
+fn draft_submission_and_approval_success() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(update_author(
+            Origin::root(),
+            FIRST_OWNER_PARTICIPANT_ID,
+            true
+        ));
+
+        assert_ok!(submit_draft(FIRST_OWNER_ORIGIN, FIRST_OWNER_PARTICIPANT_ID));
+
+        // Drafts storage updated succesfully
+        assert_eq!(
+            draft_by_id(FIRST_ID),
+            Some(get_draft(FIRST_OWNER_PARTICIPANT_ID))
+        );
+        assert_eq!(TestBlogModule::draft_count(), 1);
+
+        // Events number before tested call
+        let number_of_events_before_call = System::events().len();
+
+        assert_ok!(review_drafts(Origin::root(), vec![(FIRST_ID, true)]));
+
+        // Draft published as the post of its author
+        assert!(draft_by_id(FIRST_ID).is_none());
+        let mut expected_post = get_post(false);
+        expected_post.author = Some(FIRST_OWNER_PARTICIPANT_ID);
+        assert_eq!(post_by_id(FIRST_ID), Some(expected_post));
+        assert_eq!(post_count(), 1);
+
+        // Event checked
+        let draft_approved_event = get_test_event(RawEvent::DraftApproved(FIRST_ID, FIRST_ID));
+        assert_event_success(draft_approved_event, number_of_events_before_call + 1)
+    })
+}
+
+#[test]
+fn drafts_review_by_editor_success() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(update_author(
+            Origin::signed(EDITOR_ORIGIN),
+            FIRST_OWNER_PARTICIPANT_ID,
+            true
+        ));
+
+        assert_ok!(submit_draft(FIRST_OWNER_ORIGIN, FIRST_OWNER_PARTICIPANT_ID));
+
+        // Events number before tested call
+        let number_of_events_before_call = System::events().len();
+
+        assert_ok!(review_drafts(
+            Origin::signed(EDITOR_ORIGIN),
+            vec![(FIRST_ID, true)]
+        ));
+
+        // Draft published as the post of its author
+        assert!(draft_by_id(FIRST_ID).is_none());
+        assert_eq!(post_count(), 1);
+
+        // Event checked
+        let draft_approved_event = get_test_event(RawEvent::DraftApproved(FIRST_ID, FIRST_ID));
+        assert_event_success(draft_approved_event, number_of_events_before_call + 1)
+    })
+}
+
+#[test]
+fn migrate_posts_succeeds() {
+    ExtBuilder::default().build().execute_with(|| {
+        let post = get_post(true);
+        let old_post = OldPost {
+            locked: post.locked,
+            title_hash: post.title_hash,
+            body_hash: post.body_hash,
+            replies_count: post.replies_count,
+        };
+
+        frame_support::storage::unhashed::put(
+            &<PostById<Runtime, DefaultInstance>>::hashed_key_for(FIRST_ID),
+            &old_post,
+        );
+
+        TestBlogModule::migrate_posts();
+
+        assert_eq!(post_by_id(FIRST_ID), Some(post));
+    })
+}
+
+#[test]
+fn draft_submission_authorship_error() {
+    ExtBuilder::default().build().execute_with(|| {
+        // Events number before tested call
+        let number_of_events_before_call = System::events().len();
+
+        assert_failure(
+            submit_draft(FIRST_OWNER_ORIGIN, FIRST_OWNER_PARTICIPANT_ID),
+            Error::AuthorshipError,
+            number_of_events_before_call,
+        );
+
+        assert_failure(
+            update_author(
+                Origin::signed(FIRST_OWNER_ORIGIN),
+                FIRST_OWNER_PARTICIPANT_ID,
+                true,
+            ),
+            Error::BlogOwnershipError,
+            number_of_events_before_call,
+        );
+
+        assert_failure(
+            update_author(Origin::root(), FIRST_OWNER_PARTICIPANT_ID, false),
+            Error::AuthorNotChanged,
+            number_of_events_before_call,
+        );
+
+        assert_ok!(update_author(
+            Origin::root(),
+            FIRST_OWNER_PARTICIPANT_ID,
+            true
+        ));
+        assert_ok!(submit_draft(FIRST_OWNER_ORIGIN, FIRST_OWNER_PARTICIPANT_ID));
+
+        // Revoked authors can no longer edit their drafts
+        assert_ok!(update_author(
+            Origin::root(),
+            FIRST_OWNER_PARTICIPANT_ID,
+            false
+        ));
+
+        let number_of_events_before_call = System::events().len();
+
+        assert_failure(
+            edit_draft(FIRST_OWNER_ORIGIN, FIRST_OWNER_PARTICIPANT_ID, FIRST_ID),
+            Error::AuthorshipError,
+            number_of_events_before_call,
+        );
+    })
+}
+
+#[test]
+fn draft_editing_and_withdrawal_success() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(update_author(
+            Origin::root(),
+            FIRST_OWNER_PARTICIPANT_ID,
+            true
+        ));
+        assert_ok!(update_author(
+            Origin::root(),
+            SECOND_OWNER_PARTICIPANT_ID,
+            true
+        ));
+        assert_ok!(submit_draft(FIRST_OWNER_ORIGIN, FIRST_OWNER_PARTICIPANT_ID));
+
+        // Events number before tested call
+        let number_of_events_before_call = System::events().len();
+
+        assert_failure(
+            edit_draft(SECOND_OWNER_ORIGIN, SECOND_OWNER_PARTICIPANT_ID, FIRST_ID),
+            Error::DraftOwnershipError,
+            number_of_events_before_call,
+        );
+        assert_failure(
+            withdraw_draft(SECOND_OWNER_ORIGIN, SECOND_OWNER_PARTICIPANT_ID, FIRST_ID),
+            Error::DraftOwnershipError,
+            number_of_events_before_call,
+        );
+        assert_failure(
+            edit_draft(FIRST_OWNER_ORIGIN, FIRST_OWNER_PARTICIPANT_ID, SECOND_ID),
+            Error::DraftNotFound,
+            number_of_events_before_call,
+        );
+
+        assert_ok!(edit_draft(
+            FIRST_OWNER_ORIGIN,
+            FIRST_OWNER_PARTICIPANT_ID,
+            FIRST_ID
+        ));
+        assert_eq!(
+            draft_by_id(FIRST_ID),
+            Some(get_draft(FIRST_OWNER_PARTICIPANT_ID))
+        );
+
+        assert_ok!(withdraw_draft(
+            FIRST_OWNER_ORIGIN,
+            FIRST_OWNER_PARTICIPANT_ID,
+            FIRST_ID
+        ));
+        assert!(draft_by_id(FIRST_ID).is_none());
+
+        // Event checked
+        let draft_withdrawn_event = get_test_event(RawEvent::DraftWithdrawn(
+            FIRST_OWNER_PARTICIPANT_ID,
+            FIRST_ID,
+        ));
+        assert_event_success(draft_withdrawn_event, number_of_events_before_call + 2);
+
+        // Withdrawn draft can no longer be reviewed
+        assert_failure(
+            review_drafts(Origin::root(), vec![(FIRST_ID, true)]),
+            Error::DraftNotFound,
+            number_of_events_before_call + 2,
+        );
+    })
+}
+
+#[test]
+fn drafts_review_rejection_and_posts_limit() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(update_author(
+            Origin::root(),
+            FIRST_OWNER_PARTICIPANT_ID,
+            true
+        ));
+        assert_ok!(submit_draft(FIRST_OWNER_ORIGIN, FIRST_OWNER_PARTICIPANT_ID));
+        assert_ok!(submit_draft(FIRST_OWNER_ORIGIN, FIRST_OWNER_PARTICIPANT_ID));
+
+        // Fill the blog up to the posts limit minus one
+        for _ in 1..PostsMaxNumber::get() {
+            assert_ok!(create_post(Origin::root()));
+        }
+
+        // Events number before tested call
+        let number_of_events_before_call = System::events().len();
+
+        assert_failure(
+            review_drafts(Origin::signed(FIRST_OWNER_ORIGIN), vec![(FIRST_ID, true)]),
+            Error::BlogOwnershipError,
+            number_of_events_before_call,
+        );
+        assert_failure(
+            review_drafts(Origin::root(), vec![(FIRST_ID, true), (SECOND_ID, true)]),
+            Error::PostLimitReached,
+            number_of_events_before_call,
+        );
+
+        assert_ok!(review_drafts(
+            Origin::root(),
+            vec![(FIRST_ID, false), (SECOND_ID, true)]
+        ));
+
+        // Rejected draft is removed without publishing
+        assert!(draft_by_id(FIRST_ID).is_none());
+        assert!(draft_by_id(SECOND_ID).is_none());
+        assert_eq!(post_count(), PostsMaxNumber::get());
+
+        assert!(System::events()
+            .iter()
+            .any(|record| record.event == get_test_event(RawEvent::DraftRejected(FIRST_ID))));
+
+        let draft_approved_event = get_test_event(RawEvent::DraftApproved(
+            SECOND_ID,
+            PostsMaxNumber::get() - 1,
+        ));
+        assert_event_success(draft_approved_event, number_of_events_before_call + 2)
+    })
+}
+
+// Reactions and tips
+#[test]
+fn reaction_success() {
+    ExtBuilder::default().build().execute_with(|| {
+        // Create post for future reactions
+        create_post(Origin::root()).unwrap();
+
+        // Non editable replies can be reacted to, though they are not stored
+        assert_ok!(create_reply(
+            SECOND_OWNER_ORIGIN,
+            SECOND_OWNER_PARTICIPANT_ID,
+            FIRST_ID,
+            None,
+            false
+        ));
+
+        // Events number before tested call
+        let number_of_events_before_call = System::events().len();
+
+        assert_ok!(react(
+            FIRST_OWNER_ORIGIN,
+            FIRST_OWNER_PARTICIPANT_ID,
+            FIRST_ID,
+            None,
+            SECOND_ID
+        ));
+
+        // Event checked
+        let post_reacted_event = get_test_event(RawEvent::PostReacted(
+            FIRST_OWNER_PARTICIPANT_ID,
+            FIRST_ID,
+            SECOND_ID,
+        ));
+        assert_event_success(post_reacted_event, number_of_events_before_call + 1);
+
+        assert_ok!(react(
+            FIRST_OWNER_ORIGIN,
+            FIRST_OWNER_PARTICIPANT_ID,
+            FIRST_ID,
+            Some(FIRST_ID),
+            SECOND_ID
+        ));
+
+        // Event checked
+        let reply_reacted_event = get_test_event(RawEvent::ReplyReacted(
+            FIRST_OWNER_PARTICIPANT_ID,
+            FIRST_ID,
+            FIRST_ID,
+            SECOND_ID,
+        ));
+        assert_event_success(reply_reacted_event, number_of_events_before_call + 2)
+    })
+}
+
+#[test]
+fn reaction_failures() {
+    ExtBuilder::default().build().execute_with(|| {
+        // Create post for future reactions
+        create_post(Origin::root()).unwrap();
+
+        // Events number before tested call
+        let number_of_events_before_call = System::events().len();
+
+        assert_failure(
+            react(
+                FIRST_OWNER_ORIGIN,
+                FIRST_OWNER_PARTICIPANT_ID,
+                FIRST_ID,
+                None,
+                ReactionsMaxNumber::get(),
+            ),
+            Error::InvalidReactionIndex,
+            number_of_events_before_call,
+        );
+        assert_failure(
+            react(FIRST_OWNER_ORIGIN, BAD_MEMBER_ID, FIRST_ID, None, FIRST_ID),
+            Error::MembershipError,
+            number_of_events_before_call,
+        );
+        assert_failure(
+            react(
+                FIRST_OWNER_ORIGIN,
+                FIRST_OWNER_PARTICIPANT_ID,
+                SECOND_ID,
+                None,
+                FIRST_ID,
+            ),
+            Error::PostNotFound,
+            number_of_events_before_call,
+        );
+        assert_failure(
+            react(
+                FIRST_OWNER_ORIGIN,
+                FIRST_OWNER_PARTICIPANT_ID,
+                FIRST_ID,
+                Some(FIRST_ID),
+                FIRST_ID,
+            ),
+            Error::ReplyNotFound,
+            number_of_events_before_call,
+        );
+
+        lock_post(Origin::root(), FIRST_ID).unwrap();
+
+        let number_of_events_before_call = System::events().len();
+
+        assert_failure(
+            react(
+                FIRST_OWNER_ORIGIN,
+                FIRST_OWNER_PARTICIPANT_ID,
+                FIRST_ID,
+                None,
+                FIRST_ID,
+            ),
+            Error::PostLockedError,
+            number_of_events_before_call,
+        );
+    })
+}
+
+#[test]
+fn tip_success() {
+    ExtBuilder::default().build().execute_with(|| {
+        // Publish the post of the first participant
+        update_author(Origin::root(), FIRST_OWNER_PARTICIPANT_ID, true).unwrap();
+        submit_draft(FIRST_OWNER_ORIGIN, FIRST_OWNER_PARTICIPANT_ID).unwrap();
+        review_drafts(Origin::root(), vec![(FIRST_ID, true)]).unwrap();
+
+        let tip_amount = 100;
+
+        // Second participant replies to the post and has some funds left for a tip
+        Balances::<Runtime>::make_free_balance_be(
+            &SECOND_OWNER_ORIGIN,
+            <Runtime as Trait>::ReplyDeposit::get() + tip_amount,
+        );
+        create_reply(
+            SECOND_OWNER_ORIGIN,
+            SECOND_OWNER_PARTICIPANT_ID,
+            FIRST_ID,
+            None,
+            true,
+        )
+        .unwrap();
+
+        // Events number before tested call
+        let number_of_events_before_call = System::events().len();
+
+        assert_ok!(tip(
+            SECOND_OWNER_ORIGIN,
+            SECOND_OWNER_PARTICIPANT_ID,
+            FIRST_ID,
+            None,
+            tip_amount
+        ));
+
+        assert_eq!(Balances::<Runtime>::usable_balance(&SECOND_OWNER_ORIGIN), 0);
+        assert_eq!(
+            Balances::<Runtime>::usable_balance(&FIRST_OWNER_ORIGIN),
+            tip_amount
+        );
+
+        // Event checked
+        let post_tipped_event = get_test_event(RawEvent::PostTipped(
+            SECOND_OWNER_PARTICIPANT_ID,
+            FIRST_ID,
+            FIRST_OWNER_PARTICIPANT_ID,
+            tip_amount,
+        ));
+        assert_event_success(post_tipped_event, number_of_events_before_call + 4);
+
+        // The post author passes the tip to the reply owner
+        assert_ok!(tip(
+            FIRST_OWNER_ORIGIN,
+            FIRST_OWNER_PARTICIPANT_ID,
+            FIRST_ID,
+            Some(FIRST_ID),
+            tip_amount
+        ));
+
+        assert_eq!(Balances::<Runtime>::usable_balance(&FIRST_OWNER_ORIGIN), 0);
+        assert_eq!(
+            Balances::<Runtime>::usable_balance(&SECOND_OWNER_ORIGIN),
+            tip_amount
+        );
+
+        // Event checked
+        let reply_tipped_event = get_test_event(RawEvent::ReplyTipped(
+            FIRST_OWNER_PARTICIPANT_ID,
+            FIRST_ID,
+            FIRST_ID,
+            SECOND_OWNER_PARTICIPANT_ID,
+            tip_amount,
+        ));
+        assert_event_success(reply_tipped_event, number_of_events_before_call + 6)
+    })
+}
+
+#[test]
+fn tip_failures() {
+    ExtBuilder::default().build().execute_with(|| {
+        // Posts created by the blog owner have no author
+        create_post(Origin::root()).unwrap();
+
+        Balances::<Runtime>::make_free_balance_be(&SECOND_OWNER_ORIGIN, 100);
+
+        // Events number before tested call
+        let number_of_events_before_call = System::events().len();
+
+        assert_failure(
+            tip(
+                SECOND_OWNER_ORIGIN,
+                SECOND_OWNER_PARTICIPANT_ID,
+                FIRST_ID,
+                None,
+                0,
+            ),
+            Error::ZeroTipAmount,
+            number_of_events_before_call,
+        );
+        assert_failure(
+            tip(
+                SECOND_OWNER_ORIGIN,
+                SECOND_OWNER_PARTICIPANT_ID,
+                FIRST_ID,
+                None,
+                100,
+            ),
+            Error::PostAuthorNotFound,
+            number_of_events_before_call,
+        );
+        assert_failure(
+            tip(
+                SECOND_OWNER_ORIGIN,
+                SECOND_OWNER_PARTICIPANT_ID,
+                FIRST_ID,
+                Some(FIRST_ID),
+                100,
+            ),
+            Error::ReplyNotFound,
+            number_of_events_before_call,
+        );
+
+        // Non editable replies aren't stored, so their owners can't be tipped
+        create_reply(
+            FIRST_OWNER_ORIGIN,
+            FIRST_OWNER_PARTICIPANT_ID,
+            FIRST_ID,
+            None,
+            false,
+        )
+        .unwrap();
+
+        let number_of_events_before_call = System::events().len();
+
+        assert_failure(
+            tip(
+                SECOND_OWNER_ORIGIN,
+                SECOND_OWNER_PARTICIPANT_ID,
+                FIRST_ID,
+                Some(FIRST_ID),
+                100,
+            ),
+            Error::ReplyNotFound,
+            number_of_events_before_call,
+        );
+
+        // Publish the post of the first participant
+        update_author(Origin::root(), FIRST_OWNER_PARTICIPANT_ID, true).unwrap();
+        submit_draft(FIRST_OWNER_ORIGIN, FIRST_OWNER_PARTICIPANT_ID).unwrap();
+        review_drafts(Origin::root(), vec![(FIRST_ID, true)]).unwrap();
+
+        let number_of_events_before_call = System::events().len();
+
+        assert_failure(
+            tip(
+                SECOND_OWNER_ORIGIN,
+                SECOND_OWNER_PARTICIPANT_ID,
+                SECOND_ID,
+                None,
+                101,
+            ),
+            Error::InsufficientBalanceForTip,
+            number_of_events_before_call,
+        );
+
+        // The post author can't tip themselves
+        assert_failure(
+            tip(
+                FIRST_OWNER_ORIGIN,
+                FIRST_OWNER_PARTICIPANT_ID,
+                SECOND_ID,
+                None,
+                100,
+            ),
+            Error::CannotTipSelf,
+            number_of_events_before_call,
+        );
+    })
+}
+
 fn replies_storage_unchanged(post_id: PostId, reply_id: <Runtime as Trait>::ReplyId) -> bool {
     match post_by_id(post_id) {
         Some(post) if post.replies_count() == 0 && reply_by_id(post_id, reply_id).is_none() => true,
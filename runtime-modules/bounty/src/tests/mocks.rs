@@ -243,6 +243,11 @@ parameter_types! {
     pub const StakingCandidateLockId: [u8; 8] = [3; 8];
     pub const DefaultInitialInvitationBalance: u64 = 100;
     pub const CandidateStake: u64 = 130;
+    pub const HandleDisputeLockId: [u8; 8] = [4; 8];
+    pub const HandleDisputeStake: u64 = 150;
+    pub const HandleDisputeGracePeriod: u64 = 10;
+    pub const MaxRecoveryGuardians: u32 = 5;
+    pub const RecoveryDelay: u64 = 10;
 }
 
 // Weights info stub
@@ -305,6 +310,57 @@ impl membership::WeightInfo for Weights {
     fn member_remark() -> Weight {
         unimplemented!()
     }
+    fn reserve_handle(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn release_handle(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn open_handle_dispute(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn resolve_handle_dispute() -> Weight {
+        unimplemented!()
+    }
+    fn execute_handle_dispute_resolution() -> Weight {
+        unimplemented!()
+    }
+    fn withdraw_handle_dispute() -> Weight {
+        unimplemented!()
+    }
+    fn add_member_verification() -> Weight {
+        unimplemented!()
+    }
+    fn revoke_member_verification() -> Weight {
+        unimplemented!()
+    }
+    fn set_second_level_referral_cut() -> Weight {
+        unimplemented!()
+    }
+    fn start_referral_campaign() -> Weight {
+        unimplemented!()
+    }
+    fn end_referral_campaign() -> Weight {
+        unimplemented!()
+    }
+    fn set_recovery_guardians(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn initiate_recovery() -> Weight {
+        unimplemented!()
+    }
+    fn approve_recovery() -> Weight {
+        unimplemented!()
+    }
+    fn reject_recovery() -> Weight {
+        unimplemented!()
+    }
+    fn cancel_recovery() -> Weight {
+        unimplemented!()
+    }
+    fn execute_recovery() -> Weight {
+        unimplemented!()
+    }
 }
 
 impl pallet_timestamp::Trait for Test {
@@ -325,6 +381,11 @@ impl membership::Trait for Test {
         staking_handler::StakingManager<Self, StakingCandidateLockId>;
     type WeightInfo = Weights;
     type CandidateStake = CandidateStake;
+    type HandleDisputeStakingHandler = staking_handler::StakingManager<Self, HandleDisputeLockId>;
+    type HandleDisputeStake = HandleDisputeStake;
+    type HandleDisputeGracePeriod = HandleDisputeGracePeriod;
+    type MaxRecoveryGuardians = MaxRecoveryGuardians;
+    type RecoveryDelay = RecoveryDelay;
 }
 
 impl LockComparator<<Test as balances::Trait>::Balance> for Test {
@@ -409,6 +470,7 @@ parameter_types! {
     pub const CandidacyLockId: LockIdentifier = *b"council1";
     pub const CouncilorLockId: LockIdentifier = *b"council2";
     pub const ElectedMemberRewardPeriod: u64 = 10;
+    pub const MaxVestingMilestones: u32 = 10;
     pub const BudgetRefillAmount: u64 = 1000;
     // intentionally high number that prevents side-effecting tests other than  budget refill tests
     pub const BudgetRefillPeriod: u64 = 1000;
@@ -427,6 +489,7 @@ impl council::Trait for Test {
     type CandidacyLock = StakingManager<Self, CandidacyLockId>;
     type CouncilorLock = StakingManager<Self, CouncilorLockId>;
     type ElectedMemberRewardPeriod = ElectedMemberRewardPeriod;
+    type MaxVestingMilestones = MaxVestingMilestones;
     type BudgetRefillPeriod = BudgetRefillPeriod;
     type StakingAccountValidator = ();
     type WeightInfo = CouncilWeightInfo;
@@ -482,6 +545,15 @@ impl council::WeightInfo for CouncilWeightInfo {
     fn candidate_remark() -> Weight {
         0
     }
+    fn vesting_funding_request(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn claim_vesting_funding(_: u32) -> Weight {
+        0
+    }
+    fn cancel_vesting_funding_request(_: u32) -> Weight {
+        0
+    }
 }
 
 parameter_types! {
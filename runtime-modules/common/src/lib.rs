@@ -15,7 +15,7 @@ use serde::{Deserialize, Serialize};
 use frame_support::traits::LockIdentifier;
 use frame_support::Parameter;
 pub use membership::{ActorId, MemberId, MembershipTypes, StakingAccountValidator};
-use sp_arithmetic::traits::BaseArithmetic;
+use sp_arithmetic::traits::{BaseArithmetic, Saturating, Zero};
 use sp_runtime::traits::{MaybeSerialize, Member};
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::vec::Vec;
@@ -74,6 +74,80 @@ pub struct FundingRequestParameters<Balance, AccountId> {
     pub amount: Balance,
 }
 
+/// Release schedule of the 'Vesting Funding Request' funds.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Debug, Eq)]
+pub enum VestingSchedule<BlockNumber, Balance> {
+    /// Funds are released linearly starting from the `start` block until the `end` block.
+    Linear {
+        /// First block of the vesting period
+        start: BlockNumber,
+
+        /// Block at which all funds are released
+        end: BlockNumber,
+    },
+
+    /// Funds are released in milestones: (release block, amount) pairs ordered by block.
+    Milestones(Vec<(BlockNumber, Balance)>),
+}
+
+impl<BlockNumber: Default, Balance> Default for VestingSchedule<BlockNumber, Balance> {
+    fn default() -> Self {
+        Self::Linear {
+            start: Default::default(),
+            end: Default::default(),
+        }
+    }
+}
+
+impl<BlockNumber: PartialOrd, Balance: BaseArithmetic + Copy>
+    VestingSchedule<BlockNumber, Balance>
+{
+    /// Verifies the schedule for the provided total amount: linear schedule requires a non-empty
+    /// block range, milestones must be strictly ordered by block, have non-zero amounts and sum up
+    /// to the total amount.
+    pub fn is_valid_for(&self, total_amount: Balance) -> bool {
+        match self {
+            VestingSchedule::Linear { start, end } => start < end,
+            VestingSchedule::Milestones(milestones) => {
+                let ordered = milestones
+                    .windows(2)
+                    .all(|window| window[0].0 < window[1].0);
+
+                let non_zero = milestones.iter().all(|(_, amount)| !amount.is_zero());
+
+                let sum = milestones.iter().fold(Balance::zero(), |acc, (_, amount)| {
+                    acc.saturating_add(*amount)
+                });
+
+                !milestones.is_empty() && ordered && non_zero && sum == total_amount
+            }
+        }
+    }
+
+    /// Returns the number of the schedule milestones, zero for the linear schedule.
+    pub fn milestones_number(&self) -> usize {
+        match self {
+            VestingSchedule::Linear { .. } => 0,
+            VestingSchedule::Milestones(milestones) => milestones.len(),
+        }
+    }
+}
+
+/// Parameters for the 'Vesting Funding Request' proposal.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Debug, Eq)]
+pub struct VestingFundingRequestParameters<Balance, AccountId, BlockNumber> {
+    /// Single reciever account of funding request
+    pub account: AccountId,
+
+    /// Total amount of funds the account will recieve
+    pub amount: Balance,
+
+    /// Release schedule of the funds
+    pub schedule: VestingSchedule<BlockNumber, Balance>,
+}
+
 /// Kind of Balance for `Update Working Group Budget`.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, Copy, PartialEq, Debug, Eq)]
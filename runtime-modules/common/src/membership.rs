@@ -1,8 +1,12 @@
-use codec::Codec;
+use codec::{Codec, Decode, Encode};
 use frame_support::dispatch::DispatchError;
 use frame_support::Parameter;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_arithmetic::traits::BaseArithmetic;
 use sp_runtime::traits::{MaybeSerialize, MaybeSerializeDeserialize, Member};
+#[cfg(feature = "std")]
+use strum_macros::EnumIter;
 
 /// Member id type alias
 pub type MemberId<T> = <T as MembershipTypes>::MemberId;
@@ -59,3 +63,23 @@ pub trait MembershipInfoProvider<T: MembershipTypes> {
     /// Returns current controller account for a member.
     fn controller_account_id(member_id: MemberId<T>) -> Result<T::AccountId, DispatchError>;
 }
+
+/// Kinds of the member profile verifications issued by the membership workers.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, EnumIter))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Copy, Debug, PartialOrd, Ord)]
+pub enum VerificationKind {
+    /// The real world identity behind the membership was verified.
+    Identity,
+
+    /// The membership belongs to a known content creator.
+    Creator,
+
+    /// The membership represents an organisation.
+    Organisation,
+}
+
+/// Gives access to the typed member profile verifications.
+pub trait MemberVerificationProvider<MemberId> {
+    /// Verifies that the member has a non-expired verification of the provided kind.
+    fn has_valid_verification(member_id: &MemberId, kind: VerificationKind) -> bool;
+}
@@ -37,6 +37,28 @@ pub enum WorkingGroup {
     Membership,
 }
 
+/// Permissions the working group leader can delegate to the deputy workers.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, EnumIter))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Copy, Debug, PartialOrd, Ord)]
+pub enum DeputyPermission {
+    /// Manage regular worker openings and their applications.
+    ManageOpenings,
+
+    /// Manage storage buckets: storage pallet, storage working group.
+    /// The storage policy and limits settings are reserved for the lead.
+    ManageStorageBuckets,
+
+    /// Manage distribution buckets and their families: storage pallet, distribution working group.
+    /// The distribution policy and limits settings are reserved for the lead.
+    ManageDistributionBuckets,
+
+    /// Manage curator groups: content pallet, content working group.
+    ManageCuratorGroups,
+
+    /// Act as the forum lead: forum pallet, forum working group.
+    ManageForum,
+}
+
 /// Working group interface to work with its members - workers and leaders.
 pub trait WorkingGroupAuthenticator<T: crate::MembershipTypes> {
     /// Validate origin for the worker.
@@ -45,12 +67,29 @@ pub trait WorkingGroupAuthenticator<T: crate::MembershipTypes> {
     /// Validate origin for the active leader.
     fn ensure_leader_origin(origin: T::Origin) -> DispatchResult;
 
+    /// Validate origin for the active leader or the deputy with the delegated permission.
+    fn ensure_leader_or_deputy_origin(
+        origin: T::Origin,
+        _permission: DeputyPermission,
+    ) -> DispatchResult {
+        Self::ensure_leader_origin(origin)
+    }
+
     /// Get member ID of the current leader.
     fn get_leader_member_id() -> Option<T::MemberId>;
 
     /// Verifies that given account ID belongs to the leader.
     fn is_leader_account_id(account_id: &T::AccountId) -> bool;
 
+    /// Verifies that given account ID belongs to the leader or to the deputy with the delegated
+    /// permission.
+    fn is_leader_or_deputy_account_id(
+        account_id: &T::AccountId,
+        _permission: DeputyPermission,
+    ) -> bool {
+        Self::is_leader_account_id(account_id)
+    }
+
     /// Verifies that given account ID and worker ID belong to the working group member.
     fn is_worker_account_id(account_id: &T::AccountId, worker_id: &T::ActorId) -> bool;
 
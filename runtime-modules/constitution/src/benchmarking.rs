@@ -25,8 +25,9 @@ benchmarks! {
     amend_constitution{
         let i in 1 .. MAX_BYTES;
         let text = vec![0u8].repeat(i as usize);
+        let base_amendment_number = Module::<T>::amendment_number();
 
-    }: _ (RawOrigin::Root, text.clone())
+    }: _ (RawOrigin::Root, text.clone(), Some(base_amendment_number))
     verify {
             let hashed = T::Hashing::hash(&text);
             let hash = hashed.as_ref().to_vec();
@@ -36,6 +37,7 @@ benchmarks! {
             };
 
             assert_eq!(Module::<T>::constitution(), constitution_info);
+            assert_eq!(Module::<T>::amendment_number(), base_amendment_number + 1);
             assert_last_event::<T>(Event::ConstutionAmended(hash, text).into());
     }
 }
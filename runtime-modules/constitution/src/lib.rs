@@ -3,6 +3,10 @@
 //! It contains current constitution text hash and amendment number in the storage and extrinsic for
 //! setting the new constitution.
 //!
+//! Every amendment is recorded as a constitution version with its amendment number, block,
+//! amending proposal ID and text hash. An amendment can reference the base version it amends and
+//! is rejected if the constitution was amended in the meantime.
+//!
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -14,7 +18,7 @@ mod benchmarking;
 
 use codec::{Decode, Encode};
 use frame_support::weights::Weight;
-use frame_support::{decl_event, decl_module, decl_storage};
+use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure, Parameter};
 use frame_system::ensure_root;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
@@ -35,6 +39,12 @@ pub trait Trait: frame_system::Trait {
 
     /// Weight information for extrinsics in this pallet.
     type WeightInfo: WeightInfo;
+
+    /// ID type of the proposal amending the constitution.
+    type ProposalId: Parameter + Default + Copy;
+
+    /// Returns the ID of the proposal currently being executed, if any.
+    fn executing_proposal_id() -> Option<Self::ProposalId>;
 }
 
 /// Contains constitution text hash and its amendment number.
@@ -45,9 +55,37 @@ pub struct ConstitutionInfo {
     pub text_hash: Vec<u8>,
 }
 
+/// Constitution version created by an amendment.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, Default)]
+pub struct ConstitutionVersion<BlockNumber, ProposalId> {
+    /// Amendment number of the version.
+    pub amendment_number: u32,
+
+    /// Block of the amendment.
+    pub amended_at: BlockNumber,
+
+    /// Proposal that amended the constitution, `None` for direct root calls.
+    pub proposal_id: Option<ProposalId>,
+
+    /// Constitution text hash.
+    pub text_hash: Vec<u8>,
+}
+
+/// Alias for the constitution version.
+pub type ConstitutionVersionOf<T> =
+    ConstitutionVersion<<T as frame_system::Trait>::BlockNumber, <T as Trait>::ProposalId>;
+
 decl_storage! {
     trait Store for Module<T: Trait> as Constitution {
         Constitution get(fn constitution) : ConstitutionInfo;
+
+        /// Amendment number of the current constitution. Zero means no amendments were made.
+        pub AmendmentNumber get(fn amendment_number) : u32;
+
+        /// Constitution versions by their amendment number.
+        pub ConstitutionVersions get(fn constitution_versions):
+            map hasher(blake2_128_concat) u32 => ConstitutionVersionOf<T>;
     }
 }
 
@@ -61,20 +99,46 @@ decl_event! {
     }
 }
 
+decl_error! {
+    /// Constitution errors
+    pub enum Error for Module<T: Trait> {
+        /// The constitution was amended after the base version of the amendment.
+        ConstitutionChangedSinceBaseVersion,
+    }
+}
+
 decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+        /// Predefined errors
+        type Error = Error<T>;
+
         fn deposit_event() = default;
 
         /// Sets the current constitution hash. Requires root origin.
+        /// When `base_amendment_number` is provided the amendment fails if the current
+        /// constitution amendment number differs from it.
         /// # <weight>
         /// - Complexity: `O(C)` where C is the length of the constitution text.
-        /// - Db reads: 0
-        /// - Db writes: 1 (constant value)
+        /// - Db reads: 1
+        /// - Db writes: 3 (constant value)
         /// # </weight>
         #[weight = WeightInfoConstitution::<T>::amend_constitution(constitution_text.len().saturated_into())]
-        pub fn amend_constitution(origin, constitution_text: Vec<u8>) {
+        pub fn amend_constitution(
+            origin,
+            constitution_text: Vec<u8>,
+            base_amendment_number: Option<u32>,
+        ) {
             ensure_root(origin)?;
 
+            let current_amendment_number = Self::amendment_number();
+
+            if let Some(base_amendment_number) = base_amendment_number {
+                ensure!(
+                    base_amendment_number == current_amendment_number,
+                    Error::<T>::ConstitutionChangedSinceBaseVersion
+                );
+            }
+
             //
             // == MUTATION SAFE ==
             //
@@ -88,6 +152,20 @@ decl_module! {
 
             Constitution::put(constitution);
 
+            let amendment_number = current_amendment_number.saturating_add(1);
+
+            AmendmentNumber::put(amendment_number);
+
+            <ConstitutionVersions<T>>::insert(
+                amendment_number,
+                ConstitutionVersion {
+                    amendment_number,
+                    amended_at: <frame_system::Module<T>>::block_number(),
+                    proposal_id: T::executing_proposal_id(),
+                    text_hash: hash.clone(),
+                },
+            );
+
             Self::deposit_event(Event::ConstutionAmended(hash, constitution_text));
         }
     }
@@ -8,6 +8,7 @@ use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
     Perbill,
 };
+use std::cell::RefCell;
 
 impl_outer_origin! {
     pub enum Origin for Test {}
@@ -62,9 +63,22 @@ impl frame_system::Trait for Test {
     type PalletInfo = ();
 }
 
+thread_local! {
+    pub static EXECUTING_PROPOSAL_ID: RefCell<Option<u64>> = RefCell::new(None);
+}
+
 impl Trait for Test {
     type Event = TestEvent;
     type WeightInfo = ();
+    type ProposalId = u64;
+
+    fn executing_proposal_id() -> Option<u64> {
+        EXECUTING_PROPOSAL_ID.with(|id| *id.borrow())
+    }
+}
+
+pub fn set_executing_proposal_id(proposal_id: Option<u64>) {
+    EXECUTING_PROPOSAL_ID.with(|id| *id.borrow_mut() = proposal_id);
 }
 
 impl crate::WeightInfo for () {
@@ -2,11 +2,13 @@
 
 pub(crate) mod mocks;
 
-use crate::{ConstitutionInfo, Event};
+use crate::{ConstitutionInfo, ConstitutionVersion, Error, Event};
 use frame_support::dispatch::DispatchResult;
 use frame_support::traits::{OnFinalize, OnInitialize};
 use frame_system::{EventRecord, Phase, RawOrigin};
-use mocks::{build_test_externalities, Constitution, System, Test, TestEvent};
+use mocks::{
+    build_test_externalities, set_executing_proposal_id, Constitution, System, Test, TestEvent,
+};
 use sp_runtime::traits::Hash;
 use sp_runtime::DispatchError;
 
@@ -44,6 +46,7 @@ impl EventFixture {
 pub struct AmendConstitutionFixture {
     origin: RawOrigin<u64>,
     text: Vec<u8>,
+    base_amendment_number: Option<u32>,
 }
 
 impl AmendConstitutionFixture {
@@ -51,6 +54,7 @@ impl AmendConstitutionFixture {
         Self {
             origin: RawOrigin::Root,
             text: Vec::new(),
+            base_amendment_number: None,
         }
     }
     pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
@@ -61,11 +65,22 @@ impl AmendConstitutionFixture {
         Self { text, ..self }
     }
 
+    pub fn with_base_amendment_number(self, base_amendment_number: u32) -> Self {
+        Self {
+            base_amendment_number: Some(base_amendment_number),
+            ..self
+        }
+    }
+
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
         let old_constitution = Constitution::constitution();
+        let old_amendment_number = Constitution::amendment_number();
 
-        let actual_result =
-            Constitution::amend_constitution(self.origin.clone().into(), self.text.clone());
+        let actual_result = Constitution::amend_constitution(
+            self.origin.clone().into(),
+            self.text.clone(),
+            self.base_amendment_number,
+        );
 
         assert_eq!(actual_result, expected_result);
 
@@ -74,9 +89,22 @@ impl AmendConstitutionFixture {
             let hashed = <Test as frame_system::Trait>::Hashing::hash(&self.text);
             let hash = hashed.as_ref().to_vec();
 
-            assert_eq!(new_constitution, ConstitutionInfo { text_hash: hash });
+            assert_eq!(
+                new_constitution,
+                ConstitutionInfo {
+                    text_hash: hash.clone()
+                }
+            );
+
+            let new_amendment_number = old_amendment_number + 1;
+            assert_eq!(Constitution::amendment_number(), new_amendment_number);
+            assert_eq!(
+                Constitution::constitution_versions(new_amendment_number).text_hash,
+                hash
+            );
         } else {
             assert_eq!(old_constitution, new_constitution);
+            assert_eq!(Constitution::amendment_number(), old_amendment_number);
         }
     }
 }
@@ -108,3 +136,72 @@ fn amend_contitution_fais_with_invalid_origin() {
         amend_constitution_fixture.call_and_assert(Err(DispatchError::BadOrigin));
     });
 }
+
+#[test]
+fn amend_contitution_records_versions() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let text = b"Constitution text".to_vec();
+        let hash = <Test as frame_system::Trait>::Hashing::hash(&text)
+            .as_ref()
+            .to_vec();
+
+        AmendConstitutionFixture::default()
+            .with_text(text)
+            .call_and_assert(Ok(()));
+
+        run_to_block(starting_block + 1);
+
+        let proposal_id = 7;
+        set_executing_proposal_id(Some(proposal_id));
+
+        let new_text = b"Amended constitution text".to_vec();
+        let new_hash = <Test as frame_system::Trait>::Hashing::hash(&new_text)
+            .as_ref()
+            .to_vec();
+
+        AmendConstitutionFixture::default()
+            .with_text(new_text)
+            .with_base_amendment_number(1)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            Constitution::constitution_versions(1),
+            ConstitutionVersion {
+                amendment_number: 1,
+                amended_at: starting_block,
+                proposal_id: None,
+                text_hash: hash,
+            }
+        );
+
+        assert_eq!(
+            Constitution::constitution_versions(2),
+            ConstitutionVersion {
+                amendment_number: 2,
+                amended_at: starting_block + 1,
+                proposal_id: Some(proposal_id),
+                text_hash: new_hash,
+            }
+        );
+    });
+}
+
+#[test]
+fn amend_contitution_fails_with_outdated_base_version() {
+    build_test_externalities().execute_with(|| {
+        AmendConstitutionFixture::default()
+            .with_text(b"First amendment".to_vec())
+            .with_base_amendment_number(0)
+            .call_and_assert(Ok(()));
+
+        AmendConstitutionFixture::default()
+            .with_text(b"Concurrent amendment".to_vec())
+            .with_base_amendment_number(0)
+            .call_and_assert(Err(
+                Error::<Test>::ConstitutionChangedSinceBaseVersion.into()
+            ));
+    });
+}
@@ -208,6 +208,12 @@ decl_error! {
         /// Extension period is greater then auction duration
         ExtensionPeriodIsGreaterThenAuctionDuration,
 
+        /// Nft marketplace parameters update is empty
+        NftMarketplaceParametersUpdateIsEmpty,
+
+        /// Nft marketplace parameters lower bound is greater than the upper bound
+        NftMarketplaceParametersBoundsViolated,
+
         /// No assets to be removed have been specified
         NoAssetsSpecified,
 
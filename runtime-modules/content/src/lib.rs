@@ -27,8 +27,9 @@ pub use storage::{
 };
 
 pub use common::{
-    membership::MembershipInfoProvider, working_group::WorkingGroup, MembershipTypes,
-    StorageOwnership, Url,
+    membership::MembershipInfoProvider,
+    working_group::{DeputyPermission, WorkingGroup},
+    MembershipTypes, StorageOwnership, Url,
 };
 use frame_support::{
     decl_event, decl_module, decl_storage,
@@ -38,7 +39,7 @@ use frame_support::{
     Parameter,
 };
 
-use frame_system::ensure_signed;
+use frame_system::{ensure_root, ensure_signed};
 
 #[cfg(feature = "std")]
 pub use serde::{Deserialize, Serialize};
@@ -220,8 +221,8 @@ decl_module! {
         ) {
 
             let sender = ensure_signed(origin)?;
-            // Ensure given origin is lead
-            ensure_lead_auth_success::<T>(&sender)?;
+            // Ensure given origin is lead or deputy managing curator groups
+            ensure_lead_or_deputy_auth_success::<T>(&sender, DeputyPermission::ManageCuratorGroups)?;
 
             //
             // == MUTATION SAFE ==
@@ -249,8 +250,8 @@ decl_module! {
 
             // Ensure given origin is lead
             let sender = ensure_signed(origin)?;
-            // Ensure given origin is lead
-            ensure_lead_auth_success::<T>(&sender)?;
+            // Ensure given origin is lead or deputy managing curator groups
+            ensure_lead_or_deputy_auth_success::<T>(&sender, DeputyPermission::ManageCuratorGroups)?;
 
             // Ensure curator group under provided curator_group_id already exist
             Self::ensure_curator_group_under_given_id_exists(&curator_group_id)?;
@@ -278,8 +279,8 @@ decl_module! {
 
             // Ensure given origin is lead
             let sender = ensure_signed(origin)?;
-            // Ensure given origin is lead
-            ensure_lead_auth_success::<T>(&sender)?;
+            // Ensure given origin is lead or deputy managing curator groups
+            ensure_lead_or_deputy_auth_success::<T>(&sender, DeputyPermission::ManageCuratorGroups)?;
 
             // Ensure curator group under provided curator_group_id already exist, retrieve corresponding one
             let curator_group = Self::ensure_curator_group_exists(&curator_group_id)?;
@@ -316,8 +317,8 @@ decl_module! {
 
             // Ensure given origin is lead
             let sender = ensure_signed(origin)?;
-            // Ensure given origin is lead
-            ensure_lead_auth_success::<T>(&sender)?;
+            // Ensure given origin is lead or deputy managing curator groups
+            ensure_lead_or_deputy_auth_success::<T>(&sender, DeputyPermission::ManageCuratorGroups)?;
 
             // Ensure curator group under provided curator_group_id already exist, retrieve corresponding one
             let curator_group = Self::ensure_curator_group_exists(&curator_group_id)?;
@@ -1304,20 +1305,38 @@ decl_module! {
 
         #[weight = 10_000_000] // TODO: adjust Weight
         pub fn update_max_reward_allowed(origin, amount: BalanceOf<T>) {
-            let sender = ensure_signed(origin)?;
-            ensure_authorized_to_update_max_reward::<T>(&sender)?;
+            ensure_authorized_to_update_max_reward::<T>(origin)?;
             <MaxRewardAllowed<T>>::put(amount);
             Self::deposit_event(RawEvent::MaxRewardUpdated(amount));
         }
 
         #[weight = 10_000_000] // TODO: adjust Weight
         pub fn update_min_cashout_allowed(origin, amount: BalanceOf<T>) {
-            let sender = ensure_signed(origin)?;
-            ensure_authorized_to_update_min_cashout::<T>(&sender)?;
+            ensure_authorized_to_update_min_cashout::<T>(origin)?;
             <MinCashoutAllowed<T>>::put(amount);
             Self::deposit_event(RawEvent::MinCashoutUpdated(amount));
         }
 
+        /// Update nft marketplace parameters. Only the provided values are updated.
+        /// Should be used as callable object to pass to the proposals `engine` module.
+        #[weight = 10_000_000] // TODO: adjust Weight
+        pub fn update_nft_marketplace_parameters(
+            origin,
+            params: NftMarketplaceParameters<T>,
+        ) {
+            ensure_root(origin)?;
+
+            Self::ensure_nft_marketplace_parameters_are_valid(&params)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::update_nft_marketplace_parameters_storage(&params);
+
+            Self::deposit_event(RawEvent::NftMarketplaceParametersUpdated(params));
+        }
+
         /// Issue NFT
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn issue_nft(
@@ -2479,6 +2498,7 @@ decl_event!(
         OpenAuctionParams = OpenAuctionParams<T>,
         OpenAuctionId = <T as Trait>::OpenAuctionId,
         NftIssuanceParameters = NftIssuanceParameters<T>,
+        NftMarketplaceParameters = NftMarketplaceParameters<T>,
         Balance = BalanceOf<T>,
         CurrencyAmount = BalanceOf<T>,
         ChannelCreationParameters = ChannelCreationParameters<T>,
@@ -2578,6 +2598,7 @@ decl_event!(
         BuyNowCanceled(VideoId, ContentActor),
         BuyNowPriceUpdated(VideoId, ContentActor, CurrencyAmount),
         NftSlingedBackToTheOriginalArtist(VideoId, ContentActor),
+        NftMarketplaceParametersUpdated(NftMarketplaceParameters),
 
         /// Metaprotocols related event
         ChannelOwnerRemarked(ContentActor, ChannelId, Vec<u8>),
@@ -235,6 +235,118 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    /// Ensure nft marketplace parameters update keeps every lower bound below its upper bound
+    pub(crate) fn ensure_nft_marketplace_parameters_are_valid(
+        params: &NftMarketplaceParameters<T>,
+    ) -> DispatchResult {
+        ensure!(
+            !params.is_empty(),
+            Error::<T>::NftMarketplaceParametersUpdateIsEmpty
+        );
+
+        ensure!(
+            params
+                .min_auction_duration
+                .unwrap_or_else(Self::min_auction_duration)
+                <= params
+                    .max_auction_duration
+                    .unwrap_or_else(Self::max_auction_duration),
+            Error::<T>::NftMarketplaceParametersBoundsViolated
+        );
+        ensure!(
+            params
+                .min_auction_extension_period
+                .unwrap_or_else(Self::min_auction_extension_period)
+                <= params
+                    .max_auction_extension_period
+                    .unwrap_or_else(Self::max_auction_extension_period),
+            Error::<T>::NftMarketplaceParametersBoundsViolated
+        );
+        ensure!(
+            params
+                .min_bid_lock_duration
+                .unwrap_or_else(Self::min_bid_lock_duration)
+                <= params
+                    .max_bid_lock_duration
+                    .unwrap_or_else(Self::max_bid_lock_duration),
+            Error::<T>::NftMarketplaceParametersBoundsViolated
+        );
+        ensure!(
+            params
+                .min_starting_price
+                .unwrap_or_else(Self::min_starting_price)
+                <= params
+                    .max_starting_price
+                    .unwrap_or_else(Self::max_starting_price),
+            Error::<T>::NftMarketplaceParametersBoundsViolated
+        );
+        ensure!(
+            params
+                .min_creator_royalty
+                .unwrap_or_else(Self::min_creator_royalty)
+                <= params
+                    .max_creator_royalty
+                    .unwrap_or_else(Self::max_creator_royalty),
+            Error::<T>::NftMarketplaceParametersBoundsViolated
+        );
+        ensure!(
+            params.min_bid_step.unwrap_or_else(Self::min_bid_step)
+                <= params.max_bid_step.unwrap_or_else(Self::max_bid_step),
+            Error::<T>::NftMarketplaceParametersBoundsViolated
+        );
+
+        Ok(())
+    }
+
+    /// Update the nft marketplace parameters that are provided
+    pub(crate) fn update_nft_marketplace_parameters_storage(params: &NftMarketplaceParameters<T>) {
+        if let Some(min_auction_duration) = params.min_auction_duration {
+            MinAuctionDuration::<T>::put(min_auction_duration);
+        }
+        if let Some(max_auction_duration) = params.max_auction_duration {
+            MaxAuctionDuration::<T>::put(max_auction_duration);
+        }
+        if let Some(min_auction_extension_period) = params.min_auction_extension_period {
+            MinAuctionExtensionPeriod::<T>::put(min_auction_extension_period);
+        }
+        if let Some(max_auction_extension_period) = params.max_auction_extension_period {
+            MaxAuctionExtensionPeriod::<T>::put(max_auction_extension_period);
+        }
+        if let Some(min_bid_lock_duration) = params.min_bid_lock_duration {
+            MinBidLockDuration::<T>::put(min_bid_lock_duration);
+        }
+        if let Some(max_bid_lock_duration) = params.max_bid_lock_duration {
+            MaxBidLockDuration::<T>::put(max_bid_lock_duration);
+        }
+        if let Some(min_starting_price) = params.min_starting_price {
+            MinStartingPrice::<T>::put(min_starting_price);
+        }
+        if let Some(max_starting_price) = params.max_starting_price {
+            MaxStartingPrice::<T>::put(max_starting_price);
+        }
+        if let Some(min_creator_royalty) = params.min_creator_royalty {
+            MinCreatorRoyalty::put(min_creator_royalty);
+        }
+        if let Some(max_creator_royalty) = params.max_creator_royalty {
+            MaxCreatorRoyalty::put(max_creator_royalty);
+        }
+        if let Some(min_bid_step) = params.min_bid_step {
+            MinBidStep::<T>::put(min_bid_step);
+        }
+        if let Some(max_bid_step) = params.max_bid_step {
+            MaxBidStep::<T>::put(max_bid_step);
+        }
+        if let Some(platform_fee_percentage) = params.platform_fee_percentage {
+            PlatfromFeePercentage::put(platform_fee_percentage);
+        }
+        if let Some(auction_starts_at_max_delta) = params.auction_starts_at_max_delta {
+            AuctionStartsAtMaxDelta::<T>::put(auction_starts_at_max_delta);
+        }
+        if let Some(max_auction_whitelist_length) = params.max_auction_whitelist_length {
+            MaxAuctionWhiteListLength::put(max_auction_whitelist_length);
+        }
+    }
+
     /// Ensure given participant have sufficient free balance
     pub(crate) fn ensure_sufficient_free_balance(
         participant_account_id: &T::AccountId,
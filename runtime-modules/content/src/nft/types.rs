@@ -474,6 +474,66 @@ pub type OpenAuction<T> = OpenAuctionRecord<
     <T as common::MembershipTypes>::MemberId,
 >;
 
+/// Nft marketplace parameters update. Only the provided values are updated.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct NftMarketplaceParametersRecord<BlockNumber, Balance> {
+    /// Min auction duration
+    pub min_auction_duration: Option<BlockNumber>,
+    /// Max auction duration
+    pub max_auction_duration: Option<BlockNumber>,
+    /// Min auction extension period
+    pub min_auction_extension_period: Option<BlockNumber>,
+    /// Max auction extension period
+    pub max_auction_extension_period: Option<BlockNumber>,
+    /// Min bid lock duration
+    pub min_bid_lock_duration: Option<BlockNumber>,
+    /// Max bid lock duration
+    pub max_bid_lock_duration: Option<BlockNumber>,
+    /// Min auction staring price
+    pub min_starting_price: Option<Balance>,
+    /// Max auction staring price
+    pub max_starting_price: Option<Balance>,
+    /// Min creator royalty percentage
+    pub min_creator_royalty: Option<Royalty>,
+    /// Max creator royalty percentage
+    pub max_creator_royalty: Option<Royalty>,
+    /// Min auction bid step
+    pub min_bid_step: Option<Balance>,
+    /// Max auction bid step
+    pub max_bid_step: Option<Balance>,
+    /// Platform fee percentage
+    pub platform_fee_percentage: Option<Perbill>,
+    /// Max delta between current block and starts at
+    pub auction_starts_at_max_delta: Option<BlockNumber>,
+    /// Max nft auction whitelist length
+    pub max_auction_whitelist_length: Option<MaxNumber>,
+}
+
+impl<BlockNumber, Balance> NftMarketplaceParametersRecord<BlockNumber, Balance> {
+    /// Checks whether no parameter is provided for update
+    pub fn is_empty(&self) -> bool {
+        self.min_auction_duration.is_none()
+            && self.max_auction_duration.is_none()
+            && self.min_auction_extension_period.is_none()
+            && self.max_auction_extension_period.is_none()
+            && self.min_bid_lock_duration.is_none()
+            && self.max_bid_lock_duration.is_none()
+            && self.min_starting_price.is_none()
+            && self.max_starting_price.is_none()
+            && self.min_creator_royalty.is_none()
+            && self.max_creator_royalty.is_none()
+            && self.min_bid_step.is_none()
+            && self.max_bid_step.is_none()
+            && self.platform_fee_percentage.is_none()
+            && self.auction_starts_at_max_delta.is_none()
+            && self.max_auction_whitelist_length.is_none()
+    }
+}
+
+pub type NftMarketplaceParameters<T> =
+    NftMarketplaceParametersRecord<<T as frame_system::Trait>::BlockNumber, BalanceOf<T>>;
+
 pub type EnglishAuctionParams<T> = EnglishAuctionParamsRecord<
     <T as frame_system::Trait>::BlockNumber,
     BalanceOf<T>,
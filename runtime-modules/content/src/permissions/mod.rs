@@ -67,6 +67,11 @@ pub trait ContentActorAuthenticator: frame_system::Trait + common::MembershipTyp
     /// Authorize actor as lead
     fn is_lead(account_id: &Self::AccountId) -> bool;
 
+    /// Authorize actor as lead or as the deputy with the delegated permission
+    fn is_lead_or_deputy(account_id: &Self::AccountId, _permission: DeputyPermission) -> bool {
+        Self::is_lead(account_id)
+    }
+
     /// Checks if Id represents a worker id in the working group
     fn is_valid_curator_id(curator_id: &Self::CuratorId) -> bool;
 
@@ -120,6 +125,18 @@ pub fn ensure_lead_auth_success<T: Trait>(account_id: &T::AccountId) -> Dispatch
     Ok(())
 }
 
+// Ensure lead or deputy authorization performed succesfully
+pub fn ensure_lead_or_deputy_auth_success<T: Trait>(
+    account_id: &T::AccountId,
+    permission: DeputyPermission,
+) -> DispatchResult {
+    ensure!(
+        T::is_lead_or_deputy(account_id, permission),
+        Error::<T>::LeadAuthFailed
+    );
+    Ok(())
+}
+
 // authenticate actor
 pub fn ensure_actor_auth_success<T: Trait>(
     sender: &T::AccountId,
@@ -473,10 +490,21 @@ pub fn ensure_authorized_to_update_commitment<T: Trait>(sender: &T::AccountId) -
     ensure_lead_auth_success::<T>(sender)
 }
 
-pub fn ensure_authorized_to_update_max_reward<T: Trait>(sender: &T::AccountId) -> DispatchResult {
-    ensure_lead_auth_success::<T>(sender)
+// Reward limits can be updated either by the lead or by the council through a proposal (root)
+pub fn ensure_authorized_to_update_max_reward<T: Trait>(origin: T::Origin) -> DispatchResult {
+    ensure_root_or_lead_auth_success::<T>(origin)
 }
 
-pub fn ensure_authorized_to_update_min_cashout<T: Trait>(sender: &T::AccountId) -> DispatchResult {
-    ensure_lead_auth_success::<T>(sender)
+pub fn ensure_authorized_to_update_min_cashout<T: Trait>(origin: T::Origin) -> DispatchResult {
+    ensure_root_or_lead_auth_success::<T>(origin)
+}
+
+// Ensure origin is either root or signed by the lead
+fn ensure_root_or_lead_auth_success<T: Trait>(origin: T::Origin) -> DispatchResult {
+    if ensure_root(origin.clone()).is_ok() {
+        return Ok(());
+    }
+
+    let sender = ensure_signed(origin)?;
+    ensure_lead_auth_success::<T>(&sender)
 }
@@ -22,6 +22,21 @@ fn successful_reward_update_by_lead_account() {
     })
 }
 
+#[test]
+fn successful_reward_update_by_root() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+        let new_amount = 100;
+
+        assert_eq!(
+            Content::update_max_reward_allowed(Origin::root(), new_amount),
+            Ok(())
+        );
+
+        assert_eq!(Content::max_reward_allowed(), new_amount);
+    })
+}
+
 #[test]
 fn unsuccessful_cashout_update_by_non_lead_account() {
     with_default_mock_builder(|| {
@@ -40,6 +55,21 @@ fn successful_cashout_update_by_lead_account() {
     })
 }
 
+#[test]
+fn successful_cashout_update_by_root() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+        let new_amount = 100;
+
+        assert_eq!(
+            Content::update_min_cashout_allowed(Origin::root(), new_amount),
+            Ok(())
+        );
+
+        assert_eq!(Content::min_cashout_allowed(), new_amount);
+    })
+}
+
 #[test]
 fn unsuccessful_commitment_update_by_non_lead_account() {
     with_default_mock_builder(|| {
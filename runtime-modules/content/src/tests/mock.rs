@@ -517,6 +517,11 @@ parameter_types! {
     pub const InvitedMemberLockId: [u8; 8] = [2; 8];
     pub const StakingCandidateLockId: [u8; 8] = [3; 8];
     pub const CandidateStake: u64 = 100;
+    pub const HandleDisputeLockId: [u8; 8] = [4; 8];
+    pub const HandleDisputeStake: u64 = 150;
+    pub const HandleDisputeGracePeriod: u64 = 10;
+    pub const MaxRecoveryGuardians: u32 = 5;
+    pub const RecoveryDelay: u64 = 10;
 }
 
 parameter_types! {
@@ -539,6 +544,11 @@ impl membership::Trait for Test {
     type StakingCandidateStakingHandler =
         staking_handler::StakingManager<Self, StakingCandidateLockId>;
     type CandidateStake = CandidateStake;
+    type HandleDisputeStakingHandler = staking_handler::StakingManager<Self, HandleDisputeLockId>;
+    type HandleDisputeStake = HandleDisputeStake;
+    type HandleDisputeGracePeriod = HandleDisputeGracePeriod;
+    type MaxRecoveryGuardians = MaxRecoveryGuardians;
+    type RecoveryDelay = RecoveryDelay;
     type WeightInfo = ();
 }
 
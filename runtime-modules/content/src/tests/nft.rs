@@ -13,3 +13,4 @@ mod sell_nft;
 mod sling_nft_back;
 mod start_nft_auction;
 mod update_buy_now;
+mod update_nft_marketplace_parameters;
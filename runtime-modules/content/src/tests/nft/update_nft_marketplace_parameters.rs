@@ -0,0 +1,126 @@
+#![cfg(test)]
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::{assert_err, assert_ok};
+use sp_runtime::DispatchError;
+
+#[test]
+fn update_nft_marketplace_parameters() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let params = NftMarketplaceParameters::<Test> {
+            max_auction_duration: Some(Content::max_auction_duration() + 10),
+            max_bid_step: Some(Content::max_bid_step() + 10),
+            platform_fee_percentage: Some(Perbill::from_percent(5)),
+            max_creator_royalty: Some(Perbill::from_percent(30)),
+            ..NftMarketplaceParameters::<Test>::default()
+        };
+
+        let min_auction_duration = Content::min_auction_duration();
+
+        // Events number before tested calls
+        let number_of_events_before_call = System::events().len();
+
+        assert_ok!(Content::update_nft_marketplace_parameters(
+            Origin::root(),
+            params.clone(),
+        ));
+
+        // Runtime tested state after call
+
+        // Ensure only provided parameters were updated
+        assert_eq!(
+            Some(Content::max_auction_duration()),
+            params.max_auction_duration
+        );
+        assert_eq!(Some(Content::max_bid_step()), params.max_bid_step);
+        assert_eq!(
+            Some(Content::platform_fee_percentage()),
+            params.platform_fee_percentage
+        );
+        assert_eq!(
+            Some(Content::max_creator_royalty()),
+            params.max_creator_royalty
+        );
+        assert_eq!(Content::min_auction_duration(), min_auction_duration);
+
+        // Last event checked
+        assert_event(
+            MetaEvent::content(RawEvent::NftMarketplaceParametersUpdated(params)),
+            number_of_events_before_call + 1,
+        );
+    })
+}
+
+#[test]
+fn update_nft_marketplace_parameters_bad_origin() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let params = NftMarketplaceParameters::<Test> {
+            max_bid_step: Some(Content::max_bid_step() + 10),
+            ..NftMarketplaceParameters::<Test>::default()
+        };
+
+        // Make an attempt to update nft marketplace parameters with signed origin
+        let update_nft_marketplace_parameters_result =
+            Content::update_nft_marketplace_parameters(Origin::signed(LEAD_ACCOUNT_ID), params);
+
+        // Failure checked
+        assert_err!(
+            update_nft_marketplace_parameters_result,
+            DispatchError::BadOrigin
+        );
+    })
+}
+
+#[test]
+fn update_nft_marketplace_parameters_empty_update() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        // Make an attempt to update nft marketplace parameters without any parameter provided
+        let update_nft_marketplace_parameters_result = Content::update_nft_marketplace_parameters(
+            Origin::root(),
+            NftMarketplaceParameters::<Test>::default(),
+        );
+
+        // Failure checked
+        assert_err!(
+            update_nft_marketplace_parameters_result,
+            Error::<Test>::NftMarketplaceParametersUpdateIsEmpty
+        );
+    })
+}
+
+#[test]
+fn update_nft_marketplace_parameters_bounds_violated() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let max_bid_step = Content::max_bid_step();
+
+        // Min bid step greater than the current max bid step
+        let params = NftMarketplaceParameters::<Test> {
+            min_bid_step: Some(max_bid_step + 1),
+            ..NftMarketplaceParameters::<Test>::default()
+        };
+
+        // Make an attempt to update nft marketplace parameters with inconsistent bounds
+        let update_nft_marketplace_parameters_result =
+            Content::update_nft_marketplace_parameters(Origin::root(), params);
+
+        // Failure checked
+        assert_err!(
+            update_nft_marketplace_parameters_result,
+            Error::<Test>::NftMarketplaceParametersBoundsViolated
+        );
+
+        assert_eq!(Content::max_bid_step(), max_bid_step);
+    })
+}
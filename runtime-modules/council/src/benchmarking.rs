@@ -70,6 +70,65 @@ fn make_free_balance_be<T: Trait>(account_id: &T::AccountId, balance: Balance<T>
     Balances::<T>::make_free_balance_be(&account_id, balance);
 }
 
+// Creates the vesting schedule with the provided number of milestones, linear schedule for zero
+// milestones. Returns the schedule, its total amount and the block all funds are released at.
+fn vesting_schedule<T: Trait>(
+    milestones_number: u32,
+) -> (
+    VestingSchedule<T::BlockNumber, Balance<T>>,
+    Balance<T>,
+    T::BlockNumber,
+) {
+    let start = System::<T>::block_number();
+    let amount: Balance<T> = 100u32.into();
+
+    if milestones_number == 0 {
+        let end = start + 10u32.into();
+
+        (VestingSchedule::Linear { start, end }, amount, end)
+    } else {
+        let milestones = (1..=milestones_number)
+            .map(|milestone| (start + milestone.into(), amount))
+            .collect();
+
+        (
+            VestingSchedule::Milestones(milestones),
+            amount * milestones_number.into(),
+            start + milestones_number.into(),
+        )
+    }
+}
+
+fn create_vesting_funding_request<T: Trait>(
+    milestones_number: u32,
+) -> (
+    VestingFundingRequestId,
+    T::AccountId,
+    Balance<T>,
+    T::BlockNumber,
+)
+where
+    T::AccountId: CreateAccountId,
+{
+    Council::<T>::set_budget(RawOrigin::Root.into(), Balance::<T>::max_value()).unwrap();
+
+    let account = T::AccountId::create_account_id(0);
+    let (schedule, amount, end) = vesting_schedule::<T>(milestones_number);
+    let request_id = Council::<T>::next_vesting_funding_request_id();
+
+    Council::<T>::vesting_funding_request(
+        RawOrigin::Root.into(),
+        vec![VestingFundingRequestParameters {
+            account: account.clone(),
+            amount,
+            schedule,
+        }],
+    )
+    .unwrap();
+
+    (request_id, account, amount, end)
+}
+
 fn start_announcing_period<T: Trait>() {
     Mutations::<T>::start_announcing_period();
 
@@ -351,6 +410,68 @@ benchmarks! {
         }
     }
 
+    vesting_funding_request {
+        let i in 1 .. MAX_FUNDING_REQUESTS;
+        let m in 0 .. T::MaxVestingMilestones::get();
+        Council::<T>::set_budget(RawOrigin::Root.into(), Balance::<T>::max_value()).unwrap();
+        let mut funding_requests = Vec::new();
+        let (schedule, amount, _) = vesting_schedule::<T>(m);
+
+        for id in 0 .. i {
+            let account = T::AccountId::create_account_id(id);
+            funding_requests.push(VestingFundingRequestParameters {
+                amount,
+                account,
+                schedule: schedule.clone(),
+            });
+        }
+
+    }: _(RawOrigin::Root, funding_requests.clone())
+    verify {
+        assert_eq!(
+            Council::<T>::budget(),
+            Balance::<T>::max_value() - Balance::<T>::from(i) * amount
+        );
+
+        for (request_id, fund_request) in funding_requests.into_iter().enumerate() {
+            let request_id = request_id as VestingFundingRequestId;
+
+            assert!(VestingFundingRequests::<T>::contains_key(request_id));
+
+            assert_in_events::<T>(
+                RawEvent::VestingFundingRequestCreated(
+                    request_id,
+                    fund_request.account,
+                    fund_request.amount
+                ).into()
+            );
+        }
+    }
+
+    claim_vesting_funding {
+        let m in 0 .. T::MaxVestingMilestones::get();
+        let (request_id, account, amount, end) = create_vesting_funding_request::<T>(m);
+        System::<T>::set_block_number(end);
+    }: _(RawOrigin::Signed(account.clone()), request_id)
+    verify {
+        assert!(!VestingFundingRequests::<T>::contains_key(request_id));
+        assert_eq!(Balances::<T>::total_balance(&account), amount);
+        assert_last_event::<T>(
+            RawEvent::VestingFundingClaimed(request_id, account, amount).into()
+        );
+    }
+
+    cancel_vesting_funding_request {
+        let m in 0 .. T::MaxVestingMilestones::get();
+        let (request_id, account, amount, end) = create_vesting_funding_request::<T>(m);
+        let half_way = System::<T>::block_number() + (end - System::<T>::block_number()) / 2u32.into();
+        System::<T>::set_block_number(half_way);
+    }: _(RawOrigin::Root, request_id)
+    verify {
+        assert!(!VestingFundingRequests::<T>::contains_key(request_id));
+        assert!(Balances::<T>::total_balance(&account) < amount);
+    }
+
     // We calculate `on_finalize` as `try_progress_stage + try_process_budget`
     try_process_budget {
         // We need to make sure that the block number starts at 0 to make payment/budget refill
@@ -882,6 +1003,30 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_vesting_funding_request() {
+        let config = default_genesis_config();
+        build_test_externalities(config).execute_with(|| {
+            assert_ok!(test_benchmark_vesting_funding_request::<Runtime>());
+        })
+    }
+
+    #[test]
+    fn test_claim_vesting_funding() {
+        let config = default_genesis_config();
+        build_test_externalities(config).execute_with(|| {
+            assert_ok!(test_benchmark_claim_vesting_funding::<Runtime>());
+        })
+    }
+
+    #[test]
+    fn test_cancel_vesting_funding_request() {
+        let config = default_genesis_config();
+        build_test_externalities(config).execute_with(|| {
+            assert_ok!(test_benchmark_cancel_vesting_funding_request::<Runtime>());
+        })
+    }
+
     #[test]
     fn test_councilor_remark() {
         let config = default_genesis_config();
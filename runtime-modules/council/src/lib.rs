@@ -29,6 +29,9 @@
 //! - [set_budget_increment](./struct.Module.html#method.set_budget_increment)
 //! - [set_councilor_reward](./struct.Module.html#method.set_councilor_reward)
 //! - [funding_request](./struct.Module.html#method.funding_request)
+//! - [vesting_funding_request](./struct.Module.html#method.vesting_funding_request)
+//! - [claim_vesting_funding](./struct.Module.html#method.claim_vesting_funding)
+//! - [cancel_vesting_funding_request](./struct.Module.html#method.cancel_vesting_funding_request)
 //!
 //! ## Important functions
 //! These functions have to be called by the runtime for the council to work properly.
@@ -49,15 +52,19 @@ use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure, e
 
 use core::marker::PhantomData;
 use frame_support::dispatch::DispatchResult;
-use frame_system::ensure_root;
+use frame_system::{ensure_root, ensure_signed};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sp_runtime::traits::{Hash, SaturatedConversion, Saturating, Zero};
+use sp_runtime::Perbill;
 use sp_std::vec::Vec;
 
 use common::council::CouncilOriginValidator;
 use common::membership::MemberOriginValidator;
-use common::{FundingRequestParameters, StakingAccountValidator};
+use common::{
+    FundingRequestParameters, StakingAccountValidator, VestingFundingRequestParameters,
+    VestingSchedule,
+};
 use referendum::{CastVote, OptionResult, ReferendumManager};
 use staking_handler::StakingHandler;
 
@@ -170,8 +177,28 @@ impl<AccountId, MemberId, Balance, Hash, VotePower, BlockNumber>
     }
 }
 
+/// Vesting funding request paid from the council budget.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, PartialEq, Eq, Debug, Default, Clone)]
+pub struct VestingFundingRequest<AccountId, Balance, BlockNumber> {
+    /// Account recieving the funds.
+    pub account: AccountId,
+
+    /// Total amount of the request.
+    pub amount: Balance,
+
+    /// Amount already claimed by the account.
+    pub claimed: Balance,
+
+    /// Release schedule of the funds.
+    pub schedule: VestingSchedule<BlockNumber, Balance>,
+}
+
 /////////////////// Type aliases ///////////////////////////////////////////////
 
+/// Vesting funding request identifier.
+pub type VestingFundingRequestId = u64;
+
 pub type Balance<T> = <T as balances::Trait>::Balance;
 pub type VotePowerOf<T> = <<T as Trait>::Referendum as ReferendumManager<
     <T as frame_system::Trait>::Origin,
@@ -198,6 +225,16 @@ pub type CandidateOf<T> = Candidate<
     VotePowerOf<T>,
 >;
 pub type CouncilStageUpdateOf<T> = CouncilStageUpdate<<T as frame_system::Trait>::BlockNumber>;
+pub type VestingFundingRequestOf<T> = VestingFundingRequest<
+    <T as frame_system::Trait>::AccountId,
+    Balance<T>,
+    <T as frame_system::Trait>::BlockNumber,
+>;
+pub type VestingFundingRequestParametersOf<T> = VestingFundingRequestParameters<
+    Balance<T>,
+    <T as frame_system::Trait>::AccountId,
+    <T as frame_system::Trait>::BlockNumber,
+>;
 
 /////////////////// Traits, Storage, Errors, and Events /////////////////////////
 
@@ -219,6 +256,9 @@ pub trait WeightInfo {
     fn plan_budget_refill() -> Weight;
     fn councilor_remark() -> Weight;
     fn candidate_remark() -> Weight;
+    fn vesting_funding_request(i: u32, m: u32) -> Weight;
+    fn claim_vesting_funding(m: u32) -> Weight;
+    fn cancel_vesting_funding_request(m: u32) -> Weight;
 }
 
 type CouncilWeightInfo<T> = <T as Trait>::WeightInfo;
@@ -274,6 +314,9 @@ pub trait Trait:
     /// Interval between automatic budget refills.
     type BudgetRefillPeriod: Get<Self::BlockNumber>;
 
+    /// Maximum number of milestones of the vesting funding request schedule.
+    type MaxVestingMilestones: Get<u32>;
+
     /// Weight information for extrinsics in this pallet.
     type WeightInfo: WeightInfo;
 
@@ -345,6 +388,14 @@ decl_storage! {
 
         /// Councilor reward per block
         pub CouncilorReward get(fn councilor_reward) config(): Balance<T>;
+
+        /// Active vesting funding requests paid from the council budget.
+        pub VestingFundingRequests get(fn vesting_funding_requests): map hasher(blake2_128_concat)
+            VestingFundingRequestId => VestingFundingRequestOf<T>;
+
+        /// Identifier for the next vesting funding request.
+        pub NextVestingFundingRequestId get(fn next_vesting_funding_request_id):
+            VestingFundingRequestId;
     }
 }
 
@@ -409,6 +460,26 @@ decl_event! {
 
         /// Candidate remark message
         CandidateRemarked(MemberId, Vec<u8>),
+
+        /// Vesting funding request was created.
+        /// Params:
+        /// - vesting funding request id
+        /// - recieving account
+        /// - total amount reserved from the council budget
+        VestingFundingRequestCreated(VestingFundingRequestId, AccountId, Balance),
+
+        /// Vested funds were transferred to the recieving account.
+        /// Params:
+        /// - vesting funding request id
+        /// - recieving account
+        /// - transferred amount
+        VestingFundingClaimed(VestingFundingRequestId, AccountId, Balance),
+
+        /// Vesting funding request was canceled.
+        /// Params:
+        /// - vesting funding request id
+        /// - unvested amount returned to the council budget
+        VestingFundingRequestCanceled(VestingFundingRequestId, Balance),
     }
 }
 
@@ -476,6 +547,21 @@ decl_error! {
 
         /// Candidate id not found
         CandidateDoesNotExist,
+
+        /// Invalid vesting schedule for the vesting funding request amount
+        InvalidVestingSchedule,
+
+        /// Vesting funding request id not found
+        VestingFundingRequestDoesNotExist,
+
+        /// Origin is not the recieving account of the vesting funding request
+        NotVestingFundingRequestAccount,
+
+        /// No vested funds are available to claim yet
+        NoVestedFundsToClaim,
+
+        /// Vesting schedule milestones number exceeds the limit
+        TooManyVestingMilestones,
     }
 }
 
@@ -523,6 +609,9 @@ decl_module! {
         /// Interval between automatic budget refills.
         const BudgetRefillPeriod: T::BlockNumber = T::BudgetRefillPeriod::get();
 
+        /// Maximum number of milestones of the vesting funding request schedule.
+        const MaxVestingMilestones: u32 = T::MaxVestingMilestones::get();
+
         /// Exports const - candidacy lock id.
         const CandidacyLockId: LockIdentifier = T::CandidacyLock::lock_id();
 
@@ -867,6 +956,165 @@ decl_module! {
             }
         }
 
+        /// Reserves funds from council budget to be released to accounts over a block range
+        ///
+        /// # <weight>
+        ///
+        /// ## weight
+        /// `O (F + M)` where:
+        /// `F` is the length of `funding_requests`
+        /// `M` is the maximum number of the schedule milestones among `funding_requests`
+        /// - db:
+        ///    - `O(F)`
+        /// # </weight>
+        #[weight = CouncilWeightInfo::<T>::vesting_funding_request(
+            funding_requests.len().saturated_into(),
+            funding_requests
+                .iter()
+                .map(|funding_request| funding_request.schedule.milestones_number())
+                .max()
+                .unwrap_or_default()
+                .saturated_into(),
+        )]
+        pub fn vesting_funding_request(
+            origin,
+            funding_requests: Vec<VestingFundingRequestParametersOf<T>>
+        ) {
+            // Checks
+            ensure_root(origin)?;
+
+            let funding_total: Balance<T> =
+                funding_requests.iter().fold(
+                    Zero::zero(),
+                    |accumulated, funding_request| accumulated.saturating_add(funding_request.amount),
+                );
+
+            let current_budget = Self::budget();
+
+            ensure!(
+                funding_total <= current_budget,
+                Error::<T>::InsufficientFundsForFundingRequest
+            );
+
+            ensure!(!funding_requests.is_empty(), Error::<T>::EmptyFundingRequests);
+
+            let mut recieving_accounts = Vec::<&T::AccountId>::new();
+
+            for funding_request in &funding_requests {
+                ensure!(
+                    funding_request.amount != Zero::zero(),
+                    Error::<T>::ZeroBalanceFundRequest
+                );
+
+                ensure!(
+                    !recieving_accounts.contains(&&funding_request.account),
+                    Error::<T>::RepeatedFundRequestAccount
+                );
+
+                ensure!(
+                    funding_request.schedule.milestones_number() <=
+                        T::MaxVestingMilestones::get().saturated_into(),
+                    Error::<T>::TooManyVestingMilestones
+                );
+
+                ensure!(
+                    funding_request.schedule.is_valid_for(funding_request.amount),
+                    Error::<T>::InvalidVestingSchedule
+                );
+
+                recieving_accounts.push(&funding_request.account);
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Mutations::<T>::set_budget(current_budget.saturating_sub(funding_total));
+
+            for funding_request in funding_requests {
+                let request_id = Mutations::<T>::create_vesting_funding_request(funding_request.clone());
+
+                Self::deposit_event(RawEvent::VestingFundingRequestCreated(
+                    request_id,
+                    funding_request.account,
+                    funding_request.amount
+                ));
+            }
+        }
+
+        /// Transfers the vested and not yet claimed funds of the vesting funding request to its
+        /// recieving account
+        ///
+        /// # <weight>
+        ///
+        /// ## weight
+        /// `O (M)` where:
+        /// `M` is the maximum number of the schedule milestones
+        /// - db:
+        ///    - `O(1)` doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = CouncilWeightInfo::<T>::claim_vesting_funding(T::MaxVestingMilestones::get())]
+        pub fn claim_vesting_funding(origin, request_id: VestingFundingRequestId) {
+            let (request, claimable_amount) =
+                EnsureChecks::<T>::can_claim_vesting_funding(origin, &request_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Mutations::<T>::claim_vesting_funding(&request_id, request.clone(), claimable_amount);
+
+            Self::deposit_event(RawEvent::VestingFundingClaimed(
+                request_id,
+                request.account,
+                claimable_amount
+            ));
+        }
+
+        /// Cancels the remaining schedule of the vesting funding request. Already vested funds
+        /// are transferred to the recieving account, the rest returns to the council budget.
+        ///
+        /// # <weight>
+        ///
+        /// ## weight
+        /// `O (M)` where:
+        /// `M` is the maximum number of the schedule milestones
+        /// - db:
+        ///    - `O(1)` doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = CouncilWeightInfo::<T>::cancel_vesting_funding_request(
+            T::MaxVestingMilestones::get()
+        )]
+        pub fn cancel_vesting_funding_request(origin, request_id: VestingFundingRequestId) {
+            let request = EnsureChecks::<T>::can_cancel_vesting_funding_request(origin, &request_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let now = frame_system::Module::<T>::block_number();
+            let vested_amount = Self::vested_amount(&request, now);
+            let claimable_amount = vested_amount.saturating_sub(request.claimed);
+            let unvested_amount = request.amount.saturating_sub(vested_amount);
+
+            Mutations::<T>::cancel_vesting_funding_request(
+                &request_id,
+                &request.account,
+                claimable_amount,
+                unvested_amount,
+            );
+
+            if !claimable_amount.is_zero() {
+                Self::deposit_event(RawEvent::VestingFundingClaimed(
+                    request_id,
+                    request.account,
+                    claimable_amount
+                ));
+            }
+
+            Self::deposit_event(RawEvent::VestingFundingRequestCanceled(request_id, unvested_amount));
+        }
+
         /// Councilor makes a remark message
         ///
         /// # <weight>
@@ -950,6 +1198,27 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    // Calculates the amount of the vesting funding request released by the `now` block.
+    fn vested_amount(request: &VestingFundingRequestOf<T>, now: T::BlockNumber) -> Balance<T> {
+        match request.schedule {
+            VestingSchedule::Linear { start, end } => {
+                if now >= end {
+                    request.amount
+                } else if now <= start {
+                    Zero::zero()
+                } else {
+                    Perbill::from_rational_approximation(now - start, end - start) * request.amount
+                }
+            }
+            VestingSchedule::Milestones(ref milestones) => milestones
+                .iter()
+                .filter(|(block, _)| *block <= now)
+                .fold(Zero::zero(), |acc: Balance<T>, (_, amount)| {
+                    acc.saturating_add(*amount)
+                }),
+        }
+    }
+
     // Checkout elected council members reward payments.
     fn try_process_budget(now: T::BlockNumber) {
         // budget autorefill
@@ -1491,6 +1760,62 @@ impl<T: Trait> Mutations<T> {
         });
     }
 
+    // Create a new vesting funding request and return its id.
+    fn create_vesting_funding_request(
+        params: VestingFundingRequestParametersOf<T>,
+    ) -> VestingFundingRequestId {
+        let request_id = NextVestingFundingRequestId::get();
+
+        VestingFundingRequests::<T>::insert(
+            request_id,
+            VestingFundingRequest {
+                account: params.account,
+                amount: params.amount,
+                claimed: Zero::zero(),
+                schedule: params.schedule,
+            },
+        );
+
+        NextVestingFundingRequestId::put(request_id + 1);
+
+        request_id
+    }
+
+    // Transfer claimable vested funds and remove the request when fully claimed.
+    fn claim_vesting_funding(
+        request_id: &VestingFundingRequestId,
+        request: VestingFundingRequestOf<T>,
+        amount: Balance<T>,
+    ) {
+        let _ = balances::Module::<T>::deposit_creating(&request.account, amount);
+
+        let claimed = request.claimed.saturating_add(amount);
+        if claimed >= request.amount {
+            VestingFundingRequests::<T>::remove(request_id);
+        } else {
+            VestingFundingRequests::<T>::insert(
+                request_id,
+                VestingFundingRequest { claimed, ..request },
+            );
+        }
+    }
+
+    // Pay the vested funds, return unvested funds to the budget and remove the request.
+    fn cancel_vesting_funding_request(
+        request_id: &VestingFundingRequestId,
+        account_id: &T::AccountId,
+        claimable_amount: Balance<T>,
+        unvested_amount: Balance<T>,
+    ) {
+        if !claimable_amount.is_zero() {
+            let _ = balances::Module::<T>::deposit_creating(account_id, claimable_amount);
+        }
+
+        Self::refill_budget(unvested_amount);
+
+        VestingFundingRequests::<T>::remove(request_id);
+    }
+
     // Save reward-payments-related changes and plan the next reward payout.
     fn finish_reward_payments(new_balance: Balance<T>, now: T::BlockNumber) {
         // update budget's balance
@@ -1694,6 +2019,52 @@ impl<T: Trait> EnsureChecks<T> {
 
         Ok(())
     }
+
+    // Ensures the vesting funding request exists.
+    fn ensure_vesting_funding_request_exists(
+        request_id: &VestingFundingRequestId,
+    ) -> Result<VestingFundingRequestOf<T>, Error<T>> {
+        if !VestingFundingRequests::<T>::contains_key(request_id) {
+            return Err(Error::VestingFundingRequestDoesNotExist);
+        }
+
+        Ok(VestingFundingRequests::<T>::get(request_id))
+    }
+
+    // Ensures there is no problem in claiming vested funds. Returns the request and the
+    // claimable amount.
+    fn can_claim_vesting_funding(
+        origin: T::Origin,
+        request_id: &VestingFundingRequestId,
+    ) -> Result<(VestingFundingRequestOf<T>, Balance<T>), Error<T>> {
+        let account_id = ensure_signed(origin)?;
+
+        let request = Self::ensure_vesting_funding_request_exists(request_id)?;
+
+        if request.account != account_id {
+            return Err(Error::NotVestingFundingRequestAccount);
+        }
+
+        let now = frame_system::Module::<T>::block_number();
+        let claimable_amount =
+            Module::<T>::vested_amount(&request, now).saturating_sub(request.claimed);
+
+        if claimable_amount.is_zero() {
+            return Err(Error::NoVestedFundsToClaim);
+        }
+
+        Ok((request, claimable_amount))
+    }
+
+    // Ensures there is no problem in canceling the vesting funding request.
+    fn can_cancel_vesting_funding_request(
+        origin: T::Origin,
+        request_id: &VestingFundingRequestId,
+    ) -> Result<VestingFundingRequestOf<T>, Error<T>> {
+        ensure_root(origin)?;
+
+        Self::ensure_vesting_funding_request_exists(request_id)
+    }
 }
 
 impl<T: Trait + common::membership::MembershipTypes>
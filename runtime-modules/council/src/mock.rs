@@ -65,6 +65,7 @@ parameter_types! {
     pub const CandidacyLockId: LockIdentifier = *b"council1";
     pub const CouncilorLockId: LockIdentifier = *b"council2";
     pub const ElectedMemberRewardPeriod: u64 = 10;
+    pub const MaxVestingMilestones: u32 = 10;
     // intentionally high number that prevents side-effecting tests other than  budget refill tests
     pub const BudgetRefillPeriod: u64 = 1000;
 }
@@ -89,6 +90,7 @@ impl Trait for Runtime {
     type CouncilorLock = StakingManager<Self, CouncilorLockId>;
 
     type ElectedMemberRewardPeriod = ElectedMemberRewardPeriod;
+    type MaxVestingMilestones = MaxVestingMilestones;
 
     type StakingAccountValidator = ();
 
@@ -179,6 +181,15 @@ impl WeightInfo for () {
     fn candidate_remark() -> Weight {
         0
     }
+    fn vesting_funding_request(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn claim_vesting_funding(_: u32) -> Weight {
+        0
+    }
+    fn cancel_vesting_funding_request(_: u32) -> Weight {
+        0
+    }
 }
 
 /////////////////// Module implementation //////////////////////////////////////
@@ -269,6 +280,11 @@ parameter_types! {
     pub const InvitedMemberLockId: [u8; 8] = [2; 8];
     pub const StakingCandidateLockId: [u8; 8] = [3; 8];
     pub const CandidateStake: u64 = 100;
+    pub const HandleDisputeLockId: [u8; 8] = [4; 8];
+    pub const HandleDisputeStake: u64 = 150;
+    pub const HandleDisputeGracePeriod: u64 = 10;
+    pub const MaxRecoveryGuardians: u32 = 5;
+    pub const RecoveryDelay: u64 = 10;
     pub const MaxWinnerTargetCount: u64 = 10;
     pub const ReferralCutMaximumPercent: u8 = 50;
 }
@@ -436,6 +452,57 @@ impl membership::WeightInfo for Weights {
     fn member_remark() -> Weight {
         unimplemented!()
     }
+    fn reserve_handle(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn release_handle(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn open_handle_dispute(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn resolve_handle_dispute() -> Weight {
+        unimplemented!()
+    }
+    fn execute_handle_dispute_resolution() -> Weight {
+        unimplemented!()
+    }
+    fn withdraw_handle_dispute() -> Weight {
+        unimplemented!()
+    }
+    fn add_member_verification() -> Weight {
+        unimplemented!()
+    }
+    fn revoke_member_verification() -> Weight {
+        unimplemented!()
+    }
+    fn set_second_level_referral_cut() -> Weight {
+        unimplemented!()
+    }
+    fn start_referral_campaign() -> Weight {
+        unimplemented!()
+    }
+    fn end_referral_campaign() -> Weight {
+        unimplemented!()
+    }
+    fn set_recovery_guardians(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn initiate_recovery() -> Weight {
+        unimplemented!()
+    }
+    fn approve_recovery() -> Weight {
+        unimplemented!()
+    }
+    fn reject_recovery() -> Weight {
+        unimplemented!()
+    }
+    fn cancel_recovery() -> Weight {
+        unimplemented!()
+    }
+    fn execute_recovery() -> Weight {
+        unimplemented!()
+    }
 }
 
 impl balances::Trait for Runtime {
@@ -459,6 +526,11 @@ impl membership::Trait for Runtime {
     type StakingCandidateStakingHandler =
         staking_handler::StakingManager<Self, StakingCandidateLockId>;
     type CandidateStake = CandidateStake;
+    type HandleDisputeStakingHandler = staking_handler::StakingManager<Self, HandleDisputeLockId>;
+    type HandleDisputeStake = HandleDisputeStake;
+    type HandleDisputeGracePeriod = HandleDisputeGracePeriod;
+    type MaxRecoveryGuardians = MaxRecoveryGuardians;
+    type RecoveryDelay = RecoveryDelay;
 }
 
 impl common::working_group::WorkingGroupBudgetHandler<Runtime> for () {
@@ -2,11 +2,12 @@
 
 use super::{
     AnnouncementPeriodNr, Budget, BudgetIncrement, CouncilMemberOf, CouncilMembers,
-    CouncilStageAnnouncing, Error, Module, Trait,
+    CouncilStageAnnouncing, Error, Module, RawEvent, Trait, VestingFundingRequests,
 };
 use crate::mock::*;
 use common::council::CouncilBudgetManager;
 use common::council::CouncilOriginValidator;
+use common::{VestingFundingRequestParameters, VestingSchedule};
 use frame_support::traits::Currency;
 use frame_support::{assert_err, assert_ok, StorageValue};
 use frame_system::RawOrigin;
@@ -1887,3 +1888,241 @@ fn candidate_remark_unsuccessful_with_invalid_origin() {
         );
     });
 }
+
+fn vesting_funding_request_params(
+    account: u64,
+    amount: u64,
+    schedule: VestingSchedule<u64, u64>,
+) -> Vec<VestingFundingRequestParameters<u64, u64, u64>> {
+    vec![VestingFundingRequestParameters {
+        account,
+        amount,
+        schedule,
+    }]
+}
+
+#[test]
+fn vesting_funding_request_linear_succeeds() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let account_id = 1;
+        let amount = 100;
+        let start = frame_system::Module::<Runtime>::block_number();
+        let end = start + 10;
+
+        Mocks::set_budget(OriginType::Root, 150, Ok(()));
+
+        assert_ok!(Council::vesting_funding_request(
+            RawOrigin::Root.into(),
+            vesting_funding_request_params(
+                account_id,
+                amount,
+                VestingSchedule::Linear { start, end }
+            ),
+        ));
+        assert_eq!(Council::budget(), 50);
+
+        MockUtils::increase_block_number(5);
+
+        assert_ok!(Council::claim_vesting_funding(
+            RawOrigin::Signed(account_id).into(),
+            0
+        ));
+        assert_eq!(balances::Module::<Runtime>::free_balance(account_id), 50);
+        assert_eq!(Council::vesting_funding_requests(0).claimed, 50);
+
+        MockUtils::increase_block_number(10);
+
+        assert_ok!(Council::claim_vesting_funding(
+            RawOrigin::Signed(account_id).into(),
+            0
+        ));
+        assert_eq!(
+            balances::Module::<Runtime>::free_balance(account_id),
+            amount
+        );
+        assert!(!VestingFundingRequests::<Runtime>::contains_key(0));
+    });
+}
+
+#[test]
+fn vesting_funding_request_milestones_succeeds() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let account_id = 1;
+        let now = frame_system::Module::<Runtime>::block_number();
+
+        Mocks::set_budget(OriginType::Root, 100, Ok(()));
+
+        assert_ok!(Council::vesting_funding_request(
+            RawOrigin::Root.into(),
+            vesting_funding_request_params(
+                account_id,
+                100,
+                VestingSchedule::Milestones(vec![(now + 2, 30), (now + 4, 70)])
+            ),
+        ));
+
+        assert_err!(
+            Council::claim_vesting_funding(RawOrigin::Signed(account_id).into(), 0),
+            Error::<Runtime>::NoVestedFundsToClaim,
+        );
+
+        MockUtils::increase_block_number(3);
+
+        assert_ok!(Council::claim_vesting_funding(
+            RawOrigin::Signed(account_id).into(),
+            0
+        ));
+        assert_eq!(balances::Module::<Runtime>::free_balance(account_id), 30);
+    });
+}
+
+#[test]
+fn vesting_funding_request_fails_with_invalid_schedule() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let now = frame_system::Module::<Runtime>::block_number();
+
+        Mocks::set_budget(OriginType::Root, 100, Ok(()));
+
+        assert_err!(
+            Council::vesting_funding_request(
+                RawOrigin::Root.into(),
+                vesting_funding_request_params(
+                    1,
+                    100,
+                    VestingSchedule::Linear {
+                        start: now + 10,
+                        end: now
+                    }
+                ),
+            ),
+            Error::<Runtime>::InvalidVestingSchedule,
+        );
+
+        assert_err!(
+            Council::vesting_funding_request(
+                RawOrigin::Root.into(),
+                vesting_funding_request_params(
+                    1,
+                    100,
+                    VestingSchedule::Milestones(vec![(now + 2, 30), (now + 4, 30)])
+                ),
+            ),
+            Error::<Runtime>::InvalidVestingSchedule,
+        );
+    });
+}
+
+#[test]
+fn vesting_funding_request_fails_with_too_many_milestones() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let now = frame_system::Module::<Runtime>::block_number();
+        let milestones_number = <Runtime as Trait>::MaxVestingMilestones::get() as u64 + 1;
+        let milestones = (1..=milestones_number)
+            .map(|milestone| (now + milestone, 1))
+            .collect::<Vec<_>>();
+
+        Mocks::set_budget(OriginType::Root, 100, Ok(()));
+
+        assert_err!(
+            Council::vesting_funding_request(
+                RawOrigin::Root.into(),
+                vesting_funding_request_params(
+                    1,
+                    milestones_number,
+                    VestingSchedule::Milestones(milestones)
+                ),
+            ),
+            Error::<Runtime>::TooManyVestingMilestones,
+        );
+    });
+}
+
+#[test]
+fn claim_vesting_funding_fails_with_invalid_account() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let start = frame_system::Module::<Runtime>::block_number();
+
+        Mocks::set_budget(OriginType::Root, 100, Ok(()));
+
+        assert_ok!(Council::vesting_funding_request(
+            RawOrigin::Root.into(),
+            vesting_funding_request_params(
+                1,
+                100,
+                VestingSchedule::Linear {
+                    start,
+                    end: start + 10
+                }
+            ),
+        ));
+
+        MockUtils::increase_block_number(5);
+
+        assert_err!(
+            Council::claim_vesting_funding(RawOrigin::Signed(2).into(), 0),
+            Error::<Runtime>::NotVestingFundingRequestAccount,
+        );
+
+        assert_err!(
+            Council::claim_vesting_funding(RawOrigin::Signed(1).into(), 1),
+            Error::<Runtime>::VestingFundingRequestDoesNotExist,
+        );
+    });
+}
+
+#[test]
+fn cancel_vesting_funding_request_succeeds() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let account_id = 1;
+        let start = frame_system::Module::<Runtime>::block_number();
+
+        Mocks::set_budget(OriginType::Root, 100, Ok(()));
+
+        assert_ok!(Council::vesting_funding_request(
+            RawOrigin::Root.into(),
+            vesting_funding_request_params(
+                account_id,
+                100,
+                VestingSchedule::Linear {
+                    start,
+                    end: start + 10
+                }
+            ),
+        ));
+
+        MockUtils::increase_block_number(5);
+
+        assert_err!(
+            Council::cancel_vesting_funding_request(RawOrigin::Signed(account_id).into(), 0),
+            Error::<Runtime>::BadOrigin,
+        );
+
+        assert_ok!(Council::cancel_vesting_funding_request(
+            RawOrigin::Root.into(),
+            0
+        ));
+
+        assert_eq!(balances::Module::<Runtime>::free_balance(account_id), 50);
+        assert_eq!(Council::budget(), 50);
+        assert!(!VestingFundingRequests::<Runtime>::contains_key(0));
+        assert_eq!(
+            frame_system::Module::<Runtime>::events()
+                .last()
+                .unwrap()
+                .event,
+            TestEvent::event_mod(RawEvent::VestingFundingRequestCanceled(0, 50)),
+        );
+    });
+}
@@ -3,8 +3,8 @@ use super::*;
 use balances::Module as Balances;
 use core::convert::TryInto;
 use frame_benchmarking::{account, benchmarks};
-use frame_support::storage::StorageMap;
-use frame_support::traits::Currency;
+use frame_support::storage::{StorageMap, StorageValue};
+use frame_support::traits::{Currency, LockableCurrency, WithdrawReasons};
 use frame_system::Module as System;
 use frame_system::{EventRecord, RawOrigin};
 use membership::Module as Membership;
@@ -220,6 +220,8 @@ fn add_opening_helper<T: Trait + working_group::Trait<ForumWorkingGroupInstance>
                 working_group::Trait<ForumWorkingGroupInstance>>::MinUnstakingPeriodLimit::get() + One::one(),
         },
         Some(One::one()),
+        None,
+        None,
     )
     .unwrap();
 
@@ -318,11 +320,61 @@ fn add_thread_post<T: Trait>(
         thread_id,
         text,
         true,
+        BTreeSet::new(),
+        None,
+        BTreeSet::new(),
     )
     .unwrap();
     Module::<T>::next_post_id() - T::PostId::one()
 }
 
+// Makes the post a reply to a new post with the maximum number of replies and adds the provided
+// number of direct replies to the post
+fn add_post_replies<T: Trait>(
+    account_id: T::AccountId,
+    forum_user_id: crate::ForumUserId<T>,
+    category_id: T::CategoryId,
+    thread_id: T::ThreadId,
+    post_id: T::PostId,
+    replies_number: u32,
+) {
+    let parent_post_id =
+        add_thread_post::<T>(account_id, forum_user_id, category_id, thread_id, vec![0u8]);
+
+    let max_replies = <<<T as Trait>::MapLimits as StorageLimits>::MaxRepliesPerPost>::get();
+    let mut parent_replies: BTreeSet<T::PostId> = (1..max_replies)
+        .map(|reply_id| (reply_id + 1_000_000).saturated_into())
+        .collect();
+    parent_replies.insert(post_id);
+
+    <PostReplies<T>>::insert(thread_id, parent_post_id, parent_replies);
+
+    let mut post = Module::<T>::post_by_id(thread_id, post_id);
+    post.parent_post_id = Some(parent_post_id);
+
+    <PostById<T>>::insert(thread_id, post_id, post.clone());
+
+    let replies: BTreeSet<T::PostId> = (0..replies_number)
+        .map(|_| {
+            let reply_id = Module::<T>::next_post_id();
+            <NextPostId<T>>::put(reply_id + T::PostId::one());
+
+            <PostById<T>>::insert(
+                thread_id,
+                reply_id,
+                Post {
+                    parent_post_id: Some(post_id),
+                    ..post.clone()
+                },
+            );
+
+            reply_id
+        })
+        .collect();
+
+    <PostReplies<T>>::insert(thread_id, post_id, replies);
+}
+
 fn good_poll_alternative_text() -> Vec<u8> {
     b"poll alternative".to_vec()
 }
@@ -346,7 +398,45 @@ pub fn generate_poll_input<T: Trait>(
             }
             alternatives
         },
+        max_choices: 1,
+        vote_weighting: PollVoteWeighting::Equal,
+    }
+}
+
+/// Subscribes `s` distinct forum users to the thread (if any) and the categories of the path,
+/// at most the maximum number of subscribers to each of them. Returns the subscribed forum users.
+pub fn subscribe_forum_users<T: Trait>(
+    category_id: T::CategoryId,
+    thread_id: Option<T::ThreadId>,
+    s: u32,
+) -> BTreeSet<ForumUserId<T>> {
+    let max_subscribers =
+        <<<T as Trait>::MapLimits as StorageLimits>::MaxSubscribers>::get() as usize;
+
+    let subscriber_ids: Vec<ForumUserId<T>> = (1..=s)
+        .map(|subscriber_id| subscriber_id.saturated_into())
+        .collect();
+    let mut chunks = subscriber_ids.chunks(max_subscribers);
+
+    let mut subscribers = BTreeSet::new();
+
+    if let Some(thread_id) = thread_id {
+        if let Some(chunk) = chunks.next() {
+            let thread_subscribers: BTreeSet<ForumUserId<T>> = chunk.iter().cloned().collect();
+            subscribers.extend(thread_subscribers.iter().cloned());
+            <ThreadSubscribers<T>>::insert(thread_id, thread_subscribers);
+        }
+    }
+
+    for (path_category_id, _) in Module::<T>::build_category_tree_path(&category_id) {
+        if let Some(chunk) = chunks.next() {
+            let category_subscribers: BTreeSet<ForumUserId<T>> = chunk.iter().cloned().collect();
+            subscribers.extend(category_subscribers.iter().cloned());
+            <CategorySubscribers<T>>::insert(path_category_id, category_subscribers);
+        }
     }
+
+    subscribers
 }
 
 /// Generates categories tree
@@ -394,6 +484,22 @@ pub fn generate_categories_tree<T: Trait>(
     (category_id, parent_category_id)
 }
 
+/// Fills the moderation log of the moderator up to its size limit
+// Fills the moderation log up to its size limit with the largest possible entries
+fn fill_moderation_log<T: Trait>(moderator_id: ModeratorId<T>) {
+    let max_log_size = <T::MapLimits as StorageLimits>::MaxModerationLogSize::get();
+    let stickied_ids: Vec<T::ThreadId> = (0..MAX_THREADS as u64)
+        .map(|thread_id| thread_id.saturated_into())
+        .collect();
+    let entry = ModerationLogEntry {
+        action: ModerationAction::StickiedThreadsSet(T::CategoryId::default(), stickied_ids),
+        rationale_hash: Some(T::calculate_hash(&[0u8])),
+        block: System::<T>::block_number(),
+    };
+
+    <ModerationLog<T>>::insert(moderator_id, vec![entry].repeat(max_log_size as usize));
+}
+
 benchmarks! {
     where_clause { where
         T: balances::Trait,
@@ -873,6 +979,9 @@ benchmarks! {
 
         let category_counter = <Module<T>>::category_counter();
 
+        // Fill the moderation log up to its size limit
+        fill_moderation_log::<T>(moderator_id);
+
     }: delete_category(RawOrigin::Signed(caller_id), PrivilegedActor::Moderator(moderator_id), category_id)
     verify {
         let text = vec![0u8].repeat(MAX_BYTES as usize);
@@ -916,10 +1025,18 @@ benchmarks! {
 
         let z in 1 .. (<<<T as Trait>::MapLimits as StorageLimits>::MaxPollAlternativesNumber>::get() - 1) as u32;
 
+        let s in 0 .. (
+            T::MaxCategoryDepth::get() *
+            <<<T as Trait>::MapLimits as StorageLimits>::MaxSubscribers>::get()
+        ) as u32;
+
         // Generate categories tree
         let (category_id, _) = generate_categories_tree::<T>(caller_id.clone(), i, None);
         let mut category = Module::<T>::category_by_id(category_id);
 
+        // Subscribe forum users to the categories
+        let subscribers = subscribe_forum_users::<T>(category_id, None, s);
+
         let metadata = vec![0u8].repeat(j as usize);
 
         let text = vec![0u8].repeat(k as usize);
@@ -954,6 +1071,7 @@ benchmarks! {
             text_hash: T::calculate_hash(&text),
             author_id: forum_user_id.saturated_into(),
             thread_id: next_thread_id,
+            parent_post_id: None,
             last_edited: System::<T>::block_number(),
             cleanup_pay_off: T::PostDeposit::get(),
         };
@@ -978,17 +1096,28 @@ benchmarks! {
 
         assert_eq!(Module::<T>::next_post_id(), next_post_id + T::PostId::one());
 
-        assert_last_event::<T>(
-            RawEvent::ThreadCreated(
-                category_id,
-                next_thread_id,
-                next_post_id,
-                forum_user_id.saturated_into(),
-                metadata,
-                text,
-                poll_input,
-            ).into()
-        );
+        if subscribers.is_empty() {
+            assert_last_event::<T>(
+                RawEvent::ThreadCreated(
+                    category_id,
+                    next_thread_id,
+                    next_post_id,
+                    forum_user_id.saturated_into(),
+                    metadata,
+                    text,
+                    poll_input,
+                ).into()
+            );
+        } else {
+            assert_last_event::<T>(
+                RawEvent::PostSubscribersNotified(
+                    next_post_id,
+                    category_id,
+                    next_thread_id,
+                    subscribers,
+                ).into()
+            );
+        }
     }
 
     edit_thread_metadata {
@@ -1219,6 +1348,9 @@ benchmarks! {
         let mut category = Module::<T>::category_by_id(category_id);
         let mut new_category = Module::<T>::category_by_id(new_category_id);
 
+        // Fill the moderation log up to its size limit
+        fill_moderation_log::<T>(moderator_id);
+
     }: move_thread_to_category(RawOrigin::Signed(caller_id), PrivilegedActor::Moderator(moderator_id), category_id, thread_id, new_category_id)
     verify {
         // Ensure thread was successfully moved to the new category
@@ -1279,6 +1411,7 @@ benchmarks! {
                     { PollAlternative {
                         alternative_text_hash: old_value.alternative_text_hash,
                         vote_count: old_value.vote_count + 1,
+                        vote_weight: old_value.vote_weight + 1,
                     }
                     } else {
                         old_value.clone()
@@ -1393,6 +1526,9 @@ benchmarks! {
 
         let rationale = vec![0u8].repeat(k as usize);
 
+        // Fill the moderation log up to its size limit
+        fill_moderation_log::<T>(moderator_id);
+
     }: moderate_thread(RawOrigin::Signed(caller_id), PrivilegedActor::Moderator(moderator_id), category_id, thread_id, rationale.clone())
     verify {
         // Thread balance was correctly slashed
@@ -1430,6 +1566,15 @@ benchmarks! {
 
         let j in 0 .. MAX_BYTES;
 
+        let k in 0 .. <<<T as Trait>::MapLimits as StorageLimits>::MaxMentionsPerPost>::get() as u32;
+
+        let l in 0 .. <<<T as Trait>::MapLimits as StorageLimits>::MaxQuotesPerPost>::get() as u32;
+
+        let s in 0 .. (
+            (T::MaxCategoryDepth::get() + 1) *
+            <<<T as Trait>::MapLimits as StorageLimits>::MaxSubscribers>::get()
+        ) as u32;
+
         let text = vec![0u8].repeat(j as usize);
 
         // Generate categories tree
@@ -1441,11 +1586,40 @@ benchmarks! {
             vec![0u8].repeat(MAX_BYTES as usize), vec![0u8].repeat(MAX_BYTES as usize), None
         );
 
+        // Subscribe forum users to the thread and the categories
+        let subscribers = subscribe_forum_users::<T>(category_id, Some(thread_id), s);
+
+        let mentions: BTreeSet<ForumUserId<T>> = (1..=k)
+            .map(|mentioned_id| mentioned_id.saturated_into())
+            .collect();
+
+        // Reply to a post which already has the maximum number of replies minus one
+        let parent_post_id = add_thread_post::<T>(
+            caller_id.clone(), forum_user_id.saturated_into(), category_id, thread_id, vec![0u8]
+        );
+
+        let max_replies = <<<T as Trait>::MapLimits as StorageLimits>::MaxRepliesPerPost>::get();
+        let replies: BTreeSet<T::PostId> = (1..max_replies)
+            .map(|reply_id| (reply_id + 1_000_000).saturated_into())
+            .collect();
+
+        <PostReplies<T>>::insert(thread_id, parent_post_id, replies);
+
+        let quotes: BTreeSet<ExtendedPostId<T>> = (0..l)
+            .map(|_| ExtendedPostIdObject {
+                category_id,
+                thread_id,
+                post_id: add_thread_post::<T>(
+                    caller_id.clone(), forum_user_id.saturated_into(), category_id, thread_id, vec![0u8]
+                ),
+            })
+            .collect();
+
         let thread = Module::<T>::thread_by_id(category_id, thread_id);
         let post_id = Module::<T>::next_post_id();
 
         let initial_balance = Balances::<T>::usable_balance(&caller_id);
-    }: _ (RawOrigin::Signed(caller_id.clone()), forum_user_id.saturated_into(), category_id, thread_id, text.clone(), true)
+    }: _ (RawOrigin::Signed(caller_id.clone()), forum_user_id.saturated_into(), category_id, thread_id, text.clone(), true, mentions.clone(), Some(parent_post_id), quotes.clone())
     verify {
         assert_eq!(
             Balances::<T>::usable_balance(&caller_id),
@@ -1457,24 +1631,47 @@ benchmarks! {
             text_hash: T::calculate_hash(&text),
             author_id: forum_user_id.saturated_into(),
             thread_id,
+            parent_post_id: Some(parent_post_id),
             last_edited: System::<T>::block_number(),
             cleanup_pay_off: T::PostDeposit::get(),
         };
 
         assert_eq!(Module::<T>::post_by_id(thread_id, post_id), new_post);
 
+        assert!(Module::<T>::post_replies(thread_id, parent_post_id).contains(&post_id));
+
         assert_eq!(Module::<T>::next_post_id(), post_id + T::PostId::one());
 
-        assert_last_event::<T>(
-            RawEvent::PostAdded(
-                post_id,
-                forum_user_id.saturated_into(),
-                category_id,
-                thread_id,
-                text,
-                true,
-            ).into()
-        );
+        if mentions.is_empty() && subscribers.is_empty() {
+            assert_last_event::<T>(
+                RawEvent::PostReferencesAdded(
+                    post_id,
+                    category_id,
+                    thread_id,
+                    Some(parent_post_id),
+                    quotes,
+                ).into()
+            );
+        } else if mentions.is_empty() {
+            assert_last_event::<T>(
+                RawEvent::PostSubscribersNotified(
+                    post_id,
+                    category_id,
+                    thread_id,
+                    subscribers,
+                ).into()
+            );
+        } else {
+            assert_last_event::<T>(
+                RawEvent::MembersMentioned(
+                    post_id,
+                    forum_user_id.saturated_into(),
+                    category_id,
+                    thread_id,
+                    mentions,
+                ).into()
+            );
+        }
     }
 
     react_post {
@@ -1580,6 +1777,8 @@ benchmarks! {
 
         let j in 0 .. MAX_BYTES;
 
+        let k in 0 .. <<<T as Trait>::MapLimits as StorageLimits>::MaxRepliesPerPost>::get() as u32;
+
         // Generate categories tree
         let (category_id, _) = generate_categories_tree::<T>(caller_id.clone(), i, None);
 
@@ -1596,6 +1795,10 @@ benchmarks! {
         );
         let post_id = add_thread_post::<T>(caller_id.clone(), forum_user_id.saturated_into(), category_id, thread_id, text);
 
+        add_post_replies::<T>(
+            caller_id.clone(), forum_user_id.saturated_into(), category_id, thread_id, post_id, k
+        );
+
         let mut thread = Module::<T>::thread_by_id(category_id, thread_id);
 
         let rationale = vec![0u8].repeat(j as usize);
@@ -1606,6 +1809,7 @@ benchmarks! {
         assert_eq!(Module::<T>::thread_by_id(category_id, thread_id), thread);
 
         assert!(!<PostById<T>>::contains_key(thread_id, post_id));
+        assert!(!<PostReplies<T>>::contains_key(thread_id, post_id));
 
         assert_last_event::<T>(
             RawEvent::PostModerated(
@@ -1627,6 +1831,8 @@ benchmarks! {
 
         let j in 0 .. MAX_BYTES;
 
+        let k in 0 .. <<<T as Trait>::MapLimits as StorageLimits>::MaxRepliesPerPost>::get() as u32;
+
         // Generate categories tree
         let (category_id, _) = generate_categories_tree::<T>(caller_id.clone(), i, None);
 
@@ -1643,6 +1849,10 @@ benchmarks! {
         );
         let post_id = add_thread_post::<T>(caller_id.clone(), forum_user_id.saturated_into(), category_id, thread_id, text);
 
+        add_post_replies::<T>(
+            caller_id.clone(), forum_user_id.saturated_into(), category_id, thread_id, post_id, k
+        );
+
         let mut thread = Module::<T>::thread_by_id(category_id, thread_id);
 
         let moderator_id = ModeratorId::<T>::from(forum_user_id.try_into().unwrap());
@@ -1654,12 +1864,16 @@ benchmarks! {
 
         let rationale = vec![0u8].repeat(j as usize);
 
+        // Fill the moderation log up to its size limit
+        fill_moderation_log::<T>(moderator_id);
+
     }: moderate_post(RawOrigin::Signed(caller_id), PrivilegedActor::Moderator(moderator_id), category_id, thread_id, post_id, rationale.clone())
     verify {
         thread.number_of_posts -= 1;
         assert_eq!(Module::<T>::thread_by_id(category_id, thread_id), thread);
 
         assert!(!<PostById<T>>::contains_key(thread_id, post_id));
+        assert!(!<PostReplies<T>>::contains_key(thread_id, post_id));
 
         assert_last_event::<T>(
             RawEvent::PostModerated(
@@ -1683,6 +1897,8 @@ benchmarks! {
 
         let k in 1 .. MAX_POSTS;
 
+        let l in 0 .. <<<T as Trait>::MapLimits as StorageLimits>::MaxRepliesPerPost>::get() as u32;
+
         // Generate categories tree
         let (category_id, _) = generate_categories_tree::<T>(caller_id.clone(), i, None);
 
@@ -1700,20 +1916,19 @@ benchmarks! {
         let hide = false;
         let mut posts = BTreeMap::new();
         for _ in 0 .. k {
-            posts.insert(
-                ExtendedPostIdObject {
-                    category_id,
-                    thread_id,
-                    post_id: add_thread_post::<T>(
-                        caller_id.clone(),
-                        forum_user_id.saturated_into(),
-                        category_id,
-                        thread_id,
-                        vec![0u8],
-                    ),
-                },
-                hide
+            let post_id = add_thread_post::<T>(
+                caller_id.clone(),
+                forum_user_id.saturated_into(),
+                category_id,
+                thread_id,
+                vec![0u8],
+            );
+
+            add_post_replies::<T>(
+                caller_id.clone(), forum_user_id.saturated_into(), category_id, thread_id, post_id, l
             );
+
+            posts.insert(ExtendedPostIdObject { category_id, thread_id, post_id }, hide);
         }
 
         let post_id = add_thread_post::<T>(caller_id.clone(), forum_user_id.saturated_into(), category_id, thread_id, text);
@@ -1741,6 +1956,7 @@ benchmarks! {
 
         for (extended_post, _) in &posts {
             assert!(!<PostById<T>>::contains_key(extended_post.thread_id, extended_post.post_id));
+            assert!(!<PostReplies<T>>::contains_key(extended_post.thread_id, extended_post.post_id));
         }
 
         assert_last_event::<T>(
@@ -1830,6 +2046,9 @@ benchmarks! {
 
         let mut category =  Module::<T>::category_by_id(category_id);
 
+        // Fill the moderation log up to its size limit
+        fill_moderation_log::<T>(moderator_id);
+
     }: set_stickied_threads(RawOrigin::Signed(caller_id), PrivilegedActor::Moderator(moderator_id), category_id, stickied_ids.clone())
     verify {
         // Ensure category stickied_ids updated successfully.
@@ -1844,6 +2063,404 @@ benchmarks! {
             ).into()
         );
     }
+    ban_forum_user_lead {
+        let forum_user_id = 0;
+        let caller_id =
+            insert_a_leader::<T>(forum_user_id);
+
+        let i in 1 .. (T::MaxCategoryDepth::get() + 1) as u32;
+
+        let j in 0 .. MAX_BYTES;
+
+        // Generate categories tree
+        let (category_id, _) = generate_categories_tree::<T>(caller_id.clone(), i, None);
+
+        let banned_forum_user_id: ForumUserId<T> = 1u64.saturated_into();
+        let duration: Option<T::BlockNumber> = Some(10u32.into());
+        let rationale = vec![0u8].repeat(j as usize);
+
+    }: ban_forum_user(RawOrigin::Signed(caller_id), PrivilegedActor::Lead, category_id, banned_forum_user_id, duration, rationale.clone())
+    verify {
+        let now = System::<T>::block_number();
+        let expires_at = duration.map(|duration| now + duration);
+
+        assert_eq!(
+            Module::<T>::forum_user_ban_by_category(category_id, banned_forum_user_id),
+            ForumUserBan {
+                banned_at: now,
+                expires_at,
+                rationale_hash: T::calculate_hash(rationale.as_slice()),
+            }
+        );
+
+        assert_last_event::<T>(
+            RawEvent::ForumUserBanned(
+                banned_forum_user_id,
+                category_id,
+                expires_at,
+                rationale,
+                PrivilegedActor::Lead
+            ).into()
+        );
+    }
+
+    ban_forum_user_moderator {
+        let forum_user_id = 0;
+        let caller_id =
+            insert_a_leader::<T>(forum_user_id);
+
+        let i in 1 .. (T::MaxCategoryDepth::get() + 1) as u32;
+
+        let j in 0 .. MAX_BYTES;
+
+        // Generate categories tree
+        let (category_id, _) = generate_categories_tree::<T>(caller_id.clone(), i, None);
+
+        let moderator_id = ModeratorId::<T>::from(forum_user_id.try_into().unwrap());
+
+        // Set up category membership of moderator.
+        Module::<T>::update_category_membership_of_moderator(
+            RawOrigin::Signed(caller_id.clone()).into(), moderator_id, category_id, true
+        ).unwrap();
+
+        let banned_forum_user_id: ForumUserId<T> = 1u64.saturated_into();
+        let duration: Option<T::BlockNumber> = Some(10u32.into());
+        let rationale = vec![0u8].repeat(j as usize);
+
+        // Fill the moderation log up to its size limit
+        fill_moderation_log::<T>(moderator_id);
+
+    }: ban_forum_user(RawOrigin::Signed(caller_id), PrivilegedActor::Moderator(moderator_id), category_id, banned_forum_user_id, duration, rationale.clone())
+    verify {
+        let now = System::<T>::block_number();
+        let expires_at = duration.map(|duration| now + duration);
+
+        assert_eq!(
+            Module::<T>::forum_user_ban_by_category(category_id, banned_forum_user_id),
+            ForumUserBan {
+                banned_at: now,
+                expires_at,
+                rationale_hash: T::calculate_hash(rationale.as_slice()),
+            }
+        );
+
+        assert_last_event::<T>(
+            RawEvent::ForumUserBanned(
+                banned_forum_user_id,
+                category_id,
+                expires_at,
+                rationale,
+                PrivilegedActor::Moderator(moderator_id)
+            ).into()
+        );
+    }
+
+    unban_forum_user_lead {
+        let forum_user_id = 0;
+        let caller_id =
+            insert_a_leader::<T>(forum_user_id);
+
+        let i in 1 .. (T::MaxCategoryDepth::get() + 1) as u32;
+
+        // Generate categories tree
+        let (category_id, _) = generate_categories_tree::<T>(caller_id.clone(), i, None);
+
+        let banned_forum_user_id: ForumUserId<T> = 1u64.saturated_into();
+
+        Module::<T>::ban_forum_user(
+            RawOrigin::Signed(caller_id.clone()).into(),
+            PrivilegedActor::Lead,
+            category_id,
+            banned_forum_user_id,
+            None,
+            vec![0u8],
+        ).unwrap();
+
+    }: unban_forum_user(RawOrigin::Signed(caller_id), PrivilegedActor::Lead, category_id, banned_forum_user_id)
+    verify {
+        assert!(!<ForumUserBanByCategory<T>>::contains_key(category_id, banned_forum_user_id));
+
+        assert_last_event::<T>(
+            RawEvent::ForumUserUnbanned(
+                banned_forum_user_id,
+                category_id,
+                PrivilegedActor::Lead
+            ).into()
+        );
+    }
+
+    unban_forum_user_moderator {
+        let forum_user_id = 0;
+        let caller_id =
+            insert_a_leader::<T>(forum_user_id);
+
+        let i in 1 .. (T::MaxCategoryDepth::get() + 1) as u32;
+
+        // Generate categories tree
+        let (category_id, _) = generate_categories_tree::<T>(caller_id.clone(), i, None);
+
+        let moderator_id = ModeratorId::<T>::from(forum_user_id.try_into().unwrap());
+
+        // Set up category membership of moderator.
+        Module::<T>::update_category_membership_of_moderator(
+            RawOrigin::Signed(caller_id.clone()).into(), moderator_id, category_id, true
+        ).unwrap();
+
+        let banned_forum_user_id: ForumUserId<T> = 1u64.saturated_into();
+
+        Module::<T>::ban_forum_user(
+            RawOrigin::Signed(caller_id.clone()).into(),
+            PrivilegedActor::Moderator(moderator_id),
+            category_id,
+            banned_forum_user_id,
+            None,
+            vec![0u8],
+        ).unwrap();
+
+        // Fill the moderation log up to its size limit
+        fill_moderation_log::<T>(moderator_id);
+
+    }: unban_forum_user(RawOrigin::Signed(caller_id), PrivilegedActor::Moderator(moderator_id), category_id, banned_forum_user_id)
+    verify {
+        assert!(!<ForumUserBanByCategory<T>>::contains_key(category_id, banned_forum_user_id));
+
+        assert_last_event::<T>(
+            RawEvent::ForumUserUnbanned(
+                banned_forum_user_id,
+                category_id,
+                PrivilegedActor::Moderator(moderator_id)
+            ).into()
+        );
+    }
+    update_thread_subscription {
+        let forum_user_id = 0;
+        let caller_id =
+            insert_a_leader::<T>(forum_user_id);
+
+        // Generate categories tree
+        let (category_id, _) = generate_categories_tree::<T>(caller_id.clone(), 1, None);
+
+        // Create thread
+        let text = vec![1u8].repeat(MAX_BYTES as usize);
+        let thread_id = create_new_thread::<T>(
+            caller_id.clone(), forum_user_id.saturated_into(), category_id,
+            text.clone(), text, None
+        );
+
+        let s in 0 .. (<<<T as Trait>::MapLimits as StorageLimits>::MaxSubscribers>::get() - 1) as u32;
+
+        // Subscribe the forum users to the thread
+        let mut subscribers: BTreeSet<ForumUserId<T>> = (1..=s)
+            .map(|subscriber_id| subscriber_id.saturated_into())
+            .collect();
+
+        <ThreadSubscribers<T>>::insert(thread_id, subscribers.clone());
+
+    }: _ (RawOrigin::Signed(caller_id), forum_user_id.saturated_into(), category_id, thread_id, true)
+    verify {
+        subscribers.insert(forum_user_id.saturated_into());
+        assert_eq!(Module::<T>::thread_subscribers(thread_id), subscribers);
+
+        assert_last_event::<T>(
+            RawEvent::ThreadSubscriptionUpdated(
+                forum_user_id.saturated_into(),
+                category_id,
+                thread_id,
+                true
+            ).into()
+        );
+    }
+
+    update_category_subscription {
+        let forum_user_id = 0;
+        let caller_id =
+            insert_a_leader::<T>(forum_user_id);
+
+        // Generate categories tree
+        let (category_id, _) = generate_categories_tree::<T>(caller_id.clone(), 1, None);
+
+        let s in 0 .. (<<<T as Trait>::MapLimits as StorageLimits>::MaxSubscribers>::get() - 1) as u32;
+
+        // Subscribe the forum users to the category
+        let mut subscribers: BTreeSet<ForumUserId<T>> = (1..=s)
+            .map(|subscriber_id| subscriber_id.saturated_into())
+            .collect();
+
+        <CategorySubscribers<T>>::insert(category_id, subscribers.clone());
+
+    }: _ (RawOrigin::Signed(caller_id), forum_user_id.saturated_into(), category_id, true)
+    verify {
+        subscribers.insert(forum_user_id.saturated_into());
+        assert_eq!(Module::<T>::category_subscribers(category_id), subscribers);
+
+        assert_last_event::<T>(
+            RawEvent::CategorySubscriptionUpdated(
+                forum_user_id.saturated_into(),
+                category_id,
+                true
+            ).into()
+        );
+    }
+
+    unlock_poll_vote_stake {
+        let forum_user_id = 0;
+        let caller_id =
+            insert_a_leader::<T>(forum_user_id);
+
+        // Lock the stake of the ended poll votes
+        let amount = Balances::<T>::free_balance(&caller_id);
+        Balances::<T>::set_lock(
+            T::PollVoteLockId::get(),
+            &caller_id,
+            amount,
+            WithdrawReasons::all(),
+        );
+
+        let unlocks_at = pallet_timestamp::Module::<T>::now();
+        <PollVoteLocks<T>>::insert(&caller_id, PollVoteLock { amount, unlocks_at });
+
+        pallet_timestamp::Module::<T>::set_timestamp(unlocks_at + 1u32.into());
+
+    }: _ (RawOrigin::Signed(caller_id.clone()))
+    verify {
+        assert!(Module::<T>::poll_vote_lock_by_account_id(&caller_id).is_none());
+        assert_eq!(
+            Balances::<T>::usable_balance(&caller_id),
+            Balances::<T>::free_balance(&caller_id)
+        );
+
+        assert_last_event::<T>(RawEvent::PollVoteStakeUnlocked(caller_id).into());
+    }
+
+    update_category_privacy_lead {
+        let forum_user_id = 0;
+        let caller_id =
+            insert_a_leader::<T>(forum_user_id);
+
+        let i in 1 .. (T::MaxCategoryDepth::get() + 1) as u32;
+
+        // Generate categories tree
+        let (category_id, _) = generate_categories_tree::<T>(caller_id.clone(), i, None);
+
+    }: update_category_privacy(RawOrigin::Signed(caller_id), PrivilegedActor::Lead, category_id, true)
+    verify {
+        assert_eq!(Module::<T>::private_category_whitelist(category_id), Some(BTreeSet::new()));
+
+        assert_last_event::<T>(
+            RawEvent::CategoryPrivacyUpdated(
+                category_id,
+                true,
+                PrivilegedActor::Lead
+            ).into()
+        );
+    }
+
+    update_category_privacy_moderator {
+        let forum_user_id = 0;
+        let caller_id =
+            insert_a_leader::<T>(forum_user_id);
+
+        let i in 1 .. (T::MaxCategoryDepth::get() + 1) as u32;
+
+        // Generate categories tree
+        let (category_id, _) = generate_categories_tree::<T>(caller_id.clone(), i, None);
+
+        let moderator_id = ModeratorId::<T>::from(forum_user_id.try_into().unwrap());
+
+        // Set up category membership of moderator.
+        Module::<T>::update_category_membership_of_moderator(
+            RawOrigin::Signed(caller_id.clone()).into(), moderator_id, category_id, true
+        ).unwrap();
+
+    }: update_category_privacy(RawOrigin::Signed(caller_id), PrivilegedActor::Moderator(moderator_id), category_id, true)
+    verify {
+        assert_eq!(Module::<T>::private_category_whitelist(category_id), Some(BTreeSet::new()));
+
+        assert_last_event::<T>(
+            RawEvent::CategoryPrivacyUpdated(
+                category_id,
+                true,
+                PrivilegedActor::Moderator(moderator_id)
+            ).into()
+        );
+    }
+
+    update_category_whitelist_membership_lead {
+        let forum_user_id = 0;
+        let caller_id =
+            insert_a_leader::<T>(forum_user_id);
+
+        let i in 1 .. (T::MaxCategoryDepth::get() + 1) as u32;
+
+        // Generate categories tree
+        let (category_id, _) = generate_categories_tree::<T>(caller_id.clone(), i, None);
+
+        // Whitelist the maximum number of forum users except one in the private category
+        let max_whitelisted = <<<T as Trait>::MapLimits as StorageLimits>::MaxWhitelistedMembers>::get();
+        let mut whitelist: BTreeSet<ForumUserId<T>> = (1..max_whitelisted)
+            .map(|whitelisted_id| whitelisted_id.saturated_into())
+            .collect();
+
+        <PrivateCategoryWhitelist<T>>::insert(category_id, whitelist.clone());
+
+        let whitelisted_forum_user_id: ForumUserId<T> = max_whitelisted.saturated_into();
+
+    }: update_category_whitelist_membership(RawOrigin::Signed(caller_id), PrivilegedActor::Lead, category_id, whitelisted_forum_user_id, true)
+    verify {
+        whitelist.insert(whitelisted_forum_user_id);
+        assert_eq!(Module::<T>::private_category_whitelist(category_id), Some(whitelist));
+
+        assert_last_event::<T>(
+            RawEvent::CategoryWhitelistMembershipUpdated(
+                category_id,
+                whitelisted_forum_user_id,
+                true,
+                PrivilegedActor::Lead
+            ).into()
+        );
+    }
+
+    update_category_whitelist_membership_moderator {
+        let forum_user_id = 0;
+        let caller_id =
+            insert_a_leader::<T>(forum_user_id);
+
+        let i in 1 .. (T::MaxCategoryDepth::get() + 1) as u32;
+
+        // Generate categories tree
+        let (category_id, _) = generate_categories_tree::<T>(caller_id.clone(), i, None);
+
+        let moderator_id = ModeratorId::<T>::from(forum_user_id.try_into().unwrap());
+
+        // Set up category membership of moderator.
+        Module::<T>::update_category_membership_of_moderator(
+            RawOrigin::Signed(caller_id.clone()).into(), moderator_id, category_id, true
+        ).unwrap();
+
+        // Whitelist the maximum number of forum users except one in the private category
+        let max_whitelisted = <<<T as Trait>::MapLimits as StorageLimits>::MaxWhitelistedMembers>::get();
+        let mut whitelist: BTreeSet<ForumUserId<T>> = (1..max_whitelisted)
+            .map(|whitelisted_id| whitelisted_id.saturated_into())
+            .collect();
+
+        <PrivateCategoryWhitelist<T>>::insert(category_id, whitelist.clone());
+
+        let whitelisted_forum_user_id: ForumUserId<T> = max_whitelisted.saturated_into();
+
+    }: update_category_whitelist_membership(RawOrigin::Signed(caller_id), PrivilegedActor::Moderator(moderator_id), category_id, whitelisted_forum_user_id, true)
+    verify {
+        whitelist.insert(whitelisted_forum_user_id);
+        assert_eq!(Module::<T>::private_category_whitelist(category_id), Some(whitelist));
+
+        assert_last_event::<T>(
+            RawEvent::CategoryWhitelistMembershipUpdated(
+                category_id,
+                whitelisted_forum_user_id,
+                true,
+                PrivilegedActor::Moderator(moderator_id)
+            ).into()
+        );
+    }
 }
 
 #[cfg(test)]
@@ -2044,4 +2661,83 @@ mod tests {
             assert_ok!(test_benchmark_delete_posts::<Runtime>());
         });
     }
+
+    #[test]
+    fn test_ban_forum_user_lead() {
+        with_test_externalities(|| {
+            assert_ok!(test_benchmark_ban_forum_user_lead::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn test_ban_forum_user_moderator() {
+        with_test_externalities(|| {
+            assert_ok!(test_benchmark_ban_forum_user_moderator::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn test_unban_forum_user_lead() {
+        with_test_externalities(|| {
+            assert_ok!(test_benchmark_unban_forum_user_lead::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn test_unban_forum_user_moderator() {
+        with_test_externalities(|| {
+            assert_ok!(test_benchmark_unban_forum_user_moderator::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn test_update_thread_subscription() {
+        with_test_externalities(|| {
+            assert_ok!(test_benchmark_update_thread_subscription::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn test_update_category_subscription() {
+        with_test_externalities(|| {
+            assert_ok!(test_benchmark_update_category_subscription::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn test_unlock_poll_vote_stake() {
+        with_test_externalities(|| {
+            assert_ok!(test_benchmark_unlock_poll_vote_stake::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn test_update_category_privacy_lead() {
+        with_test_externalities(|| {
+            assert_ok!(test_benchmark_update_category_privacy_lead::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn test_update_category_privacy_moderator() {
+        with_test_externalities(|| {
+            assert_ok!(test_benchmark_update_category_privacy_moderator::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn test_update_category_whitelist_membership_lead() {
+        with_test_externalities(|| {
+            assert_ok!(test_benchmark_update_category_whitelist_membership_lead::<
+                Runtime,
+            >());
+        });
+    }
+
+    #[test]
+    fn test_update_category_whitelist_membership_moderator() {
+        with_test_externalities(|| {
+            assert_ok!(test_benchmark_update_category_whitelist_membership_moderator::<Runtime>());
+        });
+    }
 }
@@ -7,29 +7,35 @@ pub use serde::{Deserialize, Serialize};
 
 use codec::{Codec, Decode, Encode};
 pub use frame_support::dispatch::DispatchResult;
-use frame_support::traits::{Currency, ExistenceRequirement};
+use frame_support::storage::IterableStorageDoubleMap;
+use frame_support::traits::{
+    Currency, ExistenceRequirement, LockIdentifier, LockableCurrency, WithdrawReasons,
+};
 use frame_support::weights::Weight;
 use frame_support::{
     decl_error, decl_event, decl_module, decl_storage, ensure, traits::Get, Parameter,
 };
 use frame_system::ensure_signed;
-use sp_arithmetic::traits::{BaseArithmetic, One};
+use sp_arithmetic::traits::{BaseArithmetic, One, Saturating, Zero};
 pub use sp_io::storage::clear_prefix;
 use sp_runtime::traits::{AccountIdConversion, MaybeSerialize, Member};
 use sp_runtime::{ModuleId, SaturatedConversion};
+use sp_std::cell::Cell;
 use sp_std::collections::btree_map::BTreeMap;
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::fmt::Debug;
 use sp_std::prelude::*;
 
 use common::membership::MemberOriginValidator;
-use common::working_group::WorkingGroupAuthenticator;
+use common::working_group::{DeputyPermission, WorkingGroupAuthenticator};
 
 mod mock;
 mod tests;
 
 mod benchmarking;
 
+pub mod runtime_api;
+
 /// Type for keeping track of number of posts in a thread
 pub type NumberOfPosts = u64;
 
@@ -52,6 +58,48 @@ pub type ThreadOf<T> = Thread<
     BalanceOf<T>,
 >;
 
+/// Alias for the post
+pub type PostOf<T> = Post<
+    ForumUserId<T>,
+    <T as Trait>::ThreadId,
+    <T as Trait>::PostId,
+    <T as frame_system::Trait>::Hash,
+    BalanceOf<T>,
+    <T as frame_system::Trait>::BlockNumber,
+>;
+
+/// Alias for the thread in the format preceding the multi-choice and weighted polls
+type OldThreadOf<T> = OldThread<
+    ForumUserId<T>,
+    <T as Trait>::CategoryId,
+    <T as pallet_timestamp::Trait>::Moment,
+    <T as frame_system::Trait>::Hash,
+    BalanceOf<T>,
+>;
+
+/// Alias for the post in the format preceding the post replies
+type OldPostOf<T> = OldPost<
+    ForumUserId<T>,
+    <T as Trait>::ThreadId,
+    <T as frame_system::Trait>::Hash,
+    BalanceOf<T>,
+    <T as frame_system::Trait>::BlockNumber,
+>;
+
+/// Alias for the forum user ban
+pub type ForumUserBanOf<T> =
+    ForumUserBan<<T as frame_system::Trait>::BlockNumber, <T as frame_system::Trait>::Hash>;
+
+/// Alias for the moderation log entry
+pub type ModerationLogEntryOf<T> = ModerationLogEntry<
+    <T as Trait>::CategoryId,
+    <T as Trait>::ThreadId,
+    <T as Trait>::PostId,
+    ForumUserId<T>,
+    <T as frame_system::Trait>::Hash,
+    <T as frame_system::Trait>::BlockNumber,
+>;
+
 /// Type alias for `ExtendedPostIdObject`
 pub type ExtendedPostId<T> =
     ExtendedPostIdObject<<T as Trait>::CategoryId, <T as Trait>::ThreadId, <T as Trait>::PostId>;
@@ -81,7 +129,7 @@ pub trait WeightInfo {
     fn update_category_description_moderator(i: u32, j: u32) -> Weight;
     fn delete_category_lead(i: u32) -> Weight;
     fn delete_category_moderator(i: u32) -> Weight;
-    fn create_thread(j: u32, k: u32, i: u32) -> Weight;
+    fn create_thread(j: u32, k: u32, i: u32, s: u32) -> Weight;
     fn edit_thread_metadata(i: u32, j: u32) -> Weight;
     fn delete_thread(i: u32) -> Weight;
     fn move_thread_to_category_lead(i: u32) -> Weight;
@@ -89,14 +137,25 @@ pub trait WeightInfo {
     fn vote_on_poll(i: u32, j: u32) -> Weight;
     fn moderate_thread_lead(i: u32, k: u32) -> Weight;
     fn moderate_thread_moderator(i: u32, k: u32) -> Weight;
-    fn add_post(i: u32, j: u32) -> Weight;
+    fn add_post(i: u32, j: u32, k: u32, l: u32, s: u32) -> Weight;
     fn react_post(i: u32) -> Weight;
     fn edit_post_text(i: u32, j: u32) -> Weight;
-    fn moderate_post_lead(i: u32, j: u32) -> Weight;
-    fn moderate_post_moderator(i: u32, j: u32) -> Weight;
-    fn delete_posts(i: u32, j: u32, k: u32) -> Weight;
+    fn moderate_post_lead(i: u32, j: u32, k: u32) -> Weight;
+    fn moderate_post_moderator(i: u32, j: u32, k: u32) -> Weight;
+    fn delete_posts(i: u32, j: u32, k: u32, l: u32) -> Weight;
     fn set_stickied_threads_lead(i: u32, j: u32) -> Weight;
     fn set_stickied_threads_moderator(i: u32, j: u32) -> Weight;
+    fn ban_forum_user_lead(i: u32, j: u32) -> Weight;
+    fn ban_forum_user_moderator(i: u32, j: u32) -> Weight;
+    fn unban_forum_user_lead(i: u32) -> Weight;
+    fn unban_forum_user_moderator(i: u32) -> Weight;
+    fn update_thread_subscription(s: u32) -> Weight;
+    fn update_category_subscription(s: u32) -> Weight;
+    fn unlock_poll_vote_stake() -> Weight;
+    fn update_category_privacy_lead(i: u32) -> Weight;
+    fn update_category_privacy_moderator(i: u32) -> Weight;
+    fn update_category_whitelist_membership_lead(i: u32) -> Weight;
+    fn update_category_whitelist_membership_moderator(i: u32) -> Weight;
 }
 
 pub trait Trait:
@@ -164,12 +223,18 @@ pub trait Trait:
     /// Maximum number of blocks before a post can be erased by anyone
     type PostLifeTime: Get<Self::BlockNumber>;
 
+    /// Number of blocks the moderation log entries are kept for
+    type ModerationLogLifeTime: Get<Self::BlockNumber>;
+
     /// Type defining the limits for different Storage items in the forum pallet
     type MapLimits: StorageLimits;
 
     /// The forum module Id, used to derive the account Id to hold the thread bounty
     type ModuleId: Get<ModuleId>;
 
+    /// Lock identifier of the stake backing the stake-weighted poll votes
+    type PollVoteLockId: Get<LockIdentifier>;
+
     /// Weight information for extrinsics in this pallet.
     type WeightInfo: WeightInfo;
 
@@ -184,6 +249,10 @@ pub trait Trait:
     >;
 
     fn calculate_hash(text: &[u8]) -> Self::Hash;
+
+    /// Returns whether the member has a valid (non-expired) verification, used for weighting
+    /// the poll votes
+    fn is_verified_member(member_id: &ForumUserId<Self>) -> bool;
 }
 
 /// Upper bounds for storage maps and double maps. Needed to prevent potential block exhaustion during deletion, etc.
@@ -201,6 +270,44 @@ pub trait StorageLimits {
 
     /// Maximum number of poll alternatives
     type MaxPollAlternativesNumber: Get<u64>;
+
+    /// Maximum number of subscribers for a single thread or category
+    type MaxSubscribers: Get<u64>;
+
+    /// Maximum number of members mentioned in a single post
+    type MaxMentionsPerPost: Get<u64>;
+
+    /// Maximum number of direct replies to a single post
+    type MaxRepliesPerPost: Get<u64>;
+
+    /// Maximum number of posts quoted in a single post
+    type MaxQuotesPerPost: Get<u64>;
+
+    /// Maximum number of whitelisted members of a single private category
+    type MaxWhitelistedMembers: Get<u64>;
+
+    /// Maximum number of entries in the moderation log of a single moderator
+    type MaxModerationLogSize: Get<u64>;
+}
+
+/// Defines how the poll votes are weighted
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PollVoteWeighting {
+    /// Every vote has the weight of one
+    Equal,
+
+    /// Vote weight equals the voter account balance locked for the poll until its end
+    LockedStake,
+
+    /// Votes of verified members have the provided weight, other votes have the weight of one
+    VerifiedMembership(u32),
+}
+
+impl Default for PollVoteWeighting {
+    fn default() -> Self {
+        Self::Equal
+    }
 }
 
 /// Represents all poll alternative text hashes and vote count for each one
@@ -212,8 +319,26 @@ pub struct PollAlternative<Hash> {
 
     /// Vote count for the alternative
     pub vote_count: u32,
+
+    /// Total weight of the votes for the alternative
+    pub vote_weight: u128,
+}
+
+/// Stake locked on the voter account for the stake-weighted poll votes
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct PollVoteLock<Balance, Timestamp> {
+    /// Balance backing the vote weight
+    pub amount: Balance,
+
+    /// Timestamp of the latest end of the polls voted for with the stake
+    pub unlocks_at: Timestamp,
 }
 
+/// Alias for the poll vote lock
+pub type PollVoteLockOf<T> =
+    PollVoteLock<<T as balances::Trait>::Balance, <T as pallet_timestamp::Trait>::Moment>;
+
 /// Represents a poll input
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
@@ -226,6 +351,12 @@ pub struct PollInput<Timestamp> {
 
     /// Alternative polls description
     pub poll_alternatives: Vec<Vec<u8>>,
+
+    /// Maximum number of alternatives a single forum user can vote for
+    pub max_choices: u32,
+
+    /// Defines how the votes are weighted
+    pub vote_weighting: PollVoteWeighting,
 }
 
 /// Represents a poll
@@ -240,12 +371,65 @@ pub struct Poll<Timestamp, Hash> {
 
     /// Alternative description and count
     pub poll_alternatives: Vec<PollAlternative<Hash>>,
+
+    /// Maximum number of alternatives a single forum user can vote for
+    pub max_choices: u32,
+
+    /// Defines how the votes are weighted
+    pub vote_weighting: PollVoteWeighting,
+}
+
+/// Poll alternative in the format preceding the weighted polls.
+/// Used by the stored threads migration only.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub(crate) struct OldPollAlternative<Hash> {
+    /// hash of alternative description
+    pub alternative_text_hash: Hash,
+
+    /// Vote count for the alternative
+    pub vote_count: u32,
+}
+
+/// Poll in the format preceding the multi-choice and weighted polls.
+/// Used by the stored threads migration only.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub(crate) struct OldPoll<Timestamp, Hash> {
+    /// hash of description
+    pub description_hash: Hash,
+
+    /// timestamp of poll end
+    pub end_time: Timestamp,
+
+    /// Alternative description and count
+    pub poll_alternatives: Vec<OldPollAlternative<Hash>>,
 }
 
 /// Represents a thread post
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
-pub struct Post<ForumUserId, ThreadId, Hash, Balance, BlockNumber> {
+pub struct Post<ForumUserId, ThreadId, PostId, Hash, Balance, BlockNumber> {
+    /// Id of thread to which this post corresponds.
+    pub thread_id: ThreadId,
+
+    /// Id of the post (in the same thread) this post replies to.
+    pub parent_post_id: Option<PostId>,
+
+    /// Hash of current text
+    pub text_hash: Hash,
+
+    /// Author of post.
+    pub author_id: ForumUserId,
+
+    /// Cleanup pay off
+    pub cleanup_pay_off: Balance,
+
+    /// When it was created or last edited
+    pub last_edited: BlockNumber,
+}
+
+/// Thread post in the format preceding the post replies. Used by the stored posts migration only.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub(crate) struct OldPost<ForumUserId, ThreadId, Hash, Balance, BlockNumber> {
     /// Id of thread to which this post corresponds.
     pub thread_id: ThreadId,
 
@@ -282,6 +466,26 @@ pub struct Thread<ForumUserId, CategoryId, Moment, Hash, Balance> {
     pub number_of_posts: NumberOfPosts,
 }
 
+/// Thread in the format preceding the multi-choice and weighted polls.
+/// Used by the stored threads migration only.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug, Eq)]
+pub(crate) struct OldThread<ForumUserId, CategoryId, Moment, Hash, Balance> {
+    /// Category in which this thread lives
+    pub category_id: CategoryId,
+
+    /// Author of post.
+    pub author_id: ForumUserId,
+
+    /// poll description.
+    pub poll: Option<OldPoll<Moment, Hash>>,
+
+    /// Pay off by deleting
+    pub cleanup_pay_off: Balance,
+
+    /// Number of posts in the thread
+    pub number_of_posts: NumberOfPosts,
+}
+
 /// Represents a category
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
@@ -310,6 +514,69 @@ pub struct Category<CategoryId, ThreadId, Hash> {
     pub sticky_thread_ids: Vec<ThreadId>,
 }
 
+/// Represents a ban of the forum user in a category and all of its subcategories
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct ForumUserBan<BlockNumber, Hash> {
+    /// Block at which the ban was issued
+    pub banned_at: BlockNumber,
+
+    /// Block at which the suspension ends, the ban is permanent if not set
+    pub expires_at: Option<BlockNumber>,
+
+    /// Hash of the ban rationale
+    pub rationale_hash: Hash,
+}
+
+impl<BlockNumber: PartialOrd, Hash> ForumUserBan<BlockNumber, Hash> {
+    /// Whether the ban is still in effect at the provided block
+    pub fn is_active(&self, now: &BlockNumber) -> bool {
+        self.expires_at
+            .as_ref()
+            .map_or(true, |expires_at| now < expires_at)
+    }
+}
+
+/// Moderation action with its target ids
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub enum ModerationAction<CategoryId, ThreadId, PostId, ForumUserId> {
+    /// Category was deleted
+    CategoryDeleted(CategoryId),
+
+    /// Thread was moved from the first category to the second one
+    ThreadMoved(CategoryId, ThreadId, CategoryId),
+
+    /// Thread was moderated
+    ThreadModerated(CategoryId, ThreadId),
+
+    /// Post was moderated
+    PostModerated(CategoryId, ThreadId, PostId),
+
+    /// Sticky threads of the category were set
+    StickiedThreadsSet(CategoryId, Vec<ThreadId>),
+
+    /// Forum user was banned in the category
+    ForumUserBanned(CategoryId, ForumUserId),
+
+    /// Forum user ban in the category was lifted
+    ForumUserUnbanned(CategoryId, ForumUserId),
+}
+
+/// Represents a moderation action in the moderator log
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct ModerationLogEntry<CategoryId, ThreadId, PostId, ForumUserId, Hash, BlockNumber> {
+    /// Performed action with its target ids
+    pub action: ModerationAction<CategoryId, ThreadId, PostId, ForumUserId>,
+
+    /// Hash of the action rationale, if the action has one
+    pub rationale_hash: Option<Hash>,
+
+    /// Block at which the action was performed
+    pub block: BlockNumber,
+}
+
 #[derive(Encode, Decode, Clone, PartialEq, Eq)]
 pub enum PrivilegedActor<T: Trait> {
     Lead,
@@ -433,6 +700,27 @@ decl_error! {
         /// Forum user has already voted.
         AlreadyVotedOnPoll,
 
+        /// Forum user has already voted for the maximum number of poll alternatives.
+        PollChoicesLimitReached,
+
+        /// Maximum number of poll choices should be between one and the number of alternatives.
+        PollMaxChoicesInvalid,
+
+        /// Verified membership vote weight should be greater than zero.
+        PollVoteWeightInvalid,
+
+        /// Stake-backed poll vote requires a usable or already locked balance on the voter account.
+        InsufficientStakeForPollVote,
+
+        /// The voter account stake was already used by another forum user in the poll.
+        PollVoteStakeAlreadyUsed,
+
+        /// The voter account has no stake locked for the poll votes.
+        PollVoteStakeNotLocked,
+
+        /// The stake is locked until the end of the polls it was used in.
+        PollVoteStakeStillLocked,
+
         // Error data migration
 
         /// data migration not done yet.
@@ -445,6 +733,48 @@ decl_error! {
 
         /// Category path len should be greater than zero
         PathLengthShouldBeGreaterThanZero,
+
+        // Errors about bans.
+
+        /// Forum user is banned in the category or one of its ancestors.
+        ForumUserBanned,
+
+        /// Forum user is not banned in the category.
+        ForumUserNotBanned,
+
+        /// Ban duration should be greater than zero.
+        InvalidBanDuration,
+
+        // Errors about subscriptions.
+
+        /// Forum user is already subscribed.
+        AlreadySubscribed,
+
+        /// Forum user is not subscribed.
+        NotSubscribed,
+
+        /// Number of members mentioned in the post exceeds the limit.
+        MaxMentionsPerPostExceeded,
+
+        /// Post to reply to does not exist in the thread.
+        ParentPostDoesNotExist,
+
+        /// Quoted post does not exist.
+        QuotedPostDoesNotExist,
+
+        /// Number of posts quoted in the post exceeds the limit.
+        MaxQuotesPerPostExceeded,
+
+        // Errors about private categories.
+
+        /// Category is not private.
+        CategoryNotPrivate,
+
+        /// Forum user is not whitelisted in the private category or one of its private ancestors.
+        ForumUserNotWhitelisted,
+
+        /// Forum user is already whitelisted in the private category.
+        ForumUserAlreadyWhitelisted,
     }
 }
 
@@ -481,16 +811,50 @@ decl_storage! {
             hasher(blake2_128_concat) T::ThreadId,
             hasher(blake2_128_concat) ForumUserId<T> => bool;
 
+        /// Poll alternative indices chosen by the forum user. Limits the choices number for
+        /// the multi-choice polls.
+        PollChoices get(fn poll_choices_by_thread_id_by_forum_user_id): double_map
+            hasher(blake2_128_concat) T::ThreadId,
+            hasher(blake2_128_concat) ForumUserId<T> => Vec<u32>;
+
+        /// Forum user who used the account stake in the stake-weighted poll. Prevents the same
+        /// stake from backing the votes of several forum users.
+        PollStakeVoters get(fn poll_stake_voter_by_thread_id_by_account_id): double_map
+            hasher(blake2_128_concat) T::ThreadId,
+            hasher(blake2_128_concat) T::AccountId => Option<ForumUserId<T>>;
+
+        /// Stake locked for the stake-weighted poll votes by the voter account
+        pub PollVoteLocks get(fn poll_vote_lock_by_account_id): map
+            hasher(blake2_128_concat) T::AccountId => Option<PollVoteLockOf<T>>;
+
         /// Map post identifier to corresponding post.
         pub PostById get(fn post_by_id) config(): double_map hasher(blake2_128_concat) T::ThreadId,
-            hasher(blake2_128_concat) T::PostId =>
-                                                Post<
-                                                    ForumUserId<T>,
-                                                    T::ThreadId,
-                                                    T::Hash,
-                                                    BalanceOf<T>,
-                                                    T::BlockNumber
-                                                >;
+            hasher(blake2_128_concat) T::PostId => PostOf<T>;
+
+        /// Direct replies to the post
+        pub PostReplies get(fn post_replies): double_map hasher(blake2_128_concat) T::ThreadId,
+            hasher(blake2_128_concat) T::PostId => BTreeSet<T::PostId>;
+
+        /// Forum user bans (permanent or timed) for each category and its subcategories
+        pub ForumUserBanByCategory get(fn forum_user_ban_by_category): double_map
+            hasher(blake2_128_concat) T::CategoryId,
+            hasher(blake2_128_concat) ForumUserId<T> => ForumUserBanOf<T>;
+
+        /// Forum users subscribed to the thread posts
+        pub ThreadSubscribers get(fn thread_subscribers): map
+            hasher(blake2_128_concat) T::ThreadId => BTreeSet<ForumUserId<T>>;
+
+        /// Forum users subscribed to the posts in the category and its subcategories
+        pub CategorySubscribers get(fn category_subscribers): map
+            hasher(blake2_128_concat) T::CategoryId => BTreeSet<ForumUserId<T>>;
+
+        /// Whitelisted members of the private categories, public categories have no entry
+        pub PrivateCategoryWhitelist get(fn private_category_whitelist): map
+            hasher(blake2_128_concat) T::CategoryId => Option<BTreeSet<ForumUserId<T>>>;
+
+        /// Recent moderation actions of each moderator, oldest first
+        pub ModerationLog get(fn moderation_log_by_moderator_id): map
+            hasher(blake2_128_concat) ModeratorId<T> => Vec<ModerationLogEntryOf<T>>;
     }
 }
 
@@ -507,6 +871,8 @@ decl_event!(
         PrivilegedActor = PrivilegedActor<T>,
         ExtendedPostId = ExtendedPostId<T>,
         PollInput = PollInput<<T as pallet_timestamp::Trait>::Moment>,
+        <T as frame_system::Trait>::BlockNumber,
+        <T as frame_system::Trait>::AccountId,
     {
         /// A category was introduced
         CategoryCreated(CategoryId, Option<CategoryId>, Vec<u8>, Vec<u8>),
@@ -549,6 +915,10 @@ decl_event!(
         /// Post with given id was created.
         PostAdded(PostId, ForumUserId, CategoryId, ThreadId, Vec<u8>, bool),
 
+        /// Post with given id was added as a reply to a post of the same thread
+        /// and/or quoting other posts.
+        PostReferencesAdded(PostId, CategoryId, ThreadId, Option<PostId>, BTreeSet<ExtendedPostId>),
+
         /// Post with givne id was moderated.
         PostModerated(PostId, Vec<u8>, PrivilegedActor, CategoryId, ThreadId),
 
@@ -570,6 +940,39 @@ decl_event!(
 
         /// An moderator ability to moderate a category and its subcategories updated
         CategoryMembershipOfModeratorUpdated(ModeratorId, CategoryId, bool),
+
+        /// A forum user was banned in the category and its subcategories.
+        /// The third argument reflects the block at which the suspension ends (none for a permanent ban).
+        ForumUserBanned(ForumUserId, CategoryId, Option<BlockNumber>, Vec<u8>, PrivilegedActor),
+
+        /// A forum user ban in the category was lifted.
+        ForumUserUnbanned(ForumUserId, CategoryId, PrivilegedActor),
+
+        /// A forum user subscription to the thread was updated.
+        /// The last argument reflects the new subscription status.
+        ThreadSubscriptionUpdated(ForumUserId, CategoryId, ThreadId, bool),
+
+        /// A forum user subscription to the category was updated.
+        /// The last argument reflects the new subscription status.
+        CategorySubscriptionUpdated(ForumUserId, CategoryId, bool),
+
+        /// Subscribers of the thread and its categories were notified about the new post.
+        PostSubscribersNotified(PostId, CategoryId, ThreadId, BTreeSet<ForumUserId>),
+
+        /// Members were mentioned in the new post.
+        /// The second argument reflects the post author.
+        MembersMentioned(PostId, ForumUserId, CategoryId, ThreadId, BTreeSet<ForumUserId>),
+
+        /// A privacy of category with given id was updated.
+        /// The second argument reflects whether the category is now private.
+        CategoryPrivacyUpdated(CategoryId, bool, PrivilegedActor),
+
+        /// A forum user membership in the private category whitelist was updated.
+        /// The third argument reflects the new membership status.
+        CategoryWhitelistMembershipUpdated(CategoryId, ForumUserId, bool, PrivilegedActor),
+
+        /// The stake locked for the poll votes was released from the account.
+        PollVoteStakeUnlocked(AccountId),
     }
 );
 
@@ -595,6 +998,27 @@ decl_module! {
         /// MaxCategories
         const MaxCategories: u64 = <T::MapLimits as StorageLimits>::MaxCategories::get();
 
+        /// MaxSubscribers
+        const MaxSubscribers: u64 = <T::MapLimits as StorageLimits>::MaxSubscribers::get();
+
+        /// MaxMentionsPerPost
+        const MaxMentionsPerPost: u64 = <T::MapLimits as StorageLimits>::MaxMentionsPerPost::get();
+
+        /// MaxRepliesPerPost
+        const MaxRepliesPerPost: u64 = <T::MapLimits as StorageLimits>::MaxRepliesPerPost::get();
+
+        /// MaxQuotesPerPost
+        const MaxQuotesPerPost: u64 = <T::MapLimits as StorageLimits>::MaxQuotesPerPost::get();
+
+        /// MaxWhitelistedMembers
+        const MaxWhitelistedMembers: u64 = <T::MapLimits as StorageLimits>::MaxWhitelistedMembers::get();
+
+        /// MaxModerationLogSize
+        const MaxModerationLogSize: u64 = <T::MapLimits as StorageLimits>::MaxModerationLogSize::get();
+
+        /// Number of blocks the moderation log entries are kept for
+        const ModerationLogLifeTime: T::BlockNumber = T::ModerationLogLifeTime::get();
+
         /// Enable a moderator can moderate a category and its sub categories.
         ///
         /// <weight>
@@ -878,6 +1302,8 @@ decl_module! {
 
             // Delete category
             <CategoryById<T>>::remove(category_id);
+            <CategorySubscribers<T>>::remove(category_id);
+            <PrivateCategoryWhitelist<T>>::remove(category_id);
             if let Some(parent_category_id) = category.parent_category_id {
                 <CategoryById<T>>::mutate(parent_category_id, |tmp_category| tmp_category.num_direct_subcategories -= 1);
             }
@@ -885,6 +1311,8 @@ decl_module! {
             // Update total category count
             <CategoryCounter<T>>::mutate(|value| *value -= One::one());
 
+            Self::log_moderation_action(&actor, ModerationAction::CategoryDeleted(category_id), None);
+
             // Store the event
             Self::deposit_event(RawEvent::CategoryDeleted(category_id, actor));
 
@@ -896,11 +1324,12 @@ decl_module! {
         /// <weight>
         ///
         /// ## Weight
-        /// `O (W + V + X + Y)` where:
+        /// `O (W + V + X + Y + S)` where:
         /// - `W` is the category depth
         /// - `V` is the length of the thread title.
         /// - `X` is the length of the thread text.
         /// - `Y` is the number of poll alternatives.
+        /// - `S` is the maximum number of subscribers of the categories in the path
         /// - DB:
         ///    - O(W)
         /// # </weight>
@@ -908,6 +1337,9 @@ decl_module! {
             metadata.len().saturated_into(),
             text.len().saturated_into(),
             T::MaxCategoryDepth::get() as u32,
+            T::MaxCategoryDepth::get().saturating_mul(
+                <<<T as Trait>::MapLimits as StorageLimits>::MaxSubscribers>::get()
+            ) as u32,
         )]
         fn create_thread(
             origin,
@@ -972,6 +1404,7 @@ decl_module! {
                 &text,
                 forum_user_id,
                 true,
+                None,
             );
 
             // Update next thread id
@@ -993,6 +1426,8 @@ decl_module! {
                 )
             );
 
+            Self::notify_post_subscribers(category_id, new_thread_id, initial_post_id, forum_user_id);
+
             Ok(())
         }
 
@@ -1137,6 +1572,12 @@ decl_module! {
             <CategoryById<T>>::mutate(thread.category_id, |category| category.num_direct_threads -= 1);
             <CategoryById<T>>::mutate(new_category_id, |category| category.num_direct_threads += 1);
 
+            Self::log_moderation_action(
+                &actor,
+                ModerationAction::ThreadMoved(category_id, thread_id, new_category_id),
+                None,
+            );
+
             // Store the event
             Self::deposit_event(
                 RawEvent::ThreadMoved(thread_id, new_category_id, actor, category_id)
@@ -1145,7 +1586,10 @@ decl_module! {
             Ok(())
         }
 
-        /// Submit a poll
+        /// Submit a poll. Multi-choice poll alternatives are voted for one per call, up to
+        /// the `max_choices` of the poll. The vote weight is calculated according to the poll
+        /// vote weighting at the moment of the vote, no votes are accepted after the poll end.
+        /// The stake-weighted votes lock the voter account balance until the poll end.
         ///
         /// <weight>
         ///
@@ -1174,9 +1618,19 @@ decl_module! {
 
             let category_id = thread.category_id;
 
+            // Ensure forum user is not banned in the category
+            Self::ensure_forum_user_is_not_banned(&forum_user_id, &category_id)?;
+
             // Make sure poll exist
             let poll = Self::ensure_vote_is_valid(thread, index, &thread_id, &forum_user_id)?;
 
+            let (vote_weight, poll_vote_lock) = Self::ensure_poll_vote_weight(
+                &account_id,
+                &forum_user_id,
+                &thread_id,
+                &poll,
+            )?;
+
             //
             // == MUTATION SAFE ==
             //
@@ -1189,6 +1643,7 @@ decl_module! {
                     { PollAlternative {
                         alternative_text_hash: old_value.alternative_text_hash,
                         vote_count: old_value.vote_count + 1,
+                        vote_weight: old_value.vote_weight.saturating_add(vote_weight),
                     }
                     } else {
                         old_value.clone()
@@ -1211,6 +1666,22 @@ decl_module! {
             // Update unique votes collection.
             <PollVotes<T>>::insert(&thread_id, &forum_user_id, true);
 
+            // Update the forum user choices.
+            <PollChoices<T>>::append(&thread_id, &forum_user_id, index);
+
+            // Lock the stake backing the vote until the poll end.
+            if let Some(poll_vote_lock) = poll_vote_lock {
+                Balances::<T>::set_lock(
+                    T::PollVoteLockId::get(),
+                    &account_id,
+                    Balances::<T>::free_balance(&account_id),
+                    WithdrawReasons::all(),
+                );
+
+                <PollVoteLocks<T>>::insert(&account_id, poll_vote_lock);
+                <PollStakeVoters<T>>::insert(&thread_id, &account_id, forum_user_id);
+            }
+
             // Store the event
             Self::deposit_event(
                 RawEvent::VoteOnPoll(thread_id, index, forum_user_id, category_id)
@@ -1258,6 +1729,12 @@ decl_module! {
             // Delete thread
             Self::delete_thread_inner(thread.category_id, thread_id);
 
+            Self::log_moderation_action(
+                &actor,
+                ModerationAction::ThreadModerated(category_id, thread_id),
+                Some(rationale.as_slice()),
+            );
+
             // Generate event
             Self::deposit_event(
                 RawEvent::ThreadModerated(thread_id, rationale, actor, category_id)
@@ -1266,20 +1743,30 @@ decl_module! {
             Ok(())
         }
 
-        /// Add post
+        /// Add post. Thread and category subscribers are notified about the post, and the
+        /// mentioned members get a mention event. The post can reply to another post of the
+        /// same thread and quote posts of any thread.
         ///
         /// <weight>
         ///
         /// ## Weight
-        /// `O (W + V)` where:
+        /// `O (W + V + X + Y + S)` where:
         /// - `W` is the category depth,
         /// - `V` is the length of the text
+        /// - `X` is the number of mentioned members
+        /// - `Y` is the number of quoted posts
+        /// - `S` is the maximum number of subscribers of the thread and the categories in the path
         /// - DB:
-        ///    - O(W)
+        ///    - O(W + Y)
         /// # </weight>
         #[weight = WeightInfoForum::<T>::add_post(
             T::MaxCategoryDepth::get() as u32,
             text.len().saturated_into(),
+            mentions.len().saturated_into(),
+            quotes.len().saturated_into(),
+            T::MaxCategoryDepth::get().saturating_add(1).saturating_mul(
+                <<<T as Trait>::MapLimits as StorageLimits>::MaxSubscribers>::get()
+            ) as u32,
         )]
         fn add_post(
             origin,
@@ -1288,6 +1775,9 @@ decl_module! {
             thread_id: T::ThreadId,
             text: Vec<u8>,
             editable: bool,
+            mentions: BTreeSet<ForumUserId<T>>,
+            parent_post_id: Option<T::PostId>,
+            quotes: BTreeSet<ExtendedPostId<T>>,
         ) -> DispatchResult {
             // Ensure data migration is done
             Self::ensure_data_migration_done()?;
@@ -1297,6 +1787,17 @@ decl_module! {
             // Make sure thread exists and is mutable
             let _ = Self::ensure_can_add_post(&account_id, &forum_user_id, &category_id, &thread_id)?;
 
+            ensure!(
+                mentions.len() as u64 <= <T::MapLimits as StorageLimits>::MaxMentionsPerPost::get(),
+                Error::<T>::MaxMentionsPerPostExceeded
+            );
+
+            if let Some(parent_post_id) = parent_post_id {
+                Self::ensure_can_reply_to_post(&category_id, &thread_id, &parent_post_id)?;
+            }
+
+            Self::ensure_quoted_posts_exist(&quotes)?;
+
             if editable {
                 ensure!(
                     Self::ensure_enough_balance(T::PostDeposit::get(), &account_id),
@@ -1325,6 +1826,7 @@ decl_module! {
                     text.as_slice(),
                     forum_user_id,
                     editable,
+                    parent_post_id,
                 );
 
             // Generate event
@@ -1332,6 +1834,20 @@ decl_module! {
                 RawEvent::PostAdded(post_id, forum_user_id, category_id, thread_id, text, editable)
             );
 
+            if parent_post_id.is_some() || !quotes.is_empty() {
+                Self::deposit_event(
+                    RawEvent::PostReferencesAdded(post_id, category_id, thread_id, parent_post_id, quotes)
+                );
+            }
+
+            Self::notify_post_subscribers(category_id, thread_id, post_id, forum_user_id);
+
+            if !mentions.is_empty() {
+                Self::deposit_event(
+                    RawEvent::MembersMentioned(post_id, forum_user_id, category_id, thread_id, mentions)
+                );
+            }
+
             Ok(())
         }
 
@@ -1360,6 +1876,9 @@ decl_module! {
             // Make sure the thread exists and is mutable
             Self::ensure_thread_is_mutable(&category_id, &thread_id)?;
 
+            // Ensure forum user is not banned in the category
+            Self::ensure_forum_user_is_not_banned(&forum_user_id, &category_id)?;
+
             //
             // == MUTATION SAFE ==
             //
@@ -1436,18 +1955,21 @@ decl_module! {
         /// <weight>
         ///
         /// ## Weight
-        /// `O (W + V)` where:
+        /// `O (W + V + R)` where:
         /// - `W` is the category depth,
         /// - `V` is the length of the rationale
+        /// - `R` is the maximum number of the post replies
         /// - DB:
-        ///    - O(W)
+        ///    - O(W + R)
         /// # </weight>
         #[weight = WeightInfoForum::<T>::moderate_post_lead(
             T::MaxCategoryDepth::get() as u32,
             rationale.len().saturated_into(),
+            <<<T as Trait>::MapLimits as StorageLimits>::MaxRepliesPerPost>::get() as u32,
         ).max(WeightInfoForum::<T>::moderate_post_moderator(
             T::MaxCategoryDepth::get() as u32,
             rationale.len().saturated_into(),
+            <<<T as Trait>::MapLimits as StorageLimits>::MaxRepliesPerPost>::get() as u32,
         ))]
         fn moderate_post(origin, actor: PrivilegedActor<T>, category_id: T::CategoryId, thread_id: T::ThreadId, post_id: T::PostId, rationale: Vec<u8>) -> DispatchResult {
             // Ensure data migration is done
@@ -1472,6 +1994,12 @@ decl_module! {
 
             Self::delete_post_inner(category_id, thread_id, post_id);
 
+            Self::log_moderation_action(
+                &actor,
+                ModerationAction::PostModerated(category_id, thread_id, post_id),
+                Some(rationale.as_slice()),
+            );
+
             // Generate event
             Self::deposit_event(
                 RawEvent::PostModerated(post_id, rationale, actor, category_id, thread_id)
@@ -1486,17 +2014,19 @@ decl_module! {
         /// where the last bool is whether you want to hide it apart from deleting it
         ///
         /// ## Weight
-        /// `O (W + V + P)` where:
+        /// `O (W + V + P * R)` where:
         /// - `W` is the category depth,
         /// - `V` is the length of the rationale
         /// - `P` is the number of posts to delete
+        /// - `R` is the maximum number of the post replies
         /// - DB:
-        ///    - O(W + P)
+        ///    - O(W + P * R)
         /// # </weight>
         #[weight = WeightInfoForum::<T>::delete_posts(
             T::MaxCategoryDepth::get() as u32,
             rationale.len().saturated_into(),
             posts.len().saturated_into(),
+            <<<T as Trait>::MapLimits as StorageLimits>::MaxRepliesPerPost>::get() as u32,
         )]
         fn delete_posts(
             origin,
@@ -1579,6 +2109,12 @@ decl_module! {
             // Update category
             <CategoryById<T>>::mutate(category_id, |category| category.sticky_thread_ids = stickied_ids.clone());
 
+            Self::log_moderation_action(
+                &actor,
+                ModerationAction::StickiedThreadsSet(category_id, stickied_ids.clone()),
+                None,
+            );
+
             // Generate event
             Self::deposit_event(
                 RawEvent::CategoryStickyThreadUpdate(category_id, stickied_ids, actor)
@@ -1586,39 +2122,489 @@ decl_module! {
 
             Ok(())
         }
-    }
-}
-
-impl<T: Trait> Module<T> {
-    /// Hash poll description and poll alternatives descriptions, coverting `PollInput` into `Poll`
-    fn from_poll_input(poll_input: PollInput<T::Moment>) -> Poll<T::Moment, T::Hash> {
-        Poll {
-            description_hash: T::calculate_hash(poll_input.description.as_slice()),
-            poll_alternatives: poll_input
-                .poll_alternatives
-                .into_iter()
-                .map(|poll_alternative| PollAlternative {
-                    alternative_text_hash: T::calculate_hash(poll_alternative.as_slice()),
-                    vote_count: 0,
-                })
-                .collect(),
-            end_time: poll_input.end_time,
-        }
-    }
-
-    fn slash_thread_account(thread_id: T::ThreadId, amount: BalanceOf<T>) {
-        let thread_account_id = T::ModuleId::get().into_sub_account(thread_id);
-        let _ = Balances::<T>::slash(&thread_account_id, amount);
-    }
 
-    fn pay_off(
-        thread_id: T::ThreadId,
-        amount: BalanceOf<T>,
-        account_id: &T::AccountId,
-    ) -> DispatchResult {
-        let state_cleanup_treasury_account = T::ModuleId::get().into_sub_account(thread_id);
-        <Balances<T> as Currency<T::AccountId>>::transfer(
-            &state_cleanup_treasury_account,
+        /// Ban or suspend forum user in the category and its subcategories.
+        /// The user is suspended for `duration` blocks or banned permanently if not set.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (W + V)` where:
+        /// - `W` is the category depth,
+        /// - `V` is the length of the rationale
+        /// - DB:
+        ///    - O(W)
+        /// # </weight>
+        #[weight = WeightInfoForum::<T>::ban_forum_user_lead(
+            T::MaxCategoryDepth::get() as u32,
+            rationale.len().saturated_into(),
+        ).max(
+            WeightInfoForum::<T>::ban_forum_user_moderator(
+                T::MaxCategoryDepth::get() as u32,
+                rationale.len().saturated_into(),
+            )
+        )]
+        fn ban_forum_user(
+            origin,
+            actor: PrivilegedActor<T>,
+            category_id: T::CategoryId,
+            forum_user_id: ForumUserId<T>,
+            duration: Option<T::BlockNumber>,
+            rationale: Vec<u8>,
+        ) -> DispatchResult {
+            // Ensure data migration is done
+            Self::ensure_data_migration_done()?;
+
+            let account_id = ensure_signed(origin)?;
+
+            Self::ensure_can_ban_forum_user(&account_id, &actor, &category_id, &duration)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let now = frame_system::Module::<T>::block_number();
+            let expires_at = duration.map(|duration| now.saturating_add(duration));
+
+            let ban = ForumUserBan {
+                banned_at: now,
+                expires_at,
+                rationale_hash: T::calculate_hash(rationale.as_slice()),
+            };
+
+            <ForumUserBanByCategory<T>>::insert(category_id, forum_user_id, ban);
+
+            Self::log_moderation_action(
+                &actor,
+                ModerationAction::ForumUserBanned(category_id, forum_user_id),
+                Some(rationale.as_slice()),
+            );
+
+            // Generate event
+            Self::deposit_event(
+                RawEvent::ForumUserBanned(forum_user_id, category_id, expires_at, rationale, actor)
+            );
+
+            Ok(())
+        }
+
+        /// Lift the forum user ban in the category.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (W)` where:
+        /// - `W` is the category depth,
+        /// - DB:
+        ///    - O(W)
+        /// # </weight>
+        #[weight = WeightInfoForum::<T>::unban_forum_user_lead(
+            T::MaxCategoryDepth::get() as u32,
+        ).max(WeightInfoForum::<T>::unban_forum_user_moderator(
+            T::MaxCategoryDepth::get() as u32,
+        ))]
+        fn unban_forum_user(
+            origin,
+            actor: PrivilegedActor<T>,
+            category_id: T::CategoryId,
+            forum_user_id: ForumUserId<T>,
+        ) -> DispatchResult {
+            // Ensure data migration is done
+            Self::ensure_data_migration_done()?;
+
+            let account_id = ensure_signed(origin)?;
+
+            // Ensure actor can moderate the category
+            Self::ensure_can_moderate_category(&account_id, &actor, &category_id)?;
+
+            ensure!(
+                <ForumUserBanByCategory<T>>::contains_key(category_id, forum_user_id),
+                Error::<T>::ForumUserNotBanned
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <ForumUserBanByCategory<T>>::remove(category_id, forum_user_id);
+
+            Self::log_moderation_action(
+                &actor,
+                ModerationAction::ForumUserUnbanned(category_id, forum_user_id),
+                None,
+            );
+
+            // Generate event
+            Self::deposit_event(
+                RawEvent::ForumUserUnbanned(forum_user_id, category_id, actor)
+            );
+
+            Ok(())
+        }
+
+        /// Make the category private or public. Only the whitelisted members can create threads
+        /// and posts in a private category and its subcategories.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (W)` where:
+        /// - `W` is the category depth
+        /// - DB:
+        ///    - O(W)
+        /// # </weight>
+        #[weight = WeightInfoForum::<T>::update_category_privacy_lead(
+            T::MaxCategoryDepth::get() as u32,
+        ).max(WeightInfoForum::<T>::update_category_privacy_moderator(
+            T::MaxCategoryDepth::get() as u32,
+        ))]
+        fn update_category_privacy(
+            origin,
+            actor: PrivilegedActor<T>,
+            category_id: T::CategoryId,
+            private: bool,
+        ) -> DispatchResult {
+            // Ensure data migration is done
+            Self::ensure_data_migration_done()?;
+
+            let account_id = ensure_signed(origin)?;
+
+            // Ensure actor can update category
+            Self::ensure_can_moderate_category(&account_id, &actor, &category_id)?;
+
+            // No change, invalid transaction
+            if private == <PrivateCategoryWhitelist<T>>::contains_key(category_id) {
+                return Err(Error::<T>::CategoryNotBeingUpdated.into())
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            if private {
+                <PrivateCategoryWhitelist<T>>::insert(category_id, BTreeSet::new());
+            } else {
+                <PrivateCategoryWhitelist<T>>::remove(category_id);
+            }
+
+            // Generate event
+            Self::deposit_event(
+                RawEvent::CategoryPrivacyUpdated(category_id, private, actor)
+            );
+
+            Ok(())
+        }
+
+        /// Add the forum user to or remove it from the private category whitelist.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (W)` where:
+        /// - `W` is the category depth
+        /// - DB:
+        ///    - O(W)
+        /// # </weight>
+        #[weight = WeightInfoForum::<T>::update_category_whitelist_membership_lead(
+            T::MaxCategoryDepth::get() as u32,
+        ).max(WeightInfoForum::<T>::update_category_whitelist_membership_moderator(
+            T::MaxCategoryDepth::get() as u32,
+        ))]
+        fn update_category_whitelist_membership(
+            origin,
+            actor: PrivilegedActor<T>,
+            category_id: T::CategoryId,
+            forum_user_id: ForumUserId<T>,
+            whitelisted: bool,
+        ) -> DispatchResult {
+            // Ensure data migration is done
+            Self::ensure_data_migration_done()?;
+
+            let account_id = ensure_signed(origin)?;
+
+            let mut whitelist = Self::ensure_can_update_category_whitelist(
+                &account_id,
+                &actor,
+                &category_id,
+                &forum_user_id,
+                whitelisted,
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            if whitelisted {
+                whitelist.insert(forum_user_id);
+            } else {
+                whitelist.remove(&forum_user_id);
+            }
+
+            <PrivateCategoryWhitelist<T>>::insert(category_id, whitelist);
+
+            // Generate event
+            Self::deposit_event(
+                RawEvent::CategoryWhitelistMembershipUpdated(category_id, forum_user_id, whitelisted, actor)
+            );
+
+            Ok(())
+        }
+
+        /// Subscribe to or unsubscribe from the thread posts.
+        /// Banned and non-whitelisted forum users can't subscribe.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (W + S)` where:
+        /// - `W` is the category depth,
+        /// - `S` is the maximum number of subscribers of a single thread.
+        /// - DB:
+        ///    - O(W)
+        /// # </weight>
+        #[weight = WeightInfoForum::<T>::update_thread_subscription(
+            <<<T as Trait>::MapLimits as StorageLimits>::MaxSubscribers>::get() as u32,
+        )]
+        fn update_thread_subscription(
+            origin,
+            forum_user_id: ForumUserId<T>,
+            category_id: T::CategoryId,
+            thread_id: T::ThreadId,
+            subscribe: bool,
+        ) -> DispatchResult {
+            // Ensure data migration is done
+            Self::ensure_data_migration_done()?;
+
+            let account_id = ensure_signed(origin)?;
+
+            // Check that account is forum member
+            Self::ensure_is_forum_user(&account_id, &forum_user_id)?;
+
+            Self::ensure_thread_exists(&category_id, &thread_id)?;
+
+            let mut subscribers = <ThreadSubscribers<T>>::get(thread_id);
+
+            Self::ensure_can_update_subscription(
+                &subscribers,
+                &forum_user_id,
+                &category_id,
+                subscribe,
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            if subscribe {
+                subscribers.insert(forum_user_id);
+            } else {
+                subscribers.remove(&forum_user_id);
+            }
+
+            <ThreadSubscribers<T>>::insert(thread_id, subscribers);
+
+            // Generate event
+            Self::deposit_event(
+                RawEvent::ThreadSubscriptionUpdated(forum_user_id, category_id, thread_id, subscribe)
+            );
+
+            Ok(())
+        }
+
+        /// Subscribe to or unsubscribe from the posts in the category and its subcategories.
+        /// Banned and non-whitelisted forum users can't subscribe.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (W + S)` where:
+        /// - `W` is the category depth,
+        /// - `S` is the maximum number of subscribers of a single category.
+        /// - DB:
+        ///    - O(W)
+        /// # </weight>
+        #[weight = WeightInfoForum::<T>::update_category_subscription(
+            <<<T as Trait>::MapLimits as StorageLimits>::MaxSubscribers>::get() as u32,
+        )]
+        fn update_category_subscription(
+            origin,
+            forum_user_id: ForumUserId<T>,
+            category_id: T::CategoryId,
+            subscribe: bool,
+        ) -> DispatchResult {
+            // Ensure data migration is done
+            Self::ensure_data_migration_done()?;
+
+            let account_id = ensure_signed(origin)?;
+
+            // Check that account is forum member
+            Self::ensure_is_forum_user(&account_id, &forum_user_id)?;
+
+            Self::ensure_category_exists(&category_id)?;
+
+            let mut subscribers = <CategorySubscribers<T>>::get(category_id);
+
+            Self::ensure_can_update_subscription(
+                &subscribers,
+                &forum_user_id,
+                &category_id,
+                subscribe,
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            if subscribe {
+                subscribers.insert(forum_user_id);
+            } else {
+                subscribers.remove(&forum_user_id);
+            }
+
+            <CategorySubscribers<T>>::insert(category_id, subscribers);
+
+            // Generate event
+            Self::deposit_event(
+                RawEvent::CategorySubscriptionUpdated(forum_user_id, category_id, subscribe)
+            );
+
+            Ok(())
+        }
+
+        /// Release the stake locked for the poll votes once all the polls it was used in ended.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoForum::<T>::unlock_poll_vote_stake()]
+        fn unlock_poll_vote_stake(origin) -> DispatchResult {
+            let account_id = ensure_signed(origin)?;
+
+            let poll_vote_lock = Self::poll_vote_lock_by_account_id(&account_id)
+                .ok_or(Error::<T>::PollVoteStakeNotLocked)?;
+
+            // Votes are accepted until the poll end inclusive.
+            ensure!(
+                poll_vote_lock.unlocks_at < <pallet_timestamp::Module<T>>::now(),
+                Error::<T>::PollVoteStakeStillLocked
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Balances::<T>::remove_lock(T::PollVoteLockId::get(), &account_id);
+
+            <PollVoteLocks<T>>::remove(&account_id);
+
+            // Generate event
+            Self::deposit_event(RawEvent::PollVoteStakeUnlocked(account_id));
+
+            Ok(())
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    /// Migrates the threads stored before the multi-choice and weighted polls were introduced.
+    /// The migrated polls are single-choice polls with equally weighted votes.
+    /// Possible application includes the runtime upgrade.
+    pub fn migrate_threads() -> Weight {
+        let migrated_threads_number = Cell::new(0 as Weight);
+
+        <ThreadById<T>>::translate(|_, _, old_thread: OldThreadOf<T>| {
+            migrated_threads_number.set(migrated_threads_number.get() + 1);
+
+            let poll = old_thread.poll.map(|old_poll| Poll {
+                description_hash: old_poll.description_hash,
+                end_time: old_poll.end_time,
+                poll_alternatives: old_poll
+                    .poll_alternatives
+                    .into_iter()
+                    .map(|old_alternative| PollAlternative {
+                        alternative_text_hash: old_alternative.alternative_text_hash,
+                        vote_count: old_alternative.vote_count,
+                        vote_weight: old_alternative.vote_count.into(),
+                    })
+                    .collect(),
+                max_choices: 1,
+                vote_weighting: PollVoteWeighting::Equal,
+            });
+
+            Some(Thread {
+                category_id: old_thread.category_id,
+                author_id: old_thread.author_id,
+                poll,
+                cleanup_pay_off: old_thread.cleanup_pay_off,
+                number_of_posts: old_thread.number_of_posts,
+            })
+        });
+
+        let migrated_threads_number = migrated_threads_number.get();
+
+        T::DbWeight::get().reads_writes(migrated_threads_number, migrated_threads_number)
+    }
+
+    /// Migrates the posts stored before the post replies were introduced. The migrated posts
+    /// don't reply to any post.
+    /// Possible application includes the runtime upgrade.
+    pub fn migrate_posts() -> Weight {
+        let migrated_posts_number = Cell::new(0 as Weight);
+
+        <PostById<T>>::translate(|_, _, old_post: OldPostOf<T>| {
+            migrated_posts_number.set(migrated_posts_number.get() + 1);
+
+            Some(Post {
+                thread_id: old_post.thread_id,
+                parent_post_id: None,
+                text_hash: old_post.text_hash,
+                author_id: old_post.author_id,
+                cleanup_pay_off: old_post.cleanup_pay_off,
+                last_edited: old_post.last_edited,
+            })
+        });
+
+        let migrated_posts_number = migrated_posts_number.get();
+
+        T::DbWeight::get().reads_writes(migrated_posts_number, migrated_posts_number)
+    }
+
+    /// Hash poll description and poll alternatives descriptions, coverting `PollInput` into `Poll`
+    fn from_poll_input(poll_input: PollInput<T::Moment>) -> Poll<T::Moment, T::Hash> {
+        Poll {
+            description_hash: T::calculate_hash(poll_input.description.as_slice()),
+            poll_alternatives: poll_input
+                .poll_alternatives
+                .into_iter()
+                .map(|poll_alternative| PollAlternative {
+                    alternative_text_hash: T::calculate_hash(poll_alternative.as_slice()),
+                    vote_count: 0,
+                    vote_weight: 0,
+                })
+                .collect(),
+            end_time: poll_input.end_time,
+            max_choices: poll_input.max_choices,
+            vote_weighting: poll_input.vote_weighting,
+        }
+    }
+
+    fn slash_thread_account(thread_id: T::ThreadId, amount: BalanceOf<T>) {
+        let thread_account_id = T::ModuleId::get().into_sub_account(thread_id);
+        let _ = Balances::<T>::slash(&thread_account_id, amount);
+    }
+
+    fn pay_off(
+        thread_id: T::ThreadId,
+        amount: BalanceOf<T>,
+        account_id: &T::AccountId,
+    ) -> DispatchResult {
+        let state_cleanup_treasury_account = T::ModuleId::get().into_sub_account(thread_id);
+        <Balances<T> as Currency<T::AccountId>>::transfer(
+            &state_cleanup_treasury_account,
             account_id,
             amount,
             ExistenceRequirement::AllowDeath,
@@ -1646,6 +2632,7 @@ impl<T: Trait> Module<T> {
         text: &[u8],
         author_id: ForumUserId<T>,
         editable: bool,
+        parent_post_id: Option<T::PostId>,
     ) -> T::PostId {
         // Make and add initial post
         let new_post_id = <NextPostId<T>>::get();
@@ -1658,12 +2645,20 @@ impl<T: Trait> Module<T> {
             let new_post = Post {
                 text_hash: T::calculate_hash(text),
                 thread_id,
+                parent_post_id,
                 author_id,
                 cleanup_pay_off: T::PostDeposit::get(),
                 last_edited: frame_system::Module::<T>::block_number(),
             };
 
             <PostById<T>>::insert(thread_id, new_post_id, new_post);
+
+            // Only the stored posts are recorded as replies.
+            if let Some(parent_post_id) = parent_post_id {
+                <PostReplies<T>>::mutate(thread_id, parent_post_id, |replies| {
+                    replies.insert(new_post_id);
+                });
+            }
         }
 
         let mut thread = <ThreadById<T>>::get(category_id, thread_id);
@@ -1674,12 +2669,45 @@ impl<T: Trait> Module<T> {
         new_post_id
     }
 
+    /// Notify the thread and its category path subscribers (except the author) about the new post
+    fn notify_post_subscribers(
+        category_id: T::CategoryId,
+        thread_id: T::ThreadId,
+        post_id: T::PostId,
+        author_id: ForumUserId<T>,
+    ) {
+        let mut subscribers = <ThreadSubscribers<T>>::get(thread_id);
+
+        for (path_category_id, _) in Self::build_category_tree_path(&category_id) {
+            subscribers.append(&mut <CategorySubscribers<T>>::get(path_category_id));
+        }
+
+        subscribers.remove(&author_id);
+
+        if !subscribers.is_empty() {
+            Self::deposit_event(RawEvent::PostSubscribersNotified(
+                post_id,
+                category_id,
+                thread_id,
+                subscribers,
+            ));
+        }
+    }
+
     fn delete_thread_inner(category_id: T::CategoryId, thread_id: T::ThreadId) {
         // Delete thread
         <ThreadById<T>>::remove(category_id, thread_id);
 
         // Remove all thread poll votes.
         <PollVotes<T>>::remove_prefix(thread_id);
+        <PollChoices<T>>::remove_prefix(thread_id);
+        <PollStakeVoters<T>>::remove_prefix(thread_id);
+
+        // Remove thread subscriptions.
+        <ThreadSubscribers<T>>::remove(thread_id);
+
+        // Remove the thread replies tree.
+        <PostReplies<T>>::remove_prefix(thread_id);
 
         // decrease category's thread counter
         <CategoryById<T>>::mutate(category_id, |category| category.num_direct_threads -= 1);
@@ -1693,9 +2721,78 @@ impl<T: Trait> Module<T> {
             <ThreadById<T>>::mutate(category_id, thread_id, |value| *value = thread);
         }
 
+        // Keep the replies tree consistent: direct replies are moved to the post's parent
+        // while it has room for them, the rest become the thread top level posts
+        let parent_post_id = <PostById<T>>::get(thread_id, post_id).parent_post_id;
+        let replies = <PostReplies<T>>::take(thread_id, post_id);
+        let max_replies = <<<T>::MapLimits as StorageLimits>::MaxRepliesPerPost>::get();
+
+        let mut parent_replies = parent_post_id.map(|parent_post_id| {
+            let mut parent_replies = <PostReplies<T>>::get(thread_id, parent_post_id);
+            parent_replies.remove(&post_id);
+            parent_replies
+        });
+
+        for reply_id in replies {
+            if !<PostById<T>>::contains_key(thread_id, reply_id) {
+                continue;
+            }
+
+            let new_parent_post_id = match parent_replies.as_mut() {
+                Some(parent_replies) if (parent_replies.len() as u64) < max_replies => {
+                    parent_replies.insert(reply_id);
+                    parent_post_id
+                }
+                _ => None,
+            };
+
+            <PostById<T>>::mutate(thread_id, reply_id, |reply| {
+                reply.parent_post_id = new_parent_post_id
+            });
+        }
+
+        if let (Some(parent_post_id), Some(parent_replies)) = (parent_post_id, parent_replies) {
+            if parent_replies.is_empty() {
+                <PostReplies<T>>::remove(thread_id, parent_post_id);
+            } else {
+                <PostReplies<T>>::insert(thread_id, parent_post_id, parent_replies);
+            }
+        }
+
         <PostById<T>>::remove(thread_id, post_id);
     }
 
+    // Ensure the post can be replied to by a new post
+    fn ensure_can_reply_to_post(
+        category_id: &T::CategoryId,
+        thread_id: &T::ThreadId,
+        parent_post_id: &T::PostId,
+    ) -> Result<(), Error<T>> {
+        Self::ensure_post_exists(category_id, thread_id, parent_post_id)
+            .map_err(|_| Error::<T>::ParentPostDoesNotExist)?;
+
+        Self::ensure_map_limits::<<<T>::MapLimits as StorageLimits>::MaxRepliesPerPost>(
+            <PostReplies<T>>::get(thread_id, parent_post_id).len() as u64,
+        )?;
+
+        Ok(())
+    }
+
+    // Ensure all the quoted posts exist
+    fn ensure_quoted_posts_exist(quotes: &BTreeSet<ExtendedPostId<T>>) -> Result<(), Error<T>> {
+        ensure!(
+            quotes.len() as u64 <= <T::MapLimits as StorageLimits>::MaxQuotesPerPost::get(),
+            Error::<T>::MaxQuotesPerPostExceeded
+        );
+
+        for quote in quotes.iter() {
+            Self::ensure_post_exists(&quote.category_id, &quote.thread_id, &quote.post_id)
+                .map_err(|_| Error::<T>::QuotedPostDoesNotExist)?;
+        }
+
+        Ok(())
+    }
+
     // Ensure poll is valid
     fn ensure_poll_input_is_valid(poll: &PollInput<T::Moment>) -> Result<(), Error<T>> {
         // Poll end time must larger than now
@@ -1703,6 +2800,16 @@ impl<T: Trait> Module<T> {
             return Err(Error::<T>::PollTimeSetting);
         }
 
+        // Forum user should be able to vote at least for one and at most for all the alternatives
+        ensure!(
+            poll.max_choices >= 1 && poll.max_choices as usize <= poll.poll_alternatives.len(),
+            Error::<T>::PollMaxChoicesInvalid
+        );
+
+        if let PollVoteWeighting::VerifiedMembership(verified_vote_weight) = poll.vote_weighting {
+            ensure!(verified_vote_weight > 0, Error::<T>::PollVoteWeightInvalid);
+        }
+
         Ok(())
     }
 
@@ -1724,8 +2831,7 @@ impl<T: Trait> Module<T> {
         category_id: &T::CategoryId,
         thread_id: &T::ThreadId,
         post_id: &T::PostId,
-    ) -> Result<Post<ForumUserId<T>, T::ThreadId, T::Hash, BalanceOf<T>, T::BlockNumber>, Error<T>>
-    {
+    ) -> Result<PostOf<T>, Error<T>> {
         // If the post is stored then it's mutable
         let post = Self::ensure_post_exists(category_id, thread_id, post_id)?;
 
@@ -1740,8 +2846,7 @@ impl<T: Trait> Module<T> {
         category_id: &T::CategoryId,
         thread_id: &T::ThreadId,
         post_id: &T::PostId,
-    ) -> Result<Post<ForumUserId<T>, T::ThreadId, T::Hash, BalanceOf<T>, T::BlockNumber>, Error<T>>
-    {
+    ) -> Result<PostOf<T>, Error<T>> {
         if !<ThreadById<T>>::contains_key(category_id, thread_id) {
             return Err(Error::<T>::PostDoesNotExist);
         }
@@ -1759,8 +2864,7 @@ impl<T: Trait> Module<T> {
         category_id: &T::CategoryId,
         thread_id: &T::ThreadId,
         post_id: &T::PostId,
-    ) -> Result<Post<ForumUserId<T>, T::ThreadId, T::Hash, BalanceOf<T>, T::BlockNumber>, Error<T>>
-    {
+    ) -> Result<PostOf<T>, Error<T>> {
         // Ensure the moderator can moderate the category
         Self::ensure_can_moderate_category(&account_id, &actor, &category_id)?;
 
@@ -1781,8 +2885,7 @@ impl<T: Trait> Module<T> {
         thread_id: &T::ThreadId,
         post_id: &T::PostId,
         hide: bool,
-    ) -> Result<Post<ForumUserId<T>, T::ThreadId, T::Hash, BalanceOf<T>, T::BlockNumber>, Error<T>>
-    {
+    ) -> Result<PostOf<T>, Error<T>> {
         let post = if Self::thread_exists(category_id, thread_id) {
             Self::ensure_post_is_mutable(&category_id, &thread_id, &post_id)?
         } else {
@@ -1803,7 +2906,7 @@ impl<T: Trait> Module<T> {
     }
 
     fn anyone_can_delete_post(
-        post: &Post<ForumUserId<T>, T::ThreadId, T::Hash, BalanceOf<T>, T::BlockNumber>,
+        post: &PostOf<T>,
         thread_id: &T::ThreadId,
         category_id: &T::CategoryId,
     ) -> bool {
@@ -1884,9 +2987,12 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
-    // Ensure forum user is lead - check via account
+    // Ensure forum user is lead or deputy acting as the forum lead - check via account
     fn ensure_is_forum_lead_account(account_id: &T::AccountId) -> Result<(), Error<T>> {
-        let is_lead = T::WorkingGroup::is_leader_account_id(account_id);
+        let is_lead = T::WorkingGroup::is_leader_or_deputy_account_id(
+            account_id,
+            DeputyPermission::ManageForum,
+        );
 
         ensure!(is_lead, Error::<T>::OriginNotForumLead);
         Ok(())
@@ -1976,13 +3082,26 @@ impl<T: Trait> Module<T> {
     ) -> Result<Category<T::CategoryId, T::ThreadId, T::Hash>, Error<T>> {
         let category_tree_path = Self::build_category_tree_path(&category_id);
 
-        Self::ensure_can_mutate_in_path_leaf(&category_tree_path)?;
+        Self::ensure_can_mutate_in_path_leaf(&category_tree_path, None)?;
+
+        Ok(category_tree_path[0].1.clone())
+    }
+
+    // Ensure the category is mutable and the forum user is whitelisted in its private ancestors
+    fn ensure_category_is_mutable_by(
+        category_id: &T::CategoryId,
+        forum_user_id: &ForumUserId<T>,
+    ) -> Result<Category<T::CategoryId, T::ThreadId, T::Hash>, Error<T>> {
+        let category_tree_path = Self::build_category_tree_path(&category_id);
+
+        Self::ensure_can_mutate_in_path_leaf(&category_tree_path, Some(forum_user_id))?;
 
         Ok(category_tree_path[0].1.clone())
     }
 
     fn ensure_can_mutate_in_path_leaf(
         category_tree_path: &CategoryTreePathArg<T::CategoryId, T::ThreadId, T::Hash>,
+        forum_user_id: Option<&ForumUserId<T>>,
     ) -> Result<(), Error<T>> {
         // Is parent category directly or indirectly deleted or archived category
         ensure!(
@@ -1992,6 +3111,16 @@ impl<T: Trait> Module<T> {
             Error::<T>::AncestorCategoryImmutable
         );
 
+        // Forum user must be whitelisted in every private category of the path
+        if let Some(forum_user_id) = forum_user_id {
+            let is_whitelisted = category_tree_path.iter().all(|(category_id, _)| {
+                <PrivateCategoryWhitelist<T>>::get(category_id)
+                    .map_or(true, |whitelist| whitelist.contains(forum_user_id))
+            });
+
+            ensure!(is_whitelisted, Error::<T>::ForumUserNotWhitelisted);
+        }
+
         Ok(())
     }
 
@@ -2009,7 +3138,7 @@ impl<T: Trait> Module<T> {
             return Err(Error::<T>::MaxValidCategoryDepthExceeded);
         }
 
-        Self::ensure_can_mutate_in_path_leaf(&category_tree_path)?;
+        Self::ensure_can_mutate_in_path_leaf(&category_tree_path, None)?;
 
         Ok(())
     }
@@ -2227,7 +3356,10 @@ impl<T: Trait> Module<T> {
 
         Self::ensure_category_exists(category_id)?;
 
-        let category = Self::ensure_category_is_mutable(category_id)?;
+        let category = Self::ensure_category_is_mutable_by(category_id, forum_user_id)?;
+
+        // Ensure forum user is not banned in the category
+        Self::ensure_forum_user_is_not_banned(forum_user_id, category_id)?;
 
         // The balance for creation of thread is the base cost plus the cost of a single post
         let minimum_balance = T::ThreadDeposit::get() + T::PostDeposit::get();
@@ -2252,7 +3384,12 @@ impl<T: Trait> Module<T> {
         // Check that account is forum member
         Self::ensure_is_forum_user(account_id, &forum_user_id)?;
 
-        let (category, thread) = Self::ensure_thread_is_mutable(category_id, thread_id)?;
+        let thread = Self::ensure_thread_exists(category_id, thread_id)?;
+
+        let category = Self::ensure_category_is_mutable_by(category_id, forum_user_id)?;
+
+        // Ensure forum user is not banned in the category
+        Self::ensure_forum_user_is_not_banned(forum_user_id, category_id)?;
 
         Ok((category, thread))
     }
@@ -2285,6 +3422,110 @@ impl<T: Trait> Module<T> {
         Ok(category)
     }
 
+    fn ensure_can_ban_forum_user(
+        account_id: &T::AccountId,
+        actor: &PrivilegedActor<T>,
+        category_id: &T::CategoryId,
+        duration: &Option<T::BlockNumber>,
+    ) -> Result<Category<T::CategoryId, T::ThreadId, T::Hash>, Error<T>> {
+        // Ensure actor can moderate the category
+        let category = Self::ensure_can_moderate_category(account_id, actor, category_id)?;
+
+        if let Some(duration) = duration {
+            ensure!(!duration.is_zero(), Error::<T>::InvalidBanDuration);
+        }
+
+        Ok(category)
+    }
+
+    fn ensure_can_update_category_whitelist(
+        account_id: &T::AccountId,
+        actor: &PrivilegedActor<T>,
+        category_id: &T::CategoryId,
+        forum_user_id: &ForumUserId<T>,
+        whitelisted: bool,
+    ) -> Result<BTreeSet<ForumUserId<T>>, Error<T>> {
+        // Ensure actor can update category
+        Self::ensure_can_moderate_category(account_id, actor, category_id)?;
+
+        let whitelist = <PrivateCategoryWhitelist<T>>::get(category_id)
+            .ok_or(Error::<T>::CategoryNotPrivate)?;
+
+        if whitelisted {
+            ensure!(
+                !whitelist.contains(forum_user_id),
+                Error::<T>::ForumUserAlreadyWhitelisted
+            );
+
+            Self::ensure_map_limits::<<<T>::MapLimits as StorageLimits>::MaxWhitelistedMembers>(
+                whitelist.len() as u64,
+            )?;
+        } else {
+            ensure!(
+                whitelist.contains(forum_user_id),
+                Error::<T>::ForumUserNotWhitelisted
+            );
+        }
+
+        Ok(whitelist)
+    }
+
+    // Ensure forum user has no active ban in the category or any of its ancestors
+    fn ensure_forum_user_is_not_banned(
+        forum_user_id: &ForumUserId<T>,
+        category_id: &T::CategoryId,
+    ) -> Result<(), Error<T>> {
+        let now = frame_system::Module::<T>::block_number();
+
+        let category_tree_path = Self::build_category_tree_path(category_id);
+
+        let is_banned = category_tree_path.iter().any(|(path_category_id, _)| {
+            <ForumUserBanByCategory<T>>::contains_key(path_category_id, forum_user_id)
+                && <ForumUserBanByCategory<T>>::get(path_category_id, forum_user_id).is_active(&now)
+        });
+
+        ensure!(!is_banned, Error::<T>::ForumUserBanned);
+
+        Ok(())
+    }
+
+    fn ensure_can_update_subscription(
+        subscribers: &BTreeSet<ForumUserId<T>>,
+        forum_user_id: &ForumUserId<T>,
+        category_id: &T::CategoryId,
+        subscribe: bool,
+    ) -> Result<(), Error<T>> {
+        if subscribe {
+            ensure!(
+                !subscribers.contains(forum_user_id),
+                Error::<T>::AlreadySubscribed
+            );
+
+            Self::ensure_forum_user_is_not_banned(forum_user_id, category_id)?;
+
+            // Forum user must be whitelisted in every private category of the path
+            let category_tree_path = Self::build_category_tree_path(category_id);
+
+            let is_whitelisted = category_tree_path.iter().all(|(path_category_id, _)| {
+                <PrivateCategoryWhitelist<T>>::get(path_category_id)
+                    .map_or(true, |whitelist| whitelist.contains(forum_user_id))
+            });
+
+            ensure!(is_whitelisted, Error::<T>::ForumUserNotWhitelisted);
+
+            Self::ensure_map_limits::<<<T>::MapLimits as StorageLimits>::MaxSubscribers>(
+                subscribers.len() as u64,
+            )?;
+        } else {
+            ensure!(
+                subscribers.contains(forum_user_id),
+                Error::<T>::NotSubscribed
+            );
+        }
+
+        Ok(())
+    }
+
     /// Check the vote is valid
     fn ensure_vote_is_valid(
         thread: ThreadOf<T>,
@@ -2295,10 +3536,15 @@ impl<T: Trait> Module<T> {
         // Ensure poll exists
         let poll = thread.poll.ok_or(Error::<T>::PollNotExist)?;
 
-        // No previous votes for a forum user.
+        let choices = Self::poll_choices_by_thread_id_by_forum_user_id(thread_id, forum_user_id);
+
+        // No previous votes for the alternative by a forum user.
+        ensure!(!choices.contains(&index), Error::<T>::AlreadyVotedOnPoll);
+
+        // Forum user can vote for more alternatives.
         ensure!(
-            !Self::poll_votes_by_thread_id_by_forum_user_id(thread_id, forum_user_id),
-            Error::<T>::AlreadyVotedOnPoll
+            (choices.len() as u32) < poll.max_choices,
+            Error::<T>::PollChoicesLimitReached
         );
 
         // Poll not expired
@@ -2315,6 +3561,59 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    /// Calculate the poll vote weight according to the poll vote weighting. The stake-weighted
+    /// votes also return the updated stake lock of the voter account.
+    fn ensure_poll_vote_weight(
+        account_id: &T::AccountId,
+        forum_user_id: &ForumUserId<T>,
+        thread_id: &T::ThreadId,
+        poll: &Poll<T::Moment, T::Hash>,
+    ) -> Result<(u128, Option<PollVoteLockOf<T>>), Error<T>> {
+        match poll.vote_weighting {
+            PollVoteWeighting::Equal => Ok((1, None)),
+            PollVoteWeighting::LockedStake => {
+                // The account stake backs the votes of a single forum user in the poll.
+                if let Some(stake_voter_id) =
+                    Self::poll_stake_voter_by_thread_id_by_account_id(thread_id, account_id)
+                {
+                    ensure!(
+                        stake_voter_id == *forum_user_id,
+                        Error::<T>::PollVoteStakeAlreadyUsed
+                    );
+                }
+
+                let poll_vote_lock =
+                    Self::poll_vote_lock_by_account_id(account_id).unwrap_or_default();
+
+                // The balance already locked for the polls is not usable, the newly usable
+                // balance gets locked as well.
+                let amount = poll_vote_lock
+                    .amount
+                    .saturating_add(Balances::<T>::usable_balance(account_id));
+
+                ensure!(!amount.is_zero(), Error::<T>::InsufficientStakeForPollVote);
+
+                let unlocks_at = if poll_vote_lock.unlocks_at < poll.end_time {
+                    poll.end_time
+                } else {
+                    poll_vote_lock.unlocks_at
+                };
+
+                Ok((
+                    amount.saturated_into(),
+                    Some(PollVoteLock { amount, unlocks_at }),
+                ))
+            }
+            PollVoteWeighting::VerifiedMembership(verified_vote_weight) => {
+                if T::is_verified_member(forum_user_id) {
+                    Ok((verified_vote_weight.into(), None))
+                } else {
+                    Ok((1, None))
+                }
+            }
+        }
+    }
+
     // supposed to be called before mutations - checks if next entity can be added
     fn ensure_map_limits<U: Get<u64>>(current_amount: u64) -> Result<(), Error<T>> {
         fn check_limit<T: Trait>(amount: u64, limit: u64) -> Result<(), Error<T>> {
@@ -2328,6 +3627,52 @@ impl<T: Trait> Module<T> {
         check_limit(current_amount, U::get())
     }
 
+    /// Moderation log of the moderator without the entries older than the log lifetime
+    pub fn moderation_log(moderator_id: ModeratorId<T>) -> Vec<ModerationLogEntryOf<T>> {
+        let now = frame_system::Module::<T>::block_number();
+
+        Self::moderation_log_by_moderator_id(moderator_id)
+            .into_iter()
+            .filter(|entry| Self::is_moderation_log_entry_recent(entry, now))
+            .collect()
+    }
+
+    fn is_moderation_log_entry_recent(
+        entry: &ModerationLogEntryOf<T>,
+        now: T::BlockNumber,
+    ) -> bool {
+        now.saturating_sub(entry.block) < T::ModerationLogLifeTime::get()
+    }
+
+    // Log the moderator action, pruning the outdated entries and the oldest ones over the log size
+    // limit. The lead actions are not logged.
+    fn log_moderation_action(
+        actor: &PrivilegedActor<T>,
+        action: ModerationAction<T::CategoryId, T::ThreadId, T::PostId, ForumUserId<T>>,
+        rationale: Option<&[u8]>,
+    ) {
+        if let PrivilegedActor::Moderator(moderator_id) = actor {
+            let now = frame_system::Module::<T>::block_number();
+            let max_log_size =
+                <T::MapLimits as StorageLimits>::MaxModerationLogSize::get() as usize;
+
+            let entry = ModerationLogEntry {
+                action,
+                rationale_hash: rationale.map(T::calculate_hash),
+                block: now,
+            };
+
+            <ModerationLog<T>>::mutate(moderator_id, |log| {
+                log.retain(|logged_entry| Self::is_moderation_log_entry_recent(logged_entry, now));
+                log.push(entry);
+
+                if log.len() > max_log_size {
+                    log.drain(..log.len() - max_log_size);
+                }
+            });
+        }
+    }
+
     /// Ensure data migration is done
     fn ensure_data_migration_done() -> Result<(), Error<T>> {
         if DataMigrationDone::get() {
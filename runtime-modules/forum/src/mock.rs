@@ -105,6 +105,11 @@ parameter_types! {
     pub const InviteMemberLockId: [u8; 8] = [9; 8];
     pub const StakingCandidateLockId: [u8; 8] = [10; 8];
     pub const CandidateStake: u64 = 100;
+    pub const HandleDisputeLockId: [u8; 8] = [4; 8];
+    pub const HandleDisputeStake: u64 = 150;
+    pub const HandleDisputeGracePeriod: u64 = 10;
+    pub const MaxRecoveryGuardians: u32 = 5;
+    pub const RecoveryDelay: u64 = 10;
     pub const MinimumApplicationStake: u32 = 50;
     pub const LeaderOpeningStake: u32 = 20;
 }
@@ -119,10 +124,11 @@ impl working_group::Trait<ForumWorkingGroupInstance> for Runtime {
     type StakingHandler = staking_handler::StakingManager<Self, LockId>;
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = Weights;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type WorkerReviewPeriod = ();
+    type MaxBudgetSpendingRecords = ();
 }
 
 impl LockComparator<<Runtime as balances::Trait>::Balance> for Runtime {
@@ -141,15 +147,11 @@ impl working_group::WeightInfo for Weights {
         unimplemented!()
     }
 
-    fn on_initialize_rewarding_with_missing_reward(_: u32) -> u64 {
+    fn on_initialize_expired_openings(_: u32, _: u32) -> u64 {
         unimplemented!()
     }
 
-    fn on_initialize_rewarding_with_missing_reward_cant_pay(_: u32) -> u64 {
-        unimplemented!()
-    }
-
-    fn on_initialize_rewarding_without_missing_reward(_: u32) -> u64 {
+    fn claim_reward() -> u64 {
         unimplemented!()
     }
 
@@ -230,6 +232,34 @@ impl working_group::WeightInfo for Weights {
     fn worker_remark() -> u64 {
         unimplemented!()
     }
+
+    fn pay_worker_bonus() -> u64 {
+        unimplemented!()
+    }
+
+    fn review_worker(_: u32) -> u64 {
+        unimplemented!()
+    }
+
+    fn shortlist_applications(_: u32) -> u64 {
+        unimplemented!()
+    }
+
+    fn reject_applications(_: u32) -> u64 {
+        unimplemented!()
+    }
+
+    fn appoint_deputy(_: u32) -> u64 {
+        unimplemented!()
+    }
+
+    fn remove_deputy() -> u64 {
+        unimplemented!()
+    }
+
+    fn set_spending_limit() -> u64 {
+        unimplemented!()
+    }
 }
 
 impl membership::WeightInfo for Weights {
@@ -290,6 +320,57 @@ impl membership::WeightInfo for Weights {
     fn member_remark() -> Weight {
         unimplemented!()
     }
+    fn reserve_handle(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn release_handle(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn open_handle_dispute(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn resolve_handle_dispute() -> Weight {
+        unimplemented!()
+    }
+    fn execute_handle_dispute_resolution() -> Weight {
+        unimplemented!()
+    }
+    fn withdraw_handle_dispute() -> Weight {
+        unimplemented!()
+    }
+    fn add_member_verification() -> Weight {
+        unimplemented!()
+    }
+    fn revoke_member_verification() -> Weight {
+        unimplemented!()
+    }
+    fn set_second_level_referral_cut() -> Weight {
+        unimplemented!()
+    }
+    fn start_referral_campaign() -> Weight {
+        unimplemented!()
+    }
+    fn end_referral_campaign() -> Weight {
+        unimplemented!()
+    }
+    fn set_recovery_guardians(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn initiate_recovery() -> Weight {
+        unimplemented!()
+    }
+    fn approve_recovery() -> Weight {
+        unimplemented!()
+    }
+    fn reject_recovery() -> Weight {
+        unimplemented!()
+    }
+    fn cancel_recovery() -> Weight {
+        unimplemented!()
+    }
+    fn execute_recovery() -> Weight {
+        unimplemented!()
+    }
 }
 
 pub const WORKING_GROUP_BUDGET: u64 = 100;
@@ -321,19 +402,32 @@ impl membership::Trait for Runtime {
     type StakingCandidateStakingHandler =
         staking_handler::StakingManager<Self, StakingCandidateLockId>;
     type CandidateStake = CandidateStake;
+    type HandleDisputeStakingHandler = staking_handler::StakingManager<Self, HandleDisputeLockId>;
+    type HandleDisputeStake = HandleDisputeStake;
+    type HandleDisputeGracePeriod = HandleDisputeGracePeriod;
+    type MaxRecoveryGuardians = MaxRecoveryGuardians;
+    type RecoveryDelay = RecoveryDelay;
 }
 
 parameter_types! {
     pub const ReferralCutMaximumPercent: u8 = 50;
     pub const MaxCategoryDepth: u64 = 20;
     pub const PostLifeTime: u64 = 100;
+    pub const ModerationLogLifeTime: u64 = 10;
     pub const MaxSubcategories: u64 = 20;
     pub const MaxModeratorsForCategory: u64 = 3;
     pub const MaxCategories: u64 = 40;
     pub const MaxPollAlternativesNumber: u64 = 20;
+    pub const MaxSubscribers: u64 = 3;
+    pub const MaxMentionsPerPost: u64 = 5;
+    pub const MaxRepliesPerPost: u64 = 3;
+    pub const MaxQuotesPerPost: u64 = 3;
+    pub const MaxWhitelistedMembers: u64 = 3;
+    pub const MaxModerationLogSize: u64 = 3;
     pub const ThreadDeposit: u64 = 100;
     pub const PostDeposit: u64 = 10;
     pub const ForumModuleId: ModuleId = ModuleId(*b"m0:forum"); // module : forum
+    pub const PollVoteLockId: LockIdentifier = *b"forumpol";
 }
 
 pub struct MapLimits;
@@ -343,6 +437,12 @@ impl StorageLimits for MapLimits {
     type MaxModeratorsForCategory = MaxModeratorsForCategory;
     type MaxCategories = MaxCategories;
     type MaxPollAlternativesNumber = MaxPollAlternativesNumber;
+    type MaxSubscribers = MaxSubscribers;
+    type MaxMentionsPerPost = MaxMentionsPerPost;
+    type MaxRepliesPerPost = MaxRepliesPerPost;
+    type MaxQuotesPerPost = MaxQuotesPerPost;
+    type MaxWhitelistedMembers = MaxWhitelistedMembers;
+    type MaxModerationLogSize = MaxModerationLogSize;
 }
 
 impl Trait for Runtime {
@@ -353,6 +453,7 @@ impl Trait for Runtime {
     type PostReactionId = u64;
     type MaxCategoryDepth = MaxCategoryDepth;
     type PostLifeTime = PostLifeTime;
+    type ModerationLogLifeTime = ModerationLogLifeTime;
 
     type MapLimits = MapLimits;
     type WorkingGroup = ();
@@ -361,11 +462,16 @@ impl Trait for Runtime {
     type PostDeposit = PostDeposit;
 
     type ModuleId = ForumModuleId;
+    type PollVoteLockId = PollVoteLockId;
 
     fn calculate_hash(text: &[u8]) -> Self::Hash {
         Self::Hashing::hash(text)
     }
 
+    fn is_verified_member(member_id: &u128) -> bool {
+        *member_id == FORUM_LEAD_ORIGIN_ID
+    }
+
     type WeightInfo = ();
 }
 
@@ -475,7 +581,7 @@ impl WeightInfo for () {
     fn delete_category_moderator(_: u32) -> Weight {
         0
     }
-    fn create_thread(_: u32, _: u32, _: u32) -> Weight {
+    fn create_thread(_: u32, _: u32, _: u32, _: u32) -> Weight {
         0
     }
     fn edit_thread_metadata(_: u32, _: u32) -> Weight {
@@ -499,7 +605,7 @@ impl WeightInfo for () {
     fn moderate_thread_moderator(_: u32, _: u32) -> Weight {
         0
     }
-    fn add_post(_: u32, _: u32) -> Weight {
+    fn add_post(_: u32, _: u32, _: u32, _: u32, _: u32) -> Weight {
         0
     }
     fn react_post(_: u32) -> Weight {
@@ -508,10 +614,10 @@ impl WeightInfo for () {
     fn edit_post_text(_: u32, _: u32) -> Weight {
         0
     }
-    fn moderate_post_lead(_: u32, _: u32) -> Weight {
+    fn moderate_post_lead(_: u32, _: u32, _: u32) -> Weight {
         0
     }
-    fn moderate_post_moderator(_: u32, _: u32) -> Weight {
+    fn moderate_post_moderator(_: u32, _: u32, _: u32) -> Weight {
         0
     }
     fn set_stickied_threads_lead(_: u32, _: u32) -> Weight {
@@ -520,7 +626,40 @@ impl WeightInfo for () {
     fn set_stickied_threads_moderator(_: u32, _: u32) -> Weight {
         0
     }
-    fn delete_posts(_: u32, _: u32, _: u32) -> Weight {
+    fn delete_posts(_: u32, _: u32, _: u32, _: u32) -> Weight {
+        0
+    }
+    fn ban_forum_user_lead(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn ban_forum_user_moderator(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn unban_forum_user_lead(_: u32) -> Weight {
+        0
+    }
+    fn unban_forum_user_moderator(_: u32) -> Weight {
+        0
+    }
+    fn update_thread_subscription(_: u32) -> Weight {
+        0
+    }
+    fn update_category_subscription(_: u32) -> Weight {
+        0
+    }
+    fn unlock_poll_vote_stake() -> Weight {
+        0
+    }
+    fn update_category_privacy_lead(_: u32) -> Weight {
+        0
+    }
+    fn update_category_privacy_moderator(_: u32) -> Weight {
+        0
+    }
+    fn update_category_whitelist_membership_lead(_: u32) -> Weight {
+        0
+    }
+    fn update_category_whitelist_membership_moderator(_: u32) -> Weight {
         0
     }
 }
@@ -633,6 +772,15 @@ pub fn good_poll_alternative_text() -> Vec<u8> {
 /// Generate a valid poll input
 pub fn generate_poll_input(
     expiration_diff: u64,
+) -> PollInput<<Runtime as pallet_timestamp::Trait>::Moment> {
+    generate_weighted_poll_input(expiration_diff, 1, PollVoteWeighting::Equal)
+}
+
+/// Generate a poll input with the provided choices limit and vote weighting
+pub fn generate_weighted_poll_input(
+    expiration_diff: u64,
+    max_choices: u32,
+    vote_weighting: PollVoteWeighting,
 ) -> PollInput<<Runtime as pallet_timestamp::Trait>::Moment> {
     PollInput {
         description: good_poll_description(),
@@ -644,6 +792,8 @@ pub fn generate_poll_input(
             }
             alternatives
         },
+        max_choices,
+        vote_weighting,
     }
 }
 
@@ -949,7 +1099,10 @@ pub fn create_post_mock(
             category_id,
             thread_id,
             text.clone(),
-            editable
+            editable,
+            BTreeSet::new(),
+            None,
+            BTreeSet::new(),
         ),
         result
     );
@@ -1386,6 +1539,223 @@ pub fn react_post_mock(
     };
 }
 
+/// Create ban forum user mock
+pub fn ban_forum_user_mock(
+    origin: OriginType,
+    actor: PrivilegedActor<Runtime>,
+    category_id: <Runtime as Trait>::CategoryId,
+    forum_user_id: ForumUserId<Runtime>,
+    duration: Option<<Runtime as frame_system::Trait>::BlockNumber>,
+    rationale: Vec<u8>,
+    result: DispatchResult,
+) {
+    assert_eq!(
+        TestForumModule::ban_forum_user(
+            mock_origin(origin),
+            actor.clone(),
+            category_id,
+            forum_user_id,
+            duration,
+            rationale.clone(),
+        ),
+        result
+    );
+    if result.is_ok() {
+        let expires_at = duration.map(|duration| System::block_number().saturating_add(duration));
+        assert_eq!(
+            TestForumModule::forum_user_ban_by_category(category_id, forum_user_id),
+            ForumUserBan {
+                banned_at: System::block_number(),
+                expires_at,
+                rationale_hash: Runtime::calculate_hash(rationale.as_slice()),
+            }
+        );
+        assert_eq!(
+            System::events().last().unwrap().event,
+            TestEvent::forum_mod(RawEvent::ForumUserBanned(
+                forum_user_id,
+                category_id,
+                expires_at,
+                rationale,
+                actor
+            ))
+        );
+    }
+}
+
+/// Create unban forum user mock
+pub fn unban_forum_user_mock(
+    origin: OriginType,
+    actor: PrivilegedActor<Runtime>,
+    category_id: <Runtime as Trait>::CategoryId,
+    forum_user_id: ForumUserId<Runtime>,
+    result: DispatchResult,
+) {
+    assert_eq!(
+        TestForumModule::unban_forum_user(
+            mock_origin(origin),
+            actor.clone(),
+            category_id,
+            forum_user_id,
+        ),
+        result
+    );
+    if result.is_ok() {
+        assert!(!<ForumUserBanByCategory<Runtime>>::contains_key(
+            category_id,
+            forum_user_id
+        ));
+        assert_eq!(
+            System::events().last().unwrap().event,
+            TestEvent::forum_mod(RawEvent::ForumUserUnbanned(
+                forum_user_id,
+                category_id,
+                actor
+            ))
+        );
+    }
+}
+
+/// Create update thread subscription mock
+pub fn update_thread_subscription_mock(
+    origin: OriginType,
+    forum_user_id: ForumUserId<Runtime>,
+    category_id: <Runtime as Trait>::CategoryId,
+    thread_id: <Runtime as Trait>::ThreadId,
+    subscribe: bool,
+    result: DispatchResult,
+) {
+    assert_eq!(
+        TestForumModule::update_thread_subscription(
+            mock_origin(origin),
+            forum_user_id,
+            category_id,
+            thread_id,
+            subscribe,
+        ),
+        result
+    );
+    if result.is_ok() {
+        assert_eq!(
+            TestForumModule::thread_subscribers(thread_id).contains(&forum_user_id),
+            subscribe
+        );
+        assert_eq!(
+            System::events().last().unwrap().event,
+            TestEvent::forum_mod(RawEvent::ThreadSubscriptionUpdated(
+                forum_user_id,
+                category_id,
+                thread_id,
+                subscribe
+            ))
+        );
+    }
+}
+
+/// Create update category subscription mock
+pub fn update_category_subscription_mock(
+    origin: OriginType,
+    forum_user_id: ForumUserId<Runtime>,
+    category_id: <Runtime as Trait>::CategoryId,
+    subscribe: bool,
+    result: DispatchResult,
+) {
+    assert_eq!(
+        TestForumModule::update_category_subscription(
+            mock_origin(origin),
+            forum_user_id,
+            category_id,
+            subscribe,
+        ),
+        result
+    );
+    if result.is_ok() {
+        assert_eq!(
+            TestForumModule::category_subscribers(category_id).contains(&forum_user_id),
+            subscribe
+        );
+        assert_eq!(
+            System::events().last().unwrap().event,
+            TestEvent::forum_mod(RawEvent::CategorySubscriptionUpdated(
+                forum_user_id,
+                category_id,
+                subscribe
+            ))
+        );
+    }
+}
+
+/// Create update category privacy mock
+pub fn update_category_privacy_mock(
+    origin: OriginType,
+    actor: PrivilegedActor<Runtime>,
+    category_id: <Runtime as Trait>::CategoryId,
+    private: bool,
+    result: DispatchResult,
+) {
+    assert_eq!(
+        TestForumModule::update_category_privacy(
+            mock_origin(origin),
+            actor.clone(),
+            category_id,
+            private,
+        ),
+        result
+    );
+    if result.is_ok() {
+        assert_eq!(
+            TestForumModule::private_category_whitelist(category_id).is_some(),
+            private
+        );
+        assert_eq!(
+            System::events().last().unwrap().event,
+            TestEvent::forum_mod(RawEvent::CategoryPrivacyUpdated(
+                category_id,
+                private,
+                actor
+            ))
+        );
+    }
+}
+
+/// Create update category whitelist membership mock
+pub fn update_category_whitelist_membership_mock(
+    origin: OriginType,
+    actor: PrivilegedActor<Runtime>,
+    category_id: <Runtime as Trait>::CategoryId,
+    forum_user_id: ForumUserId<Runtime>,
+    whitelisted: bool,
+    result: DispatchResult,
+) {
+    assert_eq!(
+        TestForumModule::update_category_whitelist_membership(
+            mock_origin(origin),
+            actor.clone(),
+            category_id,
+            forum_user_id,
+            whitelisted,
+        ),
+        result
+    );
+    if result.is_ok() {
+        assert_eq!(
+            TestForumModule::private_category_whitelist(category_id)
+                .unwrap_or_default()
+                .contains(&forum_user_id),
+            whitelisted
+        );
+        assert_eq!(
+            System::events().last().unwrap().event,
+            TestEvent::forum_mod(RawEvent::CategoryWhitelistMembershipUpdated(
+                category_id,
+                forum_user_id,
+                whitelisted,
+                actor
+            ))
+        );
+    }
+}
+
 /// Create default genesis config
 pub fn default_genesis_config() -> GenesisConfig<Runtime> {
     create_genesis_config(true)
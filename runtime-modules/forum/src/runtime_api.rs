@@ -0,0 +1,15 @@
+//! Runtime API definition for the forum module.
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    /// Forum state queries
+    pub trait ForumApi<ModeratorId, ModerationLogEntry> where
+        ModeratorId: Codec,
+        ModerationLogEntry: Codec,
+    {
+        /// Recent moderation actions of the moderator, oldest first
+        fn moderation_log(moderator_id: ModeratorId) -> Vec<ModerationLogEntry>;
+    }
+}
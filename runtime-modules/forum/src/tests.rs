@@ -1644,347 +1644,442 @@ fn vote_on_poll_fails_on_double_voting() {
 }
 
 #[test]
-// test if poll metadata created
-fn vote_on_poll_exists() {
+fn vote_on_multi_choice_poll_succeeds_up_to_max_choices() {
+    let expiration_diff = 10;
     let forum_lead = FORUM_LEAD_ORIGIN_ID;
-    let origin = OriginType::Signed(forum_lead);
     let initial_balance = 10_000_000;
+
     with_test_externalities(|| {
-        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+        Balances::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
 
         let category_id = create_category_mock(
-            origin.clone(),
+            FORUM_LEAD_ORIGIN.clone(),
             None,
             good_category_title(),
             good_category_description(),
             Ok(()),
         );
         let thread_id = create_thread_mock(
-            origin.clone(),
+            FORUM_LEAD_ORIGIN.clone(),
             forum_lead,
             forum_lead,
             category_id,
             good_thread_metadata(),
             good_thread_text(),
-            None,
+            Some(generate_weighted_poll_input(
+                expiration_diff,
+                2,
+                PollVoteWeighting::Equal,
+            )),
             Ok(()),
         );
+
         vote_on_poll_mock(
-            origin.clone(),
+            FORUM_LEAD_ORIGIN.clone(),
             forum_lead,
-            thread_id,
             category_id,
-            1,
-            Err(Error::<Runtime>::PollNotExist.into()),
-        );
-    });
-}
-
-#[test]
-// test if forum reject poll submit after expiration
-fn vote_on_poll_expired() {
-    let forum_lead = FORUM_LEAD_ORIGIN_ID;
-    let origin = OriginType::Signed(forum_lead);
-    let expiration_diff = 10;
-    let initial_balance = 10_000_000;
-    with_test_externalities(|| {
-        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
-
-        let category_id = create_category_mock(
-            origin.clone(),
-            None,
-            good_category_title(),
-            good_category_description(),
+            thread_id,
+            0,
             Ok(()),
         );
-        let thread_id = create_thread_mock(
-            origin.clone(),
+        vote_on_poll_mock(
+            FORUM_LEAD_ORIGIN.clone(),
             forum_lead,
+            category_id,
+            thread_id,
+            0,
+            Err(Error::<Runtime>::AlreadyVotedOnPoll.into()),
+        );
+        vote_on_poll_mock(
+            FORUM_LEAD_ORIGIN.clone(),
             forum_lead,
             category_id,
-            good_thread_metadata(),
-            good_thread_text(),
-            Some(generate_poll_input(expiration_diff)),
+            thread_id,
+            1,
             Ok(()),
         );
-        change_current_time(expiration_diff + 1);
         vote_on_poll_mock(
-            origin.clone(),
+            FORUM_LEAD_ORIGIN.clone(),
             forum_lead,
             category_id,
             thread_id,
-            1,
-            Err(Error::<Runtime>::PollCommitExpired.into()),
+            2,
+            Err(Error::<Runtime>::PollChoicesLimitReached.into()),
+        );
+
+        assert_eq!(
+            TestForumModule::poll_choices_by_thread_id_by_forum_user_id(thread_id, forum_lead),
+            vec![0, 1]
         );
     });
 }
 
-/*
- ** moderate_thread
- */
-
 #[test]
-// test if thread moderator registered as valid moderator
-fn moderate_thread_origin_ok() {
+fn create_thread_fails_with_invalid_poll_choices_or_weighting() {
+    let expiration_diff = 10;
     let forum_lead = FORUM_LEAD_ORIGIN_ID;
-    let origin = OriginType::Signed(forum_lead);
     let initial_balance = 10_000_000;
+
     with_test_externalities(|| {
-        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+        Balances::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
 
-        let moderator_id = forum_lead;
         let category_id = create_category_mock(
-            origin.clone(),
+            FORUM_LEAD_ORIGIN.clone(),
             None,
             good_category_title(),
             good_category_description(),
             Ok(()),
         );
-        update_category_membership_of_moderator_mock(
-            origin.clone(),
-            moderator_id,
-            category_id,
-            true,
-            Ok(()),
-        );
-        let thread_id = create_thread_mock(
-            origin.clone(),
-            forum_lead,
-            forum_lead,
-            category_id,
-            good_thread_metadata(),
-            good_thread_text(),
-            None,
-            Ok(()),
-        );
-        moderate_thread_mock(
-            origin,
-            moderator_id,
-            category_id,
-            thread_id,
-            good_moderation_rationale(),
-            Ok(()),
-        );
-    });
-}
 
-/*
- ** add_post
- */
+        let alternatives_number =
+            generate_poll_input(expiration_diff).poll_alternatives.len() as u32;
 
-#[test]
-// test if post origin registered as forum user
-fn add_post_origin() {
-    let origins = vec![FORUM_LEAD_ORIGIN, NOT_FORUM_MEMBER_ORIGIN];
-    let accounts = vec![FORUM_LEAD_ORIGIN_ID, NOT_FORUM_MEMBER_ORIGIN_ID];
-    let results = vec![
-        Ok(()),
-        Err(Error::<Runtime>::ForumUserIdNotMatchAccount.into()),
-    ];
-    for index in 0..origins.len() {
-        let forum_lead = FORUM_LEAD_ORIGIN_ID;
-        let origin = OriginType::Signed(forum_lead);
-        let initial_balance = 10_000_000;
-        with_test_externalities(|| {
-            balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
-            balances::Module::<Runtime>::make_free_balance_be(
-                &NOT_FORUM_MEMBER_ORIGIN_ID,
-                initial_balance,
-            );
-            let category_id = create_category_mock(
-                origin.clone(),
-                None,
-                good_category_title(),
-                good_category_description(),
-                Ok(()),
-            );
+        let invalid_poll_inputs = vec![
+            (
+                generate_weighted_poll_input(expiration_diff, 0, PollVoteWeighting::Equal),
+                Error::<Runtime>::PollMaxChoicesInvalid,
+            ),
+            (
+                generate_weighted_poll_input(
+                    expiration_diff,
+                    alternatives_number + 1,
+                    PollVoteWeighting::Equal,
+                ),
+                Error::<Runtime>::PollMaxChoicesInvalid,
+            ),
+            (
+                generate_weighted_poll_input(
+                    expiration_diff,
+                    1,
+                    PollVoteWeighting::VerifiedMembership(0),
+                ),
+                Error::<Runtime>::PollVoteWeightInvalid,
+            ),
+        ];
 
-            let thread_id = create_thread_mock(
-                origin.clone(),
+        for (poll_input, error) in invalid_poll_inputs {
+            create_thread_mock(
+                FORUM_LEAD_ORIGIN.clone(),
                 forum_lead,
                 forum_lead,
                 category_id,
                 good_thread_metadata(),
                 good_thread_text(),
-                None,
-                Ok(()),
-            );
-            create_post_mock(
-                origins[index].clone(),
-                accounts[index],
-                forum_lead,
-                category_id,
-                thread_id,
-                good_post_text(),
-                true,
-                results[index],
+                Some(poll_input),
+                Err(error.into()),
             );
-        });
-    }
+        }
+    });
 }
 
 #[test]
-// test that we can't add editable posst without enough balance but we can add a non-editable one.
-fn add_post_balance() {
+fn vote_on_poll_weighted_by_verified_membership() {
+    let expiration_diff = 10;
     let forum_lead = FORUM_LEAD_ORIGIN_ID;
-    let origin = OriginType::Signed(forum_lead);
+    let forum_user_id = NOT_FORUM_LEAD_ORIGIN_ID;
+    let initial_balance = 10_000_000;
+    let verified_vote_weight = 3;
+
     with_test_externalities(|| {
-        let initial_balance = <Runtime as Trait>::PostDeposit::get()
-            + <Runtime as Trait>::ThreadDeposit::get()
-            + <Runtime as balances::Trait>::ExistentialDeposit::get();
+        Balances::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
 
-        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
         let category_id = create_category_mock(
-            origin.clone(),
+            FORUM_LEAD_ORIGIN.clone(),
             None,
             good_category_title(),
             good_category_description(),
             Ok(()),
         );
-
-        assert_eq!(
-            balances::Module::<Runtime>::free_balance(&forum_lead),
-            initial_balance
-        );
-
         let thread_id = create_thread_mock(
-            origin.clone(),
+            FORUM_LEAD_ORIGIN.clone(),
             forum_lead,
             forum_lead,
             category_id,
             good_thread_metadata(),
             good_thread_text(),
-            None,
+            Some(generate_weighted_poll_input(
+                expiration_diff,
+                1,
+                PollVoteWeighting::VerifiedMembership(verified_vote_weight),
+            )),
             Ok(()),
         );
 
-        assert_eq!(
-            balances::Module::<Runtime>::free_balance(&forum_lead),
-            <Runtime as balances::Trait>::ExistentialDeposit::get()
-        );
-
-        balances::Module::<Runtime>::make_free_balance_be(
-            &forum_lead,
-            <Runtime as Trait>::PostDeposit::get() - 1,
-        );
-
-        // Can't create an editable post without enough balance
-        create_post_mock(
-            FORUM_LEAD_ORIGIN,
-            forum_lead,
+        // Forum lead is a verified member in the mock
+        vote_on_poll_mock(
+            FORUM_LEAD_ORIGIN.clone(),
             forum_lead,
             category_id,
             thread_id,
-            good_post_text(),
-            true,
-            Err(Error::<Runtime>::InsufficientBalanceForPost.into()),
+            1,
+            Ok(()),
         );
-
-        // No balance requirements for non-editable posts
-        create_post_mock(
-            FORUM_LEAD_ORIGIN,
-            forum_lead,
-            forum_lead,
+        vote_on_poll_mock(
+            NOT_FORUM_LEAD_ORIGIN.clone(),
+            forum_user_id,
             category_id,
             thread_id,
-            good_post_text(),
-            false,
+            1,
             Ok(()),
         );
+
+        let poll_alternative = TestForumModule::thread_by_id(category_id, thread_id)
+            .poll
+            .unwrap()
+            .poll_alternatives[1]
+            .clone();
+
+        assert_eq!(poll_alternative.vote_count, 2);
+        assert_eq!(
+            poll_alternative.vote_weight,
+            verified_vote_weight as u128 + 1
+        );
     });
 }
 
 #[test]
-// test if post text can be edited by author
-fn edit_post_text() {
-    let forum_users = [NOT_FORUM_LEAD_ORIGIN_ID, NOT_FORUM_LEAD_2_ORIGIN_ID];
-    let origins = [NOT_FORUM_LEAD_ORIGIN, NOT_FORUM_LEAD_2_ORIGIN];
+fn vote_on_poll_weighted_by_locked_stake() {
+    use frame_support::traits::{LockableCurrency, WithdrawReasons};
 
+    let expiration_diff = 10;
     let forum_lead = FORUM_LEAD_ORIGIN_ID;
-    let origin = OriginType::Signed(forum_lead);
-
+    let forum_user_id = NOT_FORUM_LEAD_ORIGIN_ID;
     let initial_balance = 10_000_000;
+    let locked_balance = 1_000;
+
     with_test_externalities(|| {
-        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
-        balances::Module::<Runtime>::make_free_balance_be(
-            &NOT_FORUM_LEAD_ORIGIN_ID,
-            initial_balance,
-        );
-        balances::Module::<Runtime>::make_free_balance_be(
-            &NOT_FORUM_LEAD_2_ORIGIN_ID,
-            initial_balance,
-        );
+        Balances::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+        Balances::<Runtime>::make_free_balance_be(&forum_user_id, initial_balance);
 
-        // prepare category and thread
         let category_id = create_category_mock(
-            origin.clone(),
+            FORUM_LEAD_ORIGIN.clone(),
             None,
             good_category_title(),
             good_category_description(),
             Ok(()),
         );
         let thread_id = create_thread_mock(
-            origin.clone(),
+            FORUM_LEAD_ORIGIN.clone(),
             forum_lead,
             forum_lead,
             category_id,
             good_thread_metadata(),
             good_thread_text(),
-            None,
+            Some(generate_weighted_poll_input(
+                expiration_diff,
+                2,
+                PollVoteWeighting::LockedStake,
+            )),
             Ok(()),
         );
 
-        // create post by author
-        let post_id = create_post_mock(
-            origins[0].clone(),
-            forum_users[0],
-            forum_users[0],
+        // The balance locked for other purposes doesn't back the vote.
+        Balances::<Runtime>::set_lock(
+            *b"testlock",
+            &forum_user_id,
+            initial_balance,
+            WithdrawReasons::all(),
+        );
+
+        vote_on_poll_mock(
+            NOT_FORUM_LEAD_ORIGIN.clone(),
+            forum_user_id,
             category_id,
             thread_id,
-            good_post_text(),
-            true,
-            Ok(()),
+            1,
+            Err(Error::<Runtime>::InsufficientStakeForPollVote.into()),
         );
 
-        // check author can edit text
-        edit_post_text_mock(
-            origins[0].clone(),
-            forum_users[0],
+        Balances::<Runtime>::set_lock(
+            *b"testlock",
+            &forum_user_id,
+            locked_balance,
+            WithdrawReasons::all(),
+        );
+
+        vote_on_poll_mock(
+            NOT_FORUM_LEAD_ORIGIN.clone(),
+            forum_user_id,
             category_id,
             thread_id,
-            post_id,
-            good_post_new_text(),
+            1,
             Ok(()),
         );
 
-        // check non-author is forbidden from editing text
-        edit_post_text_mock(
-            origins[1].clone(),
-            forum_users[1],
+        let stake = initial_balance - locked_balance;
+
+        assert_eq!(
+            TestForumModule::thread_by_id(category_id, thread_id)
+                .poll
+                .unwrap()
+                .poll_alternatives[1]
+                .vote_weight,
+            stake as u128
+        );
+
+        // The stake is locked until the poll end.
+        assert_eq!(Balances::<Runtime>::usable_balance(&forum_user_id), 0);
+        assert_eq!(
+            TestForumModule::poll_vote_lock_by_account_id(&forum_user_id),
+            Some(PollVoteLock {
+                amount: stake,
+                unlocks_at: Timestamp::now() + expiration_diff,
+            })
+        );
+
+        // The locked stake keeps backing the next choices.
+        vote_on_poll_mock(
+            NOT_FORUM_LEAD_ORIGIN.clone(),
+            forum_user_id,
             category_id,
             thread_id,
-            post_id,
-            good_post_new_text(),
-            Err(Error::<Runtime>::AccountDoesNotMatchPostAuthor.into()),
+            2,
+            Ok(()),
+        );
+
+        assert_eq!(
+            TestForumModule::thread_by_id(category_id, thread_id)
+                .poll
+                .unwrap()
+                .poll_alternatives[2]
+                .vote_weight,
+            stake as u128
         );
     });
 }
 
 #[test]
-// can't edit non-editable post
-fn edit_non_editable_post_text() {
+fn vote_on_poll_fails_with_stake_used_by_another_forum_user() {
+    let expiration_diff = 10;
     let forum_lead = FORUM_LEAD_ORIGIN_ID;
-    let origin = OriginType::Signed(forum_lead);
+    let forum_user_id = NOT_FORUM_LEAD_ORIGIN_ID;
+    let initial_balance = 10_000_000;
+
+    with_test_externalities(|| {
+        Balances::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+        Balances::<Runtime>::make_free_balance_be(&forum_user_id, initial_balance);
+
+        let category_id = create_category_mock(
+            FORUM_LEAD_ORIGIN.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        let thread_id = create_thread_mock(
+            FORUM_LEAD_ORIGIN.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            Some(generate_weighted_poll_input(
+                expiration_diff,
+                1,
+                PollVoteWeighting::LockedStake,
+            )),
+            Ok(()),
+        );
+
+        // Another forum user controlled by the same account used its stake in the poll.
+        <PollStakeVoters<Runtime>>::insert(thread_id, forum_user_id, forum_lead);
+
+        vote_on_poll_mock(
+            NOT_FORUM_LEAD_ORIGIN.clone(),
+            forum_user_id,
+            category_id,
+            thread_id,
+            1,
+            Err(Error::<Runtime>::PollVoteStakeAlreadyUsed.into()),
+        );
+    });
+}
 
+#[test]
+fn unlock_poll_vote_stake() {
+    let expiration_diff = 10;
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let forum_user_id = NOT_FORUM_LEAD_ORIGIN_ID;
     let initial_balance = 10_000_000;
+
     with_test_externalities(|| {
-        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
-        balances::Module::<Runtime>::make_free_balance_be(
-            &NOT_FORUM_LEAD_ORIGIN_ID,
-            initial_balance,
+        Balances::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+        Balances::<Runtime>::make_free_balance_be(&forum_user_id, initial_balance);
+
+        assert_eq!(
+            TestForumModule::unlock_poll_vote_stake(mock_origin(NOT_FORUM_LEAD_ORIGIN)),
+            Err(Error::<Runtime>::PollVoteStakeNotLocked.into())
         );
 
-        // prepare category and thread
+        let category_id = create_category_mock(
+            FORUM_LEAD_ORIGIN.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        let thread_id = create_thread_mock(
+            FORUM_LEAD_ORIGIN.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            Some(generate_weighted_poll_input(
+                expiration_diff,
+                1,
+                PollVoteWeighting::LockedStake,
+            )),
+            Ok(()),
+        );
+
+        vote_on_poll_mock(
+            NOT_FORUM_LEAD_ORIGIN.clone(),
+            forum_user_id,
+            category_id,
+            thread_id,
+            1,
+            Ok(()),
+        );
+
+        change_current_time(expiration_diff);
+
+        assert_eq!(
+            TestForumModule::unlock_poll_vote_stake(mock_origin(NOT_FORUM_LEAD_ORIGIN)),
+            Err(Error::<Runtime>::PollVoteStakeStillLocked.into())
+        );
+
+        change_current_time(1);
+
+        assert_eq!(
+            TestForumModule::unlock_poll_vote_stake(mock_origin(NOT_FORUM_LEAD_ORIGIN)),
+            Ok(())
+        );
+
+        assert_eq!(
+            Balances::<Runtime>::usable_balance(&forum_user_id),
+            initial_balance
+        );
+        assert_eq!(
+            TestForumModule::poll_vote_lock_by_account_id(&forum_user_id),
+            None
+        );
+        assert_eq!(
+            System::events().last().unwrap().event,
+            TestEvent::forum_mod(RawEvent::PollVoteStakeUnlocked(forum_user_id))
+        );
+    });
+}
+
+#[test]
+// test if poll metadata created
+fn vote_on_poll_exists() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    let initial_balance = 10_000_000;
+    with_test_externalities(|| {
+        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+
         let category_id = create_category_mock(
             origin.clone(),
             None,
@@ -2002,47 +2097,128 @@ fn edit_non_editable_post_text() {
             None,
             Ok(()),
         );
+        vote_on_poll_mock(
+            origin.clone(),
+            forum_lead,
+            thread_id,
+            category_id,
+            1,
+            Err(Error::<Runtime>::PollNotExist.into()),
+        );
+    });
+}
 
-        // create post by author
-        let post_id = create_post_mock(
+#[test]
+// test if forum reject poll submit after expiration
+fn vote_on_poll_expired() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    let expiration_diff = 10;
+    let initial_balance = 10_000_000;
+    with_test_externalities(|| {
+        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        let thread_id = create_thread_mock(
             origin.clone(),
             forum_lead,
             forum_lead,
             category_id,
-            thread_id,
-            good_post_text(),
-            false,
+            good_thread_metadata(),
+            good_thread_text(),
+            Some(generate_poll_input(expiration_diff)),
             Ok(()),
         );
+        change_current_time(expiration_diff + 1);
+        vote_on_poll_mock(
+            origin.clone(),
+            forum_lead,
+            category_id,
+            thread_id,
+            1,
+            Err(Error::<Runtime>::PollCommitExpired.into()),
+        );
+    });
+}
 
-        // check non-author is forbidden from editing text
-        edit_post_text_mock(
+/*
+ ** moderate_thread
+ */
+
+#[test]
+// test if thread moderator registered as valid moderator
+fn moderate_thread_origin_ok() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    let initial_balance = 10_000_000;
+    with_test_externalities(|| {
+        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+
+        let moderator_id = forum_lead;
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        update_category_membership_of_moderator_mock(
+            origin.clone(),
+            moderator_id,
+            category_id,
+            true,
+            Ok(()),
+        );
+        let thread_id = create_thread_mock(
             origin.clone(),
             forum_lead,
+            forum_lead,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            None,
+            Ok(()),
+        );
+        moderate_thread_mock(
+            origin,
+            moderator_id,
             category_id,
             thread_id,
-            post_id,
-            good_post_new_text(),
-            Err(Error::<Runtime>::PostDoesNotExist.into()),
+            good_moderation_rationale(),
+            Ok(()),
         );
     });
 }
 
 /*
- ** react_post
+ ** add_post
  */
+
 #[test]
-// test if post react take effect
-fn react_post() {
-    // three reations to post, test them one by one.
-    let reactions = vec![0, 1, 2];
-    for index in 0..reactions.len() {
+// test if post origin registered as forum user
+fn add_post_origin() {
+    let origins = vec![FORUM_LEAD_ORIGIN, NOT_FORUM_MEMBER_ORIGIN];
+    let accounts = vec![FORUM_LEAD_ORIGIN_ID, NOT_FORUM_MEMBER_ORIGIN_ID];
+    let results = vec![
+        Ok(()),
+        Err(Error::<Runtime>::ForumUserIdNotMatchAccount.into()),
+    ];
+    for index in 0..origins.len() {
         let forum_lead = FORUM_LEAD_ORIGIN_ID;
-        let origin = FORUM_LEAD_ORIGIN;
+        let origin = OriginType::Signed(forum_lead);
         let initial_balance = 10_000_000;
-
         with_test_externalities(|| {
             balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+            balances::Module::<Runtime>::make_free_balance_be(
+                &NOT_FORUM_MEMBER_ORIGIN_ID,
+                initial_balance,
+            );
             let category_id = create_category_mock(
                 origin.clone(),
                 None,
@@ -2061,15 +2237,267 @@ fn react_post() {
                 None,
                 Ok(()),
             );
-            let post_id = create_post_mock(
-                origin.clone(),
-                forum_lead,
+            create_post_mock(
+                origins[index].clone(),
+                accounts[index],
                 forum_lead,
                 category_id,
                 thread_id,
                 good_post_text(),
                 true,
-                Ok(()),
+                results[index],
+            );
+        });
+    }
+}
+
+#[test]
+// test that we can't add editable posst without enough balance but we can add a non-editable one.
+fn add_post_balance() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    with_test_externalities(|| {
+        let initial_balance = <Runtime as Trait>::PostDeposit::get()
+            + <Runtime as Trait>::ThreadDeposit::get()
+            + <Runtime as balances::Trait>::ExistentialDeposit::get();
+
+        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+
+        assert_eq!(
+            balances::Module::<Runtime>::free_balance(&forum_lead),
+            initial_balance
+        );
+
+        let thread_id = create_thread_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            None,
+            Ok(()),
+        );
+
+        assert_eq!(
+            balances::Module::<Runtime>::free_balance(&forum_lead),
+            <Runtime as balances::Trait>::ExistentialDeposit::get()
+        );
+
+        balances::Module::<Runtime>::make_free_balance_be(
+            &forum_lead,
+            <Runtime as Trait>::PostDeposit::get() - 1,
+        );
+
+        // Can't create an editable post without enough balance
+        create_post_mock(
+            FORUM_LEAD_ORIGIN,
+            forum_lead,
+            forum_lead,
+            category_id,
+            thread_id,
+            good_post_text(),
+            true,
+            Err(Error::<Runtime>::InsufficientBalanceForPost.into()),
+        );
+
+        // No balance requirements for non-editable posts
+        create_post_mock(
+            FORUM_LEAD_ORIGIN,
+            forum_lead,
+            forum_lead,
+            category_id,
+            thread_id,
+            good_post_text(),
+            false,
+            Ok(()),
+        );
+    });
+}
+
+#[test]
+// test if post text can be edited by author
+fn edit_post_text() {
+    let forum_users = [NOT_FORUM_LEAD_ORIGIN_ID, NOT_FORUM_LEAD_2_ORIGIN_ID];
+    let origins = [NOT_FORUM_LEAD_ORIGIN, NOT_FORUM_LEAD_2_ORIGIN];
+
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+
+    let initial_balance = 10_000_000;
+    with_test_externalities(|| {
+        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+        balances::Module::<Runtime>::make_free_balance_be(
+            &NOT_FORUM_LEAD_ORIGIN_ID,
+            initial_balance,
+        );
+        balances::Module::<Runtime>::make_free_balance_be(
+            &NOT_FORUM_LEAD_2_ORIGIN_ID,
+            initial_balance,
+        );
+
+        // prepare category and thread
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        let thread_id = create_thread_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            None,
+            Ok(()),
+        );
+
+        // create post by author
+        let post_id = create_post_mock(
+            origins[0].clone(),
+            forum_users[0],
+            forum_users[0],
+            category_id,
+            thread_id,
+            good_post_text(),
+            true,
+            Ok(()),
+        );
+
+        // check author can edit text
+        edit_post_text_mock(
+            origins[0].clone(),
+            forum_users[0],
+            category_id,
+            thread_id,
+            post_id,
+            good_post_new_text(),
+            Ok(()),
+        );
+
+        // check non-author is forbidden from editing text
+        edit_post_text_mock(
+            origins[1].clone(),
+            forum_users[1],
+            category_id,
+            thread_id,
+            post_id,
+            good_post_new_text(),
+            Err(Error::<Runtime>::AccountDoesNotMatchPostAuthor.into()),
+        );
+    });
+}
+
+#[test]
+// can't edit non-editable post
+fn edit_non_editable_post_text() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+
+    let initial_balance = 10_000_000;
+    with_test_externalities(|| {
+        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+        balances::Module::<Runtime>::make_free_balance_be(
+            &NOT_FORUM_LEAD_ORIGIN_ID,
+            initial_balance,
+        );
+
+        // prepare category and thread
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        let thread_id = create_thread_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            None,
+            Ok(()),
+        );
+
+        // create post by author
+        let post_id = create_post_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            thread_id,
+            good_post_text(),
+            false,
+            Ok(()),
+        );
+
+        // check non-author is forbidden from editing text
+        edit_post_text_mock(
+            origin.clone(),
+            forum_lead,
+            category_id,
+            thread_id,
+            post_id,
+            good_post_new_text(),
+            Err(Error::<Runtime>::PostDoesNotExist.into()),
+        );
+    });
+}
+
+/*
+ ** react_post
+ */
+#[test]
+// test if post react take effect
+fn react_post() {
+    // three reations to post, test them one by one.
+    let reactions = vec![0, 1, 2];
+    for index in 0..reactions.len() {
+        let forum_lead = FORUM_LEAD_ORIGIN_ID;
+        let origin = FORUM_LEAD_ORIGIN;
+        let initial_balance = 10_000_000;
+
+        with_test_externalities(|| {
+            balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+            let category_id = create_category_mock(
+                origin.clone(),
+                None,
+                good_category_title(),
+                good_category_description(),
+                Ok(()),
+            );
+
+            let thread_id = create_thread_mock(
+                origin.clone(),
+                forum_lead,
+                forum_lead,
+                category_id,
+                good_thread_metadata(),
+                good_thread_text(),
+                None,
+                Ok(()),
+            );
+            let post_id = create_post_mock(
+                origin.clone(),
+                forum_lead,
+                forum_lead,
+                category_id,
+                thread_id,
+                good_post_text(),
+                true,
+                Ok(()),
             );
             react_post_mock(
                 origin.clone(),
@@ -2148,33 +2576,1471 @@ fn moderate_post_origin() {
                 good_moderation_rationale(),
                 results[index],
             );
-        });
-    }
+        });
+    }
+}
+
+/*
+ * Delete post
+*/
+
+#[test]
+// Test that post creator can delete it
+fn delete_post_creator() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    let initial_balance = 10_000_000;
+    with_test_externalities(|| {
+        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+        balances::Module::<Runtime>::make_free_balance_be(
+            &NOT_FORUM_LEAD_ORIGIN_ID,
+            initial_balance,
+        );
+
+        let mut current_balance = initial_balance;
+
+        assert_eq!(
+            balances::Module::<Runtime>::free_balance(&forum_lead),
+            current_balance
+        );
+
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+
+        let thread_id = create_thread_mock(
+            NOT_FORUM_LEAD_ORIGIN.clone(),
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            None,
+            Ok(()),
+        );
+
+        current_balance -= <Runtime as Trait>::ThreadDeposit::get();
+        current_balance -= <Runtime as Trait>::PostDeposit::get();
+
+        assert_eq!(
+            balances::Module::<Runtime>::free_balance(&NOT_FORUM_LEAD_ORIGIN_ID),
+            current_balance
+        );
+
+        let post_id = create_post_mock(
+            NOT_FORUM_LEAD_ORIGIN.clone(),
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            category_id,
+            thread_id,
+            good_post_text(),
+            true,
+            Ok(()),
+        );
+
+        current_balance -= <Runtime as Trait>::PostDeposit::get();
+
+        assert_eq!(
+            balances::Module::<Runtime>::free_balance(&NOT_FORUM_LEAD_ORIGIN_ID),
+            current_balance
+        );
+
+        update_category_membership_of_moderator_mock(
+            FORUM_MODERATOR_ORIGIN.clone(),
+            FORUM_MODERATOR_ORIGIN_ID,
+            category_id,
+            true,
+            Ok(()),
+        );
+
+        // regular user will fail to delete the thread
+        delete_post_mock(
+            NOT_FORUM_MODERATOR_ORIGIN.clone(),
+            NOT_FORUM_MODERATOR_ORIGIN_ID,
+            NOT_FORUM_MODERATOR_ORIGIN_ID,
+            category_id,
+            thread_id,
+            post_id,
+            Err(Error::<Runtime>::ForumUserIdNotMatchAccount.into()),
+            false,
+        );
+
+        // moderator not associated with thread will fail to delete it
+        delete_post_mock(
+            FORUM_MODERATOR_2_ORIGIN.clone(),
+            FORUM_MODERATOR_2_ORIGIN_ID,
+            FORUM_MODERATOR_2_ORIGIN_ID,
+            category_id,
+            thread_id,
+            post_id,
+            Err(Error::<Runtime>::ForumUserIdNotMatchAccount.into()),
+            false,
+        );
+
+        // moderator will not delete thread
+        delete_post_mock(
+            FORUM_MODERATOR_ORIGIN.clone(),
+            FORUM_MODERATOR_ORIGIN_ID,
+            FORUM_MODERATOR_ORIGIN_ID,
+            category_id,
+            thread_id,
+            post_id,
+            Err(Error::<Runtime>::ForumUserIdNotMatchAccount.into()),
+            false,
+        );
+
+        // forum lead will not delete thread
+        delete_post_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            thread_id,
+            post_id,
+            Err(Error::<Runtime>::AccountDoesNotMatchPostAuthor.into()),
+            false,
+        );
+
+        // another user will not delete thread
+        delete_post_mock(
+            NOT_FORUM_LEAD_2_ORIGIN.clone(),
+            NOT_FORUM_LEAD_2_ORIGIN_ID,
+            NOT_FORUM_LEAD_2_ORIGIN_ID,
+            category_id,
+            thread_id,
+            post_id,
+            Err(Error::<Runtime>::AccountDoesNotMatchPostAuthor.into()),
+            false,
+        );
+
+        // post creator will delete thread
+        delete_post_mock(
+            NOT_FORUM_LEAD_ORIGIN.clone(),
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            category_id,
+            thread_id,
+            post_id,
+            Ok(()),
+            true,
+        );
+
+        current_balance += <Runtime as Trait>::PostDeposit::get();
+
+        assert_eq!(
+            balances::Module::<Runtime>::free_balance(&NOT_FORUM_LEAD_ORIGIN_ID),
+            current_balance
+        );
+    });
+}
+
+#[test]
+// Test that not creator of a post can delete it after N blocks
+fn delete_post_not_creator() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    let initial_balance = 10_000_000;
+    with_test_externalities(|| {
+        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+        balances::Module::<Runtime>::make_free_balance_be(
+            &NOT_FORUM_LEAD_ORIGIN_ID,
+            initial_balance,
+        );
+
+        let mut current_balance = initial_balance;
+
+        assert_eq!(
+            balances::Module::<Runtime>::free_balance(&forum_lead),
+            current_balance
+        );
+
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+
+        let thread_id = create_thread_mock(
+            NOT_FORUM_LEAD_ORIGIN.clone(),
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            None,
+            Ok(()),
+        );
+
+        current_balance -= <Runtime as Trait>::ThreadDeposit::get();
+        current_balance -= <Runtime as Trait>::PostDeposit::get();
+
+        assert_eq!(
+            balances::Module::<Runtime>::free_balance(&NOT_FORUM_LEAD_ORIGIN_ID),
+            current_balance
+        );
+
+        let post_id = create_post_mock(
+            NOT_FORUM_LEAD_ORIGIN.clone(),
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            category_id,
+            thread_id,
+            good_post_text(),
+            true,
+            Ok(()),
+        );
+
+        current_balance -= <Runtime as Trait>::PostDeposit::get();
+
+        assert_eq!(
+            balances::Module::<Runtime>::free_balance(&NOT_FORUM_LEAD_ORIGIN_ID),
+            current_balance
+        );
+
+        update_category_membership_of_moderator_mock(
+            FORUM_MODERATOR_ORIGIN.clone(),
+            FORUM_MODERATOR_ORIGIN_ID,
+            category_id,
+            true,
+            Ok(()),
+        );
+
+        delete_thread_mock(
+            NOT_FORUM_LEAD_ORIGIN.clone(),
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            category_id,
+            thread_id,
+            Ok(()),
+        );
+
+        // post creator will not delete thread now
+        delete_post_mock(
+            NOT_FORUM_LEAD_2_ORIGIN.clone(),
+            NOT_FORUM_LEAD_2_ORIGIN_ID,
+            NOT_FORUM_LEAD_2_ORIGIN_ID,
+            category_id,
+            thread_id,
+            post_id,
+            Err(Error::<Runtime>::AccountDoesNotMatchPostAuthor.into()),
+            false,
+        );
+
+        current_balance += <Runtime as Trait>::ThreadDeposit::get();
+
+        assert_eq!(
+            balances::Module::<Runtime>::free_balance(&NOT_FORUM_LEAD_ORIGIN_ID),
+            current_balance
+        );
+
+        let current_block = System::block_number();
+        run_to_block(current_block + <Runtime as Trait>::PostLifeTime::get());
+
+        let not_creator_balance =
+            balances::Module::<Runtime>::free_balance(&NOT_FORUM_LEAD_2_ORIGIN_ID);
+
+        // not post creator wil not be able to delete hiding the post
+        delete_post_mock(
+            NOT_FORUM_LEAD_2_ORIGIN.clone(),
+            NOT_FORUM_LEAD_2_ORIGIN_ID,
+            NOT_FORUM_LEAD_2_ORIGIN_ID,
+            category_id,
+            thread_id,
+            post_id,
+            Err(Error::<Runtime>::AccountDoesNotMatchPostAuthor.into()),
+            true,
+        );
+
+        // not post creator will delete thread now
+        delete_post_mock(
+            NOT_FORUM_LEAD_2_ORIGIN.clone(),
+            NOT_FORUM_LEAD_2_ORIGIN_ID,
+            NOT_FORUM_LEAD_2_ORIGIN_ID,
+            category_id,
+            thread_id,
+            post_id,
+            Ok(()),
+            false,
+        );
+
+        assert_eq!(
+            balances::Module::<Runtime>::free_balance(&NOT_FORUM_LEAD_ORIGIN_ID),
+            current_balance
+        );
+
+        assert_eq!(
+            balances::Module::<Runtime>::free_balance(&NOT_FORUM_LEAD_2_ORIGIN_ID),
+            not_creator_balance + <Runtime as Trait>::PostDeposit::get()
+        );
+    });
+}
+
+#[test]
+fn set_stickied_threads_ok() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    let initial_balance = 10_000_000;
+    with_test_externalities(|| {
+        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+
+        let moderator_id = forum_lead;
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        update_category_membership_of_moderator_mock(
+            origin.clone(),
+            moderator_id,
+            category_id,
+            true,
+            Ok(()),
+        );
+        let thread_id = create_thread_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            None,
+            Ok(()),
+        );
+        set_stickied_threads_mock(
+            origin.clone(),
+            moderator_id,
+            category_id,
+            vec![thread_id],
+            Ok(()),
+        );
+
+        let thread_id_deleted = create_thread_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            None,
+            Ok(()),
+        );
+        moderate_thread_mock(
+            origin.clone(),
+            moderator_id,
+            category_id,
+            thread_id_deleted,
+            good_moderation_rationale(),
+            Ok(()),
+        );
+        // Cannot set a deleted thread as sticky.
+        set_stickied_threads_mock(
+            origin,
+            moderator_id,
+            category_id,
+            vec![thread_id, thread_id_deleted],
+            Err(Error::<Runtime>::ThreadDoesNotExist.into()),
+        );
+    });
+}
+
+#[test]
+fn set_stickied_threads_fails_with_duplicated_ids() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    let initial_balance = 10_000_000;
+
+    with_test_externalities(|| {
+        Balances::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+
+        let moderator_id = forum_lead;
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        update_category_membership_of_moderator_mock(
+            origin.clone(),
+            moderator_id,
+            category_id,
+            true,
+            Ok(()),
+        );
+        let thread_id = create_thread_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            None,
+            Ok(()),
+        );
+        set_stickied_threads_mock(
+            origin,
+            moderator_id,
+            category_id,
+            vec![thread_id, thread_id],
+            Err(Error::<Runtime>::StickiedThreadIdsDuplicates.into()),
+        );
+    });
+}
+
+#[test]
+fn set_stickied_threads_wrong_moderator() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    let initial_balance = 10_000_000;
+    with_test_externalities(|| {
+        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+
+        let moderator_id = forum_lead;
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+
+        let thread_id = create_thread_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            None,
+            Ok(()),
+        );
+        set_stickied_threads_mock(
+            origin,
+            moderator_id,
+            category_id,
+            vec![thread_id],
+            Err(Error::<Runtime>::ModeratorCantUpdateCategory.into()),
+        );
+    });
+}
+
+#[test]
+fn set_stickied_threads_thread_not_exists() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    let initial_balance = 10_000_000;
+    with_test_externalities(|| {
+        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+
+        let moderator_id = forum_lead;
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        update_category_membership_of_moderator_mock(
+            origin.clone(),
+            moderator_id,
+            category_id,
+            true,
+            Ok(()),
+        );
+        let thread_id = create_thread_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            None,
+            Ok(()),
+        );
+        let wrong_thread_id = thread_id + 1;
+        set_stickied_threads_mock(
+            origin,
+            moderator_id,
+            category_id,
+            vec![wrong_thread_id],
+            Err(Error::<Runtime>::ThreadDoesNotExist.into()),
+        );
+    });
+}
+
+/*
+ * Forum user bans
+ */
+
+#[test]
+// Test that banned forum user can't participate in the category and its subcategories
+fn ban_forum_user_blocks_participation_in_subcategories() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    let forum_user_id = NOT_FORUM_LEAD_ORIGIN_ID;
+    let initial_balance = 10_000_000;
+    with_test_externalities(|| {
+        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+        balances::Module::<Runtime>::make_free_balance_be(&forum_user_id, initial_balance);
+
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        let subcategory_id = create_category_mock(
+            origin.clone(),
+            Some(category_id),
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        let thread_id = create_thread_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            subcategory_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            Some(generate_poll_input(10)),
+            Ok(()),
+        );
+        let post_id = create_post_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            subcategory_id,
+            thread_id,
+            good_post_text(),
+            true,
+            Ok(()),
+        );
+
+        ban_forum_user_mock(
+            origin.clone(),
+            PrivilegedActor::Lead,
+            category_id,
+            forum_user_id,
+            None,
+            good_moderation_rationale(),
+            Ok(()),
+        );
+
+        create_thread_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            forum_user_id,
+            forum_user_id,
+            subcategory_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            None,
+            Err(Error::<Runtime>::ForumUserBanned.into()),
+        );
+        create_post_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            forum_user_id,
+            forum_user_id,
+            subcategory_id,
+            thread_id,
+            good_post_text(),
+            true,
+            Err(Error::<Runtime>::ForumUserBanned.into()),
+        );
+        react_post_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            forum_user_id,
+            subcategory_id,
+            thread_id,
+            post_id,
+            1,
+            Err(Error::<Runtime>::ForumUserBanned.into()),
+        );
+        vote_on_poll_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            forum_user_id,
+            subcategory_id,
+            thread_id,
+            1,
+            Err(Error::<Runtime>::ForumUserBanned.into()),
+        );
+
+        unban_forum_user_mock(
+            origin.clone(),
+            PrivilegedActor::Lead,
+            category_id,
+            forum_user_id,
+            Ok(()),
+        );
+
+        create_post_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            forum_user_id,
+            forum_user_id,
+            subcategory_id,
+            thread_id,
+            good_post_text(),
+            true,
+            Ok(()),
+        );
+        vote_on_poll_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            forum_user_id,
+            subcategory_id,
+            thread_id,
+            1,
+            Ok(()),
+        );
+    });
+}
+
+#[test]
+// Test that forum user suspension ends after the provided number of blocks
+fn ban_forum_user_suspension_expires() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    let moderator_id = FORUM_MODERATOR_ORIGIN_ID;
+    let forum_user_id = NOT_FORUM_LEAD_ORIGIN_ID;
+    let initial_balance = 10_000_000;
+    with_test_externalities(|| {
+        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+        balances::Module::<Runtime>::make_free_balance_be(&forum_user_id, initial_balance);
+
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        update_category_membership_of_moderator_mock(
+            origin.clone(),
+            moderator_id,
+            category_id,
+            true,
+            Ok(()),
+        );
+
+        let suspension_duration = 5;
+        ban_forum_user_mock(
+            FORUM_MODERATOR_ORIGIN,
+            PrivilegedActor::Moderator(moderator_id),
+            category_id,
+            forum_user_id,
+            Some(suspension_duration),
+            good_moderation_rationale(),
+            Ok(()),
+        );
+
+        create_thread_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            forum_user_id,
+            forum_user_id,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            None,
+            Err(Error::<Runtime>::ForumUserBanned.into()),
+        );
+
+        run_to_block(System::block_number() + suspension_duration);
+
+        create_thread_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            forum_user_id,
+            forum_user_id,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            None,
+            Ok(()),
+        );
+    });
+}
+
+#[test]
+// Test that the maximum suspension duration doesn't overflow the ban expiration block
+fn ban_forum_user_with_max_duration_succeeds() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    let forum_user_id = NOT_FORUM_LEAD_ORIGIN_ID;
+    let initial_balance = 10_000_000;
+    with_test_externalities(|| {
+        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+
+        run_to_block(System::block_number() + 1);
+
+        ban_forum_user_mock(
+            origin,
+            PrivilegedActor::Lead,
+            category_id,
+            forum_user_id,
+            Some(u64::MAX),
+            good_moderation_rationale(),
+            Ok(()),
+        );
+
+        assert_eq!(
+            TestForumModule::forum_user_ban_by_category(category_id, forum_user_id).expires_at,
+            Some(u64::MAX)
+        );
+    });
+}
+
+#[test]
+// Test ban permissions and parameters validation
+fn ban_forum_user_fails_with_invalid_actor_or_parameters() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    let moderator_id = FORUM_MODERATOR_ORIGIN_ID;
+    let forum_user_id = NOT_FORUM_LEAD_ORIGIN_ID;
+    with_test_externalities(|| {
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+
+        ban_forum_user_mock(
+            FORUM_MODERATOR_ORIGIN,
+            PrivilegedActor::Moderator(moderator_id),
+            category_id,
+            forum_user_id,
+            None,
+            good_moderation_rationale(),
+            Err(Error::<Runtime>::ModeratorCantUpdateCategory.into()),
+        );
+        ban_forum_user_mock(
+            NOT_FORUM_MODERATOR_ORIGIN,
+            PrivilegedActor::Moderator(NOT_FORUM_MODERATOR_ORIGIN_ID),
+            category_id,
+            forum_user_id,
+            None,
+            good_moderation_rationale(),
+            Err(Error::<Runtime>::ModeratorIdNotMatchAccount.into()),
+        );
+        ban_forum_user_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            PrivilegedActor::Lead,
+            category_id,
+            forum_user_id,
+            None,
+            good_moderation_rationale(),
+            Err(Error::<Runtime>::OriginNotForumLead.into()),
+        );
+        ban_forum_user_mock(
+            origin.clone(),
+            PrivilegedActor::Lead,
+            category_id,
+            forum_user_id,
+            Some(0),
+            good_moderation_rationale(),
+            Err(Error::<Runtime>::InvalidBanDuration.into()),
+        );
+        unban_forum_user_mock(
+            origin.clone(),
+            PrivilegedActor::Lead,
+            category_id,
+            forum_user_id,
+            Err(Error::<Runtime>::ForumUserNotBanned.into()),
+        );
+    });
+}
+
+/*
+ * Subscriptions and mentions
+ */
+
+#[test]
+// Test that thread and category path subscribers are notified about new posts
+fn subscribers_notified_about_new_posts() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    let category_subscriber_id = NOT_FORUM_LEAD_ORIGIN_ID;
+    let thread_subscriber_id = NOT_FORUM_LEAD_2_ORIGIN_ID;
+    let initial_balance = 10_000_000;
+    with_test_externalities(|| {
+        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        let subcategory_id = create_category_mock(
+            origin.clone(),
+            Some(category_id),
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        let thread_id = create_thread_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            subcategory_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            None,
+            Ok(()),
+        );
+
+        update_category_subscription_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            category_subscriber_id,
+            category_id,
+            true,
+            Ok(()),
+        );
+        update_thread_subscription_mock(
+            NOT_FORUM_LEAD_2_ORIGIN,
+            thread_subscriber_id,
+            subcategory_id,
+            thread_id,
+            true,
+            Ok(()),
+        );
+        // Post author is not notified about own posts
+        update_thread_subscription_mock(
+            origin.clone(),
+            forum_lead,
+            subcategory_id,
+            thread_id,
+            true,
+            Ok(()),
+        );
+
+        let post_id = TestForumModule::next_post_id();
+        assert_eq!(
+            TestForumModule::add_post(
+                mock_origin(origin.clone()),
+                forum_lead,
+                subcategory_id,
+                thread_id,
+                good_post_text(),
+                true,
+                BTreeSet::new(),
+                None,
+                BTreeSet::new(),
+            ),
+            Ok(())
+        );
+
+        let mut expected_subscribers = BTreeSet::new();
+        expected_subscribers.insert(category_subscriber_id);
+        expected_subscribers.insert(thread_subscriber_id);
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            TestEvent::forum_mod(RawEvent::PostSubscribersNotified(
+                post_id,
+                subcategory_id,
+                thread_id,
+                expected_subscribers
+            ))
+        );
+
+        let new_thread_id = TestForumModule::next_thread_id();
+        let initial_post_id = TestForumModule::next_post_id();
+        assert_eq!(
+            TestForumModule::create_thread(
+                mock_origin(origin.clone()),
+                forum_lead,
+                subcategory_id,
+                good_thread_metadata(),
+                good_thread_text(),
+                None,
+            ),
+            Ok(())
+        );
+
+        let mut expected_subscribers = BTreeSet::new();
+        expected_subscribers.insert(category_subscriber_id);
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            TestEvent::forum_mod(RawEvent::PostSubscribersNotified(
+                initial_post_id,
+                subcategory_id,
+                new_thread_id,
+                expected_subscribers
+            ))
+        );
+    });
+}
+
+#[test]
+fn update_subscription_fails_with_invalid_status_or_limit() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    let initial_balance = 10_000_000;
+    with_test_externalities(|| {
+        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        let thread_id = create_thread_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            None,
+            Ok(()),
+        );
+
+        update_thread_subscription_mock(
+            origin.clone(),
+            forum_lead,
+            category_id,
+            thread_id,
+            false,
+            Err(Error::<Runtime>::NotSubscribed.into()),
+        );
+        update_thread_subscription_mock(
+            origin.clone(),
+            forum_lead,
+            category_id,
+            thread_id,
+            true,
+            Ok(()),
+        );
+        update_thread_subscription_mock(
+            origin.clone(),
+            forum_lead,
+            category_id,
+            thread_id,
+            true,
+            Err(Error::<Runtime>::AlreadySubscribed.into()),
+        );
+        update_thread_subscription_mock(
+            NOT_FORUM_MEMBER_ORIGIN,
+            NOT_FORUM_MEMBER_ORIGIN_ID,
+            category_id,
+            thread_id,
+            true,
+            Err(Error::<Runtime>::ForumUserIdNotMatchAccount.into()),
+        );
+
+        // Fill the category subscribers up to the limit
+        let max_subscribers = MaxSubscribers::get();
+        let subscribers = [
+            forum_lead,
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            NOT_FORUM_LEAD_2_ORIGIN_ID,
+            1,
+        ];
+        for (index, subscriber_id) in subscribers.iter().enumerate() {
+            let result = if (index as u64) < max_subscribers {
+                Ok(())
+            } else {
+                Err(Error::<Runtime>::MapSizeLimit.into())
+            };
+
+            update_category_subscription_mock(
+                OriginType::Signed(*subscriber_id),
+                *subscriber_id,
+                category_id,
+                true,
+                result,
+            );
+        }
+
+        update_category_subscription_mock(origin, forum_lead, category_id, false, Ok(()));
+    });
+}
+
+#[test]
+fn update_subscription_fails_for_banned_or_not_whitelisted_forum_user() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    let initial_balance = 10_000_000;
+    with_test_externalities(|| {
+        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        let thread_id = create_thread_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            None,
+            Ok(()),
+        );
+
+        ban_forum_user_mock(
+            origin.clone(),
+            PrivilegedActor::Lead,
+            category_id,
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            None,
+            good_moderation_rationale(),
+            Ok(()),
+        );
+
+        update_thread_subscription_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            category_id,
+            thread_id,
+            true,
+            Err(Error::<Runtime>::ForumUserBanned.into()),
+        );
+        update_category_subscription_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            NOT_FORUM_LEAD_ORIGIN_ID,
+            category_id,
+            true,
+            Err(Error::<Runtime>::ForumUserBanned.into()),
+        );
+
+        update_category_privacy_mock(
+            origin.clone(),
+            PrivilegedActor::Lead,
+            category_id,
+            true,
+            Ok(()),
+        );
+
+        update_thread_subscription_mock(
+            NOT_FORUM_LEAD_2_ORIGIN,
+            NOT_FORUM_LEAD_2_ORIGIN_ID,
+            category_id,
+            thread_id,
+            true,
+            Err(Error::<Runtime>::ForumUserNotWhitelisted.into()),
+        );
+        update_category_subscription_mock(
+            NOT_FORUM_LEAD_2_ORIGIN,
+            NOT_FORUM_LEAD_2_ORIGIN_ID,
+            category_id,
+            true,
+            Err(Error::<Runtime>::ForumUserNotWhitelisted.into()),
+        );
+    });
+}
+
+#[test]
+// Test that mentioned members get the mention event
+fn add_post_with_mentions() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    let initial_balance = 10_000_000;
+    with_test_externalities(|| {
+        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        let thread_id = create_thread_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            None,
+            Ok(()),
+        );
+
+        let mut mentions = BTreeSet::new();
+        mentions.insert(NOT_FORUM_LEAD_ORIGIN_ID);
+        mentions.insert(NOT_FORUM_LEAD_2_ORIGIN_ID);
+
+        let post_id = TestForumModule::next_post_id();
+        assert_eq!(
+            TestForumModule::add_post(
+                mock_origin(origin.clone()),
+                forum_lead,
+                category_id,
+                thread_id,
+                good_post_text(),
+                true,
+                mentions.clone(),
+                None,
+                BTreeSet::new(),
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            System::events().last().unwrap().event,
+            TestEvent::forum_mod(RawEvent::MembersMentioned(
+                post_id,
+                forum_lead,
+                category_id,
+                thread_id,
+                mentions
+            ))
+        );
+
+        let max_mentions = MaxMentionsPerPost::get();
+        let too_many_mentions = (0..=max_mentions as u128).collect::<BTreeSet<_>>();
+        assert_err!(
+            TestForumModule::add_post(
+                mock_origin(origin),
+                forum_lead,
+                category_id,
+                thread_id,
+                good_post_text(),
+                true,
+                too_many_mentions,
+                None,
+                BTreeSet::new(),
+            ),
+            Error::<Runtime>::MaxMentionsPerPostExceeded
+        );
+    });
+}
+
+/*
+ * Post replies and quotes
+ */
+
+#[test]
+// Test that a post can reply to a post of the same thread and quote posts of any thread
+fn add_post_with_reply_and_quotes() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    let initial_balance = 10_000_000;
+    with_test_externalities(|| {
+        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        let thread_id = create_thread_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            None,
+            Ok(()),
+        );
+        let other_thread_id = create_thread_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            None,
+            Ok(()),
+        );
+
+        let parent_post_id = create_post_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            thread_id,
+            good_post_text(),
+            true,
+            Ok(()),
+        );
+        let quoted_post_id = create_post_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            other_thread_id,
+            good_post_text(),
+            true,
+            Ok(()),
+        );
+
+        let mut quotes = BTreeSet::new();
+        quotes.insert(ExtendedPostIdObject {
+            category_id,
+            thread_id: other_thread_id,
+            post_id: quoted_post_id,
+        });
+
+        let post_id = TestForumModule::next_post_id();
+        assert_eq!(
+            TestForumModule::add_post(
+                mock_origin(origin.clone()),
+                forum_lead,
+                category_id,
+                thread_id,
+                good_post_text(),
+                true,
+                BTreeSet::new(),
+                Some(parent_post_id),
+                quotes.clone(),
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            System::events().last().unwrap().event,
+            TestEvent::forum_mod(RawEvent::PostReferencesAdded(
+                post_id,
+                category_id,
+                thread_id,
+                Some(parent_post_id),
+                quotes
+            ))
+        );
+
+        assert_eq!(
+            TestForumModule::post_by_id(thread_id, post_id).parent_post_id,
+            Some(parent_post_id)
+        );
+        assert!(TestForumModule::post_replies(thread_id, parent_post_id).contains(&post_id));
+
+        // Non-editable replies are not stored, so they are not recorded as replies.
+        let non_editable_post_id = TestForumModule::next_post_id();
+        assert_eq!(
+            TestForumModule::add_post(
+                mock_origin(origin.clone()),
+                forum_lead,
+                category_id,
+                thread_id,
+                good_post_text(),
+                false,
+                BTreeSet::new(),
+                Some(parent_post_id),
+                BTreeSet::new(),
+            ),
+            Ok(())
+        );
+        assert!(!TestForumModule::post_replies(thread_id, parent_post_id)
+            .contains(&non_editable_post_id));
+    });
+}
+
+#[test]
+// Test that replies to a deleted post are moved to the deleted post's parent
+fn delete_post_moves_replies_to_parent() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    let initial_balance = 10_000_000;
+    with_test_externalities(|| {
+        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        let thread_id = create_thread_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            None,
+            Ok(()),
+        );
+
+        let root_post_id = create_post_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            thread_id,
+            good_post_text(),
+            true,
+            Ok(()),
+        );
+
+        let add_reply = |parent_post_id| {
+            let post_id = TestForumModule::next_post_id();
+            assert_eq!(
+                TestForumModule::add_post(
+                    mock_origin(origin.clone()),
+                    forum_lead,
+                    category_id,
+                    thread_id,
+                    good_post_text(),
+                    true,
+                    BTreeSet::new(),
+                    Some(parent_post_id),
+                    BTreeSet::new(),
+                ),
+                Ok(())
+            );
+            post_id
+        };
+
+        let reply_id = add_reply(root_post_id);
+        let nested_reply_id = add_reply(reply_id);
+
+        delete_post_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            thread_id,
+            reply_id,
+            Ok(()),
+            false,
+        );
+
+        assert_eq!(
+            TestForumModule::post_by_id(thread_id, nested_reply_id).parent_post_id,
+            Some(root_post_id)
+        );
+        assert_eq!(
+            TestForumModule::post_replies(thread_id, root_post_id),
+            vec![nested_reply_id].into_iter().collect::<BTreeSet<_>>()
+        );
+        assert!(!<PostReplies<Runtime>>::contains_key(thread_id, reply_id));
+    });
+}
+
+#[test]
+// Test that replies to a deleted post which don't fit into the parent's replies become top level posts
+fn delete_post_keeps_parent_replies_limit() {
+    let forum_lead = FORUM_LEAD_ORIGIN_ID;
+    let origin = OriginType::Signed(forum_lead);
+    let initial_balance = 10_000_000;
+    with_test_externalities(|| {
+        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+
+        let category_id = create_category_mock(
+            origin.clone(),
+            None,
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
+        let thread_id = create_thread_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            None,
+            Ok(()),
+        );
+
+        let root_post_id = create_post_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            thread_id,
+            good_post_text(),
+            true,
+            Ok(()),
+        );
+
+        let add_reply = |parent_post_id| {
+            let post_id = TestForumModule::next_post_id();
+            assert_eq!(
+                TestForumModule::add_post(
+                    mock_origin(origin.clone()),
+                    forum_lead,
+                    category_id,
+                    thread_id,
+                    good_post_text(),
+                    true,
+                    BTreeSet::new(),
+                    Some(parent_post_id),
+                    BTreeSet::new(),
+                ),
+                Ok(())
+            );
+            post_id
+        };
+
+        // Fill the root post replies up to the limit
+        let reply_id = add_reply(root_post_id);
+        let mut root_replies = (1..MaxRepliesPerPost::get())
+            .map(|_| add_reply(root_post_id))
+            .collect::<BTreeSet<_>>();
+
+        let nested_reply_ids = (0..MaxRepliesPerPost::get())
+            .map(|_| add_reply(reply_id))
+            .collect::<Vec<_>>();
+
+        delete_post_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            category_id,
+            thread_id,
+            reply_id,
+            Ok(()),
+            false,
+        );
+
+        // Only one nested reply takes the place of the deleted post
+        root_replies.insert(nested_reply_ids[0]);
+        assert_eq!(
+            TestForumModule::post_replies(thread_id, root_post_id),
+            root_replies
+        );
+        assert_eq!(
+            TestForumModule::post_by_id(thread_id, nested_reply_ids[0]).parent_post_id,
+            Some(root_post_id)
+        );
+        for nested_reply_id in &nested_reply_ids[1..] {
+            assert_eq!(
+                TestForumModule::post_by_id(thread_id, nested_reply_id).parent_post_id,
+                None
+            );
+        }
+    });
 }
 
-/*
- * Delete post
-*/
-
 #[test]
-// Test that post creator can delete it
-fn delete_post_creator() {
+// Test that posts cannot reference missing posts or exceed the reply and quote limits
+fn add_post_fails_with_invalid_references() {
     let forum_lead = FORUM_LEAD_ORIGIN_ID;
     let origin = OriginType::Signed(forum_lead);
     let initial_balance = 10_000_000;
     with_test_externalities(|| {
         balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
-        balances::Module::<Runtime>::make_free_balance_be(
-            &NOT_FORUM_LEAD_ORIGIN_ID,
-            initial_balance,
-        );
-
-        let mut current_balance = initial_balance;
-
-        assert_eq!(
-            balances::Module::<Runtime>::free_balance(&forum_lead),
-            current_balance
-        );
 
         let category_id = create_category_mock(
             origin.clone(),
@@ -2183,152 +4049,114 @@ fn delete_post_creator() {
             good_category_description(),
             Ok(()),
         );
-
         let thread_id = create_thread_mock(
-            NOT_FORUM_LEAD_ORIGIN.clone(),
-            NOT_FORUM_LEAD_ORIGIN_ID,
-            NOT_FORUM_LEAD_ORIGIN_ID,
+            origin.clone(),
+            forum_lead,
+            forum_lead,
             category_id,
             good_thread_metadata(),
             good_thread_text(),
             None,
             Ok(()),
         );
-
-        current_balance -= <Runtime as Trait>::ThreadDeposit::get();
-        current_balance -= <Runtime as Trait>::PostDeposit::get();
-
-        assert_eq!(
-            balances::Module::<Runtime>::free_balance(&NOT_FORUM_LEAD_ORIGIN_ID),
-            current_balance
-        );
-
-        let post_id = create_post_mock(
-            NOT_FORUM_LEAD_ORIGIN.clone(),
-            NOT_FORUM_LEAD_ORIGIN_ID,
-            NOT_FORUM_LEAD_ORIGIN_ID,
+        let other_thread_id = create_thread_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
             category_id,
-            thread_id,
-            good_post_text(),
-            true,
+            good_thread_metadata(),
+            good_thread_text(),
+            None,
             Ok(()),
         );
-
-        current_balance -= <Runtime as Trait>::PostDeposit::get();
-
-        assert_eq!(
-            balances::Module::<Runtime>::free_balance(&NOT_FORUM_LEAD_ORIGIN_ID),
-            current_balance
-        );
-
-        update_category_membership_of_moderator_mock(
-            FORUM_MODERATOR_ORIGIN.clone(),
-            FORUM_MODERATOR_ORIGIN_ID,
+        let other_thread_post_id = create_post_mock(
+            origin.clone(),
+            forum_lead,
+            forum_lead,
             category_id,
+            other_thread_id,
+            good_post_text(),
             true,
             Ok(()),
         );
 
-        // regular user will fail to delete the thread
-        delete_post_mock(
-            NOT_FORUM_MODERATOR_ORIGIN.clone(),
-            NOT_FORUM_MODERATOR_ORIGIN_ID,
-            NOT_FORUM_MODERATOR_ORIGIN_ID,
-            category_id,
-            thread_id,
-            post_id,
-            Err(Error::<Runtime>::ForumUserIdNotMatchAccount.into()),
-            false,
+        let add_post = |parent_post_id, quotes| {
+            TestForumModule::add_post(
+                mock_origin(origin.clone()),
+                forum_lead,
+                category_id,
+                thread_id,
+                good_post_text(),
+                true,
+                BTreeSet::new(),
+                parent_post_id,
+                quotes,
+            )
+        };
+
+        // The parent post must belong to the same thread
+        assert_err!(
+            add_post(Some(other_thread_post_id), BTreeSet::new()),
+            Error::<Runtime>::ParentPostDoesNotExist
         );
 
-        // moderator not associated with thread will fail to delete it
-        delete_post_mock(
-            FORUM_MODERATOR_2_ORIGIN.clone(),
-            FORUM_MODERATOR_2_ORIGIN_ID,
-            FORUM_MODERATOR_2_ORIGIN_ID,
+        let mut missing_quote = BTreeSet::new();
+        missing_quote.insert(ExtendedPostIdObject {
             category_id,
-            thread_id,
-            post_id,
-            Err(Error::<Runtime>::ForumUserIdNotMatchAccount.into()),
-            false,
+            thread_id: other_thread_id,
+            post_id: TestForumModule::next_post_id(),
+        });
+        assert_err!(
+            add_post(None, missing_quote),
+            Error::<Runtime>::QuotedPostDoesNotExist
         );
 
-        // moderator will not delete thread
-        delete_post_mock(
-            FORUM_MODERATOR_ORIGIN.clone(),
-            FORUM_MODERATOR_ORIGIN_ID,
-            FORUM_MODERATOR_ORIGIN_ID,
-            category_id,
-            thread_id,
-            post_id,
-            Err(Error::<Runtime>::ForumUserIdNotMatchAccount.into()),
-            false,
+        let too_many_quotes = (0..=MaxQuotesPerPost::get())
+            .map(|post_id| ExtendedPostIdObject {
+                category_id,
+                thread_id: other_thread_id,
+                post_id,
+            })
+            .collect::<BTreeSet<_>>();
+        assert_err!(
+            add_post(None, too_many_quotes),
+            Error::<Runtime>::MaxQuotesPerPostExceeded
         );
 
-        // forum lead will not delete thread
-        delete_post_mock(
+        let parent_post_id = create_post_mock(
             origin.clone(),
             forum_lead,
             forum_lead,
             category_id,
             thread_id,
-            post_id,
-            Err(Error::<Runtime>::AccountDoesNotMatchPostAuthor.into()),
-            false,
-        );
-
-        // another user will not delete thread
-        delete_post_mock(
-            NOT_FORUM_LEAD_2_ORIGIN.clone(),
-            NOT_FORUM_LEAD_2_ORIGIN_ID,
-            NOT_FORUM_LEAD_2_ORIGIN_ID,
-            category_id,
-            thread_id,
-            post_id,
-            Err(Error::<Runtime>::AccountDoesNotMatchPostAuthor.into()),
-            false,
-        );
-
-        // post creator will delete thread
-        delete_post_mock(
-            NOT_FORUM_LEAD_ORIGIN.clone(),
-            NOT_FORUM_LEAD_ORIGIN_ID,
-            NOT_FORUM_LEAD_ORIGIN_ID,
-            category_id,
-            thread_id,
-            post_id,
-            Ok(()),
+            good_post_text(),
             true,
+            Ok(()),
         );
-
-        current_balance += <Runtime as Trait>::PostDeposit::get();
-
-        assert_eq!(
-            balances::Module::<Runtime>::free_balance(&NOT_FORUM_LEAD_ORIGIN_ID),
-            current_balance
+        for _ in 0..MaxRepliesPerPost::get() {
+            assert_eq!(add_post(Some(parent_post_id), BTreeSet::new()), Ok(()));
+        }
+        assert_err!(
+            add_post(Some(parent_post_id), BTreeSet::new()),
+            Error::<Runtime>::MapSizeLimit
         );
     });
 }
 
+/*
+ * Private categories
+ */
+
 #[test]
-// Test that not creator of a post can delete it after N blocks
-fn delete_post_not_creator() {
+// Test that only whitelisted members can create threads and posts in a private category subtree
+fn private_category_restricts_participation_to_whitelisted_members() {
     let forum_lead = FORUM_LEAD_ORIGIN_ID;
     let origin = OriginType::Signed(forum_lead);
+    let forum_user_id = NOT_FORUM_LEAD_ORIGIN_ID;
     let initial_balance = 10_000_000;
     with_test_externalities(|| {
         balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
-        balances::Module::<Runtime>::make_free_balance_be(
-            &NOT_FORUM_LEAD_ORIGIN_ID,
-            initial_balance,
-        );
-
-        let mut current_balance = initial_balance;
-
-        assert_eq!(
-            balances::Module::<Runtime>::free_balance(&forum_lead),
-            current_balance
-        );
+        balances::Module::<Runtime>::make_free_balance_be(&forum_user_id, initial_balance);
 
         let category_id = create_category_mock(
             origin.clone(),
@@ -2337,131 +4165,156 @@ fn delete_post_not_creator() {
             good_category_description(),
             Ok(()),
         );
-
+        let subcategory_id = create_category_mock(
+            origin.clone(),
+            Some(category_id),
+            good_category_title(),
+            good_category_description(),
+            Ok(()),
+        );
         let thread_id = create_thread_mock(
-            NOT_FORUM_LEAD_ORIGIN.clone(),
-            NOT_FORUM_LEAD_ORIGIN_ID,
-            NOT_FORUM_LEAD_ORIGIN_ID,
-            category_id,
+            origin.clone(),
+            forum_lead,
+            forum_lead,
+            subcategory_id,
             good_thread_metadata(),
             good_thread_text(),
             None,
             Ok(()),
         );
 
-        current_balance -= <Runtime as Trait>::ThreadDeposit::get();
-        current_balance -= <Runtime as Trait>::PostDeposit::get();
-
-        assert_eq!(
-            balances::Module::<Runtime>::free_balance(&NOT_FORUM_LEAD_ORIGIN_ID),
-            current_balance
-        );
-
-        let post_id = create_post_mock(
-            NOT_FORUM_LEAD_ORIGIN.clone(),
-            NOT_FORUM_LEAD_ORIGIN_ID,
-            NOT_FORUM_LEAD_ORIGIN_ID,
+        update_category_privacy_mock(
+            origin.clone(),
+            PrivilegedActor::Lead,
             category_id,
-            thread_id,
-            good_post_text(),
             true,
             Ok(()),
         );
 
-        current_balance -= <Runtime as Trait>::PostDeposit::get();
-
-        assert_eq!(
-            balances::Module::<Runtime>::free_balance(&NOT_FORUM_LEAD_ORIGIN_ID),
-            current_balance
+        create_thread_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            forum_user_id,
+            forum_user_id,
+            subcategory_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            None,
+            Err(Error::<Runtime>::ForumUserNotWhitelisted.into()),
+        );
+        create_post_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            forum_user_id,
+            forum_user_id,
+            subcategory_id,
+            thread_id,
+            good_post_text(),
+            true,
+            Err(Error::<Runtime>::ForumUserNotWhitelisted.into()),
         );
 
-        update_category_membership_of_moderator_mock(
-            FORUM_MODERATOR_ORIGIN.clone(),
-            FORUM_MODERATOR_ORIGIN_ID,
+        update_category_whitelist_membership_mock(
+            origin.clone(),
+            PrivilegedActor::Lead,
             category_id,
+            forum_user_id,
             true,
             Ok(()),
         );
 
-        delete_thread_mock(
-            NOT_FORUM_LEAD_ORIGIN.clone(),
-            NOT_FORUM_LEAD_ORIGIN_ID,
-            NOT_FORUM_LEAD_ORIGIN_ID,
-            category_id,
+        create_thread_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            forum_user_id,
+            forum_user_id,
+            subcategory_id,
+            good_thread_metadata(),
+            good_thread_text(),
+            None,
+            Ok(()),
+        );
+        create_post_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            forum_user_id,
+            forum_user_id,
+            subcategory_id,
             thread_id,
+            good_post_text(),
+            true,
             Ok(()),
         );
 
-        // post creator will not delete thread now
-        delete_post_mock(
-            NOT_FORUM_LEAD_2_ORIGIN.clone(),
-            NOT_FORUM_LEAD_2_ORIGIN_ID,
-            NOT_FORUM_LEAD_2_ORIGIN_ID,
-            category_id,
+        // Subcategory privacy applies on top of its ancestors whitelists
+        update_category_privacy_mock(
+            origin.clone(),
+            PrivilegedActor::Lead,
+            subcategory_id,
+            true,
+            Ok(()),
+        );
+        create_post_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            forum_user_id,
+            forum_user_id,
+            subcategory_id,
             thread_id,
-            post_id,
-            Err(Error::<Runtime>::AccountDoesNotMatchPostAuthor.into()),
-            false,
+            good_post_text(),
+            true,
+            Err(Error::<Runtime>::ForumUserNotWhitelisted.into()),
         );
 
-        current_balance += <Runtime as Trait>::ThreadDeposit::get();
-
-        assert_eq!(
-            balances::Module::<Runtime>::free_balance(&NOT_FORUM_LEAD_ORIGIN_ID),
-            current_balance
+        update_category_privacy_mock(
+            origin.clone(),
+            PrivilegedActor::Lead,
+            subcategory_id,
+            false,
+            Ok(()),
         );
-
-        let current_block = System::block_number();
-        run_to_block(current_block + <Runtime as Trait>::PostLifeTime::get());
-
-        let not_creator_balance =
-            balances::Module::<Runtime>::free_balance(&NOT_FORUM_LEAD_2_ORIGIN_ID);
-
-        // not post creator wil not be able to delete hiding the post
-        delete_post_mock(
-            NOT_FORUM_LEAD_2_ORIGIN.clone(),
-            NOT_FORUM_LEAD_2_ORIGIN_ID,
-            NOT_FORUM_LEAD_2_ORIGIN_ID,
+        update_category_whitelist_membership_mock(
+            origin.clone(),
+            PrivilegedActor::Lead,
             category_id,
+            forum_user_id,
+            false,
+            Ok(()),
+        );
+        create_post_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            forum_user_id,
+            forum_user_id,
+            subcategory_id,
             thread_id,
-            post_id,
-            Err(Error::<Runtime>::AccountDoesNotMatchPostAuthor.into()),
+            good_post_text(),
             true,
+            Err(Error::<Runtime>::ForumUserNotWhitelisted.into()),
         );
 
-        // not post creator will delete thread now
-        delete_post_mock(
-            NOT_FORUM_LEAD_2_ORIGIN.clone(),
-            NOT_FORUM_LEAD_2_ORIGIN_ID,
-            NOT_FORUM_LEAD_2_ORIGIN_ID,
+        update_category_privacy_mock(
+            origin.clone(),
+            PrivilegedActor::Lead,
             category_id,
-            thread_id,
-            post_id,
-            Ok(()),
             false,
+            Ok(()),
         );
-
-        assert_eq!(
-            balances::Module::<Runtime>::free_balance(&NOT_FORUM_LEAD_ORIGIN_ID),
-            current_balance
-        );
-
-        assert_eq!(
-            balances::Module::<Runtime>::free_balance(&NOT_FORUM_LEAD_2_ORIGIN_ID),
-            not_creator_balance + <Runtime as Trait>::PostDeposit::get()
+        create_post_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            forum_user_id,
+            forum_user_id,
+            subcategory_id,
+            thread_id,
+            good_post_text(),
+            true,
+            Ok(()),
         );
     });
 }
 
 #[test]
-fn set_stickied_threads_ok() {
+// Test that the category privacy and whitelist are managed by the lead and category moderators
+fn update_category_whitelist_fails_with_invalid_actor_or_state() {
     let forum_lead = FORUM_LEAD_ORIGIN_ID;
     let origin = OriginType::Signed(forum_lead);
-    let initial_balance = 10_000_000;
+    let moderator_id = FORUM_MODERATOR_ORIGIN_ID;
+    let forum_user_id = NOT_FORUM_LEAD_ORIGIN_ID;
     with_test_externalities(|| {
-        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
-
-        let moderator_id = forum_lead;
         let category_id = create_category_mock(
             origin.clone(),
             None,
@@ -2469,6 +4322,30 @@ fn set_stickied_threads_ok() {
             good_category_description(),
             Ok(()),
         );
+
+        update_category_whitelist_membership_mock(
+            origin.clone(),
+            PrivilegedActor::Lead,
+            category_id,
+            forum_user_id,
+            true,
+            Err(Error::<Runtime>::CategoryNotPrivate.into()),
+        );
+        update_category_privacy_mock(
+            FORUM_MODERATOR_ORIGIN,
+            PrivilegedActor::Moderator(moderator_id),
+            category_id,
+            true,
+            Err(Error::<Runtime>::ModeratorCantUpdateCategory.into()),
+        );
+        update_category_privacy_mock(
+            NOT_FORUM_LEAD_ORIGIN,
+            PrivilegedActor::Lead,
+            category_id,
+            true,
+            Err(Error::<Runtime>::OriginNotForumLead.into()),
+        );
+
         update_category_membership_of_moderator_mock(
             origin.clone(),
             moderator_id,
@@ -2476,63 +4353,84 @@ fn set_stickied_threads_ok() {
             true,
             Ok(()),
         );
-        let thread_id = create_thread_mock(
-            origin.clone(),
-            forum_lead,
-            forum_lead,
+        update_category_privacy_mock(
+            FORUM_MODERATOR_ORIGIN,
+            PrivilegedActor::Moderator(moderator_id),
             category_id,
-            good_thread_metadata(),
-            good_thread_text(),
-            None,
+            true,
             Ok(()),
         );
-        set_stickied_threads_mock(
+        update_category_privacy_mock(
             origin.clone(),
-            moderator_id,
+            PrivilegedActor::Lead,
             category_id,
-            vec![thread_id],
-            Ok(()),
+            true,
+            Err(Error::<Runtime>::CategoryNotBeingUpdated.into()),
         );
 
-        let thread_id_deleted = create_thread_mock(
-            origin.clone(),
-            forum_lead,
-            forum_lead,
+        update_category_whitelist_membership_mock(
+            FORUM_MODERATOR_ORIGIN,
+            PrivilegedActor::Moderator(moderator_id),
             category_id,
-            good_thread_metadata(),
-            good_thread_text(),
-            None,
-            Ok(()),
+            forum_user_id,
+            false,
+            Err(Error::<Runtime>::ForumUserNotWhitelisted.into()),
         );
-        moderate_thread_mock(
-            origin.clone(),
-            moderator_id,
+        update_category_whitelist_membership_mock(
+            FORUM_MODERATOR_ORIGIN,
+            PrivilegedActor::Moderator(moderator_id),
             category_id,
-            thread_id_deleted,
-            good_moderation_rationale(),
+            forum_user_id,
+            true,
             Ok(()),
         );
-        // Cannot set a deleted thread as sticky.
-        set_stickied_threads_mock(
-            origin,
-            moderator_id,
+        update_category_whitelist_membership_mock(
+            FORUM_MODERATOR_ORIGIN,
+            PrivilegedActor::Moderator(moderator_id),
             category_id,
-            vec![thread_id, thread_id_deleted],
-            Err(Error::<Runtime>::ThreadDoesNotExist.into()),
+            forum_user_id,
+            true,
+            Err(Error::<Runtime>::ForumUserAlreadyWhitelisted.into()),
+        );
+
+        // test MaxWhitelistedMembers
+        let max_whitelisted = MaxWhitelistedMembers::get() as u128;
+        for whitelisted_id in 1..max_whitelisted {
+            update_category_whitelist_membership_mock(
+                origin.clone(),
+                PrivilegedActor::Lead,
+                category_id,
+                forum_user_id + whitelisted_id,
+                true,
+                Ok(()),
+            );
+        }
+        update_category_whitelist_membership_mock(
+            origin.clone(),
+            PrivilegedActor::Lead,
+            category_id,
+            forum_user_id + max_whitelisted,
+            true,
+            Err(Error::<Runtime>::MapSizeLimit.into()),
         );
     });
 }
 
+/*
+ * Moderation log
+ */
+
 #[test]
-fn set_stickied_threads_fails_with_duplicated_ids() {
+// Test that moderator actions are logged per moderator and lead actions are not
+fn moderation_log_records_moderator_actions() {
     let forum_lead = FORUM_LEAD_ORIGIN_ID;
     let origin = OriginType::Signed(forum_lead);
+    let moderator_id = FORUM_MODERATOR_ORIGIN_ID;
+    let forum_user_id = NOT_FORUM_LEAD_ORIGIN_ID;
     let initial_balance = 10_000_000;
-
     with_test_externalities(|| {
-        Balances::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
+        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
 
-        let moderator_id = forum_lead;
         let category_id = create_category_mock(
             origin.clone(),
             None,
@@ -2547,72 +4445,67 @@ fn set_stickied_threads_fails_with_duplicated_ids() {
             true,
             Ok(()),
         );
-        let thread_id = create_thread_mock(
+
+        ban_forum_user_mock(
             origin.clone(),
-            forum_lead,
-            forum_lead,
+            PrivilegedActor::Lead,
             category_id,
-            good_thread_metadata(),
-            good_thread_text(),
+            forum_user_id,
             None,
+            good_moderation_rationale(),
             Ok(()),
         );
-        set_stickied_threads_mock(
-            origin,
-            moderator_id,
+        unban_forum_user_mock(
+            FORUM_MODERATOR_ORIGIN,
+            PrivilegedActor::Moderator(moderator_id),
             category_id,
-            vec![thread_id, thread_id],
-            Err(Error::<Runtime>::StickiedThreadIdsDuplicates.into()),
-        );
-    });
-}
-
-#[test]
-fn set_stickied_threads_wrong_moderator() {
-    let forum_lead = FORUM_LEAD_ORIGIN_ID;
-    let origin = OriginType::Signed(forum_lead);
-    let initial_balance = 10_000_000;
-    with_test_externalities(|| {
-        balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
-
-        let moderator_id = forum_lead;
-        let category_id = create_category_mock(
-            origin.clone(),
-            None,
-            good_category_title(),
-            good_category_description(),
+            forum_user_id,
             Ok(()),
         );
 
-        let thread_id = create_thread_mock(
-            origin.clone(),
-            forum_lead,
-            forum_lead,
+        run_to_block(System::block_number() + 1);
+
+        ban_forum_user_mock(
+            FORUM_MODERATOR_ORIGIN,
+            PrivilegedActor::Moderator(moderator_id),
             category_id,
-            good_thread_metadata(),
-            good_thread_text(),
+            forum_user_id,
             None,
+            good_moderation_rationale(),
             Ok(()),
         );
-        set_stickied_threads_mock(
-            origin,
-            moderator_id,
-            category_id,
-            vec![thread_id],
-            Err(Error::<Runtime>::ModeratorCantUpdateCategory.into()),
+
+        assert_eq!(
+            TestForumModule::moderation_log(moderator_id),
+            vec![
+                ModerationLogEntry {
+                    action: ModerationAction::ForumUserUnbanned(category_id, forum_user_id),
+                    rationale_hash: None,
+                    block: System::block_number() - 1,
+                },
+                ModerationLogEntry {
+                    action: ModerationAction::ForumUserBanned(category_id, forum_user_id),
+                    rationale_hash: Some(Runtime::calculate_hash(
+                        good_moderation_rationale().as_slice()
+                    )),
+                    block: System::block_number(),
+                },
+            ]
         );
     });
 }
 
 #[test]
-fn set_stickied_threads_thread_not_exists() {
+// Test that the moderation log is bounded in size and pruned by age
+fn moderation_log_is_bounded_and_pruned_by_age() {
     let forum_lead = FORUM_LEAD_ORIGIN_ID;
     let origin = OriginType::Signed(forum_lead);
+    let moderator_id = FORUM_MODERATOR_ORIGIN_ID;
+    let forum_user_id = NOT_FORUM_LEAD_ORIGIN_ID;
     let initial_balance = 10_000_000;
     with_test_externalities(|| {
         balances::Module::<Runtime>::make_free_balance_be(&forum_lead, initial_balance);
 
-        let moderator_id = forum_lead;
         let category_id = create_category_mock(
             origin.clone(),
             None,
@@ -2627,23 +4520,55 @@ fn set_stickied_threads_thread_not_exists() {
             true,
             Ok(()),
         );
-        let thread_id = create_thread_mock(
-            origin.clone(),
-            forum_lead,
-            forum_lead,
+
+        let max_log_size = MaxModerationLogSize::get() as usize;
+        for _ in 0..max_log_size {
+            ban_forum_user_mock(
+                FORUM_MODERATOR_ORIGIN,
+                PrivilegedActor::Moderator(moderator_id),
+                category_id,
+                forum_user_id,
+                None,
+                good_moderation_rationale(),
+                Ok(()),
+            );
+            unban_forum_user_mock(
+                FORUM_MODERATOR_ORIGIN,
+                PrivilegedActor::Moderator(moderator_id),
+                category_id,
+                forum_user_id,
+                Ok(()),
+            );
+        }
+
+        let log = TestForumModule::moderation_log(moderator_id);
+        assert_eq!(log.len(), max_log_size);
+        assert_eq!(
+            log.last().unwrap().action,
+            ModerationAction::ForumUserUnbanned(category_id, forum_user_id)
+        );
+
+        run_to_block(System::block_number() + ModerationLogLifeTime::get());
+
+        assert!(TestForumModule::moderation_log(moderator_id).is_empty());
+        assert_eq!(
+            TestForumModule::moderation_log_by_moderator_id(moderator_id).len(),
+            max_log_size
+        );
+
+        ban_forum_user_mock(
+            FORUM_MODERATOR_ORIGIN,
+            PrivilegedActor::Moderator(moderator_id),
             category_id,
-            good_thread_metadata(),
-            good_thread_text(),
+            forum_user_id,
             None,
+            good_moderation_rationale(),
             Ok(()),
         );
-        let wrong_thread_id = thread_id + 1;
-        set_stickied_threads_mock(
-            origin,
-            moderator_id,
-            category_id,
-            vec![wrong_thread_id],
-            Err(Error::<Runtime>::ThreadDoesNotExist.into()),
+
+        assert_eq!(
+            TestForumModule::moderation_log_by_moderator_id(moderator_id).len(),
+            1
         );
     });
 }
@@ -2690,6 +4615,9 @@ fn test_migration_not_done() {
                 thread_id,
                 good_post_text(),
                 true,
+                BTreeSet::new(),
+                None,
+                BTreeSet::new(),
             ),
             Error::<Runtime>::DataMigrationNotDone,
         );
@@ -2800,3 +4728,88 @@ fn storage_limit_checks() {
         }
     });
 }
+
+#[test]
+fn migrate_posts_succeeds() {
+    with_test_externalities(|| {
+        let thread_id = 1;
+        let post_id = 1;
+        let old_post = OldPost {
+            thread_id,
+            text_hash: <Runtime as Trait>::calculate_hash(b"text"),
+            author_id: 1,
+            cleanup_pay_off: 10,
+            last_edited: 5,
+        };
+
+        frame_support::storage::unhashed::put(
+            &<PostById<Runtime>>::hashed_key_for(thread_id, post_id),
+            &old_post,
+        );
+
+        TestForumModule::migrate_posts();
+
+        assert_eq!(
+            <PostById<Runtime>>::get(thread_id, post_id),
+            Post {
+                thread_id,
+                parent_post_id: None,
+                text_hash: <Runtime as Trait>::calculate_hash(b"text"),
+                author_id: 1,
+                cleanup_pay_off: 10,
+                last_edited: 5,
+            }
+        );
+    });
+}
+
+#[test]
+fn migrate_threads_succeeds() {
+    with_test_externalities(|| {
+        let category_id = 1;
+        let thread_id = 1;
+        let poll_hash = <Runtime as Trait>::calculate_hash(b"poll");
+        let old_thread = OldThread {
+            category_id,
+            author_id: 1,
+            poll: Some(OldPoll {
+                description_hash: poll_hash,
+                end_time: 100,
+                poll_alternatives: vec![OldPollAlternative {
+                    alternative_text_hash: poll_hash,
+                    vote_count: 3,
+                }],
+            }),
+            cleanup_pay_off: 10,
+            number_of_posts: 2,
+        };
+
+        frame_support::storage::unhashed::put(
+            &<ThreadById<Runtime>>::hashed_key_for(category_id, thread_id),
+            &old_thread,
+        );
+
+        TestForumModule::migrate_threads();
+
+        assert_eq!(
+            <ThreadById<Runtime>>::get(category_id, thread_id),
+            Thread {
+                category_id,
+                author_id: 1,
+                poll: Some(Poll {
+                    description_hash: poll_hash,
+                    end_time: 100,
+                    poll_alternatives: vec![PollAlternative {
+                        alternative_text_hash: poll_hash,
+                        vote_count: 3,
+                        vote_weight: 3,
+                    }],
+                    max_choices: 1,
+                    vote_weighting: PollVoteWeighting::Equal,
+                }),
+                cleanup_pay_off: 10,
+                number_of_posts: 2,
+            }
+        );
+    });
+}
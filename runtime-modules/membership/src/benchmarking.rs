@@ -1,20 +1,23 @@
 #![cfg(feature = "runtime-benchmarks")]
 use super::*;
 use crate::{
-    BuyMembershipParameters, InviteMembershipParameters, MemberIdByHandleHash, Membership,
-    MembershipById, MembershipObject, StakingAccountIdMemberStatus, StakingAccountMemberBinding,
-    Trait,
+    BuyMembershipParameters, HandleDisputeById, HandleDisputeResolution,
+    InviteMembershipParameters, MemberIdByHandleHash, MemberVerifications, Membership,
+    MembershipById, MembershipObject, RecoveryByMemberId, RecoveryConfigByMemberId,
+    ReservedHandleHashes, StakingAccountIdMemberStatus, StakingAccountMemberBinding, Trait,
 };
 use balances::Module as Balances;
+use common::membership::VerificationKind;
 use core::convert::TryInto;
 use frame_benchmarking::{account, benchmarks};
-use frame_support::storage::StorageMap;
+use frame_support::storage::{StorageDoubleMap, StorageMap};
 use frame_support::traits::Currency;
 use frame_system::Module as System;
 use frame_system::{EventRecord, RawOrigin};
 use sp_arithmetic::traits::One;
 use sp_arithmetic::Perbill;
 use sp_runtime::traits::Bounded;
+use sp_std::collections::btree_set::BTreeSet;
 use sp_std::prelude::*;
 use sp_std::vec;
 
@@ -84,6 +87,84 @@ fn handle_from_id<T: Trait>(id: u32) -> Vec<u8> {
     handle
 }
 
+// Registers and confirms the member controller account as the staking account.
+fn add_confirmed_staking_account<T: Trait>(account_id: &T::AccountId, member_id: T::MemberId) {
+    Module::<T>::add_staking_account_candidate(
+        RawOrigin::Signed(account_id.clone()).into(),
+        member_id,
+    )
+    .unwrap();
+
+    Module::<T>::confirm_staking_account(
+        RawOrigin::Signed(account_id.clone()).into(),
+        member_id,
+        account_id.clone(),
+    )
+    .unwrap();
+}
+
+// Creates the first member and sets it as the membership working group lead.
+// Returns the lead account, member and worker ids.
+fn lead_funded_account<
+    T: Trait
+        + MembershipWorkingGroupHelper<
+            <T as frame_system::Trait>::AccountId,
+            <T as common::membership::MembershipTypes>::MemberId,
+            <T as common::membership::MembershipTypes>::ActorId,
+        >,
+>() -> (T::AccountId, T::MemberId, T::ActorId) {
+    let (account_id, member_id) = member_funded_account::<T>("member", 0);
+
+    add_confirmed_staking_account::<T>(&account_id, member_id);
+
+    let leader_id = T::insert_a_lead(0, &account_id, member_id);
+
+    (account_id, member_id, leader_id)
+}
+
+// Opens a handle dispute of the complainant against the owner handle.
+// Creates a member with the provided number of recovery guardians and returns the member
+// account and id with the guardian accounts and ids.
+fn setup_recovery_guardians<T: Trait + balances::Trait>(
+    guardians_number: u32,
+    threshold: u32,
+) -> (T::AccountId, T::MemberId, Vec<(T::AccountId, T::MemberId)>) {
+    let (account_id, member_id) = member_funded_account::<T>("member", 0);
+
+    let guardians = (1..=guardians_number)
+        .map(|id| member_funded_account::<T>("guardian", id))
+        .collect::<Vec<_>>();
+
+    Module::<T>::set_recovery_guardians(
+        RawOrigin::Signed(account_id.clone()).into(),
+        member_id,
+        guardians
+            .iter()
+            .map(|(_, guardian_id)| *guardian_id)
+            .collect(),
+        threshold,
+    )
+    .unwrap();
+
+    (account_id, member_id, guardians)
+}
+
+fn setup_handle_dispute<T: Trait>(
+    complainant_account_id: &T::AccountId,
+    complainant_id: T::MemberId,
+    owner_handle: Vec<u8>,
+) {
+    add_confirmed_staking_account::<T>(complainant_account_id, complainant_id);
+
+    Module::<T>::open_handle_dispute(
+        RawOrigin::Signed(complainant_account_id.clone()).into(),
+        complainant_id,
+        owner_handle,
+        complainant_account_id.clone(),
+    )
+    .unwrap();
+}
+
 benchmarks! {
     where_clause { where T: balances::Trait, T: Trait, T: MembershipWorkingGroupHelper<<T as
         frame_system::Trait>::AccountId, <T as common::membership::MembershipTypes>::MemberId, <T as common::membership::MembershipTypes>::ActorId> }
@@ -395,6 +476,60 @@ benchmarks! {
         assert_last_event::<T>(RawEvent::ReferralCutUpdated(referral_cut).into());
     }
 
+    set_second_level_referral_cut {
+        let referral_cut = 10u8;
+
+    }: _(RawOrigin::Root, referral_cut)
+
+    verify {
+
+        assert_eq!(Module::<T>::second_level_referral_cut(), referral_cut);
+
+        assert_last_event::<T>(RawEvent::SecondLevelReferralCutUpdated(referral_cut).into());
+    }
+
+    start_referral_campaign {
+        let budget: BalanceOf<T> = 1000u32.into();
+
+        T::WorkingGroup::set_budget(budget);
+
+        let duration: T::BlockNumber = 10u32.into();
+
+        let ends_at = System::<T>::block_number() + duration;
+
+    }: _(RawOrigin::Root, budget, duration)
+
+    verify {
+
+        assert!(Module::<T>::current_referral_campaign().is_some());
+
+        assert_eq!(T::WorkingGroup::get_budget(), Zero::zero());
+
+        assert_last_event::<T>(RawEvent::ReferralCampaignStarted(budget, ends_at).into());
+    }
+
+    end_referral_campaign {
+        let budget: BalanceOf<T> = 1000u32.into();
+
+        T::WorkingGroup::set_budget(budget);
+
+        Module::<T>::start_referral_campaign(
+            RawOrigin::Root.into(),
+            budget,
+            10u32.into(),
+        ).unwrap();
+
+    }: _(RawOrigin::Root)
+
+    verify {
+
+        assert!(Module::<T>::current_referral_campaign().is_none());
+
+        assert_eq!(T::WorkingGroup::get_budget(), budget);
+
+        assert_last_event::<T>(RawEvent::ReferralCampaignEnded(budget, 0).into());
+    }
+
     transfer_invites{
 
         let first_member_id = 0;
@@ -686,6 +821,336 @@ benchmarks! {
         verify {
             assert_last_event::<T>(RawEvent::MemberRemarked(member_id, msg).into());
         }
+
+    reserve_handle {
+        let i in 1 .. MAX_BYTES;
+
+        let (lead_account_id, _, _) = lead_funded_account::<T>();
+
+        let handle = handle_from_id::<T>(i);
+
+    }: _ (RawOrigin::Signed(lead_account_id), handle.clone())
+    verify {
+        let handle_hash = T::Hashing::hash(&handle).as_ref().to_vec();
+
+        assert!(ReservedHandleHashes::contains_key(&handle_hash));
+
+        assert_last_event::<T>(RawEvent::HandleReserved(handle_hash).into());
+    }
+
+    release_handle {
+        let i in 1 .. MAX_BYTES;
+
+        let (lead_account_id, lead_member_id, _) = lead_funded_account::<T>();
+
+        let handle = handle_from_id::<T>(i);
+
+        Module::<T>::reserve_handle(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            handle.clone(),
+        ).unwrap();
+
+    }: _ (RawOrigin::Signed(lead_account_id), handle.clone(), Some(lead_member_id))
+    verify {
+        let handle_hash = T::Hashing::hash(&handle).as_ref().to_vec();
+
+        assert!(!ReservedHandleHashes::contains_key(&handle_hash));
+
+        assert_eq!(MemberIdByHandleHash::<T>::get(&handle_hash), lead_member_id);
+
+        assert_last_event::<T>(RawEvent::HandleReleased(handle_hash, Some(lead_member_id)).into());
+    }
+
+    open_handle_dispute {
+        let i in 1 .. MAX_BYTES;
+
+        let (complainant_account_id, complainant_id) =
+            member_funded_account::<T>("member", 0);
+
+        let owner_account_id = account::<T::AccountId>("owner", 1, SEED);
+
+        let owner_id = Module::<T>::members_created();
+
+        let handle = handle_from_id::<T>(i);
+
+        let params = BuyMembershipParameters {
+            root_account: owner_account_id.clone(),
+            controller_account: owner_account_id.clone(),
+            handle: Some(handle.clone()),
+            metadata: Vec::new(),
+            referrer_id: None,
+        };
+
+        let _ = Balances::<T>::make_free_balance_be(
+            &owner_account_id,
+            BalanceOf::<T>::max_value()
+        );
+
+        Module::<T>::buy_membership(RawOrigin::Signed(owner_account_id).into(), params).unwrap();
+
+        add_confirmed_staking_account::<T>(&complainant_account_id, complainant_id);
+
+    }: _ (
+        RawOrigin::Signed(complainant_account_id.clone()),
+        complainant_id,
+        handle.clone(),
+        complainant_account_id.clone()
+    )
+    verify {
+        assert!(HandleDisputeById::<T>::contains_key(0));
+
+        assert_last_event::<T>(
+            RawEvent::HandleDisputeOpened(0, owner_id, complainant_id, handle).into()
+        );
+    }
+
+    resolve_handle_dispute {
+        let (lead_account_id, _, _) = lead_funded_account::<T>();
+
+        let (complainant_account_id, complainant_id) = member_funded_account::<T>("member", 1);
+
+        member_funded_account::<T>("member", 2);
+
+        setup_handle_dispute::<T>(&complainant_account_id, complainant_id, handle_from_id::<T>(2));
+
+        let resolution = HandleDisputeResolution::Reject;
+
+    }: _ (RawOrigin::Signed(lead_account_id), 0, resolution)
+    verify {
+        assert!(!HandleDisputeById::<T>::contains_key(0));
+
+        assert_last_event::<T>(
+            RawEvent::HandleDisputeResolved(0, resolution, System::<T>::block_number()).into()
+        );
+    }
+
+    execute_handle_dispute_resolution {
+        let (complainant_account_id, complainant_id) = member_funded_account::<T>("member", 0);
+
+        let (_, owner_id) = member_funded_account::<T>("member", 1);
+
+        let handle = handle_from_id::<T>(1);
+
+        setup_handle_dispute::<T>(&complainant_account_id, complainant_id, handle.clone());
+
+        Module::<T>::resolve_handle_dispute(
+            RawOrigin::Root.into(),
+            0,
+            HandleDisputeResolution::Reassign,
+        ).unwrap();
+
+        System::<T>::set_block_number(
+            System::<T>::block_number() + T::HandleDisputeGracePeriod::get()
+        );
+
+    }: _ (RawOrigin::Signed(complainant_account_id), 0)
+    verify {
+        let handle_hash = T::Hashing::hash(&handle).as_ref().to_vec();
+
+        assert_eq!(MemberIdByHandleHash::<T>::get(&handle_hash), complainant_id);
+
+        assert!(MembershipById::<T>::get(owner_id).handle_hash.is_empty());
+
+        assert_last_event::<T>(RawEvent::HandleDisputeResolutionExecuted(0).into());
+    }
+
+    withdraw_handle_dispute {
+        let (complainant_account_id, complainant_id) = member_funded_account::<T>("member", 0);
+
+        member_funded_account::<T>("member", 1);
+
+        let handle = handle_from_id::<T>(1);
+
+        setup_handle_dispute::<T>(&complainant_account_id, complainant_id, handle.clone());
+
+    }: _ (RawOrigin::Signed(complainant_account_id), 0)
+    verify {
+        let handle_hash = T::Hashing::hash(&handle).as_ref().to_vec();
+
+        assert!(!HandleDisputeById::<T>::contains_key(0));
+
+        assert!(!HandleDisputeIdByHandleHash::contains_key(&handle_hash));
+
+        assert_last_event::<T>(RawEvent::HandleDisputeWithdrawn(0).into());
+    }
+
+    add_member_verification {
+        let (account_id, member_id, leader_id) = lead_funded_account::<T>();
+
+        let kind = VerificationKind::Identity;
+
+        let expires_at = Some(System::<T>::block_number() + One::one());
+
+    }: _(RawOrigin::Signed(account_id.clone()), leader_id, member_id, kind, expires_at)
+    verify {
+        assert!(MemberVerifications::<T>::contains_key(member_id, kind));
+
+        assert_last_event::<T>(
+            RawEvent::MemberVerificationAdded(member_id, kind, leader_id, expires_at).into()
+        );
+    }
+
+    revoke_member_verification {
+        let (account_id, member_id, leader_id) = lead_funded_account::<T>();
+
+        let kind = VerificationKind::Identity;
+
+        Module::<T>::add_member_verification(
+            RawOrigin::Signed(account_id.clone()).into(),
+            leader_id,
+            member_id,
+            kind,
+            None,
+        ).unwrap();
+
+    }: _(RawOrigin::Signed(account_id.clone()), leader_id, member_id, kind)
+    verify {
+        assert!(!MemberVerifications::<T>::contains_key(member_id, kind));
+
+        assert_last_event::<T>(
+            RawEvent::MemberVerificationRevoked(member_id, kind, leader_id).into()
+        );
+    }
+
+    set_recovery_guardians {
+        let i in 1 .. T::MaxRecoveryGuardians::get();
+
+        let (account_id, member_id) = member_funded_account::<T>("member", 0);
+
+        let guardians = (1..=i)
+            .map(|id| member_funded_account::<T>("guardian", id).1)
+            .collect::<BTreeSet<_>>();
+
+    }: _(RawOrigin::Signed(account_id.clone()), member_id, guardians.clone(), i)
+    verify {
+        assert_eq!(Module::<T>::recovery_config(member_id).guardians, guardians);
+
+        assert_last_event::<T>(RawEvent::RecoveryGuardiansUpdated(member_id, guardians, i).into());
+    }
+
+    initiate_recovery {
+        let (_, member_id, guardians) = setup_recovery_guardians::<T>(1, 1);
+
+        let (guardian_account_id, guardian_id) = guardians[0].clone();
+
+        let new_root_account_id = account::<T::AccountId>("root", 0, SEED);
+        let new_controller_account_id = account::<T::AccountId>("controller", 0, SEED);
+
+        let executable_at = System::<T>::block_number() + T::RecoveryDelay::get();
+
+    }: _(
+        RawOrigin::Signed(guardian_account_id),
+        guardian_id,
+        member_id,
+        Some(new_root_account_id),
+        Some(new_controller_account_id)
+    )
+    verify {
+        assert_eq!(Module::<T>::recovery(member_id).executable_at, Some(executable_at));
+
+        assert_last_event::<T>(RawEvent::RecoveryStarted(member_id, executable_at).into());
+    }
+
+    approve_recovery {
+        let (_, member_id, guardians) = setup_recovery_guardians::<T>(2, 2);
+
+        let (first_guardian_account_id, first_guardian_id) = guardians[0].clone();
+        let (second_guardian_account_id, second_guardian_id) = guardians[1].clone();
+
+        let new_root_account_id = account::<T::AccountId>("root", 0, SEED);
+
+        Module::<T>::initiate_recovery(
+            RawOrigin::Signed(first_guardian_account_id).into(),
+            first_guardian_id,
+            member_id,
+            Some(new_root_account_id),
+            None,
+        ).unwrap();
+
+        let executable_at = System::<T>::block_number() + T::RecoveryDelay::get();
+
+    }: _(RawOrigin::Signed(second_guardian_account_id), second_guardian_id, member_id)
+    verify {
+        assert!(Module::<T>::recovery(member_id).approvals.contains(&second_guardian_id));
+
+        assert_last_event::<T>(RawEvent::RecoveryStarted(member_id, executable_at).into());
+    }
+
+    reject_recovery {
+        let (_, member_id, guardians) = setup_recovery_guardians::<T>(2, 2);
+
+        let (first_guardian_account_id, first_guardian_id) = guardians[0].clone();
+        let (second_guardian_account_id, second_guardian_id) = guardians[1].clone();
+
+        let new_root_account_id = account::<T::AccountId>("root", 0, SEED);
+
+        Module::<T>::initiate_recovery(
+            RawOrigin::Signed(first_guardian_account_id).into(),
+            first_guardian_id,
+            member_id,
+            Some(new_root_account_id),
+            None,
+        ).unwrap();
+
+    }: _(RawOrigin::Signed(second_guardian_account_id), second_guardian_id, member_id)
+    verify {
+        assert!(!RecoveryByMemberId::<T>::contains_key(member_id));
+
+        assert_last_event::<T>(RawEvent::RecoveryCancelled(member_id).into());
+    }
+
+    cancel_recovery {
+        let (account_id, member_id, guardians) = setup_recovery_guardians::<T>(1, 1);
+
+        let (guardian_account_id, guardian_id) = guardians[0].clone();
+
+        let new_root_account_id = account::<T::AccountId>("root", 0, SEED);
+
+        Module::<T>::initiate_recovery(
+            RawOrigin::Signed(guardian_account_id).into(),
+            guardian_id,
+            member_id,
+            Some(new_root_account_id),
+            None,
+        ).unwrap();
+
+    }: _(RawOrigin::Signed(account_id), member_id)
+    verify {
+        assert!(!RecoveryByMemberId::<T>::contains_key(member_id));
+
+        assert_last_event::<T>(RawEvent::RecoveryCancelled(member_id).into());
+    }
+
+    execute_recovery {
+        let (_, member_id, guardians) = setup_recovery_guardians::<T>(1, 1);
+
+        let (guardian_account_id, guardian_id) = guardians[0].clone();
+
+        let new_root_account_id = account::<T::AccountId>("root", 0, SEED);
+        let new_controller_account_id = account::<T::AccountId>("controller", 0, SEED);
+
+        Module::<T>::initiate_recovery(
+            RawOrigin::Signed(guardian_account_id.clone()).into(),
+            guardian_id,
+            member_id,
+            Some(new_root_account_id.clone()),
+            Some(new_controller_account_id.clone()),
+        ).unwrap();
+
+        System::<T>::set_block_number(System::<T>::block_number() + T::RecoveryDelay::get());
+
+    }: _(RawOrigin::Signed(guardian_account_id), member_id)
+    verify {
+        let membership = Module::<T>::membership(member_id);
+
+        assert_eq!(membership.root_account, new_root_account_id);
+        assert_eq!(membership.controller_account, new_controller_account_id);
+
+        assert!(RecoveryConfigByMemberId::<T>::contains_key(member_id));
+        assert!(!RecoveryByMemberId::<T>::contains_key(member_id));
+
+        assert_last_event::<T>(RawEvent::RecoveryExecuted(member_id).into());
+    }
 }
 
 #[cfg(test)]
@@ -750,6 +1215,27 @@ mod tests {
         });
     }
 
+    #[test]
+    fn set_second_level_referral_cut() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_set_second_level_referral_cut::<Test>());
+        });
+    }
+
+    #[test]
+    fn start_referral_campaign() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_start_referral_campaign::<Test>());
+        });
+    }
+
+    #[test]
+    fn end_referral_campaign() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_end_referral_campaign::<Test>());
+        });
+    }
+
     #[test]
     fn transfer_invites() {
         build_test_externalities().execute_with(|| {
@@ -829,4 +1315,111 @@ mod tests {
             assert_ok!(test_benchmark_member_remark::<Test>());
         });
     }
+
+    #[test]
+    fn reserve_handle() {
+        TestExternalitiesBuilder::<Test>::default()
+            .with_lead()
+            .build()
+            .execute_with(|| {
+                assert_ok!(test_benchmark_reserve_handle::<Test>());
+            });
+    }
+
+    #[test]
+    fn release_handle() {
+        TestExternalitiesBuilder::<Test>::default()
+            .with_lead()
+            .build()
+            .execute_with(|| {
+                assert_ok!(test_benchmark_release_handle::<Test>());
+            });
+    }
+
+    #[test]
+    fn open_handle_dispute() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_open_handle_dispute::<Test>());
+        });
+    }
+
+    #[test]
+    fn resolve_handle_dispute() {
+        TestExternalitiesBuilder::<Test>::default()
+            .with_lead()
+            .build()
+            .execute_with(|| {
+                assert_ok!(test_benchmark_resolve_handle_dispute::<Test>());
+            });
+    }
+
+    #[test]
+    fn execute_handle_dispute_resolution() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_execute_handle_dispute_resolution::<Test>());
+        });
+    }
+
+    #[test]
+    fn withdraw_handle_dispute() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_withdraw_handle_dispute::<Test>());
+        });
+    }
+
+    #[test]
+    fn add_member_verification() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_add_member_verification::<Test>());
+        });
+    }
+
+    #[test]
+    fn revoke_member_verification() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_revoke_member_verification::<Test>());
+        });
+    }
+
+    #[test]
+    fn set_recovery_guardians() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_set_recovery_guardians::<Test>());
+        });
+    }
+
+    #[test]
+    fn initiate_recovery() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_initiate_recovery::<Test>());
+        });
+    }
+
+    #[test]
+    fn approve_recovery() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_approve_recovery::<Test>());
+        });
+    }
+
+    #[test]
+    fn reject_recovery() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_reject_recovery::<Test>());
+        });
+    }
+
+    #[test]
+    fn cancel_recovery() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_cancel_recovery::<Test>());
+        });
+    }
+
+    #[test]
+    fn execute_recovery() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_execute_recovery::<Test>());
+        });
+    }
 }
@@ -34,10 +34,44 @@
 //! - [update_accounts](./struct.Module.html#method.update_accounts) - updates member accounts.
 //! - [update_profile_verification](./struct.Module.html#method.update_profile_verification) -
 //! updates member profile verification status.
+//! - [add_member_verification](./struct.Module.html#method.add_member_verification) -
+//! issues a typed member profile verification.
+//! - [revoke_member_verification](./struct.Module.html#method.revoke_member_verification) -
+//! revokes a typed member profile verification.
 //! - [set_referral_cut](./struct.Module.html#method.set_referral_cut) -
 //! updates the referral cut percent value.
 //! - [transfer_invites](./struct.Module.html#method.transfer_invites) - transfers the invites
 //! from one member to another.
+//! - [set_second_level_referral_cut](./struct.Module.html#method.set_second_level_referral_cut) -
+//! updates the second-level referral cut percent value.
+//! - [start_referral_campaign](./struct.Module.html#method.start_referral_campaign) - starts
+//! a time-bounded referral campaign funded from the working group budget.
+//! - [end_referral_campaign](./struct.Module.html#method.end_referral_campaign) - ends the
+//! referral campaign.
+//! - [reserve_handle](./struct.Module.html#method.reserve_handle) - reserves a handle so it
+//! cannot be registered.
+//! - [release_handle](./struct.Module.html#method.release_handle) - releases a reserved handle,
+//! optionally assigning it to a member.
+//! - [open_handle_dispute](./struct.Module.html#method.open_handle_dispute) - stakes a claim
+//! against a registered handle.
+//! - [resolve_handle_dispute](./struct.Module.html#method.resolve_handle_dispute) - decides a
+//! handle dispute.
+//! - [execute_handle_dispute_resolution](./struct.Module.html#method.execute_handle_dispute_resolution) -
+//! applies a handle dispute resolution after the grace period.
+//! - [withdraw_handle_dispute](./struct.Module.html#method.withdraw_handle_dispute) - withdraws
+//! an unresolved handle dispute and unlocks the complainant stake.
+//! - [set_recovery_guardians](./struct.Module.html#method.set_recovery_guardians) - configures
+//! the member recovery guardians and their threshold.
+//! - [initiate_recovery](./struct.Module.html#method.initiate_recovery) - proposes new member
+//! accounts on behalf of a guardian.
+//! - [approve_recovery](./struct.Module.html#method.approve_recovery) - approves the proposed
+//! member accounts recovery.
+//! - [reject_recovery](./struct.Module.html#method.reject_recovery) - rejects the proposed
+//! member accounts recovery.
+//! - [cancel_recovery](./struct.Module.html#method.cancel_recovery) - cancels the member accounts
+//! recovery.
+//! - [execute_recovery](./struct.Module.html#method.execute_recovery) - updates the member
+//! accounts after the recovery delay.
 //!
 //! [Joystream handbook description](https://joystream.gitbook.io/joystream-handbook/subsystems/membership)
 
@@ -51,7 +85,9 @@ mod tests;
 
 use codec::{Decode, Encode};
 use frame_support::dispatch::DispatchError;
-use frame_support::traits::{Currency, Get, LockIdentifier, WithdrawReason, WithdrawReasons};
+use frame_support::traits::{
+    Currency, ExistenceRequirement, Get, Imbalance, LockIdentifier, WithdrawReason, WithdrawReasons,
+};
 pub use frame_support::weights::Weight;
 use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure};
 use frame_system::{ensure_root, ensure_signed};
@@ -59,9 +95,12 @@ use sp_arithmetic::traits::{One, Zero};
 use sp_arithmetic::Perbill;
 use sp_runtime::traits::{Hash, Saturating};
 use sp_runtime::SaturatedConversion;
+use sp_std::collections::btree_set::BTreeSet;
 use sp_std::vec::Vec;
 
-use common::membership::{MemberOriginValidator, MembershipInfoProvider};
+use common::membership::{
+    MemberOriginValidator, MemberVerificationProvider, MembershipInfoProvider, VerificationKind,
+};
 use common::working_group::{WorkingGroupAuthenticator, WorkingGroupBudgetHandler};
 use staking_handler::StakingHandler;
 
@@ -95,6 +134,23 @@ pub trait WeightInfo {
     fn confirm_staking_account() -> Weight;
     fn remove_staking_account() -> Weight;
     fn member_remark() -> Weight;
+    fn reserve_handle(i: u32) -> Weight;
+    fn release_handle(i: u32) -> Weight;
+    fn open_handle_dispute(i: u32) -> Weight;
+    fn resolve_handle_dispute() -> Weight;
+    fn execute_handle_dispute_resolution() -> Weight;
+    fn withdraw_handle_dispute() -> Weight;
+    fn add_member_verification() -> Weight;
+    fn revoke_member_verification() -> Weight;
+    fn set_second_level_referral_cut() -> Weight;
+    fn start_referral_campaign() -> Weight;
+    fn end_referral_campaign() -> Weight;
+    fn set_recovery_guardians(i: u32) -> Weight;
+    fn initiate_recovery() -> Weight;
+    fn approve_recovery() -> Weight;
+    fn reject_recovery() -> Weight;
+    fn cancel_recovery() -> Weight;
+    fn execute_recovery() -> Weight;
 }
 
 pub trait Trait:
@@ -140,6 +196,27 @@ pub trait Trait:
 
     /// Stake needed to candidate as staking account.
     type CandidateStake: Get<BalanceOf<Self>>;
+
+    /// Staking handler used for the handle dispute stake.
+    type HandleDisputeStakingHandler: StakingHandler<
+        Self::AccountId,
+        BalanceOf<Self>,
+        Self::MemberId,
+        LockIdentifier,
+    >;
+
+    /// Stake needed to open a handle dispute.
+    type HandleDisputeStake: Get<BalanceOf<Self>>;
+
+    /// Number of blocks the current handle owner has before a handle dispute resolution
+    /// can be executed.
+    type HandleDisputeGracePeriod: Get<Self::BlockNumber>;
+
+    /// Maximum number of recovery guardians per member.
+    type MaxRecoveryGuardians: Get<u32>;
+
+    /// Number of blocks the current root account has to cancel an approved recovery.
+    type RecoveryDelay: Get<Self::BlockNumber>;
 }
 
 pub(crate) const DEFAULT_MEMBER_INVITES_COUNT: u32 = 5;
@@ -183,6 +260,143 @@ pub struct StakingAccountMemberBinding<MemberId> {
     pub confirmed: bool,
 }
 
+/// Referral statistics of a member.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug, Eq)]
+pub struct ReferralStats<Balance> {
+    /// Number of members bought the membership with this member as the referrer.
+    pub direct_referrals: u32,
+
+    /// Number of members referred by the members this member has referred.
+    pub second_level_referrals: u32,
+
+    /// Total referral rewards received.
+    pub rewards: Balance,
+}
+
+/// Referral campaign alias.
+pub type ReferralCampaignOf<T> =
+    ReferralCampaign<BalanceOf<T>, <T as frame_system::Trait>::BlockNumber>;
+
+/// Time-bounded referral campaign funded from the membership working group budget.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug, Eq)]
+pub struct ReferralCampaign<Balance, BlockNumber> {
+    /// Budget left for the referral rewards.
+    pub budget: Balance,
+
+    /// Block at which the campaign ends.
+    pub ends_at: BlockNumber,
+
+    /// Number of referrals counted during the campaign.
+    pub referrals: u32,
+}
+
+/// Typed member profile verification alias.
+pub type VerificationOf<T> = Verification<
+    <T as common::membership::MembershipTypes>::ActorId,
+    <T as frame_system::Trait>::BlockNumber,
+>;
+
+/// Typed member profile verification issued by a membership worker.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug, Eq)]
+pub struct Verification<ActorId, BlockNumber> {
+    /// Worker that issued the verification.
+    pub issuer: ActorId,
+
+    /// Block at which the verification was issued.
+    pub issued_at: BlockNumber,
+
+    /// Block from which the verification is no longer valid, if it expires.
+    pub expires_at: Option<BlockNumber>,
+}
+
+/// Handle dispute identifier.
+pub type HandleDisputeId = u64;
+
+/// Handle dispute alias.
+pub type HandleDisputeOf<T> = HandleDispute<
+    <T as common::membership::MembershipTypes>::MemberId,
+    <T as frame_system::Trait>::AccountId,
+    BalanceOf<T>,
+    <T as frame_system::Trait>::BlockNumber,
+>;
+
+/// Decision made on a handle dispute.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Debug, Eq)]
+pub enum HandleDisputeResolution {
+    /// Transfer the handle from the current owner to the complainant.
+    Reassign,
+
+    /// Take the handle away from the current owner and reserve it.
+    ForceRename,
+
+    /// Dismiss the claim and slash the complainant stake.
+    Reject,
+}
+
+/// Claim staked by a member against the owner of a registered handle.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug, Eq)]
+pub struct HandleDispute<MemberId, AccountId, Balance, BlockNumber> {
+    /// The hash of the disputed handle.
+    pub handle_hash: Vec<u8>,
+
+    /// Handle owner at the moment the dispute was opened.
+    pub owner_id: MemberId,
+
+    /// Member claiming the handle.
+    pub complainant_id: MemberId,
+
+    /// Account holding the complainant stake.
+    pub staking_account_id: AccountId,
+
+    /// Locked complainant stake.
+    pub stake: Balance,
+
+    /// Resolution pending execution, if the dispute was decided.
+    pub resolution: Option<HandleDisputeResolution>,
+
+    /// Block after which the pending resolution can be executed.
+    pub grace_period_end: BlockNumber,
+}
+
+/// Recovery guardians configuration alias.
+pub type RecoveryConfigOf<T> = RecoveryConfig<<T as common::membership::MembershipTypes>::MemberId>;
+
+/// Guardian members allowed to recover the member accounts.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug, Eq)]
+pub struct RecoveryConfig<MemberId: Ord> {
+    /// Guardian members.
+    pub guardians: BTreeSet<MemberId>,
+
+    /// Number of guardian approvals required to start the recovery.
+    pub threshold: u32,
+}
+
+/// Member accounts recovery alias.
+pub type RecoveryOf<T> = Recovery<
+    <T as common::membership::MembershipTypes>::MemberId,
+    <T as frame_system::Trait>::AccountId,
+    <T as frame_system::Trait>::BlockNumber,
+>;
+
+/// Member accounts recovery proposed by the guardians.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug, Eq)]
+pub struct Recovery<MemberId: Ord, AccountId, BlockNumber> {
+    /// New member root account.
+    pub new_root_account: Option<AccountId>,
+
+    /// New member controller account.
+    pub new_controller_account: Option<AccountId>,
+
+    /// Guardians approved the recovery.
+    pub approvals: BTreeSet<MemberId>,
+
+    /// Guardians rejected the recovery.
+    pub rejections: BTreeSet<MemberId>,
+
+    /// Block after which the recovery can be executed. Set once the threshold is reached.
+    pub executable_at: Option<BlockNumber>,
+}
+
 /// Parameters for the buy_membership extrinsic.
 #[derive(Encode, Decode, Default, Clone, PartialEq, Debug, Eq)]
 pub struct BuyMembershipParameters<AccountId, MemberId> {
@@ -281,6 +495,99 @@ decl_error! {
 
         /// Insufficient balance to cover stake.
         InsufficientBalanceToCoverStake,
+
+        /// Handle is reserved.
+        HandleIsReserved,
+
+        /// Handle is not reserved.
+        HandleIsNotReserved,
+
+        /// Handle is under dispute.
+        HandleIsDisputed,
+
+        /// Handle is not registered.
+        HandleIsNotRegistered,
+
+        /// Cannot open a dispute against the own handle.
+        CannotDisputeOwnHandle,
+
+        /// Staking account is not confirmed for the complainant.
+        StakingAccountIsNotConfirmed,
+
+        /// Handle dispute doesn't exist.
+        HandleDisputeDoesntExist,
+
+        /// Handle dispute has already been resolved.
+        HandleDisputeAlreadyResolved,
+
+        /// Handle dispute has not been resolved yet.
+        HandleDisputeNotResolved,
+
+        /// Handle dispute grace period has not expired yet.
+        HandleDisputeGracePeriodNotExpired,
+
+        /// Verification expiry should be in the future.
+        InvalidVerificationExpiry,
+
+        /// Member doesn't have the verification of the provided kind.
+        VerificationDoesntExist,
+
+        /// Only the issuing worker or the leader can revoke the verification.
+        VerificationIssuerRequired,
+
+        /// Referral campaign is already active.
+        ReferralCampaignIsActive,
+
+        /// There is no referral campaign to end.
+        NoReferralCampaign,
+
+        /// Referral campaign budget cannot be zero.
+        ReferralCampaignBudgetIsZero,
+
+        /// Referral campaign duration cannot be zero.
+        ReferralCampaignDurationIsZero,
+
+        /// Working group budget is not sufficient to fund the referral campaign.
+        WorkingGroupBudgetIsNotSufficientForReferralCampaign,
+
+        /// Recovery guardians number exceeds the limit.
+        TooManyRecoveryGuardians,
+
+        /// Recovery threshold should be greater than zero and not exceed the guardians number.
+        InvalidRecoveryThreshold,
+
+        /// Member cannot be its own recovery guardian.
+        CannotBeOwnRecoveryGuardian,
+
+        /// Recovery guardian is not a member.
+        RecoveryGuardianIsNotMember,
+
+        /// Member has no recovery guardians.
+        RecoveryGuardiansNotConfigured,
+
+        /// Member is not a recovery guardian of the recovered member.
+        NotRecoveryGuardian,
+
+        /// Recovery has no new accounts.
+        RecoveryAccountsAreEmpty,
+
+        /// Member accounts recovery is already in progress.
+        RecoveryIsInProgress,
+
+        /// Member accounts recovery doesn't exist.
+        RecoveryDoesntExist,
+
+        /// Guardian has already approved the recovery.
+        RecoveryAlreadyApproved,
+
+        /// Guardian has already rejected the recovery.
+        RecoveryAlreadyRejected,
+
+        /// Recovery threshold has not been reached yet.
+        RecoveryThresholdNotReached,
+
+        /// Recovery delay has not expired yet.
+        RecoveryDelayNotExpired,
     }
 }
 
@@ -301,6 +608,21 @@ decl_storage! {
         /// Referral cut percent of the membership fee to receive on buying the membership.
         pub ReferralCut get(fn referral_cut) : u8;
 
+        /// Referral cut percent of the membership fee the referrer of the direct referrer receives.
+        pub SecondLevelReferralCut get(fn second_level_referral_cut) : u8;
+
+        /// Members bought with a referrer and their referrer.
+        pub ReferrerByMemberId get(fn referrer_by_member_id) : map hasher(blake2_128_concat)
+            T::MemberId => T::MemberId;
+
+        /// Referral statistics per member.
+        pub ReferralStatsByMemberId get(fn referral_stats) : map hasher(blake2_128_concat)
+            T::MemberId => ReferralStats<BalanceOf<T>>;
+
+        /// Current referral campaign.
+        pub CurrentReferralCampaign get(fn current_referral_campaign) :
+            Option<ReferralCampaignOf<T>>;
+
         /// Current membership price.
         pub MembershipPrice get(fn membership_price) : BalanceOf<T> =
             T::DefaultMembershipPrice::get();
@@ -317,6 +639,33 @@ decl_storage! {
         pub(crate) StakingAccountIdMemberStatus get(fn staking_account_id_member_status):
             map hasher(blake2_128_concat) T::AccountId => StakingAccountMemberBinding<T::MemberId>;
 
+        /// Handle hashes reserved by the membership lead. Reserved handles cannot be registered.
+        pub ReservedHandleHashes get(fn reserved_handle_hashes) : map hasher(blake2_128_concat)
+            Vec<u8> => ();
+
+        /// Handle dispute id to assign to the next dispute.
+        pub NextHandleDisputeId get(fn next_handle_dispute_id) : HandleDisputeId;
+
+        /// Open handle disputes.
+        pub HandleDisputeById get(fn handle_dispute_by_id) : map hasher(blake2_128_concat)
+            HandleDisputeId => HandleDisputeOf<T>;
+
+        /// Disputed handle hashes and their open dispute.
+        pub HandleDisputeIdByHandleHash get(fn handle_dispute_id_by_handle_hash) :
+            map hasher(blake2_128_concat) Vec<u8> => HandleDisputeId;
+
+        /// Typed member profile verifications.
+        pub MemberVerifications get(fn member_verifications) : double_map
+            hasher(blake2_128_concat) T::MemberId,
+            hasher(blake2_128_concat) VerificationKind => VerificationOf<T>;
+
+        /// Recovery guardians of the members.
+        pub RecoveryConfigByMemberId get(fn recovery_config) : map hasher(blake2_128_concat)
+            T::MemberId => RecoveryConfigOf<T>;
+
+        /// Member accounts recoveries in progress.
+        pub RecoveryByMemberId get(fn recovery) : map hasher(blake2_128_concat)
+            T::MemberId => RecoveryOf<T>;
     }
     add_extra_genesis {
         config(members) : Vec<genesis::Member<T::MemberId, T::AccountId>>;
@@ -350,6 +699,7 @@ decl_event! {
           <T as common::membership::MembershipTypes>::MemberId,
         >,
       <T as common::membership::MembershipTypes>::ActorId,
+      <T as frame_system::Trait>::BlockNumber,
       InviteMembershipParameters = InviteMembershipParameters<
           <T as frame_system::Trait>::AccountId,
           <T as common::membership::MembershipTypes>::MemberId,
@@ -374,6 +724,24 @@ decl_event! {
         StakingAccountRemoved(AccountId, MemberId),
         StakingAccountConfirmed(AccountId, MemberId),
         MemberRemarked(MemberId, Vec<u8>),
+        HandleReserved(Vec<u8>),
+        HandleReleased(Vec<u8>, Option<MemberId>),
+        HandleDisputeOpened(HandleDisputeId, MemberId, MemberId, Vec<u8>),
+        HandleDisputeResolved(HandleDisputeId, HandleDisputeResolution, BlockNumber),
+        HandleDisputeResolutionExecuted(HandleDisputeId),
+        HandleDisputeWithdrawn(HandleDisputeId),
+        MemberVerificationAdded(MemberId, VerificationKind, ActorId, Option<BlockNumber>),
+        MemberVerificationRevoked(MemberId, VerificationKind, ActorId),
+        SecondLevelReferralCutUpdated(u8),
+        ReferralCampaignStarted(Balance, BlockNumber),
+        ReferralCampaignEnded(Balance, u32),
+        RecoveryGuardiansUpdated(MemberId, BTreeSet<MemberId>, u32),
+        RecoveryInitiated(MemberId, MemberId, Option<AccountId>, Option<AccountId>),
+        RecoveryApproved(MemberId, MemberId),
+        RecoveryRejected(MemberId, MemberId),
+        RecoveryStarted(MemberId, BlockNumber),
+        RecoveryCancelled(MemberId),
+        RecoveryExecuted(MemberId),
     }
 }
 
@@ -403,6 +771,21 @@ decl_module! {
         /// Exports const - staking candidate lock id.
         const StakingCandidateLockId: LockIdentifier = T::StakingCandidateStakingHandler::lock_id();
 
+        /// Exports const - stake needed to open a handle dispute.
+        const HandleDisputeStake: BalanceOf<T> = T::HandleDisputeStake::get();
+
+        /// Exports const - handle dispute grace period.
+        const HandleDisputeGracePeriod: T::BlockNumber = T::HandleDisputeGracePeriod::get();
+
+        /// Exports const - handle dispute lock id.
+        const HandleDisputeLockId: LockIdentifier = T::HandleDisputeStakingHandler::lock_id();
+
+        /// Exports const - maximum number of recovery guardians per member.
+        const MaxRecoveryGuardians: u32 = T::MaxRecoveryGuardians::get();
+
+        /// Exports const - member accounts recovery delay.
+        const RecoveryDelay: T::BlockNumber = T::RecoveryDelay::get();
+
         /// Non-members can buy membership.
         ///
         /// <weight>
@@ -453,20 +836,16 @@ decl_module! {
                 Self::initial_invitation_count(),
             );
 
-            // Collect membership fee (just burn it).
-            let _ = balances::Module::<T>::slash(&who, fee);
+            // Reward the referring members.
+            let fee_rewards = if let (Some(referrer_id), Some(referrer)) =
+                (params.referrer_id, referrer) {
+                Self::reward_referrers(&who, member_id, referrer_id, referrer)
+            } else {
+                Zero::zero()
+            };
 
-            // Reward the referring member.
-            if let Some(referrer) = referrer {
-                let referral_cut: BalanceOf<T> = Self::get_referral_bonus();
-
-                if referral_cut > Zero::zero() {
-                    let _ = balances::Module::<T>::deposit_creating(
-                        &referrer.controller_account,
-                        referral_cut
-                    );
-                }
-            }
+            // Collect the rest of the membership fee (just burn it).
+            let _ = balances::Module::<T>::slash(&who, fee.saturating_sub(fee_rewards));
 
             // Fire the event.
             Self::deposit_event(RawEvent::MembershipBought(member_id, params));
@@ -549,7 +928,7 @@ decl_module! {
             }
 
             let sender = ensure_signed(origin)?;
-            let mut membership = Self::ensure_membership(member_id)?;
+            let membership = Self::ensure_membership(member_id)?;
 
             ensure!(membership.root_account == sender, Error::<T>::RootAccountRequired);
 
@@ -557,20 +936,12 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
-            if let Some(root_account) = new_root_account.clone() {
-                membership.root_account = root_account;
-            }
-
-            if let Some(controller_account) = new_controller_account.clone() {
-                membership.controller_account = controller_account;
-            }
-
-            <MembershipById<T>>::insert(member_id, membership);
-            Self::deposit_event(RawEvent::MemberAccountsUpdated(
-                    member_id,
-                    new_root_account,
-                    new_controller_account
-                ));
+            Self::update_member_accounts(
+                member_id,
+                membership,
+                new_root_account,
+                new_controller_account,
+            );
         }
 
         /// Updates member profile verification status. Requires working group member origin.
@@ -606,6 +977,96 @@ decl_module! {
             );
         }
 
+        /// Issues a typed member profile verification. Replaces the existing verification of
+        /// the same kind. Requires working group member origin.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::add_member_verification()]
+        pub fn add_member_verification(
+            origin,
+            worker_id: T::ActorId,
+            target_member_id: T::MemberId,
+            kind: VerificationKind,
+            expires_at: Option<T::BlockNumber>,
+        ) {
+            T::WorkingGroup::ensure_worker_origin(origin, &worker_id)?;
+
+            Self::ensure_membership(target_member_id)?;
+
+            let current_block = <frame_system::Module<T>>::block_number();
+
+            if let Some(expires_at) = expires_at {
+                ensure!(expires_at > current_block, Error::<T>::InvalidVerificationExpiry);
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <MemberVerifications<T>>::insert(
+                target_member_id,
+                kind,
+                Verification {
+                    issuer: worker_id,
+                    issued_at: current_block,
+                    expires_at,
+                }
+            );
+
+            Self::deposit_event(
+                RawEvent::MemberVerificationAdded(target_member_id, kind, worker_id, expires_at)
+            );
+        }
+
+        /// Revokes a typed member profile verification. Requires the origin of the issuing
+        /// worker or the leader.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::revoke_member_verification()]
+        pub fn revoke_member_verification(
+            origin,
+            worker_id: T::ActorId,
+            target_member_id: T::MemberId,
+            kind: VerificationKind,
+        ) {
+            T::WorkingGroup::ensure_worker_origin(origin.clone(), &worker_id)?;
+
+            ensure!(
+                <MemberVerifications<T>>::contains_key(target_member_id, kind),
+                Error::<T>::VerificationDoesntExist
+            );
+
+            let verification = Self::member_verifications(target_member_id, kind);
+
+            ensure!(
+                verification.issuer == worker_id
+                    || T::WorkingGroup::ensure_leader_origin(origin).is_ok(),
+                Error::<T>::VerificationIssuerRequired
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <MemberVerifications<T>>::remove(target_member_id, kind);
+
+            Self::deposit_event(
+                RawEvent::MemberVerificationRevoked(target_member_id, kind, worker_id)
+            );
+        }
+
         /// Updates membership referral cut percent value. Requires root origin.
         ///
         /// <weight>
@@ -633,6 +1094,124 @@ decl_module! {
             Self::deposit_event(RawEvent::ReferralCutUpdated(percent_value));
         }
 
+        /// Updates second-level referral cut percent value. Requires root origin.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::set_second_level_referral_cut()]
+        pub fn set_second_level_referral_cut(origin, percent_value: u8) {
+            ensure_root(origin)?;
+
+            ensure!(
+                percent_value <= T::ReferralCutMaximumPercent::get(),
+                Error::<T>::CannotExceedReferralCutPercentLimit
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            SecondLevelReferralCut::put(percent_value);
+
+            Self::deposit_event(RawEvent::SecondLevelReferralCutUpdated(percent_value));
+        }
+
+        /// Starts a referral campaign. The campaign budget is taken from the working group
+        /// budget, and the unspent budget of the previous ended campaign is returned to it.
+        /// Referral rewards are paid from the campaign budget while it covers them until
+        /// the campaign ends. Requires root origin.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::start_referral_campaign()]
+        pub fn start_referral_campaign(
+            origin,
+            budget: BalanceOf<T>,
+            duration: T::BlockNumber,
+        ) {
+            ensure_root(origin)?;
+
+            ensure!(budget != Zero::zero(), Error::<T>::ReferralCampaignBudgetIsZero);
+
+            ensure!(duration != Zero::zero(), Error::<T>::ReferralCampaignDurationIsZero);
+
+            ensure!(
+                Self::active_referral_campaign().is_none(),
+                Error::<T>::ReferralCampaignIsActive
+            );
+
+            let ended_campaign_budget = Self::current_referral_campaign()
+                .map(|campaign| campaign.budget)
+                .unwrap_or_else(Zero::zero);
+
+            let available_wg_budget =
+                T::WorkingGroup::get_budget().saturating_add(ended_campaign_budget);
+
+            ensure!(
+                budget <= available_wg_budget,
+                Error::<T>::WorkingGroupBudgetIsNotSufficientForReferralCampaign
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // Move the campaign budget from the working group budget.
+            T::WorkingGroup::set_budget(available_wg_budget.saturating_sub(budget));
+
+            let ends_at = <frame_system::Module<T>>::block_number().saturating_add(duration);
+
+            <CurrentReferralCampaign<T>>::put(ReferralCampaign {
+                budget,
+                ends_at,
+                referrals: 0,
+            });
+
+            Self::deposit_event(RawEvent::ReferralCampaignStarted(budget, ends_at));
+        }
+
+        /// Ends the current referral campaign and returns its unspent budget to the working
+        /// group budget. Ends the active campaign before its end block or settles the campaign
+        /// that has already ended. Requires root origin.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::end_referral_campaign()]
+        pub fn end_referral_campaign(origin) {
+            ensure_root(origin)?;
+
+            let campaign = Self::current_referral_campaign()
+                .ok_or(Error::<T>::NoReferralCampaign)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <CurrentReferralCampaign<T>>::kill();
+
+            let current_wg_budget = T::WorkingGroup::get_budget();
+            T::WorkingGroup::set_budget(current_wg_budget.saturating_add(campaign.budget));
+
+            Self::deposit_event(
+                RawEvent::ReferralCampaignEnded(campaign.budget, campaign.referrals)
+            );
+        }
+
         /// Transfers invites from one member to another.
         ///
         /// <weight>
@@ -1011,12 +1590,676 @@ decl_module! {
 
             Self::deposit_event(RawEvent::MemberRemarked(member_id, msg));
         }
-    }
-}
 
-impl<T: Trait> Module<T> {
-    // Helper for update_account extrinsic weight calculation
-    fn calculate_weight_for_update_account(
+        /// Reserves a handle so it cannot be registered. Requires the membership lead origin.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (W)` where:
+        /// - `W` is the handle length
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::reserve_handle(handle.len().saturated_into())]
+        pub fn reserve_handle(origin, handle: Vec<u8>) {
+            T::WorkingGroup::ensure_leader_origin(origin)?;
+
+            let handle_hash = Self::get_handle_hash(&Some(handle))?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <ReservedHandleHashes>::insert(handle_hash.clone(), ());
+
+            Self::deposit_event(RawEvent::HandleReserved(handle_hash));
+        }
+
+        /// Releases a reserved handle. The handle is assigned to the provided member, otherwise
+        /// it becomes available for registration. Requires the membership lead origin.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (W)` where:
+        /// - `W` is the handle length
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::release_handle(handle.len().saturated_into())]
+        pub fn release_handle(origin, handle: Vec<u8>, new_owner_id: Option<T::MemberId>) {
+            T::WorkingGroup::ensure_leader_origin(origin)?;
+
+            let handle_hash = Self::hash_handle(&handle);
+
+            ensure!(
+                <ReservedHandleHashes>::contains_key(&handle_hash),
+                Error::<T>::HandleIsNotReserved
+            );
+
+            if let Some(member_id) = new_owner_id {
+                Self::ensure_membership(member_id)?;
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <ReservedHandleHashes>::remove(&handle_hash);
+
+            if let Some(member_id) = new_owner_id {
+                Self::assign_handle_hash(member_id, handle_hash.clone());
+            }
+
+            Self::deposit_event(RawEvent::HandleReleased(handle_hash, new_owner_id));
+        }
+
+        /// Opens a dispute against the owner of a registered handle. The complainant stake is
+        /// locked on the confirmed staking account until the dispute is resolved or withdrawn.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (W)` where:
+        /// - `W` is the handle length
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::open_handle_dispute(handle.len().saturated_into())]
+        pub fn open_handle_dispute(
+            origin,
+            member_id: T::MemberId,
+            handle: Vec<u8>,
+            staking_account_id: T::AccountId,
+        ) {
+            Self::ensure_member_controller_account_origin_signed(origin, &member_id)?;
+
+            let handle_hash = Self::hash_handle(&handle);
+
+            ensure!(
+                <MemberIdByHandleHash<T>>::contains_key(&handle_hash),
+                Error::<T>::HandleIsNotRegistered
+            );
+
+            ensure!(
+                !<HandleDisputeIdByHandleHash>::contains_key(&handle_hash),
+                Error::<T>::HandleIsDisputed
+            );
+
+            let owner_id = Self::handles(&handle_hash);
+
+            ensure!(owner_id != member_id, Error::<T>::CannotDisputeOwnHandle);
+
+            ensure!(
+                Self::staking_account_confirmed(&staking_account_id, &member_id),
+                Error::<T>::StakingAccountIsNotConfirmed
+            );
+
+            ensure!(
+                T::HandleDisputeStakingHandler::is_account_free_of_conflicting_stakes(
+                    &staking_account_id
+                ),
+                Error::<T>::ConflictStakesOnAccount
+            );
+
+            let stake = T::HandleDisputeStake::get();
+
+            ensure!(
+                T::HandleDisputeStakingHandler::is_enough_balance_for_stake(
+                    &staking_account_id,
+                    stake
+                ),
+                Error::<T>::InsufficientBalanceToCoverStake
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            T::HandleDisputeStakingHandler::lock(&staking_account_id, stake);
+
+            let dispute_id = Self::next_handle_dispute_id();
+
+            <HandleDisputeById<T>>::insert(
+                dispute_id,
+                HandleDispute {
+                    handle_hash: handle_hash.clone(),
+                    owner_id,
+                    complainant_id: member_id,
+                    staking_account_id,
+                    stake,
+                    resolution: None,
+                    grace_period_end: Zero::zero(),
+                }
+            );
+
+            <HandleDisputeIdByHandleHash>::insert(handle_hash, dispute_id);
+
+            NextHandleDisputeId::put(dispute_id + 1);
+
+            Self::deposit_event(RawEvent::HandleDisputeOpened(
+                dispute_id,
+                owner_id,
+                member_id,
+                handle
+            ));
+        }
+
+        /// Decides a handle dispute. Requires the membership lead or root origin.
+        /// A rejected dispute is closed immediately and the complainant stake is slashed.
+        /// Other resolutions can be executed once the grace period for the handle owner expires.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::resolve_handle_dispute()]
+        pub fn resolve_handle_dispute(
+            origin,
+            dispute_id: HandleDisputeId,
+            resolution: HandleDisputeResolution,
+        ) {
+            if ensure_root(origin.clone()).is_err() {
+                T::WorkingGroup::ensure_leader_origin(origin)?;
+            }
+
+            let dispute = Self::ensure_handle_dispute_exists(dispute_id)?;
+
+            ensure!(dispute.resolution.is_none(), Error::<T>::HandleDisputeAlreadyResolved);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let current_block = <frame_system::Module<T>>::block_number();
+
+            if resolution == HandleDisputeResolution::Reject {
+                T::HandleDisputeStakingHandler::slash(&dispute.staking_account_id, None);
+
+                Self::remove_handle_dispute(dispute_id, &dispute.handle_hash);
+
+                Self::deposit_event(RawEvent::HandleDisputeResolved(
+                    dispute_id,
+                    resolution,
+                    current_block
+                ));
+            } else {
+                let grace_period_end = current_block + T::HandleDisputeGracePeriod::get();
+
+                <HandleDisputeById<T>>::mutate(dispute_id, |dispute| {
+                    dispute.resolution = Some(resolution);
+                    dispute.grace_period_end = grace_period_end;
+                });
+
+                Self::deposit_event(RawEvent::HandleDisputeResolved(
+                    dispute_id,
+                    resolution,
+                    grace_period_end
+                ));
+            }
+        }
+
+        /// Executes a decided handle dispute after the grace period expired and unlocks
+        /// the complainant stake. Can be called by any signed origin.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::execute_handle_dispute_resolution()]
+        pub fn execute_handle_dispute_resolution(origin, dispute_id: HandleDisputeId) {
+            ensure_signed(origin)?;
+
+            let dispute = Self::ensure_handle_dispute_exists(dispute_id)?;
+
+            let resolution = dispute.resolution.ok_or(Error::<T>::HandleDisputeNotResolved)?;
+
+            ensure!(
+                <frame_system::Module<T>>::block_number() >= dispute.grace_period_end,
+                Error::<T>::HandleDisputeGracePeriodNotExpired
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // The owner could have already picked another handle during the grace period.
+            if <MemberIdByHandleHash<T>>::contains_key(&dispute.handle_hash) {
+                let owner_id = Self::handles(&dispute.handle_hash);
+
+                <MemberIdByHandleHash<T>>::remove(&dispute.handle_hash);
+
+                <MembershipById<T>>::mutate(&owner_id, |membership| {
+                    membership.handle_hash = Vec::new();
+                });
+            }
+
+            match resolution {
+                HandleDisputeResolution::Reassign => {
+                    Self::assign_handle_hash(dispute.complainant_id, dispute.handle_hash.clone());
+                }
+                HandleDisputeResolution::ForceRename => {
+                    <ReservedHandleHashes>::insert(dispute.handle_hash.clone(), ());
+                }
+                HandleDisputeResolution::Reject => {}
+            }
+
+            T::HandleDisputeStakingHandler::unlock(&dispute.staking_account_id);
+
+            Self::remove_handle_dispute(dispute_id, &dispute.handle_hash);
+
+            Self::deposit_event(RawEvent::HandleDisputeResolutionExecuted(dispute_id));
+        }
+
+        /// Withdraws a handle dispute that has not been decided yet and unlocks the complainant
+        /// stake. Requires the complainant controller account.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::withdraw_handle_dispute()]
+        pub fn withdraw_handle_dispute(origin, dispute_id: HandleDisputeId) {
+            let dispute = Self::ensure_handle_dispute_exists(dispute_id)?;
+
+            Self::ensure_member_controller_account_origin_signed(origin, &dispute.complainant_id)?;
+
+            ensure!(dispute.resolution.is_none(), Error::<T>::HandleDisputeAlreadyResolved);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            T::HandleDisputeStakingHandler::unlock(&dispute.staking_account_id);
+
+            Self::remove_handle_dispute(dispute_id, &dispute.handle_hash);
+
+            Self::deposit_event(RawEvent::HandleDisputeWithdrawn(dispute_id));
+        }
+
+        /// Sets the member recovery guardians and the number of guardian approvals required to
+        /// start the member accounts recovery. Empty guardians remove the configuration.
+        /// Requires the member root account.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (W)` where:
+        /// - `W` is the number of guardians
+        /// - DB:
+        ///    - O(W)
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::set_recovery_guardians(
+            guardians.len().saturated_into())
+        ]
+        pub fn set_recovery_guardians(
+            origin,
+            member_id: T::MemberId,
+            guardians: BTreeSet<T::MemberId>,
+            threshold: u32,
+        ) {
+            let sender = ensure_signed(origin)?;
+            let membership = Self::ensure_membership(member_id)?;
+
+            ensure!(membership.root_account == sender, Error::<T>::RootAccountRequired);
+
+            ensure!(
+                !<RecoveryByMemberId<T>>::contains_key(member_id),
+                Error::<T>::RecoveryIsInProgress
+            );
+
+            if !guardians.is_empty() {
+                ensure!(
+                    guardians.len() <= T::MaxRecoveryGuardians::get().saturated_into(),
+                    Error::<T>::TooManyRecoveryGuardians
+                );
+
+                ensure!(
+                    threshold > 0 && threshold <= guardians.len().saturated_into(),
+                    Error::<T>::InvalidRecoveryThreshold
+                );
+
+                ensure!(
+                    !guardians.contains(&member_id),
+                    Error::<T>::CannotBeOwnRecoveryGuardian
+                );
+
+                for guardian_id in guardians.iter() {
+                    Self::ensure_membership_with_error(
+                        *guardian_id,
+                        Error::<T>::RecoveryGuardianIsNotMember,
+                    )?;
+                }
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            if guardians.is_empty() {
+                <RecoveryConfigByMemberId<T>>::remove(member_id);
+            } else {
+                <RecoveryConfigByMemberId<T>>::insert(member_id, RecoveryConfig {
+                    guardians: guardians.clone(),
+                    threshold,
+                });
+            }
+
+            Self::deposit_event(RawEvent::RecoveryGuardiansUpdated(member_id, guardians, threshold));
+        }
+
+        /// Proposes new accounts for the member on behalf of the recovery guardian. The proposal
+        /// counts as the guardian approval. Requires the guardian controller account.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::initiate_recovery()]
+        pub fn initiate_recovery(
+            origin,
+            guardian_id: T::MemberId,
+            member_id: T::MemberId,
+            new_root_account: Option<T::AccountId>,
+            new_controller_account: Option<T::AccountId>,
+        ) {
+            Self::ensure_member_controller_account_origin_signed(origin, &guardian_id)?;
+
+            let config = Self::ensure_recovery_guardian(member_id, guardian_id)?;
+
+            ensure!(
+                new_root_account.is_some() || new_controller_account.is_some(),
+                Error::<T>::RecoveryAccountsAreEmpty
+            );
+
+            ensure!(
+                !<RecoveryByMemberId<T>>::contains_key(member_id),
+                Error::<T>::RecoveryIsInProgress
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let mut recovery = Recovery {
+                new_root_account: new_root_account.clone(),
+                new_controller_account: new_controller_account.clone(),
+                approvals: BTreeSet::new(),
+                rejections: BTreeSet::new(),
+                executable_at: None,
+            };
+
+            recovery.approvals.insert(guardian_id);
+
+            Self::deposit_event(RawEvent::RecoveryInitiated(
+                member_id,
+                guardian_id,
+                new_root_account,
+                new_controller_account,
+            ));
+
+            Self::try_start_recovery(member_id, &config, &mut recovery);
+
+            <RecoveryByMemberId<T>>::insert(member_id, recovery);
+        }
+
+        /// Approves the member accounts recovery. The recovery delay starts once the guardians
+        /// threshold is reached. Requires the guardian controller account.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::approve_recovery()]
+        pub fn approve_recovery(origin, guardian_id: T::MemberId, member_id: T::MemberId) {
+            Self::ensure_member_controller_account_origin_signed(origin, &guardian_id)?;
+
+            let config = Self::ensure_recovery_guardian(member_id, guardian_id)?;
+
+            let mut recovery = Self::ensure_recovery_exists(member_id)?;
+
+            ensure!(
+                !recovery.approvals.contains(&guardian_id),
+                Error::<T>::RecoveryAlreadyApproved
+            );
+
+            ensure!(
+                !recovery.rejections.contains(&guardian_id),
+                Error::<T>::RecoveryAlreadyRejected
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            recovery.approvals.insert(guardian_id);
+
+            Self::deposit_event(RawEvent::RecoveryApproved(member_id, guardian_id));
+
+            Self::try_start_recovery(member_id, &config, &mut recovery);
+
+            <RecoveryByMemberId<T>>::insert(member_id, recovery);
+        }
+
+        /// Rejects the member accounts recovery. The recovery is cancelled once the guardians
+        /// that didn't reject it can no longer reach the threshold, so a single guardian cannot
+        /// block the member recovery with a wrong proposal. Requires the guardian controller
+        /// account.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::reject_recovery()]
+        pub fn reject_recovery(origin, guardian_id: T::MemberId, member_id: T::MemberId) {
+            Self::ensure_member_controller_account_origin_signed(origin, &guardian_id)?;
+
+            let config = Self::ensure_recovery_guardian(member_id, guardian_id)?;
+
+            let mut recovery = Self::ensure_recovery_exists(member_id)?;
+
+            ensure!(
+                !recovery.approvals.contains(&guardian_id),
+                Error::<T>::RecoveryAlreadyApproved
+            );
+
+            ensure!(
+                !recovery.rejections.contains(&guardian_id),
+                Error::<T>::RecoveryAlreadyRejected
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            recovery.rejections.insert(guardian_id);
+
+            Self::deposit_event(RawEvent::RecoveryRejected(member_id, guardian_id));
+
+            let remaining_guardians: u32 = config
+                .guardians
+                .len()
+                .saturating_sub(recovery.rejections.len())
+                .saturated_into();
+
+            if remaining_guardians < config.threshold {
+                <RecoveryByMemberId<T>>::remove(member_id);
+
+                Self::deposit_event(RawEvent::RecoveryCancelled(member_id));
+            } else {
+                <RecoveryByMemberId<T>>::insert(member_id, recovery);
+            }
+        }
+
+        /// Cancels the member accounts recovery. Requires the member root account.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::cancel_recovery()]
+        pub fn cancel_recovery(origin, member_id: T::MemberId) {
+            let sender = ensure_signed(origin)?;
+            let membership = Self::ensure_membership(member_id)?;
+
+            ensure!(membership.root_account == sender, Error::<T>::RootAccountRequired);
+
+            Self::ensure_recovery_exists(member_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <RecoveryByMemberId<T>>::remove(member_id);
+
+            Self::deposit_event(RawEvent::RecoveryCancelled(member_id));
+        }
+
+        /// Updates the member accounts with the recovered ones after the recovery delay expired.
+        /// Can be called by any signed origin.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::execute_recovery()]
+        pub fn execute_recovery(origin, member_id: T::MemberId) {
+            ensure_signed(origin)?;
+
+            let membership = Self::ensure_membership(member_id)?;
+
+            let recovery = Self::ensure_recovery_exists(member_id)?;
+
+            let executable_at = recovery
+                .executable_at
+                .ok_or(Error::<T>::RecoveryThresholdNotReached)?;
+
+            ensure!(
+                <frame_system::Module<T>>::block_number() >= executable_at,
+                Error::<T>::RecoveryDelayNotExpired
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <RecoveryByMemberId<T>>::remove(member_id);
+
+            Self::update_member_accounts(
+                member_id,
+                membership,
+                recovery.new_root_account,
+                recovery.new_controller_account,
+            );
+
+            Self::deposit_event(RawEvent::RecoveryExecuted(member_id));
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    // Updates member accounts with the provided ones.
+    fn update_member_accounts(
+        member_id: T::MemberId,
+        mut membership: Membership<T>,
+        new_root_account: Option<T::AccountId>,
+        new_controller_account: Option<T::AccountId>,
+    ) {
+        if let Some(root_account) = new_root_account.clone() {
+            membership.root_account = root_account;
+        }
+
+        if let Some(controller_account) = new_controller_account.clone() {
+            membership.controller_account = controller_account;
+        }
+
+        <MembershipById<T>>::insert(member_id, membership);
+        Self::deposit_event(RawEvent::MemberAccountsUpdated(
+            member_id,
+            new_root_account,
+            new_controller_account,
+        ));
+    }
+
+    // Ensures the member has the provided recovery guardian and returns the recovery config.
+    fn ensure_recovery_guardian(
+        member_id: T::MemberId,
+        guardian_id: T::MemberId,
+    ) -> Result<RecoveryConfigOf<T>, Error<T>> {
+        ensure!(
+            <RecoveryConfigByMemberId<T>>::contains_key(member_id),
+            Error::<T>::RecoveryGuardiansNotConfigured
+        );
+
+        let config = Self::recovery_config(member_id);
+
+        ensure!(
+            config.guardians.contains(&guardian_id),
+            Error::<T>::NotRecoveryGuardian
+        );
+
+        Ok(config)
+    }
+
+    // Ensures the member accounts recovery exists and returns it.
+    fn ensure_recovery_exists(member_id: T::MemberId) -> Result<RecoveryOf<T>, Error<T>> {
+        ensure!(
+            <RecoveryByMemberId<T>>::contains_key(member_id),
+            Error::<T>::RecoveryDoesntExist
+        );
+
+        Ok(Self::recovery(member_id))
+    }
+
+    // Starts the recovery delay once the recovery approvals reach the guardians threshold.
+    fn try_start_recovery(
+        member_id: T::MemberId,
+        config: &RecoveryConfigOf<T>,
+        recovery: &mut RecoveryOf<T>,
+    ) {
+        if recovery.executable_at.is_some() {
+            return;
+        }
+
+        let approvals: u32 = recovery.approvals.len().saturated_into();
+
+        if approvals >= config.threshold {
+            let executable_at =
+                <frame_system::Module<T>>::block_number().saturating_add(T::RecoveryDelay::get());
+
+            recovery.executable_at = Some(executable_at);
+
+            Self::deposit_event(RawEvent::RecoveryStarted(member_id, executable_at));
+        }
+    }
+
+    // Helper for update_account extrinsic weight calculation
+    fn calculate_weight_for_update_account(
         new_root_account: &Option<T::AccountId>,
         new_controller_account: &Option<T::AccountId>,
     ) -> Weight {
@@ -1069,15 +2312,31 @@ impl<T: Trait> Module<T> {
         }
     }
 
-    // Ensure possible member handle hash is unique.
+    // Ensure possible member handle hash is unique and available for registration.
     fn ensure_unique_handle_hash(handle_hash: Vec<u8>) -> Result<(), Error<T>> {
         ensure!(
-            !<MemberIdByHandleHash<T>>::contains_key(handle_hash),
+            !<MemberIdByHandleHash<T>>::contains_key(&handle_hash),
             Error::<T>::HandleAlreadyRegistered
         );
+
+        ensure!(
+            !<ReservedHandleHashes>::contains_key(&handle_hash),
+            Error::<T>::HandleIsReserved
+        );
+
+        ensure!(
+            !<HandleDisputeIdByHandleHash>::contains_key(&handle_hash),
+            Error::<T>::HandleIsDisputed
+        );
+
         Ok(())
     }
 
+    // Hash the handle.
+    fn hash_handle(handle: &[u8]) -> Vec<u8> {
+        T::Hashing::hash(handle).as_ref().to_vec()
+    }
+
     // Validate handle and return its hash.
     fn get_handle_hash(handle: &Option<Vec<u8>>) -> Result<Vec<u8>, Error<T>> {
         // Handle is required during registration
@@ -1089,8 +2348,7 @@ impl<T: Trait> Module<T> {
             return Err(Error::<T>::HandleMustBeProvidedDuringRegistration);
         }
 
-        let hashed = T::Hashing::hash(&handle);
-        let handle_hash = hashed.as_ref().to_vec();
+        let handle_hash = Self::hash_handle(handle);
 
         Self::ensure_unique_handle_hash(handle_hash.clone())?;
 
@@ -1122,6 +2380,35 @@ impl<T: Trait> Module<T> {
         new_member_id
     }
 
+    // Replaces the member handle with the provided handle hash.
+    fn assign_handle_hash(member_id: T::MemberId, handle_hash: Vec<u8>) {
+        <MembershipById<T>>::mutate(&member_id, |membership| {
+            <MemberIdByHandleHash<T>>::remove(&membership.handle_hash);
+
+            membership.handle_hash = handle_hash.clone();
+        });
+
+        <MemberIdByHandleHash<T>>::insert(handle_hash, member_id);
+    }
+
+    // Provided that the handle dispute exists return it. Returns error otherwise.
+    fn ensure_handle_dispute_exists(
+        dispute_id: HandleDisputeId,
+    ) -> Result<HandleDisputeOf<T>, Error<T>> {
+        ensure!(
+            <HandleDisputeById<T>>::contains_key(dispute_id),
+            Error::<T>::HandleDisputeDoesntExist
+        );
+
+        Ok(Self::handle_dispute_by_id(dispute_id))
+    }
+
+    // Removes the handle dispute and unblocks the disputed handle.
+    fn remove_handle_dispute(dispute_id: HandleDisputeId, handle_hash: &[u8]) {
+        <HandleDisputeById<T>>::remove(dispute_id);
+        <HandleDisputeIdByHandleHash>::remove(handle_hash);
+    }
+
     // Ensure origin corresponds to the controller account of the member.
     fn ensure_member_controller_account_origin_signed(
         origin: T::Origin,
@@ -1153,6 +2440,133 @@ impl<T: Trait> Module<T> {
         Ok(membership)
     }
 
+    // Returns the current referral campaign if it has not ended yet.
+    fn active_referral_campaign() -> Option<ReferralCampaignOf<T>> {
+        let current_block = <frame_system::Module<T>>::block_number();
+
+        Self::current_referral_campaign().filter(|campaign| campaign.ends_at > current_block)
+    }
+
+    // Records the referral and rewards the direct and the second-level referrers. Rewards are
+    // paid from the active referral campaign budget if it covers them, and are transferred
+    // from the membership fee payer otherwise. Returns the rewards paid from the membership fee.
+    fn reward_referrers(
+        payer: &T::AccountId,
+        member_id: T::MemberId,
+        referrer_id: T::MemberId,
+        referrer: Membership<T>,
+    ) -> BalanceOf<T> {
+        <ReferrerByMemberId<T>>::insert(member_id, referrer_id);
+
+        let referral_cut = Self::get_referral_bonus();
+
+        let second_level_referrer_id = if <ReferrerByMemberId<T>>::contains_key(referrer_id) {
+            Some(Self::referrer_by_member_id(referrer_id))
+        } else {
+            None
+        };
+
+        let second_level_referral_cut = second_level_referrer_id
+            .map(|_| Self::get_second_level_referral_bonus(referral_cut))
+            .unwrap_or_else(Zero::zero);
+
+        let mut paid_by_campaign = false;
+
+        if let Some(mut campaign) = Self::active_referral_campaign() {
+            let total_rewards = referral_cut.saturating_add(second_level_referral_cut);
+
+            campaign.referrals = campaign.referrals.saturating_add(1);
+
+            if campaign.budget >= total_rewards {
+                campaign.budget = campaign.budget.saturating_sub(total_rewards);
+                paid_by_campaign = true;
+            }
+
+            <CurrentReferralCampaign<T>>::put(campaign);
+        }
+
+        let referral_reward = Self::pay_referral_reward(
+            payer,
+            &referrer.controller_account,
+            referral_cut,
+            paid_by_campaign,
+        );
+
+        <ReferralStatsByMemberId<T>>::mutate(referrer_id, |stats| {
+            stats.direct_referrals = stats.direct_referrals.saturating_add(1);
+            stats.rewards = stats.rewards.saturating_add(referral_reward);
+        });
+
+        let mut paid_rewards = referral_reward;
+
+        if let Some(second_level_referrer_id) = second_level_referrer_id {
+            let second_level_referrer = Self::membership(second_level_referrer_id);
+
+            let second_level_referral_reward = Self::pay_referral_reward(
+                payer,
+                &second_level_referrer.controller_account,
+                second_level_referral_cut,
+                paid_by_campaign,
+            );
+
+            <ReferralStatsByMemberId<T>>::mutate(second_level_referrer_id, |stats| {
+                stats.second_level_referrals = stats.second_level_referrals.saturating_add(1);
+                stats.rewards = stats.rewards.saturating_add(second_level_referral_reward);
+            });
+
+            paid_rewards = paid_rewards.saturating_add(second_level_referral_reward);
+        }
+
+        if paid_by_campaign {
+            Zero::zero()
+        } else {
+            paid_rewards
+        }
+    }
+
+    // Pays the referral reward. Rewards covered by the referral campaign are minted, because
+    // the campaign budget was already taken from the working group budget. Other rewards are
+    // transferred from the membership fee payer. Returns the reward amount actually paid.
+    fn pay_referral_reward(
+        payer: &T::AccountId,
+        account_id: &T::AccountId,
+        reward: BalanceOf<T>,
+        paid_by_campaign: bool,
+    ) -> BalanceOf<T> {
+        if reward.is_zero() {
+            return Zero::zero();
+        }
+
+        if paid_by_campaign {
+            return balances::Module::<T>::deposit_creating(account_id, reward).peek();
+        }
+
+        let transfer_result = <balances::Module<T> as Currency<T::AccountId>>::transfer(
+            payer,
+            account_id,
+            reward,
+            ExistenceRequirement::AllowDeath,
+        );
+
+        if transfer_result.is_ok() {
+            reward
+        } else {
+            Zero::zero()
+        }
+    }
+
+    // Calculate current second-level referral bonus as a percent of the membership fee.
+    pub(crate) fn get_second_level_referral_bonus(referral_bonus: BalanceOf<T>) -> BalanceOf<T> {
+        let membership_fee = Self::membership_price();
+        let second_level_referral_cut = Self::second_level_referral_cut();
+
+        let second_level_referral_cut =
+            Perbill::from_percent(second_level_referral_cut.into()) * membership_fee;
+
+        // Both referral bonuses cannot be greater than 100%
+        second_level_referral_cut.min(membership_fee.saturating_sub(referral_bonus))
+    }
+
     // Calculate current referral bonus as a percent of the membership fee.
     pub(crate) fn get_referral_bonus() -> BalanceOf<T> {
         let membership_fee = Self::membership_price();
@@ -1224,6 +2638,20 @@ impl<T: Trait> MemberOriginValidator<T::Origin, T::MemberId, T::AccountId> for M
     }
 }
 
+impl<T: Trait> MemberVerificationProvider<T::MemberId> for Module<T> {
+    fn has_valid_verification(member_id: &T::MemberId, kind: VerificationKind) -> bool {
+        if !<MemberVerifications<T>>::contains_key(member_id, kind) {
+            return false;
+        }
+
+        let verification = Self::member_verifications(member_id, kind);
+
+        verification.expires_at.map_or(true, |expires_at| {
+            <frame_system::Module<T>>::block_number() < expires_at
+        })
+    }
+}
+
 impl<T: Trait> MembershipInfoProvider<T> for Module<T> {
     fn controller_account_id(
         member_id: common::MemberId<T>,
@@ -1292,4 +2720,55 @@ impl WeightInfo for () {
     fn member_remark() -> Weight {
         0
     }
+    fn reserve_handle(_: u32) -> Weight {
+        0
+    }
+    fn release_handle(_: u32) -> Weight {
+        0
+    }
+    fn open_handle_dispute(_: u32) -> Weight {
+        0
+    }
+    fn resolve_handle_dispute() -> Weight {
+        0
+    }
+    fn execute_handle_dispute_resolution() -> Weight {
+        0
+    }
+    fn withdraw_handle_dispute() -> Weight {
+        0
+    }
+    fn add_member_verification() -> Weight {
+        0
+    }
+    fn revoke_member_verification() -> Weight {
+        0
+    }
+    fn set_second_level_referral_cut() -> Weight {
+        0
+    }
+    fn start_referral_campaign() -> Weight {
+        0
+    }
+    fn end_referral_campaign() -> Weight {
+        0
+    }
+    fn set_recovery_guardians(_: u32) -> Weight {
+        0
+    }
+    fn initiate_recovery() -> Weight {
+        0
+    }
+    fn approve_recovery() -> Weight {
+        0
+    }
+    fn reject_recovery() -> Weight {
+        0
+    }
+    fn cancel_recovery() -> Weight {
+        0
+    }
+    fn execute_recovery() -> Weight {
+        0
+    }
 }
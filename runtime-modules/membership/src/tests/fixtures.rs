@@ -1,10 +1,15 @@
 use super::mock::*;
-use crate::{BuyMembershipParameters, InviteMembershipParameters};
+use crate::{
+    BuyMembershipParameters, HandleDisputeId, HandleDisputeResolution, InviteMembershipParameters,
+};
+use common::membership::VerificationKind;
+use common::working_group::WorkingGroupBudgetHandler;
 use frame_support::dispatch::DispatchResult;
 use frame_support::traits::{OnFinalize, OnInitialize};
-use frame_support::StorageMap;
+use frame_support::{StorageDoubleMap, StorageMap};
 use frame_system::{EventRecord, Phase, RawOrigin};
 use sp_runtime::traits::Hash;
+use sp_std::collections::btree_set::BTreeSet;
 
 // Recommendation from Parity on testing on_finalize
 // https://substrate.dev/docs/en/next/development/module/tests
@@ -103,6 +108,8 @@ pub const ALICE_ACCOUNT_ID: u64 = 1;
 pub const BOB_ACCOUNT_ID: u64 = 2;
 pub const ALICE_MEMBER_ID: u64 = 0;
 pub const BOB_MEMBER_ID: u64 = 1;
+pub const CHARLIE_ACCOUNT_ID: u64 = 3;
+pub const CHARLIE_MEMBER_ID: u64 = 2;
 
 pub fn get_alice_membership_parameters() -> BuyMembershipParameters<u64, u64> {
     let info = get_alice_info();
@@ -282,6 +289,138 @@ impl SetReferralCutFixture {
     }
 }
 
+pub struct SetSecondLevelReferralCutFixture {
+    pub origin: RawOrigin<u64>,
+    pub value: u8,
+}
+
+pub const DEFAULT_SECOND_LEVEL_REFERRAL_CUT_VALUE: u8 = 20;
+
+impl Default for SetSecondLevelReferralCutFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Root,
+            value: DEFAULT_SECOND_LEVEL_REFERRAL_CUT_VALUE,
+        }
+    }
+}
+
+impl SetSecondLevelReferralCutFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result =
+            Membership::set_second_level_referral_cut(self.origin.clone().into(), self.value);
+
+        assert_eq!(expected_result, actual_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(Membership::second_level_referral_cut(), self.value);
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_referral_cut(self, value: u8) -> Self {
+        Self { value, ..self }
+    }
+}
+
+pub struct StartReferralCampaignFixture {
+    pub origin: RawOrigin<u64>,
+    pub budget: u64,
+    pub duration: u64,
+}
+
+impl Default for StartReferralCampaignFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Root,
+            budget: 80,
+            duration: 10,
+        }
+    }
+}
+
+impl StartReferralCampaignFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let ended_campaign_budget = Membership::current_referral_campaign()
+            .map(|campaign| campaign.budget)
+            .unwrap_or_default();
+        let wg_budget = <Test as crate::Trait>::WorkingGroup::get_budget();
+
+        let actual_result = Membership::start_referral_campaign(
+            self.origin.clone().into(),
+            self.budget,
+            self.duration,
+        );
+
+        assert_eq!(expected_result, actual_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(
+                <Test as crate::Trait>::WorkingGroup::get_budget(),
+                wg_budget + ended_campaign_budget - self.budget
+            );
+            assert_eq!(
+                Membership::current_referral_campaign(),
+                Some(crate::ReferralCampaign {
+                    budget: self.budget,
+                    ends_at: System::block_number() + self.duration,
+                    referrals: 0,
+                })
+            );
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_budget(self, budget: u64) -> Self {
+        Self { budget, ..self }
+    }
+
+    pub fn with_duration(self, duration: u64) -> Self {
+        Self { duration, ..self }
+    }
+}
+
+pub struct EndReferralCampaignFixture {
+    pub origin: RawOrigin<u64>,
+}
+
+impl Default for EndReferralCampaignFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Root,
+        }
+    }
+}
+
+impl EndReferralCampaignFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let campaign = Membership::current_referral_campaign();
+        let wg_budget = <Test as crate::Trait>::WorkingGroup::get_budget();
+
+        let actual_result = Membership::end_referral_campaign(self.origin.clone().into());
+
+        assert_eq!(expected_result, actual_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(Membership::current_referral_campaign(), None);
+            assert_eq!(
+                <Test as crate::Trait>::WorkingGroup::get_budget(),
+                wg_budget + campaign.unwrap().budget
+            );
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+}
+
 pub struct TransferInvitesFixture {
     pub origin: RawOrigin<u64>,
     pub source_member_id: u64,
@@ -695,3 +834,815 @@ impl ConfirmStakingAccountFixture {
         Self { member_id, ..self }
     }
 }
+
+pub fn hash_handle(handle: &[u8]) -> Vec<u8> {
+    <Test as frame_system::Trait>::Hashing::hash(handle)
+        .as_ref()
+        .to_vec()
+}
+
+pub fn get_genesis_member_handle(member_index: u64) -> Vec<u8> {
+    (10000 + member_index).to_string().into_bytes()
+}
+
+pub fn get_handle_dispute_stake_lock(account_id: u64) -> Option<u64> {
+    Balances::locks(&account_id)
+        .iter()
+        .find(|lock| lock.id == HandleDisputeLockId::get())
+        .map(|lock| lock.amount)
+}
+
+pub fn set_confirmed_staking_account(member_id: u64, account_id: u64, balance: u64) {
+    Balances::make_free_balance_be(&account_id, balance);
+
+    assert_eq!(
+        Membership::add_staking_account_candidate(RawOrigin::Signed(account_id).into(), member_id),
+        Ok(())
+    );
+
+    assert_eq!(
+        Membership::confirm_staking_account(
+            RawOrigin::Signed(account_id).into(),
+            member_id,
+            account_id
+        ),
+        Ok(())
+    );
+}
+
+pub struct ReserveHandleFixture {
+    pub origin: RawOrigin<u64>,
+    pub handle: Vec<u8>,
+}
+
+impl Default for ReserveHandleFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(ALICE_ACCOUNT_ID),
+            handle: get_bob_info().handle.unwrap(),
+        }
+    }
+}
+
+impl ReserveHandleFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result =
+            Membership::reserve_handle(self.origin.clone().into(), self.handle.clone());
+
+        assert_eq!(expected_result, actual_result);
+
+        if actual_result.is_ok() {
+            assert!(<crate::ReservedHandleHashes>::contains_key(hash_handle(
+                &self.handle
+            )));
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_handle(self, handle: Vec<u8>) -> Self {
+        Self { handle, ..self }
+    }
+}
+
+pub struct ReleaseHandleFixture {
+    pub origin: RawOrigin<u64>,
+    pub handle: Vec<u8>,
+    pub new_owner_id: Option<u64>,
+}
+
+impl Default for ReleaseHandleFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(ALICE_ACCOUNT_ID),
+            handle: get_bob_info().handle.unwrap(),
+            new_owner_id: None,
+        }
+    }
+}
+
+impl ReleaseHandleFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result = Membership::release_handle(
+            self.origin.clone().into(),
+            self.handle.clone(),
+            self.new_owner_id,
+        );
+
+        assert_eq!(expected_result, actual_result);
+
+        if actual_result.is_ok() {
+            let handle_hash = hash_handle(&self.handle);
+
+            assert!(!<crate::ReservedHandleHashes>::contains_key(&handle_hash));
+
+            if let Some(member_id) = self.new_owner_id {
+                assert_eq!(Membership::handles(&handle_hash), member_id);
+                assert_eq!(get_membership_by_id(member_id).handle_hash, handle_hash);
+            }
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_new_owner_id(self, new_owner_id: u64) -> Self {
+        Self {
+            new_owner_id: Some(new_owner_id),
+            ..self
+        }
+    }
+}
+
+pub struct OpenHandleDisputeFixture {
+    pub origin: RawOrigin<u64>,
+    pub member_id: u64,
+    pub handle: Vec<u8>,
+    pub staking_account_id: u64,
+}
+
+impl Default for OpenHandleDisputeFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(BOB_ACCOUNT_ID),
+            member_id: BOB_MEMBER_ID,
+            handle: get_genesis_member_handle(ALICE_MEMBER_ID),
+            staking_account_id: BOB_ACCOUNT_ID,
+        }
+    }
+}
+
+impl OpenHandleDisputeFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let next_dispute_id = Membership::next_handle_dispute_id();
+
+        let actual_result = Membership::open_handle_dispute(
+            self.origin.clone().into(),
+            self.member_id,
+            self.handle.clone(),
+            self.staking_account_id,
+        );
+
+        assert_eq!(expected_result, actual_result);
+
+        if actual_result.is_ok() {
+            let handle_hash = hash_handle(&self.handle);
+            let dispute = Membership::handle_dispute_by_id(next_dispute_id);
+
+            assert_eq!(dispute.handle_hash, handle_hash);
+            assert_eq!(dispute.complainant_id, self.member_id);
+            assert_eq!(dispute.owner_id, Membership::handles(&handle_hash));
+            assert_eq!(dispute.resolution, None);
+            assert_eq!(
+                Membership::handle_dispute_id_by_handle_hash(&handle_hash),
+                next_dispute_id
+            );
+            assert_eq!(Membership::next_handle_dispute_id(), next_dispute_id + 1);
+            assert_eq!(
+                get_handle_dispute_stake_lock(self.staking_account_id),
+                Some(HandleDisputeStake::get())
+            );
+        } else {
+            assert_eq!(Membership::next_handle_dispute_id(), next_dispute_id);
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_member_id(self, member_id: u64) -> Self {
+        Self { member_id, ..self }
+    }
+
+    pub fn with_handle(self, handle: Vec<u8>) -> Self {
+        Self { handle, ..self }
+    }
+
+    pub fn with_staking_account_id(self, staking_account_id: u64) -> Self {
+        Self {
+            staking_account_id,
+            ..self
+        }
+    }
+}
+
+pub struct ResolveHandleDisputeFixture {
+    pub origin: RawOrigin<u64>,
+    pub dispute_id: HandleDisputeId,
+    pub resolution: HandleDisputeResolution,
+}
+
+impl Default for ResolveHandleDisputeFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(ALICE_ACCOUNT_ID),
+            dispute_id: 0,
+            resolution: HandleDisputeResolution::Reassign,
+        }
+    }
+}
+
+impl ResolveHandleDisputeFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let dispute = Membership::handle_dispute_by_id(self.dispute_id);
+        let initial_balance = Balances::free_balance(&dispute.staking_account_id);
+
+        let actual_result = Membership::resolve_handle_dispute(
+            self.origin.clone().into(),
+            self.dispute_id,
+            self.resolution,
+        );
+
+        assert_eq!(expected_result, actual_result);
+
+        if actual_result.is_ok() {
+            if self.resolution == HandleDisputeResolution::Reject {
+                assert!(!<crate::HandleDisputeById<Test>>::contains_key(
+                    self.dispute_id
+                ));
+                assert!(!<crate::HandleDisputeIdByHandleHash>::contains_key(
+                    &dispute.handle_hash
+                ));
+                assert_eq!(
+                    Balances::free_balance(&dispute.staking_account_id),
+                    initial_balance - dispute.stake
+                );
+            } else {
+                let resolved_dispute = Membership::handle_dispute_by_id(self.dispute_id);
+
+                assert_eq!(resolved_dispute.resolution, Some(self.resolution));
+                assert_eq!(
+                    resolved_dispute.grace_period_end,
+                    System::block_number() + HandleDisputeGracePeriod::get()
+                );
+            }
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_dispute_id(self, dispute_id: HandleDisputeId) -> Self {
+        Self { dispute_id, ..self }
+    }
+
+    pub fn with_resolution(self, resolution: HandleDisputeResolution) -> Self {
+        Self { resolution, ..self }
+    }
+}
+
+pub struct ExecuteHandleDisputeResolutionFixture {
+    pub origin: RawOrigin<u64>,
+    pub dispute_id: HandleDisputeId,
+}
+
+impl Default for ExecuteHandleDisputeResolutionFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(BOB_ACCOUNT_ID),
+            dispute_id: 0,
+        }
+    }
+}
+
+impl ExecuteHandleDisputeResolutionFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let dispute = Membership::handle_dispute_by_id(self.dispute_id);
+
+        let actual_result = Membership::execute_handle_dispute_resolution(
+            self.origin.clone().into(),
+            self.dispute_id,
+        );
+
+        assert_eq!(expected_result, actual_result);
+
+        if actual_result.is_ok() {
+            assert!(!<crate::HandleDisputeById<Test>>::contains_key(
+                self.dispute_id
+            ));
+            assert!(!<crate::HandleDisputeIdByHandleHash>::contains_key(
+                &dispute.handle_hash
+            ));
+            assert_eq!(
+                get_handle_dispute_stake_lock(dispute.staking_account_id),
+                None
+            );
+
+            match dispute.resolution {
+                Some(HandleDisputeResolution::Reassign) => {
+                    assert_eq!(
+                        Membership::handles(&dispute.handle_hash),
+                        dispute.complainant_id
+                    );
+                    assert_eq!(
+                        get_membership_by_id(dispute.complainant_id).handle_hash,
+                        dispute.handle_hash
+                    );
+                }
+                Some(HandleDisputeResolution::ForceRename) => {
+                    assert!(!<crate::MemberIdByHandleHash<Test>>::contains_key(
+                        &dispute.handle_hash
+                    ));
+                    assert!(<crate::ReservedHandleHashes>::contains_key(
+                        &dispute.handle_hash
+                    ));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_dispute_id(self, dispute_id: HandleDisputeId) -> Self {
+        Self { dispute_id, ..self }
+    }
+}
+
+pub struct WithdrawHandleDisputeFixture {
+    pub origin: RawOrigin<u64>,
+    pub dispute_id: HandleDisputeId,
+}
+
+impl Default for WithdrawHandleDisputeFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(BOB_ACCOUNT_ID),
+            dispute_id: 0,
+        }
+    }
+}
+
+impl WithdrawHandleDisputeFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let dispute = Membership::handle_dispute_by_id(self.dispute_id);
+
+        let actual_result =
+            Membership::withdraw_handle_dispute(self.origin.clone().into(), self.dispute_id);
+
+        assert_eq!(expected_result, actual_result);
+
+        if actual_result.is_ok() {
+            assert!(!<crate::HandleDisputeById<Test>>::contains_key(
+                self.dispute_id
+            ));
+            assert!(!<crate::HandleDisputeIdByHandleHash>::contains_key(
+                &dispute.handle_hash
+            ));
+            assert_eq!(
+                get_handle_dispute_stake_lock(dispute.staking_account_id),
+                None
+            );
+            assert_eq!(Membership::handles(&dispute.handle_hash), dispute.owner_id);
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+}
+
+pub struct AddMemberVerificationFixture {
+    pub origin: RawOrigin<u64>,
+    pub worker_id: u64,
+    pub member_id: u64,
+    pub kind: VerificationKind,
+    pub expires_at: Option<u64>,
+}
+
+impl Default for AddMemberVerificationFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(ALICE_ACCOUNT_ID),
+            worker_id: 1,
+            member_id: ALICE_MEMBER_ID,
+            kind: VerificationKind::Identity,
+            expires_at: None,
+        }
+    }
+}
+
+impl AddMemberVerificationFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result = Membership::add_member_verification(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.member_id,
+            self.kind,
+            self.expires_at,
+        );
+
+        assert_eq!(expected_result, actual_result);
+
+        if actual_result.is_ok() {
+            let verification = Membership::member_verifications(self.member_id, self.kind);
+
+            assert_eq!(
+                verification,
+                crate::Verification {
+                    issuer: self.worker_id,
+                    issued_at: System::block_number(),
+                    expires_at: self.expires_at,
+                }
+            );
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_worker_id(self, worker_id: u64) -> Self {
+        Self { worker_id, ..self }
+    }
+
+    pub fn with_member_id(self, member_id: u64) -> Self {
+        Self { member_id, ..self }
+    }
+
+    pub fn with_kind(self, kind: VerificationKind) -> Self {
+        Self { kind, ..self }
+    }
+
+    pub fn with_expires_at(self, expires_at: u64) -> Self {
+        Self {
+            expires_at: Some(expires_at),
+            ..self
+        }
+    }
+}
+
+pub struct RevokeMemberVerificationFixture {
+    pub origin: RawOrigin<u64>,
+    pub worker_id: u64,
+    pub member_id: u64,
+    pub kind: VerificationKind,
+}
+
+impl Default for RevokeMemberVerificationFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(ALICE_ACCOUNT_ID),
+            worker_id: 1,
+            member_id: ALICE_MEMBER_ID,
+            kind: VerificationKind::Identity,
+        }
+    }
+}
+
+impl RevokeMemberVerificationFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result = Membership::revoke_member_verification(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.member_id,
+            self.kind,
+        );
+
+        assert_eq!(expected_result, actual_result);
+
+        if actual_result.is_ok() {
+            assert!(!<crate::MemberVerifications<Test>>::contains_key(
+                self.member_id,
+                self.kind
+            ));
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_worker_id(self, worker_id: u64) -> Self {
+        Self { worker_id, ..self }
+    }
+}
+
+pub const RECOVERED_ROOT_ACCOUNT_ID: u64 = 10;
+pub const RECOVERED_CONTROLLER_ACCOUNT_ID: u64 = 11;
+
+pub fn get_recovery_guardians() -> BTreeSet<u64> {
+    [BOB_MEMBER_ID, CHARLIE_MEMBER_ID].iter().cloned().collect()
+}
+
+pub struct SetRecoveryGuardiansFixture {
+    pub origin: RawOrigin<u64>,
+    pub member_id: u64,
+    pub guardians: BTreeSet<u64>,
+    pub threshold: u32,
+}
+
+impl Default for SetRecoveryGuardiansFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(ALICE_ACCOUNT_ID),
+            member_id: ALICE_MEMBER_ID,
+            guardians: get_recovery_guardians(),
+            threshold: 2,
+        }
+    }
+}
+
+impl SetRecoveryGuardiansFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_config = Membership::recovery_config(self.member_id);
+
+        let actual_result = Membership::set_recovery_guardians(
+            self.origin.clone().into(),
+            self.member_id,
+            self.guardians.clone(),
+            self.threshold,
+        );
+
+        assert_eq!(expected_result, actual_result);
+
+        let config = Membership::recovery_config(self.member_id);
+        if actual_result.is_ok() {
+            if self.guardians.is_empty() {
+                assert!(!<crate::RecoveryConfigByMemberId<Test>>::contains_key(
+                    self.member_id
+                ));
+            } else {
+                assert_eq!(config.guardians, self.guardians);
+                assert_eq!(config.threshold, self.threshold);
+            }
+        } else {
+            assert_eq!(config, old_config);
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_guardians(self, guardians: BTreeSet<u64>) -> Self {
+        Self { guardians, ..self }
+    }
+
+    pub fn with_threshold(self, threshold: u32) -> Self {
+        Self { threshold, ..self }
+    }
+}
+
+pub struct InitiateRecoveryFixture {
+    pub origin: RawOrigin<u64>,
+    pub guardian_id: u64,
+    pub member_id: u64,
+    pub new_root_account: Option<u64>,
+    pub new_controller_account: Option<u64>,
+}
+
+impl Default for InitiateRecoveryFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(BOB_ACCOUNT_ID),
+            guardian_id: BOB_MEMBER_ID,
+            member_id: ALICE_MEMBER_ID,
+            new_root_account: Some(RECOVERED_ROOT_ACCOUNT_ID),
+            new_controller_account: Some(RECOVERED_CONTROLLER_ACCOUNT_ID),
+        }
+    }
+}
+
+impl InitiateRecoveryFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let recovery_existed = <crate::RecoveryByMemberId<Test>>::contains_key(self.member_id);
+
+        let actual_result = Membership::initiate_recovery(
+            self.origin.clone().into(),
+            self.guardian_id,
+            self.member_id,
+            self.new_root_account,
+            self.new_controller_account,
+        );
+
+        assert_eq!(expected_result, actual_result);
+
+        if actual_result.is_ok() {
+            let recovery = Membership::recovery(self.member_id);
+
+            assert_eq!(recovery.new_root_account, self.new_root_account);
+            assert_eq!(recovery.new_controller_account, self.new_controller_account);
+            assert!(recovery.approvals.contains(&self.guardian_id));
+        } else {
+            assert_eq!(
+                <crate::RecoveryByMemberId<Test>>::contains_key(self.member_id),
+                recovery_existed
+            );
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_guardian_id(self, guardian_id: u64) -> Self {
+        Self {
+            guardian_id,
+            ..self
+        }
+    }
+
+    pub fn with_accounts(
+        self,
+        new_root_account: Option<u64>,
+        new_controller_account: Option<u64>,
+    ) -> Self {
+        Self {
+            new_root_account,
+            new_controller_account,
+            ..self
+        }
+    }
+}
+
+pub struct ApproveRecoveryFixture {
+    pub origin: RawOrigin<u64>,
+    pub guardian_id: u64,
+    pub member_id: u64,
+}
+
+impl Default for ApproveRecoveryFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(CHARLIE_ACCOUNT_ID),
+            guardian_id: CHARLIE_MEMBER_ID,
+            member_id: ALICE_MEMBER_ID,
+        }
+    }
+}
+
+impl ApproveRecoveryFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_recovery = Membership::recovery(self.member_id);
+
+        let actual_result = Membership::approve_recovery(
+            self.origin.clone().into(),
+            self.guardian_id,
+            self.member_id,
+        );
+
+        assert_eq!(expected_result, actual_result);
+
+        let recovery = Membership::recovery(self.member_id);
+        if actual_result.is_ok() {
+            assert!(recovery.approvals.contains(&self.guardian_id));
+            assert_eq!(recovery.approvals.len(), old_recovery.approvals.len() + 1);
+        } else {
+            assert_eq!(recovery, old_recovery);
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_guardian_id(self, guardian_id: u64) -> Self {
+        Self {
+            guardian_id,
+            ..self
+        }
+    }
+}
+
+pub struct RejectRecoveryFixture {
+    pub origin: RawOrigin<u64>,
+    pub guardian_id: u64,
+    pub member_id: u64,
+}
+
+impl Default for RejectRecoveryFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(CHARLIE_ACCOUNT_ID),
+            guardian_id: CHARLIE_MEMBER_ID,
+            member_id: ALICE_MEMBER_ID,
+        }
+    }
+}
+
+impl RejectRecoveryFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_recovery = Membership::recovery(self.member_id);
+
+        let actual_result = Membership::reject_recovery(
+            self.origin.clone().into(),
+            self.guardian_id,
+            self.member_id,
+        );
+
+        assert_eq!(expected_result, actual_result);
+
+        let recovery = Membership::recovery(self.member_id);
+        if actual_result.is_ok() {
+            if <crate::RecoveryByMemberId<Test>>::contains_key(self.member_id) {
+                assert!(recovery.rejections.contains(&self.guardian_id));
+                assert_eq!(recovery.rejections.len(), old_recovery.rejections.len() + 1);
+            }
+        } else {
+            assert_eq!(recovery, old_recovery);
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_guardian_id(self, guardian_id: u64) -> Self {
+        Self {
+            guardian_id,
+            ..self
+        }
+    }
+}
+
+pub struct CancelRecoveryFixture {
+    pub origin: RawOrigin<u64>,
+    pub member_id: u64,
+}
+
+impl Default for CancelRecoveryFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(ALICE_ACCOUNT_ID),
+            member_id: ALICE_MEMBER_ID,
+        }
+    }
+}
+
+impl CancelRecoveryFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let recovery_existed = <crate::RecoveryByMemberId<Test>>::contains_key(self.member_id);
+
+        let actual_result = Membership::cancel_recovery(self.origin.clone().into(), self.member_id);
+
+        assert_eq!(expected_result, actual_result);
+
+        assert_eq!(
+            <crate::RecoveryByMemberId<Test>>::contains_key(self.member_id),
+            recovery_existed && actual_result.is_err()
+        );
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+}
+
+pub struct ExecuteRecoveryFixture {
+    pub origin: RawOrigin<u64>,
+    pub member_id: u64,
+}
+
+impl Default for ExecuteRecoveryFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(BOB_ACCOUNT_ID),
+            member_id: ALICE_MEMBER_ID,
+        }
+    }
+}
+
+impl ExecuteRecoveryFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_membership = Membership::membership(self.member_id);
+        let recovery = Membership::recovery(self.member_id);
+
+        let actual_result =
+            Membership::execute_recovery(self.origin.clone().into(), self.member_id);
+
+        assert_eq!(expected_result, actual_result);
+
+        let membership = Membership::membership(self.member_id);
+        if actual_result.is_ok() {
+            assert_eq!(
+                membership.root_account,
+                recovery
+                    .new_root_account
+                    .unwrap_or(old_membership.root_account)
+            );
+            assert_eq!(
+                membership.controller_account,
+                recovery
+                    .new_controller_account
+                    .unwrap_or(old_membership.controller_account)
+            );
+            assert!(!<crate::RecoveryByMemberId<Test>>::contains_key(
+                self.member_id
+            ));
+        } else {
+            assert_eq!(membership, old_membership);
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+}
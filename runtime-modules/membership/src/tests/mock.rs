@@ -85,6 +85,11 @@ parameter_types! {
     pub const InvitedMemberLockId: [u8; 8] = [2; 8];
     pub const StakingCandidateLockId: [u8; 8] = [3; 8];
     pub const CandidateStake: u64 = 100;
+    pub const HandleDisputeLockId: [u8; 8] = [4; 8];
+    pub const HandleDisputeStake: u64 = 150;
+    pub const HandleDisputeGracePeriod: u64 = 10;
+    pub const MaxRecoveryGuardians: u32 = 5;
+    pub const RecoveryDelay: u64 = 10;
 }
 
 impl balances::Trait for Test {
@@ -147,6 +152,11 @@ impl Trait for Test {
     type StakingCandidateStakingHandler =
         staking_handler::StakingManager<Self, StakingCandidateLockId>;
     type CandidateStake = CandidateStake;
+    type HandleDisputeStakingHandler = staking_handler::StakingManager<Self, HandleDisputeLockId>;
+    type HandleDisputeStake = HandleDisputeStake;
+    type HandleDisputeGracePeriod = HandleDisputeGracePeriod;
+    type MaxRecoveryGuardians = MaxRecoveryGuardians;
+    type RecoveryDelay = RecoveryDelay;
     type WeightInfo = ();
 }
 
@@ -188,8 +198,17 @@ impl common::working_group::WorkingGroupAuthenticator<Test> for () {
         }
     }
 
-    fn ensure_leader_origin(_origin: <Test as frame_system::Trait>::Origin) -> DispatchResult {
-        unimplemented!()
+    fn ensure_leader_origin(origin: <Test as frame_system::Trait>::Origin) -> DispatchResult {
+        let account_id = frame_system::ensure_signed(origin)?;
+
+        let leader_account_id = Self::get_leader_member_id()
+            .map(|member_id| Membership::membership(member_id).controller_account);
+
+        if leader_account_id == Some(account_id) {
+            Ok(())
+        } else {
+            Err(DispatchError::Other("Not a leader"))
+        }
     }
 
     fn get_leader_member_id() -> Option<<Test as common::membership::MembershipTypes>::MemberId> {
@@ -3,11 +3,13 @@
 pub(crate) mod fixtures;
 pub(crate) mod mock;
 
-use crate::{Error, Event};
+use crate::{Error, Event, HandleDisputeResolution};
 pub use fixtures::*;
 pub use mock::*;
 
-use common::membership::{MemberOriginValidator, MembershipInfoProvider};
+use common::membership::{
+    MemberOriginValidator, MemberVerificationProvider, MembershipInfoProvider, VerificationKind,
+};
 use common::working_group::WorkingGroupBudgetHandler;
 use common::StakingAccountValidator;
 use frame_support::traits::{LockIdentifier, LockableCurrency, WithdrawReasons};
@@ -15,6 +17,7 @@ use frame_support::{assert_ok, StorageMap, StorageValue};
 use frame_system::RawOrigin;
 use sp_arithmetic::Perbill;
 use sp_runtime::DispatchError;
+use sp_std::collections::btree_set::BTreeSet;
 
 #[test]
 fn buy_membership_succeeds() {
@@ -1165,3 +1168,1621 @@ fn successful_member_remark() {
         assert_eq!(validation_result, Ok(()),);
     });
 }
+
+fn set_handle_dispute_staking_account() {
+    set_confirmed_staking_account(
+        BOB_MEMBER_ID,
+        BOB_ACCOUNT_ID,
+        <Test as Trait>::CandidateStake::get() + HandleDisputeStake::get(),
+    );
+}
+
+fn open_and_resolve_handle_dispute(resolution: HandleDisputeResolution) {
+    set_handle_dispute_staking_account();
+
+    OpenHandleDisputeFixture::default().call_and_assert(Ok(()));
+
+    ResolveHandleDisputeFixture::default()
+        .with_resolution(resolution)
+        .call_and_assert(Ok(()));
+}
+
+#[test]
+fn reserve_handle_succeeds() {
+    let initial_members = [(ALICE_MEMBER_ID, ALICE_ACCOUNT_ID)];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let handle = b"reserved".to_vec();
+
+        ReserveHandleFixture::default()
+            .with_handle(handle.clone())
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(Event::<Test>::HandleReserved(hash_handle(&handle)));
+    });
+}
+
+#[test]
+fn reserve_handle_fails_with_invalid_origin() {
+    let initial_members = [
+        (ALICE_MEMBER_ID, ALICE_ACCOUNT_ID),
+        (BOB_MEMBER_ID, BOB_ACCOUNT_ID),
+    ];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        ReserveHandleFixture::default()
+            .with_origin(RawOrigin::Signed(BOB_ACCOUNT_ID))
+            .call_and_assert(Err(DispatchError::Other("Not a leader")));
+    });
+}
+
+#[test]
+fn reserve_handle_fails_with_registered_handle() {
+    let initial_members = [(ALICE_MEMBER_ID, ALICE_ACCOUNT_ID)];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        ReserveHandleFixture::default()
+            .with_handle(get_genesis_member_handle(ALICE_MEMBER_ID))
+            .call_and_assert(Err(Error::<Test>::HandleAlreadyRegistered.into()));
+    });
+}
+
+#[test]
+fn reserve_handle_fails_with_reserved_handle() {
+    let initial_members = [(ALICE_MEMBER_ID, ALICE_ACCOUNT_ID)];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        ReserveHandleFixture::default().call_and_assert(Ok(()));
+
+        ReserveHandleFixture::default()
+            .call_and_assert(Err(Error::<Test>::HandleIsReserved.into()));
+    });
+}
+
+#[test]
+fn buy_membership_fails_with_reserved_handle() {
+    let initial_members = [(ALICE_MEMBER_ID, ALICE_ACCOUNT_ID)];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        ReserveHandleFixture::default().call_and_assert(Ok(()));
+
+        Balances::make_free_balance_be(&BOB_ACCOUNT_ID, DefaultMembershipPrice::get());
+
+        let buy_membership_fixture = BuyMembershipFixture::default()
+            .with_handle(get_bob_info().handle.unwrap())
+            .with_accounts(BOB_ACCOUNT_ID)
+            .with_origin(RawOrigin::Signed(BOB_ACCOUNT_ID));
+
+        buy_membership_fixture.call_and_assert(Err(Error::<Test>::HandleIsReserved.into()));
+
+        ReleaseHandleFixture::default().call_and_assert(Ok(()));
+
+        buy_membership_fixture.call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn release_handle_succeeds() {
+    let initial_members = [(ALICE_MEMBER_ID, ALICE_ACCOUNT_ID)];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        ReserveHandleFixture::default().call_and_assert(Ok(()));
+
+        ReleaseHandleFixture::default().call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(Event::<Test>::HandleReleased(
+            hash_handle(&get_bob_info().handle.unwrap()),
+            None,
+        ));
+    });
+}
+
+#[test]
+fn release_handle_succeeds_with_new_owner() {
+    let initial_members = [
+        (ALICE_MEMBER_ID, ALICE_ACCOUNT_ID),
+        (BOB_MEMBER_ID, BOB_ACCOUNT_ID),
+    ];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let old_handle_hash = get_membership_by_id(BOB_MEMBER_ID).handle_hash;
+
+        ReserveHandleFixture::default().call_and_assert(Ok(()));
+
+        ReleaseHandleFixture::default()
+            .with_new_owner_id(BOB_MEMBER_ID)
+            .call_and_assert(Ok(()));
+
+        assert!(!<crate::MemberIdByHandleHash<Test>>::contains_key(
+            &old_handle_hash
+        ));
+
+        EventFixture::assert_last_crate_event(Event::<Test>::HandleReleased(
+            hash_handle(&get_bob_info().handle.unwrap()),
+            Some(BOB_MEMBER_ID),
+        ));
+    });
+}
+
+#[test]
+fn release_handle_fails_with_not_reserved_handle() {
+    let initial_members = [(ALICE_MEMBER_ID, ALICE_ACCOUNT_ID)];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        ReleaseHandleFixture::default()
+            .call_and_assert(Err(Error::<Test>::HandleIsNotReserved.into()));
+    });
+}
+
+#[test]
+fn release_handle_fails_with_invalid_new_owner() {
+    let initial_members = [(ALICE_MEMBER_ID, ALICE_ACCOUNT_ID)];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        ReserveHandleFixture::default().call_and_assert(Ok(()));
+
+        let invalid_member_id = 222;
+
+        ReleaseHandleFixture::default()
+            .with_new_owner_id(invalid_member_id)
+            .call_and_assert(Err(Error::<Test>::MemberProfileNotFound.into()));
+    });
+}
+
+#[test]
+fn open_handle_dispute_succeeds() {
+    let initial_members = [
+        (ALICE_MEMBER_ID, ALICE_ACCOUNT_ID),
+        (BOB_MEMBER_ID, BOB_ACCOUNT_ID),
+    ];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        set_handle_dispute_staking_account();
+
+        OpenHandleDisputeFixture::default().call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(Event::<Test>::HandleDisputeOpened(
+            0,
+            ALICE_MEMBER_ID,
+            BOB_MEMBER_ID,
+            get_genesis_member_handle(ALICE_MEMBER_ID),
+        ));
+    });
+}
+
+#[test]
+fn open_handle_dispute_fails_with_invalid_origin() {
+    let initial_members = [
+        (ALICE_MEMBER_ID, ALICE_ACCOUNT_ID),
+        (BOB_MEMBER_ID, BOB_ACCOUNT_ID),
+    ];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        OpenHandleDisputeFixture::default()
+            .with_origin(RawOrigin::Signed(ALICE_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test>::ControllerAccountRequired.into()));
+    });
+}
+
+#[test]
+fn open_handle_dispute_fails_with_unregistered_handle() {
+    let initial_members = [
+        (ALICE_MEMBER_ID, ALICE_ACCOUNT_ID),
+        (BOB_MEMBER_ID, BOB_ACCOUNT_ID),
+    ];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        set_handle_dispute_staking_account();
+
+        OpenHandleDisputeFixture::default()
+            .with_handle(b"unregistered".to_vec())
+            .call_and_assert(Err(Error::<Test>::HandleIsNotRegistered.into()));
+    });
+}
+
+#[test]
+fn open_handle_dispute_fails_with_own_handle() {
+    let initial_members = [
+        (ALICE_MEMBER_ID, ALICE_ACCOUNT_ID),
+        (BOB_MEMBER_ID, BOB_ACCOUNT_ID),
+    ];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        set_handle_dispute_staking_account();
+
+        OpenHandleDisputeFixture::default()
+            .with_handle(get_genesis_member_handle(BOB_MEMBER_ID))
+            .call_and_assert(Err(Error::<Test>::CannotDisputeOwnHandle.into()));
+    });
+}
+
+#[test]
+fn open_handle_dispute_fails_with_disputed_handle() {
+    let initial_members = [
+        (ALICE_MEMBER_ID, ALICE_ACCOUNT_ID),
+        (BOB_MEMBER_ID, BOB_ACCOUNT_ID),
+    ];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        set_handle_dispute_staking_account();
+
+        OpenHandleDisputeFixture::default().call_and_assert(Ok(()));
+
+        OpenHandleDisputeFixture::default()
+            .call_and_assert(Err(Error::<Test>::HandleIsDisputed.into()));
+    });
+}
+
+#[test]
+fn open_handle_dispute_fails_with_unconfirmed_staking_account() {
+    let initial_members = [
+        (ALICE_MEMBER_ID, ALICE_ACCOUNT_ID),
+        (BOB_MEMBER_ID, BOB_ACCOUNT_ID),
+    ];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        Balances::make_free_balance_be(&BOB_ACCOUNT_ID, HandleDisputeStake::get());
+
+        OpenHandleDisputeFixture::default()
+            .call_and_assert(Err(Error::<Test>::StakingAccountIsNotConfirmed.into()));
+    });
+}
+
+#[test]
+fn open_handle_dispute_fails_with_insufficient_balance() {
+    let initial_members = [
+        (ALICE_MEMBER_ID, ALICE_ACCOUNT_ID),
+        (BOB_MEMBER_ID, BOB_ACCOUNT_ID),
+    ];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        set_confirmed_staking_account(BOB_MEMBER_ID, BOB_ACCOUNT_ID, HandleDisputeStake::get() - 1);
+
+        OpenHandleDisputeFixture::default()
+            .call_and_assert(Err(Error::<Test>::InsufficientBalanceToCoverStake.into()));
+    });
+}
+
+#[test]
+fn disputed_handle_cannot_be_registered() {
+    let initial_members = [
+        (ALICE_MEMBER_ID, ALICE_ACCOUNT_ID),
+        (BOB_MEMBER_ID, BOB_ACCOUNT_ID),
+    ];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        let disputed_handle = get_genesis_member_handle(ALICE_MEMBER_ID);
+
+        set_handle_dispute_staking_account();
+
+        OpenHandleDisputeFixture::default().call_and_assert(Ok(()));
+
+        // The owner picks another handle during the dispute.
+        assert_ok!(Membership::update_profile(
+            Origin::signed(ALICE_ACCOUNT_ID),
+            ALICE_MEMBER_ID,
+            Some(b"new_alice_handle".to_vec()),
+            None,
+        ));
+
+        let charlie_account_id = 3;
+        Balances::make_free_balance_be(&charlie_account_id, DefaultMembershipPrice::get());
+
+        BuyMembershipFixture::default()
+            .with_handle(disputed_handle)
+            .with_accounts(charlie_account_id)
+            .with_origin(RawOrigin::Signed(charlie_account_id))
+            .call_and_assert(Err(Error::<Test>::HandleIsDisputed.into()));
+    });
+}
+
+#[test]
+fn resolve_handle_dispute_succeeds() {
+    let initial_members = [
+        (ALICE_MEMBER_ID, ALICE_ACCOUNT_ID),
+        (BOB_MEMBER_ID, BOB_ACCOUNT_ID),
+    ];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        open_and_resolve_handle_dispute(HandleDisputeResolution::Reassign);
+
+        EventFixture::assert_last_crate_event(Event::<Test>::HandleDisputeResolved(
+            0,
+            HandleDisputeResolution::Reassign,
+            starting_block + HandleDisputeGracePeriod::get(),
+        ));
+    });
+}
+
+#[test]
+fn resolve_handle_dispute_succeeds_with_root() {
+    let initial_members = [
+        (ALICE_MEMBER_ID, ALICE_ACCOUNT_ID),
+        (BOB_MEMBER_ID, BOB_ACCOUNT_ID),
+    ];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        set_handle_dispute_staking_account();
+
+        OpenHandleDisputeFixture::default().call_and_assert(Ok(()));
+
+        ResolveHandleDisputeFixture::default()
+            .with_origin(RawOrigin::Root)
+            .with_resolution(HandleDisputeResolution::ForceRename)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn resolve_handle_dispute_with_rejection_slashes_stake() {
+    let initial_members = [
+        (ALICE_MEMBER_ID, ALICE_ACCOUNT_ID),
+        (BOB_MEMBER_ID, BOB_ACCOUNT_ID),
+    ];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        open_and_resolve_handle_dispute(HandleDisputeResolution::Reject);
+
+        assert_eq!(
+            Membership::handles(&hash_handle(&get_genesis_member_handle(ALICE_MEMBER_ID))),
+            ALICE_MEMBER_ID
+        );
+
+        EventFixture::assert_last_crate_event(Event::<Test>::HandleDisputeResolved(
+            0,
+            HandleDisputeResolution::Reject,
+            starting_block,
+        ));
+    });
+}
+
+#[test]
+fn resolve_handle_dispute_fails_with_invalid_origin() {
+    let initial_members = [
+        (ALICE_MEMBER_ID, ALICE_ACCOUNT_ID),
+        (BOB_MEMBER_ID, BOB_ACCOUNT_ID),
+    ];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        set_handle_dispute_staking_account();
+
+        OpenHandleDisputeFixture::default().call_and_assert(Ok(()));
+
+        ResolveHandleDisputeFixture::default()
+            .with_origin(RawOrigin::Signed(BOB_ACCOUNT_ID))
+            .call_and_assert(Err(DispatchError::Other("Not a leader")));
+    });
+}
+
+#[test]
+fn resolve_handle_dispute_fails_with_invalid_dispute_id() {
+    let initial_members = [(ALICE_MEMBER_ID, ALICE_ACCOUNT_ID)];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        let invalid_dispute_id = 222;
+
+        ResolveHandleDisputeFixture::default()
+            .with_dispute_id(invalid_dispute_id)
+            .call_and_assert(Err(Error::<Test>::HandleDisputeDoesntExist.into()));
+    });
+}
+
+#[test]
+fn resolve_handle_dispute_fails_with_resolved_dispute() {
+    let initial_members = [
+        (ALICE_MEMBER_ID, ALICE_ACCOUNT_ID),
+        (BOB_MEMBER_ID, BOB_ACCOUNT_ID),
+    ];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        open_and_resolve_handle_dispute(HandleDisputeResolution::Reassign);
+
+        ResolveHandleDisputeFixture::default()
+            .call_and_assert(Err(Error::<Test>::HandleDisputeAlreadyResolved.into()));
+    });
+}
+
+#[test]
+fn execute_handle_dispute_resolution_reassigns_handle() {
+    let initial_members = [
+        (ALICE_MEMBER_ID, ALICE_ACCOUNT_ID),
+        (BOB_MEMBER_ID, BOB_ACCOUNT_ID),
+    ];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let old_bob_handle_hash = get_membership_by_id(BOB_MEMBER_ID).handle_hash;
+
+        open_and_resolve_handle_dispute(HandleDisputeResolution::Reassign);
+
+        run_to_block(starting_block + HandleDisputeGracePeriod::get());
+
+        ExecuteHandleDisputeResolutionFixture::default().call_and_assert(Ok(()));
+
+        assert!(get_membership_by_id(ALICE_MEMBER_ID).handle_hash.is_empty());
+        assert!(!<crate::MemberIdByHandleHash<Test>>::contains_key(
+            &old_bob_handle_hash
+        ));
+
+        EventFixture::assert_last_crate_event(Event::<Test>::HandleDisputeResolutionExecuted(0));
+    });
+}
+
+#[test]
+fn execute_handle_dispute_resolution_reserves_force_renamed_handle() {
+    let initial_members = [
+        (ALICE_MEMBER_ID, ALICE_ACCOUNT_ID),
+        (BOB_MEMBER_ID, BOB_ACCOUNT_ID),
+    ];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        open_and_resolve_handle_dispute(HandleDisputeResolution::ForceRename);
+
+        run_to_block(starting_block + HandleDisputeGracePeriod::get());
+
+        ExecuteHandleDisputeResolutionFixture::default().call_and_assert(Ok(()));
+
+        assert!(get_membership_by_id(ALICE_MEMBER_ID).handle_hash.is_empty());
+
+        // The owner can pick a new handle.
+        assert_ok!(Membership::update_profile(
+            Origin::signed(ALICE_ACCOUNT_ID),
+            ALICE_MEMBER_ID,
+            Some(b"new_alice_handle".to_vec()),
+            None,
+        ));
+    });
+}
+
+#[test]
+fn execute_handle_dispute_resolution_succeeds_after_owner_renamed() {
+    let initial_members = [
+        (ALICE_MEMBER_ID, ALICE_ACCOUNT_ID),
+        (BOB_MEMBER_ID, BOB_ACCOUNT_ID),
+    ];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        open_and_resolve_handle_dispute(HandleDisputeResolution::Reassign);
+
+        let new_handle = b"new_alice_handle".to_vec();
+
+        assert_ok!(Membership::update_profile(
+            Origin::signed(ALICE_ACCOUNT_ID),
+            ALICE_MEMBER_ID,
+            Some(new_handle.clone()),
+            None,
+        ));
+
+        run_to_block(starting_block + HandleDisputeGracePeriod::get());
+
+        ExecuteHandleDisputeResolutionFixture::default().call_and_assert(Ok(()));
+
+        assert_eq!(
+            get_membership_by_id(ALICE_MEMBER_ID).handle_hash,
+            hash_handle(&new_handle)
+        );
+    });
+}
+
+#[test]
+fn execute_handle_dispute_resolution_fails_with_unresolved_dispute() {
+    let initial_members = [
+        (ALICE_MEMBER_ID, ALICE_ACCOUNT_ID),
+        (BOB_MEMBER_ID, BOB_ACCOUNT_ID),
+    ];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        set_handle_dispute_staking_account();
+
+        OpenHandleDisputeFixture::default().call_and_assert(Ok(()));
+
+        ExecuteHandleDisputeResolutionFixture::default()
+            .call_and_assert(Err(Error::<Test>::HandleDisputeNotResolved.into()));
+    });
+}
+
+#[test]
+fn execute_handle_dispute_resolution_fails_before_grace_period_end() {
+    let initial_members = [
+        (ALICE_MEMBER_ID, ALICE_ACCOUNT_ID),
+        (BOB_MEMBER_ID, BOB_ACCOUNT_ID),
+    ];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        open_and_resolve_handle_dispute(HandleDisputeResolution::Reassign);
+
+        run_to_block(starting_block + HandleDisputeGracePeriod::get() - 1);
+
+        ExecuteHandleDisputeResolutionFixture::default()
+            .call_and_assert(Err(Error::<Test>::HandleDisputeGracePeriodNotExpired.into()));
+    });
+}
+
+#[test]
+fn execute_handle_dispute_resolution_fails_with_invalid_dispute_id() {
+    build_test_externalities().execute_with(|| {
+        ExecuteHandleDisputeResolutionFixture::default()
+            .call_and_assert(Err(Error::<Test>::HandleDisputeDoesntExist.into()));
+    });
+}
+
+#[test]
+fn withdraw_handle_dispute_succeeds() {
+    let initial_members = [
+        (ALICE_MEMBER_ID, ALICE_ACCOUNT_ID),
+        (BOB_MEMBER_ID, BOB_ACCOUNT_ID),
+    ];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        set_handle_dispute_staking_account();
+
+        OpenHandleDisputeFixture::default().call_and_assert(Ok(()));
+
+        WithdrawHandleDisputeFixture::default().call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(Event::<Test>::HandleDisputeWithdrawn(0));
+
+        // The handle can be disputed again.
+        OpenHandleDisputeFixture::default().call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn withdraw_handle_dispute_fails_with_invalid_origin() {
+    let initial_members = [
+        (ALICE_MEMBER_ID, ALICE_ACCOUNT_ID),
+        (BOB_MEMBER_ID, BOB_ACCOUNT_ID),
+    ];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        set_handle_dispute_staking_account();
+
+        OpenHandleDisputeFixture::default().call_and_assert(Ok(()));
+
+        WithdrawHandleDisputeFixture::default()
+            .with_origin(RawOrigin::Signed(ALICE_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test>::ControllerAccountRequired.into()));
+    });
+}
+
+#[test]
+fn withdraw_handle_dispute_fails_with_resolved_dispute() {
+    let initial_members = [
+        (ALICE_MEMBER_ID, ALICE_ACCOUNT_ID),
+        (BOB_MEMBER_ID, BOB_ACCOUNT_ID),
+    ];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        open_and_resolve_handle_dispute(HandleDisputeResolution::Reassign);
+
+        WithdrawHandleDisputeFixture::default()
+            .call_and_assert(Err(Error::<Test>::HandleDisputeAlreadyResolved.into()));
+    });
+}
+
+#[test]
+fn withdraw_handle_dispute_fails_with_invalid_dispute_id() {
+    build_test_externalities().execute_with(|| {
+        WithdrawHandleDisputeFixture::default()
+            .call_and_assert(Err(Error::<Test>::HandleDisputeDoesntExist.into()));
+    });
+}
+
+#[test]
+fn add_member_verification_succeeds() {
+    let initial_members = [(ALICE_MEMBER_ID, ALICE_ACCOUNT_ID)];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let expires_at = 10;
+
+        AddMemberVerificationFixture::default()
+            .with_kind(VerificationKind::Creator)
+            .with_expires_at(expires_at)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(Event::<Test>::MemberVerificationAdded(
+            ALICE_MEMBER_ID,
+            VerificationKind::Creator,
+            1,
+            Some(expires_at),
+        ));
+    });
+}
+
+#[test]
+fn add_member_verification_replaces_existing_verification() {
+    let initial_members = [(ALICE_MEMBER_ID, ALICE_ACCOUNT_ID)];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        AddMemberVerificationFixture::default()
+            .with_expires_at(10)
+            .call_and_assert(Ok(()));
+
+        AddMemberVerificationFixture::default()
+            .with_worker_id(0)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn add_member_verification_fails_with_invalid_origin() {
+    let initial_members = [(ALICE_MEMBER_ID, ALICE_ACCOUNT_ID)];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        AddMemberVerificationFixture::default()
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn add_member_verification_fails_with_invalid_member_id() {
+    let initial_members = [(ALICE_MEMBER_ID, ALICE_ACCOUNT_ID)];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        let invalid_member_id = 222;
+
+        AddMemberVerificationFixture::default()
+            .with_member_id(invalid_member_id)
+            .call_and_assert(Err(Error::<Test>::MemberProfileNotFound.into()));
+    });
+}
+
+#[test]
+fn add_member_verification_fails_with_invalid_expiry() {
+    let initial_members = [(ALICE_MEMBER_ID, ALICE_ACCOUNT_ID)];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        let starting_block = 5;
+        run_to_block(starting_block);
+
+        AddMemberVerificationFixture::default()
+            .with_expires_at(starting_block)
+            .call_and_assert(Err(Error::<Test>::InvalidVerificationExpiry.into()));
+    });
+}
+
+#[test]
+fn revoke_member_verification_succeeds() {
+    let initial_members = [(ALICE_MEMBER_ID, ALICE_ACCOUNT_ID)];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        AddMemberVerificationFixture::default().call_and_assert(Ok(()));
+
+        RevokeMemberVerificationFixture::default().call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(Event::<Test>::MemberVerificationRevoked(
+            ALICE_MEMBER_ID,
+            VerificationKind::Identity,
+            1,
+        ));
+    });
+}
+
+#[test]
+fn revoke_member_verification_succeeds_with_leader() {
+    let initial_members = [(ALICE_MEMBER_ID, ALICE_ACCOUNT_ID)];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        AddMemberVerificationFixture::default()
+            .with_origin(RawOrigin::Signed(BOB_ACCOUNT_ID))
+            .call_and_assert(Ok(()));
+
+        RevokeMemberVerificationFixture::default()
+            .with_worker_id(0)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn revoke_member_verification_fails_with_not_issuer() {
+    let initial_members = [(ALICE_MEMBER_ID, ALICE_ACCOUNT_ID)];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        AddMemberVerificationFixture::default().call_and_assert(Ok(()));
+
+        RevokeMemberVerificationFixture::default()
+            .with_origin(RawOrigin::Signed(BOB_ACCOUNT_ID))
+            .with_worker_id(0)
+            .call_and_assert(Err(Error::<Test>::VerificationIssuerRequired.into()));
+    });
+}
+
+#[test]
+fn revoke_member_verification_fails_with_missing_verification() {
+    let initial_members = [(ALICE_MEMBER_ID, ALICE_ACCOUNT_ID)];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        RevokeMemberVerificationFixture::default()
+            .call_and_assert(Err(Error::<Test>::VerificationDoesntExist.into()));
+    });
+}
+
+#[test]
+fn member_verification_provider_respects_kind_and_expiry() {
+    let initial_members = [(ALICE_MEMBER_ID, ALICE_ACCOUNT_ID)];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let expires_at = 10;
+
+        AddMemberVerificationFixture::default()
+            .with_expires_at(expires_at)
+            .call_and_assert(Ok(()));
+
+        assert!(Membership::has_valid_verification(
+            &ALICE_MEMBER_ID,
+            VerificationKind::Identity
+        ));
+        assert!(!Membership::has_valid_verification(
+            &ALICE_MEMBER_ID,
+            VerificationKind::Organisation
+        ));
+
+        run_to_block(expires_at);
+
+        assert!(!Membership::has_valid_verification(
+            &ALICE_MEMBER_ID,
+            VerificationKind::Identity
+        ));
+    });
+}
+
+#[test]
+fn set_second_level_referral_cut_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        SetSecondLevelReferralCutFixture::default().call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(Event::<Test>::SecondLevelReferralCutUpdated(
+            DEFAULT_SECOND_LEVEL_REFERRAL_CUT_VALUE,
+        ));
+    });
+}
+
+#[test]
+fn set_second_level_referral_cut_fails_exceeding_the_limit() {
+    build_test_externalities().execute_with(|| {
+        let invalid_referral_cut_value = ReferralCutMaximumPercent::get() + 1;
+
+        SetSecondLevelReferralCutFixture::default()
+            .with_referral_cut(invalid_referral_cut_value)
+            .call_and_assert(Err(
+                Error::<Test>::CannotExceedReferralCutPercentLimit.into()
+            ));
+    });
+}
+
+#[test]
+fn set_second_level_referral_cut_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        SetSecondLevelReferralCutFixture::default()
+            .with_origin(RawOrigin::Signed(ALICE_ACCOUNT_ID))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+fn buy_referred_membership(account_id: u64, handle: &[u8], referrer_id: u64) {
+    increase_total_balance_issuance_using_account_id(account_id, DefaultMembershipPrice::get());
+
+    BuyMembershipFixture::default()
+        .with_handle(handle.to_vec())
+        .with_accounts(account_id)
+        .with_origin(RawOrigin::Signed(account_id))
+        .with_referrer_id(referrer_id)
+        .call_and_assert(Ok(()));
+}
+
+#[test]
+fn buy_membership_with_second_level_referral_succeeds() {
+    let initial_members = [(ALICE_MEMBER_ID, ALICE_ACCOUNT_ID)];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        SetReferralCutFixture::default().call_and_assert(Ok(()));
+        SetSecondLevelReferralCutFixture::default().call_and_assert(Ok(()));
+
+        let charlie_account_id = 3;
+        let charlie_member_id = 2;
+
+        buy_referred_membership(BOB_ACCOUNT_ID, b"bobs_handle", ALICE_MEMBER_ID);
+        buy_referred_membership(charlie_account_id, b"charlies_handle", BOB_MEMBER_ID);
+
+        let referral_cut = Membership::get_referral_bonus();
+        let second_level_referral_cut = Membership::get_second_level_referral_bonus(referral_cut);
+
+        assert_eq!(
+            Balances::usable_balance(&ALICE_ACCOUNT_ID),
+            referral_cut + second_level_referral_cut
+        );
+        assert_eq!(Balances::usable_balance(&BOB_ACCOUNT_ID), referral_cut);
+
+        assert_eq!(
+            Membership::referrer_by_member_id(charlie_member_id),
+            BOB_MEMBER_ID
+        );
+
+        assert_eq!(
+            Membership::referral_stats(ALICE_MEMBER_ID),
+            crate::ReferralStats {
+                direct_referrals: 1,
+                second_level_referrals: 1,
+                rewards: referral_cut + second_level_referral_cut,
+            }
+        );
+        assert_eq!(
+            Membership::referral_stats(BOB_MEMBER_ID),
+            crate::ReferralStats {
+                direct_referrals: 1,
+                second_level_referrals: 0,
+                rewards: referral_cut,
+            }
+        );
+    });
+}
+
+#[test]
+fn referral_stats_count_only_paid_rewards() {
+    let initial_members = [(ALICE_MEMBER_ID, ALICE_ACCOUNT_ID)];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        // The referral reward is below the existential deposit of the empty referrer account.
+        let referral_cut = 5;
+        SetReferralCutFixture::default()
+            .with_referral_cut(referral_cut)
+            .call_and_assert(Ok(()));
+
+        buy_referred_membership(BOB_ACCOUNT_ID, b"bobs_handle", ALICE_MEMBER_ID);
+
+        assert_eq!(Balances::usable_balance(&ALICE_ACCOUNT_ID), 0);
+        assert_eq!(
+            Membership::referral_stats(ALICE_MEMBER_ID),
+            crate::ReferralStats {
+                direct_referrals: 1,
+                second_level_referrals: 0,
+                rewards: 0,
+            }
+        );
+    });
+}
+
+#[test]
+fn second_level_referral_bonus_calculated_successfully() {
+    build_test_externalities().execute_with(|| {
+        // it should not exceed the membership fee together with the referral bonus
+        let membership_fee = DefaultMembershipPrice::get();
+
+        <crate::SecondLevelReferralCut>::put(20);
+        assert_eq!(
+            Membership::get_second_level_referral_bonus(0),
+            Perbill::from_percent(20) * membership_fee
+        );
+
+        assert_eq!(
+            Membership::get_second_level_referral_bonus(membership_fee - 10),
+            10
+        );
+    });
+}
+
+#[test]
+fn start_referral_campaign_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let campaign_fixture = StartReferralCampaignFixture::default();
+        campaign_fixture.call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(Event::<Test>::ReferralCampaignStarted(
+            campaign_fixture.budget,
+            starting_block + campaign_fixture.duration,
+        ));
+    });
+}
+
+#[test]
+fn start_referral_campaign_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        StartReferralCampaignFixture::default()
+            .with_origin(RawOrigin::Signed(ALICE_ACCOUNT_ID))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn start_referral_campaign_fails_with_zero_budget() {
+    build_test_externalities().execute_with(|| {
+        StartReferralCampaignFixture::default()
+            .with_budget(0)
+            .call_and_assert(Err(Error::<Test>::ReferralCampaignBudgetIsZero.into()));
+    });
+}
+
+#[test]
+fn start_referral_campaign_fails_with_zero_duration() {
+    build_test_externalities().execute_with(|| {
+        StartReferralCampaignFixture::default()
+            .with_duration(0)
+            .call_and_assert(Err(Error::<Test>::ReferralCampaignDurationIsZero.into()));
+    });
+}
+
+#[test]
+fn start_referral_campaign_fails_with_insufficient_working_group_budget() {
+    build_test_externalities().execute_with(|| {
+        StartReferralCampaignFixture::default()
+            .with_budget(WORKING_GROUP_BUDGET + 1)
+            .call_and_assert(Err(
+                Error::<Test>::WorkingGroupBudgetIsNotSufficientForReferralCampaign.into(),
+            ));
+    });
+}
+
+#[test]
+fn start_referral_campaign_fails_with_active_campaign() {
+    build_test_externalities().execute_with(|| {
+        let campaign_fixture = StartReferralCampaignFixture::default();
+        campaign_fixture.call_and_assert(Ok(()));
+
+        campaign_fixture.call_and_assert(Err(Error::<Test>::ReferralCampaignIsActive.into()));
+
+        // Previous campaign ended.
+        run_to_block(campaign_fixture.duration);
+
+        campaign_fixture.call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn referral_rewards_are_paid_from_referral_campaign_budget() {
+    let initial_members = [(ALICE_MEMBER_ID, ALICE_ACCOUNT_ID)];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        SetReferralCutFixture::default().call_and_assert(Ok(()));
+
+        let campaign_fixture = StartReferralCampaignFixture::default();
+        campaign_fixture.call_and_assert(Ok(()));
+
+        let total_issuance = Balances::total_issuance();
+
+        buy_referred_membership(BOB_ACCOUNT_ID, b"bobs_handle", ALICE_MEMBER_ID);
+
+        let referral_cut = Membership::get_referral_bonus();
+
+        // The whole fee is burned and the reward is minted from the campaign budget.
+        assert_eq!(Balances::usable_balance(&BOB_ACCOUNT_ID), 0);
+        assert_eq!(Balances::usable_balance(&ALICE_ACCOUNT_ID), referral_cut);
+        assert_eq!(Balances::total_issuance(), total_issuance + referral_cut);
+        assert_eq!(
+            <Test as Trait>::WorkingGroup::get_budget(),
+            WORKING_GROUP_BUDGET - campaign_fixture.budget
+        );
+        assert_eq!(
+            Membership::current_referral_campaign(),
+            Some(crate::ReferralCampaign {
+                budget: campaign_fixture.budget - referral_cut,
+                ends_at: campaign_fixture.duration,
+                referrals: 1,
+            })
+        );
+    });
+}
+
+#[test]
+fn referral_rewards_are_paid_from_fee_after_referral_campaign_budget_exhausted() {
+    let initial_members = [(ALICE_MEMBER_ID, ALICE_ACCOUNT_ID)];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        SetReferralCutFixture::default().call_and_assert(Ok(()));
+
+        let referral_cut = Membership::get_referral_bonus();
+
+        let campaign_fixture = StartReferralCampaignFixture::default().with_budget(referral_cut);
+        campaign_fixture.call_and_assert(Ok(()));
+
+        buy_referred_membership(BOB_ACCOUNT_ID, b"bobs_handle", ALICE_MEMBER_ID);
+
+        let charlie_account_id = 3;
+        let fee = DefaultMembershipPrice::get();
+        let total_issuance = Balances::total_issuance();
+
+        buy_referred_membership(charlie_account_id, b"charlies_handle", ALICE_MEMBER_ID);
+
+        // The reward is transferred from the fee and only the rest of the fee is burned.
+        assert_eq!(Balances::usable_balance(&charlie_account_id), 0);
+        assert_eq!(
+            Balances::usable_balance(&ALICE_ACCOUNT_ID),
+            referral_cut + referral_cut
+        );
+        assert_eq!(
+            Balances::total_issuance(),
+            total_issuance + fee - (fee - referral_cut)
+        );
+        assert_eq!(
+            <Test as Trait>::WorkingGroup::get_budget(),
+            WORKING_GROUP_BUDGET - referral_cut
+        );
+        assert_eq!(
+            Membership::current_referral_campaign(),
+            Some(crate::ReferralCampaign {
+                budget: 0,
+                ends_at: campaign_fixture.duration,
+                referrals: 2,
+            })
+        );
+    });
+}
+
+#[test]
+fn referral_rewards_are_paid_from_fee_after_referral_campaign_ended() {
+    let initial_members = [(ALICE_MEMBER_ID, ALICE_ACCOUNT_ID)];
+
+    build_test_externalities_with_initial_members(initial_members.to_vec()).execute_with(|| {
+        SetReferralCutFixture::default().call_and_assert(Ok(()));
+
+        let campaign_fixture = StartReferralCampaignFixture::default();
+        campaign_fixture.call_and_assert(Ok(()));
+
+        run_to_block(campaign_fixture.duration);
+
+        buy_referred_membership(BOB_ACCOUNT_ID, b"bobs_handle", ALICE_MEMBER_ID);
+
+        assert_eq!(
+            Membership::current_referral_campaign(),
+            Some(crate::ReferralCampaign {
+                budget: campaign_fixture.budget,
+                ends_at: campaign_fixture.duration,
+                referrals: 0,
+            })
+        );
+    });
+}
+
+#[test]
+fn end_referral_campaign_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let campaign_fixture = StartReferralCampaignFixture::default();
+        campaign_fixture.call_and_assert(Ok(()));
+
+        EndReferralCampaignFixture::default().call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(Event::<Test>::ReferralCampaignEnded(
+            campaign_fixture.budget,
+            0,
+        ));
+    });
+}
+
+#[test]
+fn end_referral_campaign_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        StartReferralCampaignFixture::default().call_and_assert(Ok(()));
+
+        EndReferralCampaignFixture::default()
+            .with_origin(RawOrigin::Signed(ALICE_ACCOUNT_ID))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn end_referral_campaign_succeeds_with_ended_campaign() {
+    build_test_externalities().execute_with(|| {
+        let campaign_fixture = StartReferralCampaignFixture::default();
+        campaign_fixture.call_and_assert(Ok(()));
+
+        run_to_block(campaign_fixture.duration);
+
+        EndReferralCampaignFixture::default().call_and_assert(Ok(()));
+
+        assert_eq!(
+            <Test as Trait>::WorkingGroup::get_budget(),
+            WORKING_GROUP_BUDGET
+        );
+        EventFixture::assert_last_crate_event(Event::<Test>::ReferralCampaignEnded(
+            campaign_fixture.budget,
+            0,
+        ));
+    });
+}
+
+#[test]
+fn end_referral_campaign_fails_without_campaign() {
+    build_test_externalities().execute_with(|| {
+        EndReferralCampaignFixture::default()
+            .call_and_assert(Err(Error::<Test>::NoReferralCampaign.into()));
+
+        StartReferralCampaignFixture::default().call_and_assert(Ok(()));
+        EndReferralCampaignFixture::default().call_and_assert(Ok(()));
+
+        EndReferralCampaignFixture::default()
+            .call_and_assert(Err(Error::<Test>::NoReferralCampaign.into()));
+    });
+}
+
+fn recovery_test_members() -> Vec<(u64, u64)> {
+    vec![
+        (ALICE_MEMBER_ID, ALICE_ACCOUNT_ID),
+        (BOB_MEMBER_ID, BOB_ACCOUNT_ID),
+        (CHARLIE_MEMBER_ID, CHARLIE_ACCOUNT_ID),
+    ]
+}
+
+#[test]
+fn set_recovery_guardians_succeeds() {
+    build_test_externalities_with_initial_members(recovery_test_members()).execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        SetRecoveryGuardiansFixture::default().call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(Event::<Test>::RecoveryGuardiansUpdated(
+            ALICE_MEMBER_ID,
+            get_recovery_guardians(),
+            2,
+        ));
+    });
+}
+
+#[test]
+fn set_recovery_guardians_succeeds_with_removing_guardians() {
+    build_test_externalities_with_initial_members(recovery_test_members()).execute_with(|| {
+        SetRecoveryGuardiansFixture::default().call_and_assert(Ok(()));
+
+        SetRecoveryGuardiansFixture::default()
+            .with_guardians(BTreeSet::new())
+            .with_threshold(0)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn set_recovery_guardians_fails_with_invalid_origin() {
+    build_test_externalities_with_initial_members(recovery_test_members()).execute_with(|| {
+        SetRecoveryGuardiansFixture::default()
+            .with_origin(RawOrigin::Signed(BOB_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test>::RootAccountRequired.into()));
+    });
+}
+
+#[test]
+fn set_recovery_guardians_fails_with_invalid_threshold() {
+    build_test_externalities_with_initial_members(recovery_test_members()).execute_with(|| {
+        SetRecoveryGuardiansFixture::default()
+            .with_threshold(0)
+            .call_and_assert(Err(Error::<Test>::InvalidRecoveryThreshold.into()));
+
+        SetRecoveryGuardiansFixture::default()
+            .with_threshold(3)
+            .call_and_assert(Err(Error::<Test>::InvalidRecoveryThreshold.into()));
+    });
+}
+
+#[test]
+fn set_recovery_guardians_fails_with_too_many_guardians() {
+    build_test_externalities_with_initial_members(recovery_test_members()).execute_with(|| {
+        let guardians = (0..=MaxRecoveryGuardians::get() as u64).collect();
+
+        SetRecoveryGuardiansFixture::default()
+            .with_guardians(guardians)
+            .call_and_assert(Err(Error::<Test>::TooManyRecoveryGuardians.into()));
+    });
+}
+
+#[test]
+fn set_recovery_guardians_fails_with_own_guardian() {
+    build_test_externalities_with_initial_members(recovery_test_members()).execute_with(|| {
+        let guardians = [ALICE_MEMBER_ID, BOB_MEMBER_ID].iter().cloned().collect();
+
+        SetRecoveryGuardiansFixture::default()
+            .with_guardians(guardians)
+            .call_and_assert(Err(Error::<Test>::CannotBeOwnRecoveryGuardian.into()));
+    });
+}
+
+#[test]
+fn set_recovery_guardians_fails_with_invalid_guardian() {
+    build_test_externalities_with_initial_members(recovery_test_members()).execute_with(|| {
+        let invalid_member_id = 111;
+        let guardians = [BOB_MEMBER_ID, invalid_member_id].iter().cloned().collect();
+
+        SetRecoveryGuardiansFixture::default()
+            .with_guardians(guardians)
+            .call_and_assert(Err(Error::<Test>::RecoveryGuardianIsNotMember.into()));
+    });
+}
+
+#[test]
+fn set_recovery_guardians_fails_with_recovery_in_progress() {
+    build_test_externalities_with_initial_members(recovery_test_members()).execute_with(|| {
+        SetRecoveryGuardiansFixture::default().call_and_assert(Ok(()));
+
+        InitiateRecoveryFixture::default().call_and_assert(Ok(()));
+
+        SetRecoveryGuardiansFixture::default()
+            .call_and_assert(Err(Error::<Test>::RecoveryIsInProgress.into()));
+    });
+}
+
+#[test]
+fn member_accounts_recovery_succeeds() {
+    build_test_externalities_with_initial_members(recovery_test_members()).execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        SetRecoveryGuardiansFixture::default().call_and_assert(Ok(()));
+
+        InitiateRecoveryFixture::default().call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(Event::<Test>::RecoveryInitiated(
+            ALICE_MEMBER_ID,
+            BOB_MEMBER_ID,
+            Some(RECOVERED_ROOT_ACCOUNT_ID),
+            Some(RECOVERED_CONTROLLER_ACCOUNT_ID),
+        ));
+
+        // Threshold is not reached yet.
+        assert_eq!(Membership::recovery(ALICE_MEMBER_ID).executable_at, None);
+
+        ExecuteRecoveryFixture::default()
+            .call_and_assert(Err(Error::<Test>::RecoveryThresholdNotReached.into()));
+
+        ApproveRecoveryFixture::default().call_and_assert(Ok(()));
+
+        let executable_at = starting_block + RecoveryDelay::get();
+
+        EventFixture::assert_last_crate_event(Event::<Test>::RecoveryStarted(
+            ALICE_MEMBER_ID,
+            executable_at,
+        ));
+
+        ExecuteRecoveryFixture::default()
+            .call_and_assert(Err(Error::<Test>::RecoveryDelayNotExpired.into()));
+
+        run_to_block(executable_at);
+
+        ExecuteRecoveryFixture::default().call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(Event::<Test>::RecoveryExecuted(ALICE_MEMBER_ID));
+
+        let membership = Membership::membership(ALICE_MEMBER_ID);
+        assert_eq!(membership.root_account, RECOVERED_ROOT_ACCOUNT_ID);
+        assert_eq!(
+            membership.controller_account,
+            RECOVERED_CONTROLLER_ACCOUNT_ID
+        );
+    });
+}
+
+#[test]
+fn member_accounts_recovery_starts_with_single_guardian_threshold() {
+    build_test_externalities_with_initial_members(recovery_test_members()).execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        SetRecoveryGuardiansFixture::default()
+            .with_threshold(1)
+            .call_and_assert(Ok(()));
+
+        InitiateRecoveryFixture::default()
+            .with_accounts(None, Some(RECOVERED_CONTROLLER_ACCOUNT_ID))
+            .call_and_assert(Ok(()));
+
+        let executable_at = starting_block + RecoveryDelay::get();
+
+        EventFixture::assert_last_crate_event(Event::<Test>::RecoveryStarted(
+            ALICE_MEMBER_ID,
+            executable_at,
+        ));
+
+        run_to_block(executable_at);
+
+        ExecuteRecoveryFixture::default().call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn initiate_recovery_fails_with_invalid_origin() {
+    build_test_externalities_with_initial_members(recovery_test_members()).execute_with(|| {
+        SetRecoveryGuardiansFixture::default().call_and_assert(Ok(()));
+
+        InitiateRecoveryFixture::default()
+            .with_origin(RawOrigin::Signed(ALICE_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test>::ControllerAccountRequired.into()));
+    });
+}
+
+#[test]
+fn initiate_recovery_fails_without_guardians() {
+    build_test_externalities_with_initial_members(recovery_test_members()).execute_with(|| {
+        InitiateRecoveryFixture::default()
+            .call_and_assert(Err(Error::<Test>::RecoveryGuardiansNotConfigured.into()));
+    });
+}
+
+#[test]
+fn initiate_recovery_fails_with_not_guardian() {
+    build_test_externalities_with_initial_members(recovery_test_members()).execute_with(|| {
+        let guardians = [BOB_MEMBER_ID].iter().cloned().collect();
+
+        SetRecoveryGuardiansFixture::default()
+            .with_guardians(guardians)
+            .with_threshold(1)
+            .call_and_assert(Ok(()));
+
+        InitiateRecoveryFixture::default()
+            .with_origin(RawOrigin::Signed(CHARLIE_ACCOUNT_ID))
+            .with_guardian_id(CHARLIE_MEMBER_ID)
+            .call_and_assert(Err(Error::<Test>::NotRecoveryGuardian.into()));
+    });
+}
+
+#[test]
+fn initiate_recovery_fails_with_empty_accounts() {
+    build_test_externalities_with_initial_members(recovery_test_members()).execute_with(|| {
+        SetRecoveryGuardiansFixture::default().call_and_assert(Ok(()));
+
+        InitiateRecoveryFixture::default()
+            .with_accounts(None, None)
+            .call_and_assert(Err(Error::<Test>::RecoveryAccountsAreEmpty.into()));
+    });
+}
+
+#[test]
+fn initiate_recovery_fails_with_recovery_in_progress() {
+    build_test_externalities_with_initial_members(recovery_test_members()).execute_with(|| {
+        SetRecoveryGuardiansFixture::default().call_and_assert(Ok(()));
+
+        InitiateRecoveryFixture::default().call_and_assert(Ok(()));
+
+        InitiateRecoveryFixture::default()
+            .with_origin(RawOrigin::Signed(CHARLIE_ACCOUNT_ID))
+            .with_guardian_id(CHARLIE_MEMBER_ID)
+            .call_and_assert(Err(Error::<Test>::RecoveryIsInProgress.into()));
+    });
+}
+
+#[test]
+fn approve_recovery_fails_without_recovery() {
+    build_test_externalities_with_initial_members(recovery_test_members()).execute_with(|| {
+        SetRecoveryGuardiansFixture::default().call_and_assert(Ok(()));
+
+        ApproveRecoveryFixture::default()
+            .call_and_assert(Err(Error::<Test>::RecoveryDoesntExist.into()));
+    });
+}
+
+#[test]
+fn approve_recovery_fails_with_repeated_approval() {
+    build_test_externalities_with_initial_members(recovery_test_members()).execute_with(|| {
+        SetRecoveryGuardiansFixture::default().call_and_assert(Ok(()));
+
+        InitiateRecoveryFixture::default().call_and_assert(Ok(()));
+
+        ApproveRecoveryFixture::default()
+            .with_origin(RawOrigin::Signed(BOB_ACCOUNT_ID))
+            .with_guardian_id(BOB_MEMBER_ID)
+            .call_and_assert(Err(Error::<Test>::RecoveryAlreadyApproved.into()));
+    });
+}
+
+#[test]
+fn approve_recovery_fails_with_invalid_origin() {
+    build_test_externalities_with_initial_members(recovery_test_members()).execute_with(|| {
+        SetRecoveryGuardiansFixture::default().call_and_assert(Ok(()));
+
+        InitiateRecoveryFixture::default().call_and_assert(Ok(()));
+
+        ApproveRecoveryFixture::default()
+            .with_origin(RawOrigin::Signed(BOB_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test>::ControllerAccountRequired.into()));
+    });
+}
+
+#[test]
+fn reject_recovery_cancels_recovery_when_threshold_is_unreachable() {
+    build_test_externalities_with_initial_members(recovery_test_members()).execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        SetRecoveryGuardiansFixture::default().call_and_assert(Ok(()));
+
+        InitiateRecoveryFixture::default().call_and_assert(Ok(()));
+
+        RejectRecoveryFixture::default().call_and_assert(Ok(()));
+
+        assert!(!<crate::RecoveryByMemberId<Test>>::contains_key(
+            ALICE_MEMBER_ID
+        ));
+        EventFixture::assert_last_crate_event(Event::<Test>::RecoveryCancelled(ALICE_MEMBER_ID));
+
+        // Another guardian can propose the recovery.
+        InitiateRecoveryFixture::default()
+            .with_origin(RawOrigin::Signed(CHARLIE_ACCOUNT_ID))
+            .with_guardian_id(CHARLIE_MEMBER_ID)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn reject_recovery_keeps_recovery_while_threshold_is_reachable() {
+    let dave_member_id = 3;
+    let dave_account_id = 4;
+
+    let mut initial_members = recovery_test_members();
+    initial_members.push((dave_member_id, dave_account_id));
+
+    build_test_externalities_with_initial_members(initial_members).execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let mut guardians = get_recovery_guardians();
+        guardians.insert(dave_member_id);
+
+        SetRecoveryGuardiansFixture::default()
+            .with_guardians(guardians)
+            .call_and_assert(Ok(()));
+
+        InitiateRecoveryFixture::default().call_and_assert(Ok(()));
+
+        RejectRecoveryFixture::default().call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(Event::<Test>::RecoveryRejected(
+            ALICE_MEMBER_ID,
+            CHARLIE_MEMBER_ID,
+        ));
+
+        ApproveRecoveryFixture::default()
+            .with_origin(RawOrigin::Signed(dave_account_id))
+            .with_guardian_id(dave_member_id)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(Event::<Test>::RecoveryStarted(
+            ALICE_MEMBER_ID,
+            starting_block + RecoveryDelay::get(),
+        ));
+    });
+}
+
+#[test]
+fn reject_recovery_fails_with_repeated_vote() {
+    let dave_member_id = 3;
+    let dave_account_id = 4;
+
+    let mut initial_members = recovery_test_members();
+    initial_members.push((dave_member_id, dave_account_id));
+
+    build_test_externalities_with_initial_members(initial_members).execute_with(|| {
+        let mut guardians = get_recovery_guardians();
+        guardians.insert(dave_member_id);
+
+        SetRecoveryGuardiansFixture::default()
+            .with_guardians(guardians)
+            .call_and_assert(Ok(()));
+
+        InitiateRecoveryFixture::default().call_and_assert(Ok(()));
+
+        RejectRecoveryFixture::default()
+            .with_origin(RawOrigin::Signed(BOB_ACCOUNT_ID))
+            .with_guardian_id(BOB_MEMBER_ID)
+            .call_and_assert(Err(Error::<Test>::RecoveryAlreadyApproved.into()));
+
+        RejectRecoveryFixture::default().call_and_assert(Ok(()));
+
+        RejectRecoveryFixture::default()
+            .call_and_assert(Err(Error::<Test>::RecoveryAlreadyRejected.into()));
+
+        ApproveRecoveryFixture::default()
+            .call_and_assert(Err(Error::<Test>::RecoveryAlreadyRejected.into()));
+    });
+}
+
+#[test]
+fn reject_recovery_fails_with_invalid_origin() {
+    build_test_externalities_with_initial_members(recovery_test_members()).execute_with(|| {
+        SetRecoveryGuardiansFixture::default().call_and_assert(Ok(()));
+
+        InitiateRecoveryFixture::default().call_and_assert(Ok(()));
+
+        RejectRecoveryFixture::default()
+            .with_origin(RawOrigin::Signed(BOB_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test>::ControllerAccountRequired.into()));
+    });
+}
+
+#[test]
+fn reject_recovery_fails_without_recovery() {
+    build_test_externalities_with_initial_members(recovery_test_members()).execute_with(|| {
+        SetRecoveryGuardiansFixture::default().call_and_assert(Ok(()));
+
+        RejectRecoveryFixture::default()
+            .call_and_assert(Err(Error::<Test>::RecoveryDoesntExist.into()));
+    });
+}
+
+#[test]
+fn cancel_recovery_succeeds() {
+    build_test_externalities_with_initial_members(recovery_test_members()).execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        SetRecoveryGuardiansFixture::default().call_and_assert(Ok(()));
+
+        InitiateRecoveryFixture::default().call_and_assert(Ok(()));
+        ApproveRecoveryFixture::default().call_and_assert(Ok(()));
+
+        CancelRecoveryFixture::default().call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(Event::<Test>::RecoveryCancelled(ALICE_MEMBER_ID));
+
+        run_to_block(starting_block + RecoveryDelay::get());
+
+        ExecuteRecoveryFixture::default()
+            .call_and_assert(Err(Error::<Test>::RecoveryDoesntExist.into()));
+    });
+}
+
+#[test]
+fn cancel_recovery_fails_with_invalid_origin() {
+    build_test_externalities_with_initial_members(recovery_test_members()).execute_with(|| {
+        SetRecoveryGuardiansFixture::default().call_and_assert(Ok(()));
+
+        InitiateRecoveryFixture::default().call_and_assert(Ok(()));
+
+        CancelRecoveryFixture::default()
+            .with_origin(RawOrigin::Signed(BOB_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test>::RootAccountRequired.into()));
+    });
+}
+
+#[test]
+fn cancel_recovery_fails_without_recovery() {
+    build_test_externalities_with_initial_members(recovery_test_members()).execute_with(|| {
+        CancelRecoveryFixture::default()
+            .call_and_assert(Err(Error::<Test>::RecoveryDoesntExist.into()));
+    });
+}
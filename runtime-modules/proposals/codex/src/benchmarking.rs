@@ -521,7 +521,7 @@ benchmarks! {
             create_proposal_parameters::<T>(t, d);
 
         let proposal_details =
-            ProposalDetails::AmendConstitution(vec![0u8; i.try_into().unwrap()]);
+            ProposalDetails::AmendConstitution(vec![0u8; i.try_into().unwrap()], None);
     }: create_proposal(
         RawOrigin::Signed(account_id.clone()),
         general_proposal_paramters.clone(),
@@ -813,6 +813,415 @@ benchmarks! {
         );
     }
 
+    create_proposal_update_nft_marketplace_parameters {
+        let t in ...;
+        let d in ...;
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details = ProposalDetails::UpdateNftMarketplaceParameters(
+            content::NftMarketplaceParametersRecord {
+                max_auction_duration: Some(One::one()),
+                ..Default::default()
+            }
+        );
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_set_max_reward_allowed {
+        let t in ...;
+        let d in ...;
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details = ProposalDetails::SetMaxRewardAllowed(One::one());
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_set_min_cashout_allowed {
+        let t in ...;
+        let d in ...;
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details = ProposalDetails::SetMinCashoutAllowed(One::one());
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_update_storage_data_size_fee {
+        let t in ...;
+        let d in ...;
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details = ProposalDetails::UpdateStorageDataSizeFee(One::one());
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_update_storage_buckets_per_bag_limit {
+        let t in ...;
+        let d in ...;
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details = ProposalDetails::UpdateStorageBucketsPerBagLimit(10);
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_update_storage_buckets_voucher_max_limits {
+        let t in ...;
+        let d in ...;
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details = ProposalDetails::UpdateStorageBucketsVoucherMaxLimits(100, 10);
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_update_dynamic_bag_storage_buckets_number {
+        let t in ...;
+        let d in ...;
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details = ProposalDetails::UpdateDynamicBagStorageBucketsNumber(
+            storage::DynamicBagType::Channel,
+            10
+        );
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_update_storage_blacklist {
+        let t in ...;
+        let d in ...;
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details = ProposalDetails::UpdateStorageBlacklist(
+            BTreeSet::new(),
+            vec![vec![1u8]].into_iter().collect()
+        );
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_block_storage_uploads {
+        let t in ...;
+        let d in ...;
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details = ProposalDetails::BlockStorageUploads(true);
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_vesting_funding_request {
+        let t in ...;
+        let d in ...;
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details = ProposalDetails::VestingFundingRequest(vec![
+            common::VestingFundingRequestParameters {
+                account: account_id.clone(),
+                amount: One::one(),
+                schedule: common::VestingSchedule::Linear {
+                    start: Zero::zero(),
+                    end: One::one(),
+                },
+            }
+        ]);
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_cancel_vesting_funding_request {
+        let t in ...;
+        let d in ...;
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details = ProposalDetails::CancelVestingFundingRequest(0);
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_update_blog_author {
+        let t in ...;
+        let d in ...;
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details = ProposalDetails::UpdateBlogAuthor(0, true);
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_review_blog_drafts {
+        let t in ...;
+        let d in ...;
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details = ProposalDetails::ReviewBlogDrafts(vec![(0, true)].into_iter().collect());
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_set_working_group_spending_limit {
+        let t in ...;
+        let d in ...;
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let spending_limit = working_group::SpendingLimit {
+            amount: One::one(),
+            period: One::one(),
+        };
+        let proposal_details = ProposalDetails::SetWorkingGroupSpendingLimit(
+            Some(spending_limit),
+            WorkingGroup::Forum
+        );
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_spend_from_working_group_budget {
+        let t in ...;
+        let d in ...;
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details = ProposalDetails::SpendFromWorkingGroupBudget(
+            account_id.clone(),
+            One::one(),
+            vec![0u8],
+            WorkingGroup::Forum
+        );
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_start_referral_campaign {
+        let t in ...;
+        let d in ...;
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details = ProposalDetails::StartReferralCampaign(One::one(), One::one());
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_batch {
+        let t in ...;
+        let d in ...;
+        let i in 1 .. MAX_BATCH_PROPOSALS as u32;
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details = ProposalDetails::Batch(
+            vec![ProposalDetails::SetReferralCut(One::one()); i as usize]
+        );
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
 }
 
 #[cfg(test)]
@@ -992,4 +1401,131 @@ mod tests {
             assert_ok!(test_benchmark_create_proposal_veto_proposal::<Test>());
         });
     }
+
+    #[test]
+    fn test_create_proposal_update_nft_marketplace_parameters() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_create_proposal_update_nft_marketplace_parameters::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_set_max_reward_allowed() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_create_proposal_set_max_reward_allowed::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_set_min_cashout_allowed() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_create_proposal_set_min_cashout_allowed::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_update_storage_data_size_fee() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_create_proposal_update_storage_data_size_fee::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_update_storage_buckets_per_bag_limit() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(
+                test_benchmark_create_proposal_update_storage_buckets_per_bag_limit::<Test>()
+            );
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_update_storage_buckets_voucher_max_limits() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(
+                test_benchmark_create_proposal_update_storage_buckets_voucher_max_limits::<Test>()
+            );
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_update_dynamic_bag_storage_buckets_number() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(
+                test_benchmark_create_proposal_update_dynamic_bag_storage_buckets_number::<Test>()
+            );
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_update_storage_blacklist() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_create_proposal_update_storage_blacklist::<
+                Test,
+            >());
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_block_storage_uploads() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_create_proposal_block_storage_uploads::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_vesting_funding_request() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_create_proposal_vesting_funding_request::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_cancel_vesting_funding_request() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_create_proposal_cancel_vesting_funding_request::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_update_blog_author() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_create_proposal_update_blog_author::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_review_blog_drafts() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_create_proposal_review_blog_drafts::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_set_working_group_spending_limit() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_create_proposal_set_working_group_spending_limit::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_spend_from_working_group_budget() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_create_proposal_spend_from_working_group_budget::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_start_referral_campaign() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_create_proposal_start_referral_campaign::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_batch() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_create_proposal_batch::<Test>());
+        });
+    }
 }
@@ -7,9 +7,10 @@
 //! The proposals codex module serves as a facade and entry point of the proposals frame_system. It uses
 //! proposals `engine` module to maintain a lifecycle of the proposal and to execute proposals.
 //! During the proposal creation, `codex` also create a discussion thread using the `discussion`
-//! proposals module. `Codex` uses predefined parameters (eg.:`voting_period`) for each proposal and
-//! encodes extrinsic calls from dependency modules in order to create proposals inside the `engine`
-//! module.
+//! proposals module, and locks it once the proposal is removed from the `engine` (the `discussion`
+//! module removes the locked thread after the post lifetime). `Codex` uses predefined parameters
+//! (eg.:`voting_period`) for each proposal and encodes extrinsic calls from dependency modules in
+//! order to create proposals inside the `engine` module.
 //!
 //! To create a proposal you need to call the extrinsic `create_proposal` with the `ProposalDetails` variant
 //! corresponding to the proposal you want to create. [See the possible details with their proposal](./enum.ProposalDetails.html)
@@ -72,6 +73,8 @@ const MAX_SPENDING_PROPOSAL_VALUE: u32 = 50_000_000_u32;
 const MAX_VALIDATOR_COUNT: u32 = 300;
 // Max number of account that a fund request accept
 const MAX_FUNDING_REQUEST_ACCOUNTS: usize = 100;
+// Max number of proposals that a 'Batch' proposal accepts
+const MAX_BATCH_PROPOSALS: usize = 20;
 
 /// Proposal codex WeightInfo.
 /// Note: This was auto generated through the benchmark CLI using the `--weight-trait` flag
@@ -101,6 +104,23 @@ pub trait WeightInfo {
     fn create_proposal_lock_blog_post(t: u32, d: u32) -> Weight;
     fn create_proposal_unlock_blog_post(t: u32, d: u32) -> Weight;
     fn create_proposal_veto_proposal(t: u32, d: u32) -> Weight;
+    fn create_proposal_update_nft_marketplace_parameters(t: u32, d: u32) -> Weight;
+    fn create_proposal_set_max_reward_allowed(t: u32, d: u32) -> Weight;
+    fn create_proposal_set_min_cashout_allowed(t: u32, d: u32) -> Weight;
+    fn create_proposal_update_storage_data_size_fee(t: u32, d: u32) -> Weight;
+    fn create_proposal_update_storage_buckets_per_bag_limit(t: u32, d: u32) -> Weight;
+    fn create_proposal_update_storage_buckets_voucher_max_limits(t: u32, d: u32) -> Weight;
+    fn create_proposal_update_dynamic_bag_storage_buckets_number(t: u32, d: u32) -> Weight;
+    fn create_proposal_update_storage_blacklist(t: u32, d: u32) -> Weight;
+    fn create_proposal_block_storage_uploads(t: u32, d: u32) -> Weight;
+    fn create_proposal_vesting_funding_request(t: u32, d: u32) -> Weight;
+    fn create_proposal_cancel_vesting_funding_request(t: u32, d: u32) -> Weight;
+    fn create_proposal_update_blog_author(t: u32, d: u32) -> Weight;
+    fn create_proposal_review_blog_drafts(t: u32, d: u32) -> Weight;
+    fn create_proposal_set_working_group_spending_limit(t: u32, d: u32) -> Weight;
+    fn create_proposal_spend_from_working_group_budget(t: u32, d: u32) -> Weight;
+    fn create_proposal_start_referral_campaign(t: u32, d: u32) -> Weight;
+    fn create_proposal_batch(t: u32, d: u32, i: u32) -> Weight;
 }
 
 type WeightInfoCodex<T> = <T as Trait>::WeightInfo;
@@ -246,6 +266,89 @@ pub trait Trait:
 
     /// `Veto Proposal` proposal parameters
     type VetoProposalProposalParameters: Get<ProposalParameters<Self::BlockNumber, BalanceOf<Self>>>;
+
+    /// `Update Nft Marketplace Parameters` proposal parameters
+    type UpdateNftMarketplaceParametersProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Set Max Reward Allowed` proposal parameters
+    type SetMaxRewardAllowedProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Set Min Cashout Allowed` proposal parameters
+    type SetMinCashoutAllowedProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Update Storage Data Size Fee` proposal parameters
+    type UpdateStorageDataSizeFeeProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Update Storage Buckets Per Bag Limit` proposal parameters
+    type UpdateStorageBucketsPerBagLimitProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Update Storage Buckets Voucher Max Limits` proposal parameters
+    type UpdateStorageBucketsVoucherMaxLimitsProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Update Dynamic Bag Storage Buckets Number` proposal parameters
+    type UpdateDynamicBagStorageBucketsNumberProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Update Storage Blacklist` proposal parameters
+    type UpdateStorageBlacklistProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Block Storage Uploads` proposal parameters
+    type BlockStorageUploadsProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Vesting Funding Request` proposal parameters
+    type VestingFundingRequestProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Cancel Vesting Funding Request` proposal parameters
+    type CancelVestingFundingRequestProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Update Blog Author` proposal parameters
+    type UpdateBlogAuthorProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Review Blog Drafts` proposal parameters
+    type ReviewBlogDraftsProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Set Working Group Spending Limit` proposal parameters
+    type SetWorkingGroupSpendingLimitProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Spend From Working Group Budget` proposal parameters
+    type SpendFromWorkingGroupBudgetProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Start Referral Campaign` proposal parameters
+    type StartReferralCampaignProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// Maximum number of milestones of the 'Vesting Funding Request' schedule.
+    type MaxVestingMilestones: Get<u32>;
 }
 
 /// Specialized alias of GeneralProposalParams
@@ -334,6 +437,41 @@ decl_error! {
 
         /// Repeated account in 'Funding Request' proposal.
         InvalidFundingRequestProposalRepeatedAccount,
+
+        /// Invalid number of proposals for 'Batch' proposal.
+        InvalidBatchProposalNumberOfProposals,
+
+        /// 'Batch' proposal cannot contain another 'Batch' proposal.
+        NestedBatchProposal,
+
+        /// 'Update Nft Marketplace Parameters' proposal must update at least one parameter.
+        EmptyNftMarketplaceParametersUpdate,
+
+        /// 'Update Storage Blacklist' proposal must add or remove at least one CID.
+        EmptyStorageBlacklistUpdate,
+
+        /// Invalid vesting schedule for the 'Vesting Funding Request' proposal.
+        InvalidVestingFundingRequestSchedule,
+
+        /// Invalid 'Vesting Funding Request' proposal parameter - too many schedule milestones.
+        TooManyVestingFundingRequestMilestones,
+
+        /// Invalid 'Set Working Group Spending Limit' proposal parameter - period cannot be zero.
+        InvalidWorkingGroupSpendingLimitPeriod,
+
+        /// Invalid 'Spend From Working Group Budget' proposal parameter - cannot spend zero balance.
+        WorkingGroupBudgetSpendingIsZero,
+
+        /// Invalid 'Spend From Working Group Budget' proposal parameter - spending exceeds
+        /// the maximum spending proposal value.
+        WorkingGroupBudgetSpendingIsTooBig,
+
+        /// Invalid 'Start Referral Campaign' proposal parameter - budget cannot be zero or
+        /// exceed the maximum spending proposal value.
+        InvalidReferralCampaignBudget,
+
+        /// Invalid 'Start Referral Campaign' proposal parameter - duration cannot be zero.
+        InvalidReferralCampaignDuration,
     }
 }
 
@@ -446,6 +584,72 @@ decl_module! {
         const VetoProposalProposalParameters:
             ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::VetoProposalProposalParameters::get();
 
+        /// Exports `Update Nft Marketplace Parameters` proposal parameters.
+        const UpdateNftMarketplaceParametersProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::UpdateNftMarketplaceParametersProposalParameters::get();
+
+        /// Exports `Set Max Reward Allowed` proposal parameters.
+        const SetMaxRewardAllowedProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::SetMaxRewardAllowedProposalParameters::get();
+
+        /// Exports `Set Min Cashout Allowed` proposal parameters.
+        const SetMinCashoutAllowedProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::SetMinCashoutAllowedProposalParameters::get();
+
+        /// Exports `Update Storage Data Size Fee` proposal parameters.
+        const UpdateStorageDataSizeFeeProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::UpdateStorageDataSizeFeeProposalParameters::get();
+
+        /// Exports `Update Storage Buckets Per Bag Limit` proposal parameters.
+        const UpdateStorageBucketsPerBagLimitProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::UpdateStorageBucketsPerBagLimitProposalParameters::get();
+
+        /// Exports `Update Storage Buckets Voucher Max Limits` proposal parameters.
+        const UpdateStorageBucketsVoucherMaxLimitsProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::UpdateStorageBucketsVoucherMaxLimitsProposalParameters::get();
+
+        /// Exports `Update Dynamic Bag Storage Buckets Number` proposal parameters.
+        const UpdateDynamicBagStorageBucketsNumberProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::UpdateDynamicBagStorageBucketsNumberProposalParameters::get();
+
+        /// Exports `Update Storage Blacklist` proposal parameters.
+        const UpdateStorageBlacklistProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::UpdateStorageBlacklistProposalParameters::get();
+
+        /// Exports `Block Storage Uploads` proposal parameters.
+        const BlockStorageUploadsProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::BlockStorageUploadsProposalParameters::get();
+
+        /// Exports `Vesting Funding Request` proposal parameters.
+        const VestingFundingRequestProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::VestingFundingRequestProposalParameters::get();
+
+        /// Exports `Cancel Vesting Funding Request` proposal parameters.
+        const CancelVestingFundingRequestProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::CancelVestingFundingRequestProposalParameters::get();
+
+        /// Exports `Update Blog Author` proposal parameters.
+        const UpdateBlogAuthorProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::UpdateBlogAuthorProposalParameters::get();
+
+        /// Exports `Review Blog Drafts` proposal parameters.
+        const ReviewBlogDraftsProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::ReviewBlogDraftsProposalParameters::get();
+
+        /// Exports `Set Working Group Spending Limit` proposal parameters.
+        const SetWorkingGroupSpendingLimitProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::SetWorkingGroupSpendingLimitProposalParameters::get();
+
+        /// Exports `Spend From Working Group Budget` proposal parameters.
+        const SpendFromWorkingGroupBudgetProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::SpendFromWorkingGroupBudgetProposalParameters::get();
+
+        /// Exports `Start Referral Campaign` proposal parameters.
+        const StartReferralCampaignProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::StartReferralCampaignProposalParameters::get();
+
+        /// Exports maximum number of milestones of the 'Vesting Funding Request' schedule.
+        const MaxVestingMilestones: u32 = T::MaxVestingMilestones::get();
 
         /// Create a proposal, the type of proposal depends on the `proposal_details` variant
         ///
@@ -648,6 +852,150 @@ impl<T: Trait> Module<T> {
             ProposalDetails::VetoProposal(..) => {
                 // Note: No checks for this proposal for now
             }
+            ProposalDetails::UpdateNftMarketplaceParameters(ref params) => {
+                ensure!(
+                    !params.is_empty(),
+                    Error::<T>::EmptyNftMarketplaceParametersUpdate
+                );
+            }
+            ProposalDetails::SetMaxRewardAllowed(..) => {
+                // Note: No checks for this proposal for now
+            }
+            ProposalDetails::SetMinCashoutAllowed(..) => {
+                // Note: No checks for this proposal for now
+            }
+            ProposalDetails::UpdateStorageDataSizeFee(..) => {
+                // Note: No checks for this proposal for now
+            }
+            ProposalDetails::UpdateStorageBucketsPerBagLimit(..) => {
+                // Note: No checks for this proposal for now
+            }
+            ProposalDetails::UpdateStorageBucketsVoucherMaxLimits(..) => {
+                // Note: No checks for this proposal for now
+            }
+            ProposalDetails::UpdateDynamicBagStorageBucketsNumber(..) => {
+                // Note: No checks for this proposal for now
+            }
+            ProposalDetails::UpdateStorageBlacklist(ref remove_hashes, ref add_hashes) => {
+                ensure!(
+                    !remove_hashes.is_empty() || !add_hashes.is_empty(),
+                    Error::<T>::EmptyStorageBlacklistUpdate
+                );
+            }
+            ProposalDetails::BlockStorageUploads(..) => {
+                // Note: No checks for this proposal for now
+            }
+            ProposalDetails::VestingFundingRequest(ref funding_requests) => {
+                ensure!(
+                    !funding_requests.is_empty(),
+                    Error::<T>::InvalidFundingRequestProposalNumberOfAccount
+                );
+
+                ensure!(
+                    funding_requests.len() <= MAX_FUNDING_REQUEST_ACCOUNTS,
+                    Error::<T>::InvalidFundingRequestProposalNumberOfAccount
+                );
+
+                let mut visited_accounts = BTreeSet::new();
+
+                for funding_request in funding_requests {
+                    let account = &funding_request.account;
+
+                    ensure!(
+                        !visited_accounts.contains(&account),
+                        Error::<T>::InvalidFundingRequestProposalRepeatedAccount
+                    );
+
+                    ensure!(
+                        funding_request.amount != Zero::zero(),
+                        Error::<T>::InvalidFundingRequestProposalBalance
+                    );
+
+                    ensure!(
+                        funding_request.amount <= <BalanceOf<T>>::from(MAX_SPENDING_PROPOSAL_VALUE),
+                        Error::<T>::InvalidFundingRequestProposalBalance
+                    );
+
+                    ensure!(
+                        funding_request.schedule.milestones_number()
+                            <= T::MaxVestingMilestones::get().saturated_into(),
+                        Error::<T>::TooManyVestingFundingRequestMilestones
+                    );
+
+                    ensure!(
+                        funding_request
+                            .schedule
+                            .is_valid_for(funding_request.amount),
+                        Error::<T>::InvalidVestingFundingRequestSchedule
+                    );
+
+                    visited_accounts.insert(account);
+                }
+            }
+            ProposalDetails::CancelVestingFundingRequest(..) => {
+                // Note: No checks for this proposal for now
+            }
+            ProposalDetails::UpdateBlogAuthor(..) => {
+                // Note: No checks for this proposal for now
+            }
+            ProposalDetails::ReviewBlogDrafts(..) => {
+                // Note: No checks for this proposal for now
+            }
+            ProposalDetails::SetWorkingGroupSpendingLimit(ref spending_limit, _) => {
+                if let Some(spending_limit) = spending_limit {
+                    ensure!(
+                        spending_limit.period != Zero::zero(),
+                        Error::<T>::InvalidWorkingGroupSpendingLimitPeriod
+                    );
+                }
+            }
+            ProposalDetails::SpendFromWorkingGroupBudget(_, ref amount, _, _) => {
+                ensure!(
+                    *amount != Zero::zero(),
+                    Error::<T>::WorkingGroupBudgetSpendingIsZero
+                );
+
+                ensure!(
+                    *amount <= <BalanceOf<T>>::from(MAX_SPENDING_PROPOSAL_VALUE),
+                    Error::<T>::WorkingGroupBudgetSpendingIsTooBig
+                );
+            }
+            ProposalDetails::StartReferralCampaign(ref budget, ref duration) => {
+                ensure!(
+                    *budget != Zero::zero(),
+                    Error::<T>::InvalidReferralCampaignBudget
+                );
+
+                ensure!(
+                    *budget <= <BalanceOf<T>>::from(MAX_SPENDING_PROPOSAL_VALUE),
+                    Error::<T>::InvalidReferralCampaignBudget
+                );
+
+                ensure!(
+                    *duration != Zero::zero(),
+                    Error::<T>::InvalidReferralCampaignDuration
+                );
+            }
+            ProposalDetails::Batch(ref batch) => {
+                ensure!(
+                    !batch.is_empty(),
+                    Error::<T>::InvalidBatchProposalNumberOfProposals
+                );
+
+                ensure!(
+                    batch.len() <= MAX_BATCH_PROPOSALS,
+                    Error::<T>::InvalidBatchProposalNumberOfProposals
+                );
+
+                for details in batch {
+                    ensure!(
+                        !matches!(details, ProposalDetails::Batch(..)),
+                        Error::<T>::NestedBatchProposal
+                    );
+
+                    Self::ensure_details_checks(details)?;
+                }
+            }
         }
 
         Ok(())
@@ -713,6 +1061,83 @@ impl<T: Trait> Module<T> {
             ProposalDetails::LockBlogPost(..) => T::LockBlogPostProposalParameters::get(),
             ProposalDetails::UnlockBlogPost(..) => T::UnlockBlogPostProposalParameters::get(),
             ProposalDetails::VetoProposal(..) => T::VetoProposalProposalParameters::get(),
+            ProposalDetails::UpdateNftMarketplaceParameters(..) => {
+                T::UpdateNftMarketplaceParametersProposalParameters::get()
+            }
+            ProposalDetails::SetMaxRewardAllowed(..) => {
+                T::SetMaxRewardAllowedProposalParameters::get()
+            }
+            ProposalDetails::SetMinCashoutAllowed(..) => {
+                T::SetMinCashoutAllowedProposalParameters::get()
+            }
+            ProposalDetails::UpdateStorageDataSizeFee(..) => {
+                T::UpdateStorageDataSizeFeeProposalParameters::get()
+            }
+            ProposalDetails::UpdateStorageBucketsPerBagLimit(..) => {
+                T::UpdateStorageBucketsPerBagLimitProposalParameters::get()
+            }
+            ProposalDetails::UpdateStorageBucketsVoucherMaxLimits(..) => {
+                T::UpdateStorageBucketsVoucherMaxLimitsProposalParameters::get()
+            }
+            ProposalDetails::UpdateDynamicBagStorageBucketsNumber(..) => {
+                T::UpdateDynamicBagStorageBucketsNumberProposalParameters::get()
+            }
+            ProposalDetails::UpdateStorageBlacklist(..) => {
+                T::UpdateStorageBlacklistProposalParameters::get()
+            }
+            ProposalDetails::BlockStorageUploads(..) => {
+                T::BlockStorageUploadsProposalParameters::get()
+            }
+            ProposalDetails::VestingFundingRequest(..) => {
+                T::VestingFundingRequestProposalParameters::get()
+            }
+            ProposalDetails::CancelVestingFundingRequest(..) => {
+                T::CancelVestingFundingRequestProposalParameters::get()
+            }
+            ProposalDetails::UpdateBlogAuthor(..) => T::UpdateBlogAuthorProposalParameters::get(),
+            ProposalDetails::ReviewBlogDrafts(..) => T::ReviewBlogDraftsProposalParameters::get(),
+            ProposalDetails::SetWorkingGroupSpendingLimit(..) => {
+                T::SetWorkingGroupSpendingLimitProposalParameters::get()
+            }
+            ProposalDetails::SpendFromWorkingGroupBudget(..) => {
+                T::SpendFromWorkingGroupBudgetProposalParameters::get()
+            }
+            ProposalDetails::StartReferralCampaign(..) => {
+                T::StartReferralCampaignProposalParameters::get()
+            }
+            ProposalDetails::Batch(batch) => {
+                let mut batch_parameters = batch.iter().map(Self::get_proposal_parameters);
+                let first_parameters = batch_parameters.next().unwrap_or_default();
+
+                batch_parameters.fold(first_parameters, Self::strictest_proposal_parameters)
+            }
+        }
+    }
+
+    // Combines two sets of proposal parameters into the strictest one: the longest periods,
+    // the highest approval requirements, the lowest slashing requirements, the biggest stake
+    // and the highest constitutionality.
+    fn strictest_proposal_parameters(
+        first: ProposalParameters<T::BlockNumber, BalanceOf<T>>,
+        second: ProposalParameters<T::BlockNumber, BalanceOf<T>>,
+    ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+        ProposalParameters {
+            voting_period: first.voting_period.max(second.voting_period),
+            grace_period: first.grace_period.max(second.grace_period),
+            approval_quorum_percentage: first
+                .approval_quorum_percentage
+                .max(second.approval_quorum_percentage),
+            approval_threshold_percentage: first
+                .approval_threshold_percentage
+                .max(second.approval_threshold_percentage),
+            slashing_quorum_percentage: first
+                .slashing_quorum_percentage
+                .min(second.slashing_quorum_percentage),
+            slashing_threshold_percentage: first
+                .slashing_threshold_percentage
+                .min(second.slashing_threshold_percentage),
+            required_stake: first.required_stake.max(second.required_stake),
+            constitutionality: first.constitutionality.max(second.constitutionality),
         }
     }
 
@@ -790,7 +1215,7 @@ impl<T: Trait> Module<T> {
                     description_length.saturated_into(),
                 )
             }
-            ProposalDetails::AmendConstitution(new_constitution) => {
+            ProposalDetails::AmendConstitution(new_constitution, _) => {
                 WeightInfoCodex::<T>::create_proposal_amend_constitution(
                     new_constitution.len().saturated_into(),
                     description_length.saturated_into(),
@@ -880,16 +1305,127 @@ impl<T: Trait> Module<T> {
                 )
                 .saturated_into()
             }
+            ProposalDetails::UpdateNftMarketplaceParameters(..) => {
+                WeightInfoCodex::<T>::create_proposal_update_nft_marketplace_parameters(
+                    title_length.saturated_into(),
+                    description_length.saturated_into(),
+                )
+            }
+            ProposalDetails::SetMaxRewardAllowed(..) => {
+                WeightInfoCodex::<T>::create_proposal_set_max_reward_allowed(
+                    title_length.saturated_into(),
+                    description_length.saturated_into(),
+                )
+            }
+            ProposalDetails::SetMinCashoutAllowed(..) => {
+                WeightInfoCodex::<T>::create_proposal_set_min_cashout_allowed(
+                    title_length.saturated_into(),
+                    description_length.saturated_into(),
+                )
+            }
+            ProposalDetails::UpdateStorageDataSizeFee(..) => {
+                WeightInfoCodex::<T>::create_proposal_update_storage_data_size_fee(
+                    title_length.saturated_into(),
+                    description_length.saturated_into(),
+                )
+            }
+            ProposalDetails::UpdateStorageBucketsPerBagLimit(..) => {
+                WeightInfoCodex::<T>::create_proposal_update_storage_buckets_per_bag_limit(
+                    title_length.saturated_into(),
+                    description_length.saturated_into(),
+                )
+            }
+            ProposalDetails::UpdateStorageBucketsVoucherMaxLimits(..) => {
+                WeightInfoCodex::<T>::create_proposal_update_storage_buckets_voucher_max_limits(
+                    title_length.saturated_into(),
+                    description_length.saturated_into(),
+                )
+            }
+            ProposalDetails::UpdateDynamicBagStorageBucketsNumber(..) => {
+                WeightInfoCodex::<T>::create_proposal_update_dynamic_bag_storage_buckets_number(
+                    title_length.saturated_into(),
+                    description_length.saturated_into(),
+                )
+            }
+            ProposalDetails::UpdateStorageBlacklist(..) => {
+                WeightInfoCodex::<T>::create_proposal_update_storage_blacklist(
+                    title_length.saturated_into(),
+                    description_length.saturated_into(),
+                )
+            }
+            ProposalDetails::BlockStorageUploads(..) => {
+                WeightInfoCodex::<T>::create_proposal_block_storage_uploads(
+                    title_length.saturated_into(),
+                    description_length.saturated_into(),
+                )
+            }
+            ProposalDetails::VestingFundingRequest(..) => {
+                WeightInfoCodex::<T>::create_proposal_vesting_funding_request(
+                    title_length.saturated_into(),
+                    description_length.saturated_into(),
+                )
+            }
+            ProposalDetails::CancelVestingFundingRequest(..) => {
+                WeightInfoCodex::<T>::create_proposal_cancel_vesting_funding_request(
+                    title_length.saturated_into(),
+                    description_length.saturated_into(),
+                )
+            }
+            ProposalDetails::UpdateBlogAuthor(..) => {
+                WeightInfoCodex::<T>::create_proposal_update_blog_author(
+                    title_length.saturated_into(),
+                    description_length.saturated_into(),
+                )
+            }
+            ProposalDetails::ReviewBlogDrafts(..) => {
+                WeightInfoCodex::<T>::create_proposal_review_blog_drafts(
+                    title_length.saturated_into(),
+                    description_length.saturated_into(),
+                )
+            }
+            ProposalDetails::SetWorkingGroupSpendingLimit(..) => {
+                WeightInfoCodex::<T>::create_proposal_set_working_group_spending_limit(
+                    title_length.saturated_into(),
+                    description_length.saturated_into(),
+                )
+            }
+            ProposalDetails::SpendFromWorkingGroupBudget(..) => {
+                WeightInfoCodex::<T>::create_proposal_spend_from_working_group_budget(
+                    title_length.saturated_into(),
+                    description_length.saturated_into(),
+                )
+            }
+            ProposalDetails::StartReferralCampaign(..) => {
+                WeightInfoCodex::<T>::create_proposal_start_referral_campaign(
+                    title_length.saturated_into(),
+                    description_length.saturated_into(),
+                )
+            }
+            ProposalDetails::Batch(batch) => {
+                // The benchmark covers a batch of the cheapest proposals, the inner proposal
+                // weights cover the more expensive inner proposal checks.
+                let inner_weight = batch
+                    .iter()
+                    .map(|details| Self::get_create_proposal_weight(general, details))
+                    .fold(0, |total: Weight, weight: Weight| {
+                        total.saturating_add(weight)
+                    });
+
+                WeightInfoCodex::<T>::create_proposal_batch(
+                    title_length.saturated_into(),
+                    description_length.saturated_into(),
+                    batch.len().saturated_into(),
+                )
+                .max(inner_weight)
+            }
         }
     }
 }
 
 impl<T: Trait> ProposalObserver<T> for Module<T> {
     fn proposal_removed(proposal_id: &<T as proposals_engine::Trait>::ProposalId) {
-        <ThreadIdByProposalId<T>>::remove(proposal_id);
-
-        let thread_id = Self::thread_id_by_proposal_id(proposal_id);
+        let thread_id = <ThreadIdByProposalId<T>>::take(proposal_id);
 
-        proposals_discussion::ThreadById::<T>::remove(thread_id);
+        <proposals_discussion::Module<T>>::lock_thread(thread_id);
     }
 }
@@ -40,6 +40,11 @@ parameter_types! {
     pub const ReferralCutMaximumPercent: u8 = 50;
     pub const StakingCandidateLockId: [u8; 8] = [3; 8];
     pub const CandidateStake: u64 = 100;
+    pub const HandleDisputeLockId: [u8; 8] = [4; 8];
+    pub const HandleDisputeStake: u64 = 150;
+    pub const HandleDisputeGracePeriod: u64 = 10;
+    pub const MaxRecoveryGuardians: u32 = 5;
+    pub const RecoveryDelay: u64 = 10;
 }
 
 mod proposals_codex_mod {
@@ -139,6 +144,57 @@ impl membership::WeightInfo for Weights {
     fn member_remark() -> Weight {
         unimplemented!()
     }
+    fn reserve_handle(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn release_handle(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn open_handle_dispute(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn resolve_handle_dispute() -> Weight {
+        unimplemented!()
+    }
+    fn execute_handle_dispute_resolution() -> Weight {
+        unimplemented!()
+    }
+    fn withdraw_handle_dispute() -> Weight {
+        unimplemented!()
+    }
+    fn add_member_verification() -> Weight {
+        unimplemented!()
+    }
+    fn revoke_member_verification() -> Weight {
+        unimplemented!()
+    }
+    fn set_second_level_referral_cut() -> Weight {
+        unimplemented!()
+    }
+    fn start_referral_campaign() -> Weight {
+        unimplemented!()
+    }
+    fn end_referral_campaign() -> Weight {
+        unimplemented!()
+    }
+    fn set_recovery_guardians(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn initiate_recovery() -> Weight {
+        unimplemented!()
+    }
+    fn approve_recovery() -> Weight {
+        unimplemented!()
+    }
+    fn reject_recovery() -> Weight {
+        unimplemented!()
+    }
+    fn cancel_recovery() -> Weight {
+        unimplemented!()
+    }
+    fn execute_recovery() -> Weight {
+        unimplemented!()
+    }
 }
 
 impl membership::Trait for Test {
@@ -152,6 +208,11 @@ impl membership::Trait for Test {
     type StakingCandidateStakingHandler =
         staking_handler::StakingManager<Self, StakingCandidateLockId>;
     type CandidateStake = CandidateStake;
+    type HandleDisputeStakingHandler = staking_handler::StakingManager<Self, HandleDisputeLockId>;
+    type HandleDisputeStake = HandleDisputeStake;
+    type HandleDisputeGracePeriod = HandleDisputeGracePeriod;
+    type MaxRecoveryGuardians = MaxRecoveryGuardians;
+    type RecoveryDelay = RecoveryDelay;
 }
 
 impl common::working_group::WorkingGroupBudgetHandler<Test> for () {
@@ -331,6 +392,8 @@ parameter_types! {
     pub const PostLengthLimit: u32 = 2000;
     pub const MaxWhiteListSize: u32 = 20;
     pub const PostLifeTime: u64 = 10;
+    pub const MaxPostEditionNumber: u32 = 3;
+    pub const ThreadLockGracePeriod: u64 = 5;
     pub const PostDeposit: u64 = 100;
     pub const ProposalsDiscussionModuleId: ModuleId = ModuleId(*b"mo:propo");
 }
@@ -346,6 +409,8 @@ impl proposals_discussion::Trait for Test {
     type MaxWhiteListSize = MaxWhiteListSize;
     type WeightInfo = MockProposalsDiscussionWeight;
     type PostLifeTime = PostLifeTime;
+    type MaxPostEditionNumber = MaxPostEditionNumber;
+    type ThreadLockGracePeriod = ThreadLockGracePeriod;
     type PostDeposit = PostDeposit;
     type ModuleId = ProposalsDiscussionModuleId;
 }
@@ -366,6 +431,10 @@ impl proposals_discussion::WeightInfo for MockProposalsDiscussionWeight {
     fn change_thread_mode(_: u32) -> Weight {
         0
     }
+
+    fn on_initialize_remove_threads(_: u32) -> Weight {
+        0
+    }
 }
 
 pub struct MockVotersParameters;
@@ -403,23 +472,21 @@ impl working_group::Trait<ContentDirectoryWorkingGroupInstance> for Test {
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = WorkingGroupWeightInfo;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type WorkerReviewPeriod = ();
+    type MaxBudgetSpendingRecords = ();
 }
 
 impl working_group::WeightInfo for WorkingGroupWeightInfo {
     fn on_initialize_leaving(_: u32) -> Weight {
         0
     }
-    fn on_initialize_rewarding_with_missing_reward(_: u32) -> Weight {
-        0
-    }
-    fn on_initialize_rewarding_with_missing_reward_cant_pay(_: u32) -> Weight {
+    fn on_initialize_expired_openings(_: u32, _: u32) -> Weight {
         0
     }
-    fn on_initialize_rewarding_without_missing_reward(_: u32) -> Weight {
+    fn claim_reward() -> Weight {
         0
     }
     fn apply_on_opening(_: u32) -> Weight {
@@ -482,6 +549,27 @@ impl working_group::WeightInfo for WorkingGroupWeightInfo {
     fn worker_remark() -> Weight {
         0
     }
+    fn pay_worker_bonus() -> Weight {
+        0
+    }
+    fn review_worker(_: u32) -> Weight {
+        0
+    }
+    fn shortlist_applications(_: u32) -> Weight {
+        0
+    }
+    fn reject_applications(_: u32) -> Weight {
+        0
+    }
+    fn appoint_deputy(_: u32) -> Weight {
+        0
+    }
+    fn remove_deputy() -> Weight {
+        0
+    }
+    fn set_spending_limit() -> Weight {
+        0
+    }
 }
 
 impl working_group::Trait<StorageWorkingGroupInstance> for Test {
@@ -491,10 +579,11 @@ impl working_group::Trait<StorageWorkingGroupInstance> for Test {
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = WorkingGroupWeightInfo;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type WorkerReviewPeriod = ();
+    type MaxBudgetSpendingRecords = ();
 }
 
 impl working_group::Trait<ForumWorkingGroupInstance> for Test {
@@ -504,10 +593,11 @@ impl working_group::Trait<ForumWorkingGroupInstance> for Test {
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = WorkingGroupWeightInfo;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type WorkerReviewPeriod = ();
+    type MaxBudgetSpendingRecords = ();
 }
 
 impl working_group::Trait<MembershipWorkingGroupInstance> for Test {
@@ -517,10 +607,11 @@ impl working_group::Trait<MembershipWorkingGroupInstance> for Test {
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = WorkingGroupWeightInfo;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type WorkerReviewPeriod = ();
+    type MaxBudgetSpendingRecords = ();
 }
 
 pallet_staking_reward_curve::build! {
@@ -634,6 +725,23 @@ impl crate::Trait for Test {
     type LockBlogPostProposalParameters = DefaultProposalParameters;
     type UnlockBlogPostProposalParameters = DefaultProposalParameters;
     type VetoProposalProposalParameters = DefaultProposalParameters;
+    type UpdateNftMarketplaceParametersProposalParameters = DefaultProposalParameters;
+    type SetMaxRewardAllowedProposalParameters = DefaultProposalParameters;
+    type SetMinCashoutAllowedProposalParameters = DefaultProposalParameters;
+    type UpdateStorageDataSizeFeeProposalParameters = DefaultProposalParameters;
+    type UpdateStorageBucketsPerBagLimitProposalParameters = DefaultProposalParameters;
+    type UpdateStorageBucketsVoucherMaxLimitsProposalParameters = DefaultProposalParameters;
+    type UpdateDynamicBagStorageBucketsNumberProposalParameters = DefaultProposalParameters;
+    type UpdateStorageBlacklistProposalParameters = DefaultProposalParameters;
+    type BlockStorageUploadsProposalParameters = DefaultProposalParameters;
+    type VestingFundingRequestProposalParameters = DefaultProposalParameters;
+    type CancelVestingFundingRequestProposalParameters = DefaultProposalParameters;
+    type UpdateBlogAuthorProposalParameters = DefaultProposalParameters;
+    type ReviewBlogDraftsProposalParameters = DefaultProposalParameters;
+    type SetWorkingGroupSpendingLimitProposalParameters = DefaultProposalParameters;
+    type SpendFromWorkingGroupBudgetProposalParameters = DefaultProposalParameters;
+    type StartReferralCampaignProposalParameters = DefaultProposalParameters;
+    type MaxVestingMilestones = MaxVestingMilestones;
 }
 
 parameter_types! {
@@ -645,6 +753,7 @@ parameter_types! {
     pub const CandidacyLockId: LockIdentifier = *b"council1";
     pub const CouncilorLockId: LockIdentifier = *b"council2";
     pub const ElectedMemberRewardPeriod: u64 = 10;
+    pub const MaxVestingMilestones: u32 = 10;
     pub const BudgetRefillAmount: u64 = 1000;
     // intentionally high number that prevents side-effecting tests other than  budget refill tests
     pub const BudgetRefillPeriod: u64 = 1000;
@@ -667,6 +776,7 @@ impl council::Trait for Test {
     type CouncilorLock = StakingManager<Self, CouncilorLockId>;
 
     type ElectedMemberRewardPeriod = ElectedMemberRewardPeriod;
+    type MaxVestingMilestones = MaxVestingMilestones;
 
     type BudgetRefillPeriod = BudgetRefillPeriod;
 
@@ -725,6 +835,15 @@ impl council::WeightInfo for CouncilWeightInfo {
     fn candidate_remark() -> Weight {
         0
     }
+    fn vesting_funding_request(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn claim_vesting_funding(_: u32) -> Weight {
+        0
+    }
+    fn cancel_vesting_funding_request(_: u32) -> Weight {
+        0
+    }
 }
 
 parameter_types! {
@@ -903,6 +1022,57 @@ impl crate::WeightInfo for () {
     fn create_proposal_veto_proposal(_: u32, _: u32) -> Weight {
         0
     }
+    fn create_proposal_update_nft_marketplace_parameters(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn create_proposal_set_max_reward_allowed(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn create_proposal_set_min_cashout_allowed(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn create_proposal_update_storage_data_size_fee(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn create_proposal_update_storage_buckets_per_bag_limit(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn create_proposal_update_storage_buckets_voucher_max_limits(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn create_proposal_update_dynamic_bag_storage_buckets_number(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn create_proposal_update_storage_blacklist(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn create_proposal_block_storage_uploads(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn create_proposal_vesting_funding_request(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn create_proposal_cancel_vesting_funding_request(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn create_proposal_update_blog_author(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn create_proposal_review_blog_drafts(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn create_proposal_set_working_group_spending_limit(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn create_proposal_spend_from_working_group_budget(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn create_proposal_start_referral_campaign(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn create_proposal_batch(_: u32, _: u32, _: u32) -> Weight {
+        0
+    }
 }
 
 impl ProposalEncoder<Test> for () {
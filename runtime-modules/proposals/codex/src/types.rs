@@ -3,6 +3,8 @@
 use codec::{Decode, Encode};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
+use sp_std::collections::btree_map::BTreeMap;
+use sp_std::collections::btree_set::BTreeSet;
 use sp_std::vec::Vec;
 
 use common::working_group::WorkingGroup;
@@ -68,8 +70,9 @@ pub enum ProposalDetails<Balance, BlockNumber, AccountId, WorkerId, OpeningId, P
     /// Fire the working group leader with possible slashing.
     TerminateWorkingGroupLead(TerminateRoleParameters<WorkerId, Balance>),
 
-    /// `Amend constitution` proposal.
-    AmendConstitution(Vec<u8>),
+    /// `Amend constitution` proposal: (constitution text, optional base amendment number). The
+    /// amendment fails when the constitution was amended after the provided base version.
+    AmendConstitution(Vec<u8>, Option<u32>),
 
     /// `Cancel Working Group Lead Opening` proposal:
     /// Cancels an opening for a working group leader
@@ -111,6 +114,85 @@ pub enum ProposalDetails<Balance, BlockNumber, AccountId, WorkerId, OpeningId, P
 
     /// `Veto Proposal` proposal
     VetoProposal(ProposalId),
+
+    /// `Update Nft Marketplace Parameters` proposal
+    UpdateNftMarketplaceParameters(content::NftMarketplaceParametersRecord<BlockNumber, Balance>),
+
+    /// `Set Max Reward Allowed` proposal
+    SetMaxRewardAllowed(Balance),
+
+    /// `Set Min Cashout Allowed` proposal
+    SetMinCashoutAllowed(Balance),
+
+    /// `Update Storage Data Size Fee` proposal
+    UpdateStorageDataSizeFee(Balance),
+
+    /// `Update Storage Buckets Per Bag Limit` proposal
+    UpdateStorageBucketsPerBagLimit(u64),
+
+    /// `Update Storage Buckets Voucher Max Limits` proposal: (objects size limit, objects number
+    /// limit)
+    UpdateStorageBucketsVoucherMaxLimits(u64, u64),
+
+    /// `Update Dynamic Bag Storage Buckets Number` proposal: updates the number of storage
+    /// buckets in the dynamic bag creation policy
+    UpdateDynamicBagStorageBucketsNumber(storage::DynamicBagType, u64),
+
+    /// `Update Storage Blacklist` proposal: (CIDs to remove, CIDs to add)
+    UpdateStorageBlacklist(BTreeSet<Vec<u8>>, BTreeSet<Vec<u8>>),
+
+    /// `Block Storage Uploads` emergency proposal: sets the global uploading blocked status,
+    /// `false` lifts the block
+    BlockStorageUploads(bool),
+
+    /// `Vesting Funding Request` proposal: funds are released from the council budget
+    /// according to the vesting schedule
+    VestingFundingRequest(
+        Vec<common::VestingFundingRequestParameters<Balance, AccountId, BlockNumber>>,
+    ),
+
+    /// `Cancel Vesting Funding Request` proposal: cancels the remaining schedule of the vesting
+    /// funding request with the provided ID
+    CancelVestingFundingRequest(u64),
+
+    /// `Update Blog Author` proposal: authorizes the member to submit blog post drafts
+    /// or revokes the authorization
+    UpdateBlogAuthor(u64, bool),
+
+    /// `Review Blog Drafts` proposal: publishes the approved blog post drafts
+    /// and removes the rejected ones
+    ReviewBlogDrafts(BTreeMap<u64, bool>),
+
+    /// `Set Working Group Spending Limit` proposal: sets the lead spending limit for the working
+    /// group budget, `None` removes the limit
+    SetWorkingGroupSpendingLimit(
+        Option<working_group::SpendingLimit<Balance, BlockNumber>>,
+        WorkingGroup,
+    ),
+
+    /// `Spend From Working Group Budget` proposal: (recipient, amount, rationale, working group).
+    /// The spending is not limited by the lead spending limit.
+    SpendFromWorkingGroupBudget(AccountId, Balance, Vec<u8>, WorkingGroup),
+
+    /// `Start Referral Campaign` proposal: (budget, duration). Referral rewards are paid from
+    /// the campaign budget until the campaign ends.
+    StartReferralCampaign(Balance, BlockNumber),
+
+    /// `Batch` proposal: executes the provided proposals in order as a single all-or-nothing
+    /// operation. Nested `Batch` proposals are not allowed.
+    Batch(
+        Vec<
+            ProposalDetails<
+                Balance,
+                BlockNumber,
+                AccountId,
+                WorkerId,
+                OpeningId,
+                PostId,
+                ProposalId,
+            >,
+        >,
+    ),
 }
 
 impl<Balance, BlockNumber, AccountId, WorkerId, OpeningId, PostId, ProposalId> Default
@@ -212,6 +212,7 @@ fn elect_council<
 }
 
 const MAX_BYTES: u32 = 16384;
+const MAX_REMOVED_THREADS: u32 = 100;
 
 benchmarks! {
     where_clause {
@@ -249,12 +250,31 @@ benchmarks! {
 
         assert!(ThreadById::<T>::contains_key(thread_id), "Thread not created");
 
+        // Worst case scenario the post replies to another post
+        ProposalsDiscussion::<T>::add_post(
+            RawOrigin::Signed(account_id.clone()).into(),
+            caller_member_id,
+            thread_id,
+            vec![0u8],
+            true,
+            None,
+        ).unwrap();
+
+        let parent_id = T::PostId::from(1);
+
         let text = vec![0u8; j.try_into().unwrap()];
 
         assert!(Balances::<T>::usable_balance(&account_id) >= T::PostDeposit::get());
-    }: _ (RawOrigin::Signed(account_id), caller_member_id, thread_id, text.clone(), true)
+    }: _ (
+        RawOrigin::Signed(account_id),
+        caller_member_id,
+        thread_id,
+        text.clone(),
+        true,
+        Some(parent_id)
+    )
     verify {
-        let post_id = T::PostId::from(1);
+        let post_id = T::PostId::from(2);
 
         assert!(PostThreadIdByPostId::<T>::contains_key(thread_id, post_id), "Post not created");
         assert_eq!(
@@ -269,6 +289,7 @@ benchmarks! {
                 thread_id,
                 text,
                 true,
+                Some(parent_id),
             ).into()
         );
     }
@@ -293,16 +314,31 @@ benchmarks! {
             caller_member_id,
             thread_id,
             vec![0u8],
-            true
+            true,
+            None,
         ).unwrap();
 
         let post_id = T::PostId::from(1);
 
         assert!(PostThreadIdByPostId::<T>::contains_key(thread_id, post_id), "Post not created");
 
+        // Worst case scenario the post edit history is almost full
+        for _ in 1 .. T::MaxPostEditionNumber::get() {
+            ProposalsDiscussion::<T>::update_post(
+                RawOrigin::Signed(account_id.clone()).into(),
+                thread_id,
+                post_id,
+                vec![0u8],
+            ).unwrap();
+        }
+
         let new_text = vec![0u8; j.try_into().unwrap()];
     }: _ (RawOrigin::Signed(account_id), thread_id, post_id, new_text.clone())
     verify {
+        assert_eq!(
+            PostThreadIdByPostId::<T>::get(thread_id, post_id).edition_number,
+            T::MaxPostEditionNumber::get()
+        );
         assert_last_event::<T>(RawEvent::PostUpdated(post_id, caller_member_id, thread_id, new_text).into());
     }
 
@@ -324,7 +360,8 @@ benchmarks! {
             caller_member_id,
             thread_id,
             vec![0u8],
-            true
+            true,
+            None,
         ).unwrap();
 
         let post_id = T::PostId::from(1);
@@ -380,6 +417,40 @@ benchmarks! {
             ).into()
         );
     }
+
+    on_initialize_remove_threads {
+        let i in 1 .. MAX_REMOVED_THREADS;
+
+        // We do this to ignore the id 0 because the `Test` runtime
+        // returns 0 as an invalid id but 1 as a valid one
+        let (_, _) = member_account::<T>("member", 0);
+        let (_, caller_member_id) = member_account::<T>("caller_member", 1);
+
+        let thread_ids = (0 .. i)
+            .map(|_| {
+                let thread_id = ProposalsDiscussion::<T>::create_thread(
+                    caller_member_id,
+                    ThreadMode::Open
+                ).unwrap();
+
+                ProposalsDiscussion::<T>::lock_thread(thread_id);
+
+                thread_id
+            })
+            .collect::<Vec<_>>();
+
+        let removed_at = System::<T>::block_number() +
+            T::ThreadLockGracePeriod::get() +
+            T::PostLifeTime::get();
+    }: { ProposalsDiscussion::<T>::on_initialize(removed_at) }
+    verify {
+        for thread_id in thread_ids.iter() {
+            assert!(!ThreadById::<T>::contains_key(thread_id), "Thread not removed");
+        }
+
+        assert!(!ThreadsToRemove::<T>::contains_key(removed_at));
+        assert_last_event::<T>(RawEvent::ThreadRemoved(thread_ids[thread_ids.len() - 1]).into());
+    }
 }
 
 #[cfg(test)]
@@ -408,4 +479,11 @@ mod tests {
             assert_ok!(test_benchmark_change_thread_mode::<Test>());
         });
     }
+
+    #[test]
+    fn test_on_initialize_remove_threads() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_on_initialize_remove_threads::<Test>());
+        });
+    }
 }
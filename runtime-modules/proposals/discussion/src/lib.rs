@@ -6,7 +6,11 @@
 //!
 //! The proposals discussion module is used by the codex module to provide a platform for
 //! discussions about different proposals. It allows to create discussion threads and then add and
-//! update related posts.
+//! update related posts. Posts can reply to other posts of the same thread, and the text hashes
+//! of the previous post editions are kept to audit the post updates.
+//!
+//! The thread is locked after a grace period once its proposal is decided: posts can no longer
+//! be added or updated, and anyone can delete the remaining posts for their cleanup deposit.
 //!
 //! ## Supported extrinsics
 //! - [add_post](./struct.Module.html#method.add_post) - adds a post to an existing discussion thread
@@ -19,6 +23,11 @@
 //! - [create_thread](./struct.Module.html#method.create_thread) - creates a discussion thread
 //! - [ensure_can_create_thread](./struct.Module.html#method.ensure_can_create_thread) - ensures
 //! safe thread creation
+//! - [lock_thread](./struct.Module.html#method.lock_thread) - schedules the thread locking and
+//! removal
+//! - [migrate_threads](./struct.Module.html#method.migrate_threads) - migrates the stored
+//! threads
+//! - [migrate_posts](./struct.Module.html#method.migrate_posts) - migrates the stored posts
 //!
 //! ## Usage
 //!
@@ -57,25 +66,36 @@ mod types;
 use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::sp_runtime::ModuleId;
 use frame_support::sp_runtime::SaturatedConversion;
+use frame_support::storage::{IterableStorageDoubleMap, IterableStorageMap};
 use frame_support::traits::Get;
 use frame_support::traits::{Currency, ExistenceRequirement};
 use frame_support::{
     decl_error, decl_event, decl_module, decl_storage, ensure, weights::Weight, Parameter,
 };
-use sp_runtime::traits::{AccountIdConversion, Saturating};
+use sp_runtime::traits::{AccountIdConversion, Hash, Saturating};
+use sp_std::cell::Cell;
 use sp_std::clone::Clone;
 use sp_std::vec::Vec;
 
 use common::council::CouncilOriginValidator;
 use common::membership::MemberOriginValidator;
 use common::MemberId;
-use types::{DiscussionPost, DiscussionThread};
+use types::{DiscussionPost, DiscussionThread, OldDiscussionPost, OldDiscussionThread};
 
 pub use types::ThreadMode;
 
 /// Balance alias for `balances` module.
 pub type BalanceOf<T> = <T as balances::Trait>::Balance;
 
+/// Alias for the discussion post stored by the module.
+type DiscussionPostOf<T> = DiscussionPost<
+    MemberId<T>,
+    BalanceOf<T>,
+    <T as frame_system::Trait>::BlockNumber,
+    <T as Trait>::PostId,
+    <T as frame_system::Trait>::Hash,
+>;
+
 type Balances<T> = balances::Module<T>;
 
 /// Proposals discussion WeightInfo.
@@ -85,6 +105,7 @@ pub trait WeightInfo {
     fn update_post(j: u32) -> Weight;
     fn delete_post() -> Weight;
     fn change_thread_mode(i: u32) -> Weight;
+    fn on_initialize_remove_threads(i: u32) -> Weight;
 }
 
 type WeightInfoDiscussion<T> = <T as Trait>::WeightInfo;
@@ -96,12 +117,20 @@ decl_event!(
         <T as Trait>::ThreadId,
         MemberId = MemberId<T>,
         <T as Trait>::PostId,
+        <T as frame_system::Trait>::BlockNumber,
     {
         /// Emits on thread creation.
         ThreadCreated(ThreadId, MemberId),
 
         /// Emits on post creation.
-        PostCreated(PostId, MemberId, ThreadId, Vec<u8>, bool),
+        /// Params:
+        /// - post id
+        /// - author id
+        /// - thread id
+        /// - text
+        /// - whether the post is editable
+        /// - parent post id
+        PostCreated(PostId, MemberId, ThreadId, Vec<u8>, bool, Option<PostId>),
 
         /// Emits on post update.
         PostUpdated(PostId, MemberId, ThreadId, Vec<u8>),
@@ -111,6 +140,15 @@ decl_event!(
 
         /// Emits on post deleted
         PostDeleted(MemberId, ThreadId, PostId, bool),
+
+        /// Emits on thread locking scheduling.
+        /// Params:
+        /// - thread id
+        /// - block from which the thread is locked
+        ThreadLocked(ThreadId, BlockNumber),
+
+        /// Emits on the locked thread removal.
+        ThreadRemoved(ThreadId),
     }
 );
 
@@ -157,6 +195,12 @@ pub trait Trait:
 
     /// Maximum number of blocks before a post can be erased by anyone
     type PostLifeTime: Get<Self::BlockNumber>;
+
+    /// Maximum number of updates of a single post.
+    type MaxPostEditionNumber: Get<u32>;
+
+    /// Number of blocks the thread accepts new posts after its locking was scheduled.
+    type ThreadLockGracePeriod: Get<Self::BlockNumber>;
 }
 
 decl_error! {
@@ -185,6 +229,15 @@ decl_error! {
 
         /// Account can't delete post at the moment
         CannotDeletePost,
+
+        /// Parent post doesn't exist in the thread
+        ParentPostDoesntExist,
+
+        /// Post edition limit reached.
+        PostEditionNumberExceeded,
+
+        /// The thread is locked: posts can't be added or updated.
+        ThreadIsLocked,
     }
 }
 
@@ -201,10 +254,19 @@ decl_storage! {
         /// Map thread id and post id to corresponding post.
         pub PostThreadIdByPostId:
             double_map hasher(blake2_128_concat) T::ThreadId, hasher(blake2_128_concat) T::PostId =>
-                DiscussionPost<MemberId<T>, BalanceOf<T>, T::BlockNumber>;
+                DiscussionPostOf<T>;
+
+        /// Map thread id and post id to the text hashes of the previous post editions.
+        pub PostEditHistory get(fn post_edit_history):
+            double_map hasher(blake2_128_concat) T::ThreadId, hasher(blake2_128_concat) T::PostId =>
+                Vec<T::Hash>;
 
         /// Count of all posts that have been created.
         pub PostCount get(fn post_count): u64;
+
+        /// Map block number to the locked threads removed at this block.
+        pub ThreadsToRemove get(fn threads_to_remove): map hasher(blake2_128_concat)
+            T::BlockNumber => Vec<T::ThreadId>;
     }
 }
 
@@ -226,7 +288,21 @@ decl_module! {
         /// Exports const - maximum number of blocks before a post can be erased by anyone
         const PostLifeTime: T::BlockNumber = T::PostLifeTime::get();
 
-        /// Adds a post with author origin check.
+        /// Exports const - maximum number of updates of a single post
+        const MaxPostEditionNumber: u32 = T::MaxPostEditionNumber::get();
+
+        /// Exports const - number of blocks the thread accepts new posts after its locking was
+        /// scheduled
+        const ThreadLockGracePeriod: T::BlockNumber = T::ThreadLockGracePeriod::get();
+
+        /// Removes the threads locked at least the post lifetime ago.
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            Self::remove_locked_threads(now)
+        }
+
+        /// Adds a post with author origin check. The post can reply to an existing editable post
+        /// of the same thread. Non-editable posts aren't stored, so they can't be replied to.
+        /// Replies keep the parent post id after the parent post deletion.
         ///
         /// <weight>
         ///
@@ -242,7 +318,8 @@ decl_module! {
             post_author_id: MemberId<T>,
             thread_id: T::ThreadId,
             text: Vec<u8>,
-            editable: bool
+            editable: bool,
+            parent_id: Option<T::PostId>,
         ) {
             let account_id = T::AuthorOriginValidator::ensure_member_controller_account_origin(
                 origin.clone(),
@@ -251,8 +328,17 @@ decl_module! {
 
             ensure!(<ThreadById<T>>::contains_key(thread_id), Error::<T>::ThreadDoesntExist);
 
+            Self::ensure_thread_unlocked(thread_id)?;
+
             Self::ensure_thread_mode(origin, post_author_id, thread_id)?;
 
+            if let Some(parent_id) = parent_id {
+                ensure!(
+                    <PostThreadIdByPostId<T>>::contains_key(thread_id, parent_id),
+                    Error::<T>::ParentPostDoesntExist
+                );
+            }
+
             // Ensure account has enough funds
             if editable {
                 ensure!(
@@ -280,13 +366,18 @@ decl_module! {
                     author_id: post_author_id,
                     cleanup_pay_off: T::PostDeposit::get(),
                     last_edited: frame_system::Module::<T>::block_number(),
+                    parent_id,
+                    text_hash: T::Hashing::hash(&text),
+                    edition_number: 0,
                 };
 
                 <PostThreadIdByPostId<T>>::insert(thread_id, post_id, new_post);
             }
 
             PostCount::put(next_post_count_value);
-            Self::deposit_event(RawEvent::PostCreated(post_id, post_author_id, thread_id, text, editable));
+            Self::deposit_event(
+                RawEvent::PostCreated(post_id, post_author_id, thread_id, text, editable, parent_id)
+            );
        }
 
         /// Remove post from storage, with the last parameter indicating whether to also hide it
@@ -339,10 +430,12 @@ decl_module! {
             )?;
 
             <PostThreadIdByPostId<T>>::remove(thread_id, post_id);
+            <PostEditHistory<T>>::remove(thread_id, post_id);
             Self::deposit_event(RawEvent::PostDeleted(deleter_id, thread_id, post_id, hide));
         }
 
         /// Updates a post with author origin check. Update attempts number is limited.
+        /// The text hash of the previous post edition is saved to the post edit history.
         ///
         /// <weight>
         ///
@@ -365,19 +458,33 @@ decl_module! {
                 Error::<T>::PostDoesntExist
             );
 
-            let post_author_id = <PostThreadIdByPostId<T>>::get(&thread_id, &post_id).author_id;
+            Self::ensure_thread_unlocked(thread_id)?;
+
+            let post = <PostThreadIdByPostId<T>>::get(&thread_id, &post_id);
+            let post_author_id = post.author_id;
 
             T::AuthorOriginValidator::ensure_member_controller_account_origin(
                 origin,
                 post_author_id,
             )?;
 
+            ensure!(
+                post.edition_number < T::MaxPostEditionNumber::get(),
+                Error::<T>::PostEditionNumberExceeded
+            );
+
             // mutation
 
+            <PostEditHistory<T>>::append(thread_id, post_id, post.text_hash);
+
             <PostThreadIdByPostId<T>>::mutate(
                 thread_id,
                 post_id,
-                |new_post| new_post.last_edited = frame_system::Module::<T>::block_number()
+                |new_post| {
+                    new_post.last_edited = frame_system::Module::<T>::block_number();
+                    new_post.text_hash = T::Hashing::hash(&text);
+                    new_post.edition_number += 1;
+                }
             );
             Self::deposit_event(RawEvent::PostUpdated(post_id, post_author_id, thread_id, text));
        }
@@ -452,6 +559,7 @@ impl<T: Trait> Module<T> {
             activated_at: Self::current_block(),
             author_id: thread_author_id,
             mode,
+            locked_at: None,
         };
 
         // mutation
@@ -477,6 +585,77 @@ impl<T: Trait> Module<T> {
 
         Ok(())
     }
+
+    /// Schedules the thread locking after the grace period and the thread removal after the post
+    /// lifetime past the locking. Does nothing if the thread doesn't exist or its locking was
+    /// already scheduled.
+    pub fn lock_thread(thread_id: T::ThreadId) {
+        if !<ThreadById<T>>::contains_key(thread_id)
+            || Self::thread_by_id(thread_id).locked_at.is_some()
+        {
+            return;
+        }
+
+        let locked_at = Self::current_block().saturating_add(T::ThreadLockGracePeriod::get());
+        let removed_at = locked_at.saturating_add(T::PostLifeTime::get());
+
+        // mutation
+
+        <ThreadById<T>>::mutate(thread_id, |thread| thread.locked_at = Some(locked_at));
+        <ThreadsToRemove<T>>::append(removed_at, thread_id);
+        Self::deposit_event(RawEvent::ThreadLocked(thread_id, locked_at));
+    }
+
+    /// Migrates the threads stored before the thread locking was introduced. The migrated
+    /// threads are not locked. Should be called before any thread is mutated, e.g. locked by the
+    /// proposal removal.
+    /// Possible application includes the runtime upgrade.
+    pub fn migrate_threads() -> Weight {
+        let migrated_threads_number = Cell::new(0 as Weight);
+
+        <ThreadById<T>>::translate(
+            |_, old_thread: OldDiscussionThread<MemberId<T>, T::BlockNumber, MemberId<T>>| {
+                migrated_threads_number.set(migrated_threads_number.get() + 1);
+
+                Some(DiscussionThread {
+                    activated_at: old_thread.activated_at,
+                    author_id: old_thread.author_id,
+                    mode: old_thread.mode,
+                    locked_at: None,
+                })
+            },
+        );
+
+        let migrated_threads_number = migrated_threads_number.get();
+
+        T::DbWeight::get().reads_writes(migrated_threads_number, migrated_threads_number)
+    }
+
+    /// Migrates the posts stored before the post replies and edit history were introduced.
+    /// The text of the migrated posts is unknown, so their text hash is set to the default value.
+    /// Possible application includes the runtime upgrade.
+    pub fn migrate_posts() -> Weight {
+        let migrated_posts_number = Cell::new(0 as Weight);
+
+        <PostThreadIdByPostId<T>>::translate(
+            |_, _, old_post: OldDiscussionPost<MemberId<T>, BalanceOf<T>, T::BlockNumber>| {
+                migrated_posts_number.set(migrated_posts_number.get() + 1);
+
+                Some(DiscussionPost {
+                    author_id: old_post.author_id,
+                    cleanup_pay_off: old_post.cleanup_pay_off,
+                    last_edited: old_post.last_edited,
+                    parent_id: None,
+                    text_hash: Default::default(),
+                    edition_number: 0,
+                })
+            },
+        );
+
+        let migrated_posts_number = migrated_posts_number.get();
+
+        T::DbWeight::get().reads_writes(migrated_posts_number, migrated_posts_number)
+    }
 }
 
 impl<T: Trait> Module<T> {
@@ -485,12 +664,38 @@ impl<T: Trait> Module<T> {
         <frame_system::Module<T>>::block_number()
     }
 
+    // Removes the locked threads scheduled for removal at the given block. Posts can't be added
+    // or edited in the locked threads, so their remaining posts can be deleted by anyone.
+    fn remove_locked_threads(now: T::BlockNumber) -> Weight {
+        let thread_ids = <ThreadsToRemove<T>>::take(now);
+
+        for thread_id in thread_ids.iter() {
+            <ThreadById<T>>::remove(thread_id);
+            Self::deposit_event(RawEvent::ThreadRemoved(*thread_id));
+        }
+
+        WeightInfoDiscussion::<T>::on_initialize_remove_threads(thread_ids.len().saturated_into())
+    }
+
+    // Posts of the locked threads can be deleted by anyone, as well as the posts of the removed
+    // threads after the post lifetime.
     fn anyone_can_delete_post(thread_id: T::ThreadId, post_id: T::PostId) -> bool {
-        let thread_exists = <ThreadById<T>>::contains_key(thread_id);
+        if <ThreadById<T>>::contains_key(thread_id) {
+            return Self::thread_by_id(thread_id).is_locked(Self::current_block());
+        }
+
         let post = <PostThreadIdByPostId<T>>::get(thread_id, post_id);
-        !thread_exists
-            && frame_system::Module::<T>::block_number().saturating_sub(post.last_edited)
-                >= T::PostLifeTime::get()
+        frame_system::Module::<T>::block_number().saturating_sub(post.last_edited)
+            >= T::PostLifeTime::get()
+    }
+
+    fn ensure_thread_unlocked(thread_id: T::ThreadId) -> DispatchResult {
+        ensure!(
+            !Self::thread_by_id(thread_id).is_locked(Self::current_block()),
+            Error::<T>::ThreadIsLocked
+        );
+
+        Ok(())
     }
 
     fn pay_off(
@@ -10,7 +10,7 @@ pub(crate) use mock::*;
 
 struct EventFixture;
 impl EventFixture {
-    fn assert_events(expected_raw_events: Vec<RawEvent<u64, u64, u64>>) {
+    fn assert_events(expected_raw_events: Vec<RawEvent<u64, u64, u64, u64>>) {
         let expected_events = expected_raw_events
             .iter()
             .map(|ev| EventRecord {
@@ -50,6 +50,7 @@ fn assert_thread_content(thread_entry: TestThreadEntry, post_entries: Vec<TestPo
         activated_at: 0,
         author_id: 1,
         mode: Default::default(),
+        locked_at: None,
     };
     assert_eq!(actual_thread, expected_thread);
 
@@ -60,6 +61,9 @@ fn assert_thread_content(thread_entry: TestThreadEntry, post_entries: Vec<TestPo
             author_id: 1,
             cleanup_pay_off: <Test as Trait>::PostDeposit::get(),
             last_edited: frame_system::Module::<Test>::block_number(),
+            parent_id: None,
+            text_hash: <Test as frame_system::Trait>::Hashing::hash(&post_entry.text),
+            edition_number: post_entry.edition_number,
         };
 
         assert_eq!(actual_post, expected_post);
@@ -108,6 +112,7 @@ struct PostFixture {
     pub initial_balance: u64,
     pub account_id: u128,
     pub editable: bool,
+    pub parent_id: Option<u64>,
 }
 
 impl PostFixture {
@@ -121,6 +126,7 @@ impl PostFixture {
             initial_balance: <Test as Trait>::PostDeposit::get(),
             account_id: 1,
             editable: true,
+            parent_id: None,
         }
     }
 
@@ -140,6 +146,13 @@ impl PostFixture {
         PostFixture { editable, ..self }
     }
 
+    fn with_parent_id(self, parent_id: u64) -> Self {
+        PostFixture {
+            parent_id: Some(parent_id),
+            ..self
+        }
+    }
+
     fn change_thread_id(self, thread_id: u64) -> Self {
         PostFixture { thread_id, ..self }
     }
@@ -167,6 +180,7 @@ impl PostFixture {
             self.thread_id,
             self.text.clone(),
             self.editable,
+            self.parent_id,
         );
 
         assert_eq!(add_post_result, result);
@@ -473,6 +487,7 @@ fn update_post_call_succeeds() {
                 post_fixture.thread_id,
                 post_fixture.text.clone(),
                 post_fixture.editable,
+                None,
             ),
             RawEvent::PostUpdated(1, 1, post_fixture.thread_id, post_fixture.text.clone()),
         ]);
@@ -538,6 +553,321 @@ fn thread_content_check_succeeded() {
     });
 }
 
+#[test]
+fn reply_post_call_succeeds() {
+    initial_test_ext().execute_with(|| {
+        run_to_block(1);
+
+        let discussion_fixture = DiscussionFixture::default();
+
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        let parent_id = post_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        let mut reply_fixture = PostFixture::default_for_thread(thread_id)
+            .with_parent_id(parent_id)
+            .with_editable(false);
+        let reply_id = reply_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        EventFixture::assert_events(vec![
+            RawEvent::ThreadCreated(1, 1),
+            RawEvent::PostCreated(parent_id, 1, thread_id, b"text".to_vec(), true, None),
+            RawEvent::PostCreated(
+                reply_id,
+                1,
+                thread_id,
+                b"text".to_vec(),
+                false,
+                Some(parent_id),
+            ),
+        ]);
+    });
+}
+
+#[test]
+fn reply_post_call_fails_with_invalid_parent() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+        let other_thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(2))
+            .unwrap();
+
+        // Parent post belongs to another thread
+        let mut post_fixture = PostFixture::default_for_thread(other_thread_id);
+        let parent_id = post_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        let mut reply_fixture =
+            PostFixture::default_for_thread(thread_id).with_parent_id(parent_id);
+        reply_fixture.add_post_and_assert(Err(Error::<Test>::ParentPostDoesntExist.into()));
+
+        // Non-editable posts aren't stored and can't be replied to
+        let mut post_fixture = PostFixture::default_for_thread(thread_id).with_editable(false);
+        let parent_id = post_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        let mut reply_fixture =
+            PostFixture::default_for_thread(thread_id).with_parent_id(parent_id);
+        reply_fixture.add_post_and_assert(Err(Error::<Test>::ParentPostDoesntExist.into()));
+    });
+}
+
+#[test]
+fn delete_parent_post_keeps_reply_parent_id() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        let parent_id = post_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        let mut reply_fixture =
+            PostFixture::default_for_thread(thread_id).with_parent_id(parent_id);
+        let reply_id = reply_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        post_fixture.delete_post_and_assert(Ok(()));
+
+        assert_eq!(
+            <PostThreadIdByPostId<Test>>::get(thread_id, reply_id).parent_id,
+            Some(parent_id)
+        );
+    });
+}
+
+#[test]
+fn migrate_threads_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let thread_id = 1;
+        let old_thread = OldDiscussionThread {
+            activated_at: 5,
+            author_id: 1,
+            mode: ThreadMode::Closed(vec![2, 3]),
+        };
+
+        frame_support::storage::unhashed::put(
+            &<ThreadById<Test>>::hashed_key_for(thread_id),
+            &old_thread,
+        );
+
+        Discussions::migrate_threads();
+
+        assert_eq!(
+            <ThreadById<Test>>::get(thread_id),
+            DiscussionThread {
+                activated_at: 5,
+                author_id: 1,
+                mode: ThreadMode::Closed(vec![2, 3]),
+                locked_at: None,
+            }
+        );
+    });
+}
+
+#[test]
+fn migrate_posts_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let thread_id = 1;
+        let post_id = 1;
+        let old_post = OldDiscussionPost {
+            author_id: 1,
+            cleanup_pay_off: <Test as Trait>::PostDeposit::get(),
+            last_edited: 5,
+        };
+
+        frame_support::storage::unhashed::put(
+            &<PostThreadIdByPostId<Test>>::hashed_key_for(thread_id, post_id),
+            &old_post,
+        );
+
+        Discussions::migrate_posts();
+
+        assert_eq!(
+            <PostThreadIdByPostId<Test>>::get(thread_id, post_id),
+            DiscussionPost {
+                author_id: 1,
+                cleanup_pay_off: <Test as Trait>::PostDeposit::get(),
+                last_edited: 5,
+                parent_id: None,
+                text_hash: Default::default(),
+                edition_number: 0,
+            }
+        );
+    });
+}
+
+#[test]
+fn update_post_call_saves_edit_history() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        let post_id = post_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        let mut expected_history = Vec::new();
+        let mut previous_text = post_fixture.text.clone();
+        for edition_number in 1..=<Test as Trait>::MaxPostEditionNumber::get() {
+            let new_text = vec![edition_number as u8];
+            post_fixture.update_post_with_text_and_assert(new_text.clone(), Ok(()));
+
+            expected_history.push(<Test as frame_system::Trait>::Hashing::hash(&previous_text));
+            previous_text = new_text;
+
+            let post = <PostThreadIdByPostId<Test>>::get(thread_id, post_id);
+            assert_eq!(post.edition_number, edition_number);
+            assert_eq!(
+                post.text_hash,
+                <Test as frame_system::Trait>::Hashing::hash(&previous_text)
+            );
+            assert_eq!(
+                Discussions::post_edit_history(thread_id, post_id),
+                expected_history
+            );
+        }
+
+        post_fixture.update_post_and_assert(Err(Error::<Test>::PostEditionNumberExceeded.into()));
+
+        // Edit history is removed with the post
+        post_fixture.delete_post_and_assert(Ok(()));
+        assert!(!<PostEditHistory<Test>>::contains_key(thread_id, post_id));
+    });
+}
+
+#[test]
+fn locked_thread_rejects_posts_after_grace_period() {
+    initial_test_ext().execute_with(|| {
+        run_to_block(1);
+
+        let discussion_fixture = DiscussionFixture::default();
+
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        post_fixture.add_post_and_assert(Ok(()));
+
+        Discussions::lock_thread(thread_id);
+
+        let locked_at = 1 + <Test as Trait>::ThreadLockGracePeriod::get();
+        assert_eq!(
+            Discussions::thread_by_id(thread_id).locked_at,
+            Some(locked_at)
+        );
+
+        // Locking is scheduled only once
+        run_to_block(2);
+        Discussions::lock_thread(thread_id);
+        assert_eq!(
+            Discussions::thread_by_id(thread_id).locked_at,
+            Some(locked_at)
+        );
+
+        // Posts are still accepted during the grace period
+        let mut late_post_fixture = PostFixture::default_for_thread(thread_id);
+        late_post_fixture.add_post_and_assert(Ok(()));
+        late_post_fixture.update_post_and_assert(Ok(()));
+
+        run_to_block(locked_at);
+
+        PostFixture::default_for_thread(thread_id)
+            .add_post_and_assert(Err(Error::<Test>::ThreadIsLocked.into()));
+        late_post_fixture.update_post_and_assert(Err(Error::<Test>::ThreadIsLocked.into()));
+
+        EventFixture::assert_events(vec![
+            RawEvent::ThreadCreated(1, 1),
+            RawEvent::PostCreated(1, 1, thread_id, b"text".to_vec(), true, None),
+            RawEvent::ThreadLocked(thread_id, locked_at),
+            RawEvent::PostCreated(2, 1, thread_id, b"text".to_vec(), true, None),
+            RawEvent::PostUpdated(2, 1, thread_id, b"text".to_vec()),
+        ]);
+    });
+}
+
+#[test]
+fn delete_post_call_succeeds_with_any_user_in_locked_thread() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        post_fixture.add_post_and_assert(Ok(()));
+
+        Discussions::lock_thread(thread_id);
+
+        let mut other_member_fixture = post_fixture
+            .with_origin(RawOrigin::Signed(10))
+            .with_author(10)
+            .with_account_id(10);
+
+        // Only the author can delete the post during the grace period
+        other_member_fixture.delete_post_and_assert(Err(Error::<Test>::CannotDeletePost.into()));
+
+        let current_block = frame_system::Module::<Test>::block_number();
+        run_to_block(current_block + <Test as Trait>::ThreadLockGracePeriod::get());
+
+        other_member_fixture.delete_post_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn locked_thread_is_removed_after_post_lifetime() {
+    initial_test_ext().execute_with(|| {
+        run_to_block(1);
+
+        let discussion_fixture = DiscussionFixture::default();
+
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        post_fixture.add_post_and_assert(Ok(()));
+
+        Discussions::lock_thread(thread_id);
+
+        let locked_at = 1 + <Test as Trait>::ThreadLockGracePeriod::get();
+        let removed_at = locked_at + <Test as Trait>::PostLifeTime::get();
+        assert_eq!(Discussions::threads_to_remove(removed_at), vec![thread_id]);
+
+        run_to_block(removed_at - 1);
+        assert!(<ThreadById<Test>>::contains_key(thread_id));
+
+        run_to_block(removed_at);
+        assert!(!<ThreadById<Test>>::contains_key(thread_id));
+        assert!(!<ThreadsToRemove<Test>>::contains_key(removed_at));
+
+        // Remaining posts of the removed thread can be deleted by anyone
+        let mut other_member_fixture = post_fixture
+            .with_origin(RawOrigin::Signed(10))
+            .with_author(10)
+            .with_account_id(10);
+        other_member_fixture.delete_post_and_assert(Ok(()));
+
+        EventFixture::assert_events(vec![
+            RawEvent::ThreadCreated(1, 1),
+            RawEvent::PostCreated(1, 1, thread_id, b"text".to_vec(), true, None),
+            RawEvent::ThreadLocked(thread_id, locked_at),
+            RawEvent::ThreadRemoved(thread_id),
+            RawEvent::PostDeleted(10, thread_id, 1, true),
+        ]);
+    });
+}
+
 #[test]
 fn add_post_call_with_invalid_thread_failed() {
     initial_test_ext().execute_with(|| {
@@ -17,12 +17,62 @@ pub struct DiscussionThread<ThreadAuthorId, BlockNumber, MemberId> {
 
     /// Thread permission mode.
     pub mode: ThreadMode<MemberId>,
+
+    /// Block from which the thread is locked, if its locking was scheduled.
+    pub locked_at: Option<BlockNumber>,
+}
+
+impl<ThreadAuthorId, BlockNumber: PartialOrd + Copy, MemberId>
+    DiscussionThread<ThreadAuthorId, BlockNumber, MemberId>
+{
+    /// Defines whether the thread is locked at the given block.
+    pub fn is_locked(&self, now: BlockNumber) -> bool {
+        self.locked_at.map_or(false, |locked_at| now >= locked_at)
+    }
+}
+
+/// Discussion thread in the format preceding the thread locking.
+/// Used by the stored threads migration only.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub(crate) struct OldDiscussionThread<ThreadAuthorId, BlockNumber, MemberId> {
+    /// When thread was established.
+    pub activated_at: BlockNumber,
+
+    /// Author of the thread.
+    pub author_id: ThreadAuthorId,
+
+    /// Thread permission mode.
+    pub mode: ThreadMode<MemberId>,
 }
 
 /// Post for the discussion thread
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
-pub struct DiscussionPost<PostAuthorId, Balance, BlockNumber> {
+pub struct DiscussionPost<PostAuthorId, Balance, BlockNumber, PostId, Hash> {
+    /// Author of the post.
+    pub author_id: PostAuthorId,
+
+    /// Cleanup pay off
+    pub cleanup_pay_off: Balance,
+
+    /// Last time post was created/edited
+    pub last_edited: BlockNumber,
+
+    /// Post this post replies to, if any. The parent post can be deleted after the reply
+    /// creation, so the referenced post isn't guaranteed to exist.
+    pub parent_id: Option<PostId>,
+
+    /// Hash of the current post text.
+    pub text_hash: Hash,
+
+    /// Number of the post updates.
+    pub edition_number: u32,
+}
+
+/// Post for the discussion thread in the format preceding the post replies and edit history.
+/// Used by the stored posts migration only.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub(crate) struct OldDiscussionPost<PostAuthorId, Balance, BlockNumber> {
     /// Author of the post.
     pub author_id: PostAuthorId,
 
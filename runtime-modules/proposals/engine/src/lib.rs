@@ -394,6 +394,9 @@ decl_storage! {
         /// Double map for preventing duplicate votes. Should be cleaned after usage.
         pub VoteExistsByProposalByVoter get(fn vote_by_proposal_by_voter):
             double_map hasher(blake2_128_concat) T::ProposalId, hasher(blake2_128_concat) MemberId<T> => VoteKind;
+
+        /// ID of the proposal which code is being executed. Set only during the execution.
+        pub ExecutingProposalId get(fn executing_proposal_id): Option<T::ProposalId>;
     }
 }
 
@@ -864,11 +867,18 @@ impl<T: Trait> Module<T> {
 
         let execution_status = match proposal_code_result {
             Ok(proposal_code) => {
-                execution_code_weight = proposal_code.get_dispatch_info().weight;
-
-                if let Err(dispatch_error) =
-                    proposal_code.dispatch_bypass_filter(T::Origin::from(RawOrigin::Root))
-                {
+                // Account for the executing proposal id put and kill around the dispatch.
+                execution_code_weight = proposal_code
+                    .get_dispatch_info()
+                    .weight
+                    .saturating_add(T::DbWeight::get().writes(2));
+
+                <ExecutingProposalId<T>>::put(proposal_id);
+                let dispatch_result =
+                    proposal_code.dispatch_bypass_filter(T::Origin::from(RawOrigin::Root));
+                <ExecutingProposalId<T>>::kill();
+
+                if let Err(dispatch_error) = dispatch_result {
                     ExecutionStatus::failed_execution(Self::parse_dispatch_error(
                         dispatch_error.error,
                     ))
@@ -150,8 +150,8 @@ use sp_std::marker::PhantomData;
 use sp_std::vec::Vec;
 
 use common::constraints::BoundedValueConstraint;
-use common::working_group::WorkingGroup;
 use common::working_group::WorkingGroupAuthenticator;
+use common::working_group::{DeputyPermission, WorkingGroup};
 
 use random_buckets::DistributionBucketPicker;
 use random_buckets::StorageBucketPicker;
@@ -1546,7 +1546,10 @@ decl_module! {
             origin,
             storage_bucket_id: T::StorageBucketId,
         ){
-            <T as Trait>::StorageWorkingGroup::ensure_leader_origin(origin)?;
+            <T as Trait>::StorageWorkingGroup::ensure_leader_or_deputy_origin(
+                origin,
+                DeputyPermission::ManageStorageBuckets
+            )?;
 
             let bucket = Self::ensure_storage_bucket_exists(&storage_bucket_id)?;
 
@@ -1572,7 +1575,7 @@ decl_module! {
         /// Updates global uploading flag.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn update_uploading_blocked_status(origin, new_status: bool) {
-            <T as Trait>::StorageWorkingGroup::ensure_leader_origin(origin)?;
+            Self::ensure_root_or_storage_lead_origin(origin)?;
 
             //
             // == MUTATION SAFE ==
@@ -1586,7 +1589,7 @@ decl_module! {
         /// Updates size-based pricing of new objects uploaded.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn update_data_size_fee(origin, new_data_size_fee: BalanceOf<T>) {
-            <T as Trait>::StorageWorkingGroup::ensure_leader_origin(origin)?;
+            Self::ensure_root_or_storage_lead_origin(origin)?;
 
             //
             // == MUTATION SAFE ==
@@ -1600,7 +1603,7 @@ decl_module! {
         /// Updates "Storage buckets per bag" number limit.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn update_storage_buckets_per_bag_limit(origin, new_limit: u64) {
-            <T as Trait>::StorageWorkingGroup::ensure_leader_origin(origin)?;
+            Self::ensure_root_or_storage_lead_origin(origin)?;
 
             T::StorageBucketsPerBagValueConstraint::get().ensure_valid(
                 new_limit,
@@ -1624,7 +1627,7 @@ decl_module! {
             new_objects_size: u64,
             new_objects_number: u64,
         ) {
-            <T as Trait>::StorageWorkingGroup::ensure_leader_origin(origin)?;
+            Self::ensure_root_or_storage_lead_origin(origin)?;
 
             //
             // == MUTATION SAFE ==
@@ -1645,7 +1648,7 @@ decl_module! {
             dynamic_bag_type: DynamicBagType,
             number_of_storage_buckets: u64,
         ) {
-            <T as Trait>::StorageWorkingGroup::ensure_leader_origin(origin)?;
+            Self::ensure_root_or_storage_lead_origin(origin)?;
 
             //
             // == MUTATION SAFE ==
@@ -1672,7 +1675,7 @@ decl_module! {
             remove_hashes: BTreeSet<Cid>,
             add_hashes: BTreeSet<Cid>
         ){
-            <T as Trait>::StorageWorkingGroup::ensure_leader_origin(origin)?;
+            Self::ensure_root_or_storage_lead_origin(origin)?;
 
             // Get only hashes that exist in the blacklist.
             let verified_remove_hashes = Self::get_existing_hashes(&remove_hashes);
@@ -1715,7 +1718,10 @@ decl_module! {
             size_limit: u64,
             objects_limit: u64,
         ) {
-            <T as Trait>::StorageWorkingGroup::ensure_leader_origin(origin)?;
+            <T as Trait>::StorageWorkingGroup::ensure_leader_or_deputy_origin(
+                origin,
+                DeputyPermission::ManageStorageBuckets
+            )?;
 
             let voucher = Voucher {
                 size_limit,
@@ -1765,7 +1771,10 @@ decl_module! {
             add_buckets: BTreeSet<T::StorageBucketId>,
             remove_buckets: BTreeSet<T::StorageBucketId>,
         ) {
-            <T as Trait>::StorageWorkingGroup::ensure_leader_origin(origin)?;
+            <T as Trait>::StorageWorkingGroup::ensure_leader_or_deputy_origin(
+                origin,
+                DeputyPermission::ManageStorageBuckets
+            )?;
 
             Self::ensure_bag_exists(&bag_id)?;
 
@@ -1810,7 +1819,10 @@ decl_module! {
         /// Cancel pending storage bucket invite. An invitation must be pending.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn cancel_storage_bucket_operator_invite(origin, storage_bucket_id: T::StorageBucketId){
-            <T as Trait>::StorageWorkingGroup::ensure_leader_origin(origin)?;
+            <T as Trait>::StorageWorkingGroup::ensure_leader_or_deputy_origin(
+                origin,
+                DeputyPermission::ManageStorageBuckets
+            )?;
 
             let bucket = Self::ensure_storage_bucket_exists(&storage_bucket_id)?;
 
@@ -1836,7 +1848,10 @@ decl_module! {
             storage_bucket_id: T::StorageBucketId,
             operator_id: WorkerId<T>,
         ){
-            <T as Trait>::StorageWorkingGroup::ensure_leader_origin(origin)?;
+            <T as Trait>::StorageWorkingGroup::ensure_leader_or_deputy_origin(
+                origin,
+                DeputyPermission::ManageStorageBuckets
+            )?;
 
             let bucket = Self::ensure_storage_bucket_exists(&storage_bucket_id)?;
 
@@ -1864,7 +1879,10 @@ decl_module! {
             origin,
             storage_bucket_id: T::StorageBucketId,
         ){
-            <T as Trait>::StorageWorkingGroup::ensure_leader_origin(origin)?;
+            <T as Trait>::StorageWorkingGroup::ensure_leader_or_deputy_origin(
+                origin,
+                DeputyPermission::ManageStorageBuckets
+            )?;
 
             let bucket = Self::ensure_storage_bucket_exists(&storage_bucket_id)?;
 
@@ -1891,7 +1909,10 @@ decl_module! {
             storage_bucket_id: T::StorageBucketId,
             accepting_new_bags: bool
         ) {
-            <T as Trait>::StorageWorkingGroup::ensure_leader_origin(origin)?;
+            <T as Trait>::StorageWorkingGroup::ensure_leader_or_deputy_origin(
+                origin,
+                DeputyPermission::ManageStorageBuckets
+            )?;
 
             Self::ensure_storage_bucket_exists(&storage_bucket_id)?;
 
@@ -1916,6 +1937,7 @@ decl_module! {
             new_objects_size_limit: u64,
             new_objects_number_limit: u64,
         ) {
+            // The voucher limits are a policy setting: not delegated to the deputies.
             <T as Trait>::StorageWorkingGroup::ensure_leader_origin(origin)?;
 
             Self::ensure_storage_bucket_exists(&storage_bucket_id)?;
@@ -2062,7 +2084,10 @@ decl_module! {
         /// Create a distribution bucket family.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn create_distribution_bucket_family(origin) {
-            <T as Trait>::DistributionWorkingGroup::ensure_leader_origin(origin)?;
+            <T as Trait>::DistributionWorkingGroup::ensure_leader_or_deputy_origin(
+                origin,
+                DeputyPermission::ManageDistributionBuckets
+            )?;
 
             ensure!(
                 Self::distribution_bucket_family_number() <
@@ -2090,7 +2115,10 @@ decl_module! {
         /// Deletes a distribution bucket family.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn delete_distribution_bucket_family(origin, family_id: T::DistributionBucketFamilyId) {
-            <T as Trait>::DistributionWorkingGroup::ensure_leader_origin(origin)?;
+            <T as Trait>::DistributionWorkingGroup::ensure_leader_or_deputy_origin(
+                origin,
+                DeputyPermission::ManageDistributionBuckets
+            )?;
 
             Self::ensure_distribution_bucket_family_exists(&family_id)?;
 
@@ -2124,7 +2152,10 @@ decl_module! {
             family_id: T::DistributionBucketFamilyId,
             accepting_new_bags: bool,
         ) {
-            <T as Trait>::DistributionWorkingGroup::ensure_leader_origin(origin)?;
+            <T as Trait>::DistributionWorkingGroup::ensure_leader_or_deputy_origin(
+                origin,
+                DeputyPermission::ManageDistributionBuckets
+            )?;
 
             let family = Self::ensure_distribution_bucket_family_exists(&family_id)?;
 
@@ -2161,7 +2192,10 @@ decl_module! {
             bucket_id: DistributionBucketId<T>,
             accepting_new_bags: bool
         ) {
-            <T as Trait>::DistributionWorkingGroup::ensure_leader_origin(origin)?;
+            <T as Trait>::DistributionWorkingGroup::ensure_leader_or_deputy_origin(
+                origin,
+                DeputyPermission::ManageDistributionBuckets
+            )?;
 
             Self::ensure_distribution_bucket_exists(&bucket_id)?;
 
@@ -2188,7 +2222,10 @@ decl_module! {
             origin,
             bucket_id: DistributionBucketId<T>,
         ){
-            <T as Trait>::DistributionWorkingGroup::ensure_leader_origin(origin)?;
+            <T as Trait>::DistributionWorkingGroup::ensure_leader_or_deputy_origin(
+                origin,
+                DeputyPermission::ManageDistributionBuckets
+            )?;
 
             let bucket = Self::ensure_distribution_bucket_exists(&bucket_id)?;
 
@@ -2221,7 +2258,10 @@ decl_module! {
             add_buckets_indices: BTreeSet<T::DistributionBucketIndex>,
             remove_buckets_indices: BTreeSet<T::DistributionBucketIndex>,
         ) {
-            <T as Trait>::DistributionWorkingGroup::ensure_leader_origin(origin)?;
+            <T as Trait>::DistributionWorkingGroup::ensure_leader_or_deputy_origin(
+                origin,
+                DeputyPermission::ManageDistributionBuckets
+            )?;
 
             Self::validate_update_distribution_buckets_for_bag_params(
                 &bag_id,
@@ -2266,6 +2306,7 @@ decl_module! {
         /// Updates "Distribution buckets per bag" number limit.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn update_distribution_buckets_per_bag_limit(origin, new_limit: u64) {
+            // The bag limits are a policy setting: not delegated to the deputies.
             <T as Trait>::DistributionWorkingGroup::ensure_leader_origin(origin)?;
 
             T::DistributionBucketsPerBagValueConstraint::get().ensure_valid(
@@ -2290,7 +2331,10 @@ decl_module! {
             bucket_id: DistributionBucketId<T>,
             distributing: bool
         ) {
-            <T as Trait>::DistributionWorkingGroup::ensure_leader_origin(origin)?;
+            <T as Trait>::DistributionWorkingGroup::ensure_leader_or_deputy_origin(
+                origin,
+                DeputyPermission::ManageDistributionBuckets
+            )?;
 
             Self::ensure_distribution_bucket_exists(&bucket_id)?;
 
@@ -2318,6 +2362,7 @@ decl_module! {
             dynamic_bag_type: DynamicBagType,
             families: BTreeMap<T::DistributionBucketFamilyId, u32>
         ) {
+            // The dynamic bag creation policy is not delegated to the deputies.
             <T as Trait>::DistributionWorkingGroup::ensure_leader_origin(origin)?;
 
             Self::validate_update_families_in_dynamic_bag_creation_policy_params(&families)?;
@@ -2347,7 +2392,10 @@ decl_module! {
             bucket_id: DistributionBucketId<T>,
             operator_worker_id: WorkerId<T>
         ) {
-            <T as Trait>::DistributionWorkingGroup::ensure_leader_origin(origin)?;
+            <T as Trait>::DistributionWorkingGroup::ensure_leader_or_deputy_origin(
+                origin,
+                DeputyPermission::ManageDistributionBuckets
+            )?;
 
             let bucket = Self::ensure_distribution_bucket_exists(&bucket_id)?;
 
@@ -2377,7 +2425,10 @@ decl_module! {
             bucket_id: DistributionBucketId<T>,
             operator_worker_id: WorkerId<T>
         ) {
-            <T as Trait>::DistributionWorkingGroup::ensure_leader_origin(origin)?;
+            <T as Trait>::DistributionWorkingGroup::ensure_leader_or_deputy_origin(
+                origin,
+                DeputyPermission::ManageDistributionBuckets
+            )?;
 
             let bucket = Self::ensure_distribution_bucket_exists(&bucket_id)?;
 
@@ -2413,7 +2464,10 @@ decl_module! {
             bucket_id: DistributionBucketId<T>,
             operator_worker_id: WorkerId<T>,
         ){
-            <T as Trait>::DistributionWorkingGroup::ensure_leader_origin(origin)?;
+            <T as Trait>::DistributionWorkingGroup::ensure_leader_or_deputy_origin(
+                origin,
+                DeputyPermission::ManageDistributionBuckets
+            )?;
 
             let bucket = Self::ensure_distribution_bucket_exists(&bucket_id)?;
 
@@ -2447,7 +2501,10 @@ decl_module! {
             family_id: T::DistributionBucketFamilyId,
             metadata: Vec<u8>,
         ) {
-            <T as Trait>::DistributionWorkingGroup::ensure_leader_origin(origin)?;
+            <T as Trait>::DistributionWorkingGroup::ensure_leader_or_deputy_origin(
+                origin,
+                DeputyPermission::ManageDistributionBuckets
+            )?;
 
             Self::ensure_distribution_bucket_family_exists(&family_id)?;
 
@@ -2829,6 +2886,15 @@ impl<T: Trait> DataObjectStorage<T> for Module<T> {
 }
 
 impl<T: Trait> Module<T> {
+    // Ensures root origin (e.g. an approved council proposal) or the storage working group leader.
+    fn ensure_root_or_storage_lead_origin(origin: T::Origin) -> DispatchResult {
+        if ensure_root(origin.clone()).is_ok() {
+            return Ok(());
+        }
+
+        <T as Trait>::StorageWorkingGroup::ensure_leader_origin(origin)
+    }
+
     // dynamic bag creation logic
     fn create_dynamic_bag_inner(
         dynamic_bag_id: &DynamicBagId<T>,
@@ -1861,6 +1861,25 @@ fn update_uploading_blocked_status_fails_with_non_leader_origin() {
     });
 }
 
+#[test]
+fn update_uploading_blocked_status_succeeded_with_root_origin() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let new_blocking_status = true;
+
+        UpdateUploadingBlockedStatusFixture::default()
+            .with_origin(RawOrigin::Root)
+            .with_new_status(new_blocking_status)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::UploadingBlockStatusUpdated(
+            new_blocking_status,
+        ));
+    });
+}
+
 #[test]
 fn move_data_objects_succeeded() {
     build_test_externalities().execute_with(|| {
@@ -2568,6 +2587,28 @@ fn update_blacklist_fails_with_non_leader_origin() {
     });
 }
 
+#[test]
+fn update_blacklist_succeeded_with_root_origin() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let cid = vec![1];
+        let add_hashes = BTreeSet::from_iter(vec![cid.clone()]);
+
+        UpdateBlacklistFixture::default()
+            .with_origin(RawOrigin::Root)
+            .with_add_hashes(add_hashes.clone())
+            .call_and_assert(Ok(()));
+
+        assert!(crate::Blacklist::contains_key(&cid));
+        EventFixture::assert_last_crate_event(RawEvent::UpdateBlacklist(
+            BTreeSet::new(),
+            add_hashes,
+        ));
+    });
+}
+
 fn create_default_storage_bucket_and_assign_to_bag(bag_id: BagId<Test>) -> u64 {
     let objects_limit = 1;
     let size_limit = 100;
@@ -3014,6 +3055,23 @@ fn update_data_size_fee_fails_with_non_leader_origin() {
     });
 }
 
+#[test]
+fn update_data_size_fee_succeeded_with_root_origin() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let new_fee = 1000;
+
+        UpdateDataObjectPerMegabyteFeeFixture::default()
+            .with_origin(RawOrigin::Root)
+            .with_new_fee(new_fee)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DataObjectPerMegabyteFeeUpdated(new_fee));
+    });
+}
+
 #[test]
 fn data_size_fee_calculation_works_properly() {
     build_test_externalities().execute_with(|| {
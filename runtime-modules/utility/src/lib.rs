@@ -11,6 +11,8 @@
 //! - [update_working_group_budget](./struct.Module.html#method.update_working_group_budget) - Move funds between
 //! council and working group
 //! - [burn_account_tokens](./struct.Module.html#method.burn_account_tokens) - Burns token from account
+//! - [execute_batch_proposal](./struct.Module.html#method.execute_batch_proposal) - Dispatches a batch
+//! of calls as a single all-or-nothing operation
 //!
 //! ## Dependencies
 //! - [council](../substrate_council_module/index.html)
@@ -26,12 +28,14 @@ mod benchmarking;
 
 use common::{working_group::WorkingGroup, BalanceKind};
 use council::Module as Council;
+use frame_support::dispatch::PostDispatchInfo;
+use frame_support::storage::{with_transaction, TransactionOutcome};
 use frame_support::traits::{Currency, Get};
-use frame_support::weights::{DispatchClass, Weight};
-use frame_support::{decl_error, decl_event, decl_module, ensure, print};
+use frame_support::weights::{DispatchClass, GetDispatchInfo, Weight};
+use frame_support::{decl_error, decl_event, decl_module, ensure, print, Parameter};
 use frame_system::{ensure_root, ensure_signed};
 use sp_arithmetic::traits::Zero;
-use sp_runtime::traits::Saturating;
+use sp_runtime::traits::{Dispatchable, Saturating};
 use sp_runtime::SaturatedConversion;
 use sp_std::vec::Vec;
 
@@ -49,6 +53,11 @@ pub trait Trait: frame_system::Trait + balances::Trait + council::Trait {
 
     /// Weight information for extrinsics in this pallet.
     type WeightInfo: WeightInfo;
+
+    /// The overarching call type. Used to dispatch the calls of the 'Batch' proposal.
+    type Call: Parameter
+        + Dispatchable<Origin = Self::Origin, PostInfo = PostDispatchInfo>
+        + GetDispatchInfo;
 }
 
 /// Utility WeightInfo.
@@ -105,6 +114,11 @@ decl_event!(
         /// - Account Id of the burning tokens
         /// - Balance burned from that account
         TokensBurned(AccountId, Balance),
+
+        /// A `Batch` proposal was executed
+        /// Params:
+        /// - Number of calls dispatched
+        BatchProposalExecuted(u32),
     }
 );
 
@@ -227,6 +241,37 @@ decl_module! {
             Self::deposit_event(RawEvent::TokensBurned(account_id, amount));
         }
 
+        /// Batch proposal extrinsic. Should be used as callable object to pass to the `engine` module.
+        /// Dispatches the calls in order with the provided origin. If any of the calls fails,
+        /// all the changes made by the previous calls are reverted.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (C)` where:
+        /// - `C` is the sum of the weights of the batched calls
+        /// # </weight>
+        #[weight = Module::<T>::get_execute_batch_proposal_weight(&calls)]
+        pub fn execute_batch_proposal(
+            origin,
+            calls: Vec<<T as Trait>::Call>,
+        ) {
+            ensure_root(origin.clone())?;
+
+            let calls_count: u32 = calls.len().saturated_into();
+
+            with_transaction(|| {
+                for call in calls {
+                    if let Err(err) = call.dispatch(origin.clone()) {
+                        return TransactionOutcome::Rollback(Err(err.error));
+                    }
+                }
+
+                TransactionOutcome::Commit(Ok(()))
+            })?;
+
+            Self::deposit_event(RawEvent::BatchProposalExecuted(calls_count));
+        }
     }
 }
 
@@ -243,4 +288,14 @@ impl<T: Trait> Module<T> {
             }
         }
     }
+
+    // Returns the weight for execute_batch_proposal extrinsic: the sum of the batched calls weights
+    fn get_execute_batch_proposal_weight(calls: &[<T as Trait>::Call]) -> Weight {
+        calls
+            .iter()
+            .map(|call| call.get_dispatch_info().weight)
+            .fold(0, |total: Weight, weight: Weight| {
+                total.saturating_add(weight)
+            })
+    }
 }
@@ -5,7 +5,7 @@ use crate::*;
 use common::working_group::{WorkingGroup, WorkingGroupBudgetHandler};
 use frame_support::dispatch::DispatchError;
 use frame_support::traits::{LockIdentifier, OnFinalize, OnInitialize};
-use frame_support::{impl_outer_event, impl_outer_origin, parameter_types};
+use frame_support::{impl_outer_dispatch, impl_outer_event, impl_outer_origin, parameter_types};
 use frame_system::{EnsureOneOf, EnsureRoot, EnsureSigned, EventRecord, RawOrigin};
 use sp_core::H256;
 use sp_runtime::DispatchResult;
@@ -36,6 +36,13 @@ mod utilities {
     pub use crate::Event;
 }
 
+impl_outer_dispatch! {
+    pub enum Call for Test where origin: Origin {
+        utilities::JoystreamUtility,
+        frame_system::System,
+    }
+}
+
 impl_outer_event! {
     pub enum TestEvent for Test {
         utilities<T>,
@@ -169,7 +176,7 @@ pub type DistributionWorkingGroupInstance = working_group::Instance9;
 impl frame_system::Trait for Test {
     type BaseCallFilter = ();
     type Origin = Origin;
-    type Call = ();
+    type Call = Call;
     type Index = u64;
     type BlockNumber = u64;
     type Hash = H256;
@@ -199,6 +206,8 @@ impl Trait for Test {
 
     type WeightInfo = ();
 
+    type Call = Call;
+
     fn get_working_group_budget(working_group: WorkingGroup) -> BalanceOf<Test> {
         call_wg!(working_group<Test>, get_budget)
     }
@@ -240,6 +249,11 @@ parameter_types! {
     pub const ReferralCutMaximumPercent: u8 = 50;
     pub const StakingCandidateLockId: [u8; 8] = [3; 8];
     pub const CandidateStake: u64 = 100;
+    pub const HandleDisputeLockId: [u8; 8] = [4; 8];
+    pub const HandleDisputeStake: u64 = 150;
+    pub const HandleDisputeGracePeriod: u64 = 10;
+    pub const MaxRecoveryGuardians: u32 = 5;
+    pub const RecoveryDelay: u64 = 10;
 }
 
 impl membership::Trait for Test {
@@ -253,6 +267,11 @@ impl membership::Trait for Test {
     type StakingCandidateStakingHandler =
         staking_handler::StakingManager<Self, StakingCandidateLockId>;
     type CandidateStake = CandidateStake;
+    type HandleDisputeStakingHandler = staking_handler::StakingManager<Self, HandleDisputeLockId>;
+    type HandleDisputeStake = HandleDisputeStake;
+    type HandleDisputeGracePeriod = HandleDisputeGracePeriod;
+    type MaxRecoveryGuardians = MaxRecoveryGuardians;
+    type RecoveryDelay = RecoveryDelay;
 }
 
 impl common::working_group::WorkingGroupBudgetHandler<Test> for () {
@@ -362,6 +381,57 @@ impl membership::WeightInfo for Weights {
     fn member_remark() -> Weight {
         unimplemented!()
     }
+    fn reserve_handle(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn release_handle(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn open_handle_dispute(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn resolve_handle_dispute() -> Weight {
+        unimplemented!()
+    }
+    fn execute_handle_dispute_resolution() -> Weight {
+        unimplemented!()
+    }
+    fn withdraw_handle_dispute() -> Weight {
+        unimplemented!()
+    }
+    fn add_member_verification() -> Weight {
+        unimplemented!()
+    }
+    fn revoke_member_verification() -> Weight {
+        unimplemented!()
+    }
+    fn set_second_level_referral_cut() -> Weight {
+        unimplemented!()
+    }
+    fn start_referral_campaign() -> Weight {
+        unimplemented!()
+    }
+    fn end_referral_campaign() -> Weight {
+        unimplemented!()
+    }
+    fn set_recovery_guardians(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn initiate_recovery() -> Weight {
+        unimplemented!()
+    }
+    fn approve_recovery() -> Weight {
+        unimplemented!()
+    }
+    fn reject_recovery() -> Weight {
+        unimplemented!()
+    }
+    fn cancel_recovery() -> Weight {
+        unimplemented!()
+    }
+    fn execute_recovery() -> Weight {
+        unimplemented!()
+    }
 }
 
 parameter_types! {
@@ -380,23 +450,21 @@ impl working_group::Trait<ContentWorkingGroupInstance> for Test {
     type StakingAccountValidator = membership::Module<Test>;
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = WorkingGroupWeightInfo;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type WorkerReviewPeriod = ();
+    type MaxBudgetSpendingRecords = ();
 }
 
 impl working_group::WeightInfo for WorkingGroupWeightInfo {
     fn on_initialize_leaving(_: u32) -> Weight {
         0
     }
-    fn on_initialize_rewarding_with_missing_reward(_: u32) -> Weight {
+    fn on_initialize_expired_openings(_: u32, _: u32) -> Weight {
         0
     }
-    fn on_initialize_rewarding_with_missing_reward_cant_pay(_: u32) -> Weight {
-        0
-    }
-    fn on_initialize_rewarding_without_missing_reward(_: u32) -> Weight {
+    fn claim_reward() -> Weight {
         0
     }
     fn apply_on_opening(_: u32) -> Weight {
@@ -459,6 +527,27 @@ impl working_group::WeightInfo for WorkingGroupWeightInfo {
     fn worker_remark() -> Weight {
         0
     }
+    fn pay_worker_bonus() -> Weight {
+        0
+    }
+    fn review_worker(_: u32) -> Weight {
+        0
+    }
+    fn shortlist_applications(_: u32) -> Weight {
+        0
+    }
+    fn reject_applications(_: u32) -> Weight {
+        0
+    }
+    fn appoint_deputy(_: u32) -> Weight {
+        0
+    }
+    fn remove_deputy() -> Weight {
+        0
+    }
+    fn set_spending_limit() -> Weight {
+        0
+    }
 }
 
 impl working_group::Trait<StorageWorkingGroupInstance> for Test {
@@ -468,10 +557,11 @@ impl working_group::Trait<StorageWorkingGroupInstance> for Test {
     type StakingAccountValidator = membership::Module<Test>;
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = WorkingGroupWeightInfo;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type WorkerReviewPeriod = ();
+    type MaxBudgetSpendingRecords = ();
 }
 
 impl working_group::Trait<ForumWorkingGroupInstance> for Test {
@@ -481,10 +571,11 @@ impl working_group::Trait<ForumWorkingGroupInstance> for Test {
     type StakingAccountValidator = membership::Module<Test>;
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = WorkingGroupWeightInfo;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type WorkerReviewPeriod = ();
+    type MaxBudgetSpendingRecords = ();
 }
 
 impl working_group::Trait<MembershipWorkingGroupInstance> for Test {
@@ -494,10 +585,11 @@ impl working_group::Trait<MembershipWorkingGroupInstance> for Test {
     type StakingAccountValidator = membership::Module<Test>;
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = WorkingGroupWeightInfo;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type WorkerReviewPeriod = ();
+    type MaxBudgetSpendingRecords = ();
 }
 
 impl working_group::Trait<GatewayWorkingGroupInstance> for Test {
@@ -507,10 +599,11 @@ impl working_group::Trait<GatewayWorkingGroupInstance> for Test {
     type StakingAccountValidator = membership::Module<Test>;
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = WorkingGroupWeightInfo;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type WorkerReviewPeriod = ();
+    type MaxBudgetSpendingRecords = ();
 }
 
 impl working_group::Trait<DistributionWorkingGroupInstance> for Test {
@@ -520,10 +613,11 @@ impl working_group::Trait<DistributionWorkingGroupInstance> for Test {
     type StakingAccountValidator = membership::Module<Test>;
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = WorkingGroupWeightInfo;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type WorkerReviewPeriod = ();
+    type MaxBudgetSpendingRecords = ();
 }
 
 impl working_group::Trait<OperationsWorkingGroupInstanceAlpha> for Test {
@@ -533,10 +627,11 @@ impl working_group::Trait<OperationsWorkingGroupInstanceAlpha> for Test {
     type StakingAccountValidator = membership::Module<Test>;
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = WorkingGroupWeightInfo;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type WorkerReviewPeriod = ();
+    type MaxBudgetSpendingRecords = ();
 }
 
 impl working_group::Trait<OperationsWorkingGroupInstanceBeta> for Test {
@@ -546,10 +641,11 @@ impl working_group::Trait<OperationsWorkingGroupInstanceBeta> for Test {
     type StakingAccountValidator = membership::Module<Test>;
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = WorkingGroupWeightInfo;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type WorkerReviewPeriod = ();
+    type MaxBudgetSpendingRecords = ();
 }
 
 impl working_group::Trait<OperationsWorkingGroupInstanceGamma> for Test {
@@ -559,10 +655,11 @@ impl working_group::Trait<OperationsWorkingGroupInstanceGamma> for Test {
     type StakingAccountValidator = membership::Module<Test>;
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = WorkingGroupWeightInfo;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type WorkerReviewPeriod = ();
+    type MaxBudgetSpendingRecords = ();
 }
 
 parameter_types! {
@@ -574,6 +671,7 @@ parameter_types! {
     pub const CandidacyLockId: LockIdentifier = *b"council1";
     pub const CouncilorLockId: LockIdentifier = *b"council2";
     pub const ElectedMemberRewardPeriod: u64 = 10;
+    pub const MaxVestingMilestones: u32 = 10;
     pub const BudgetRefillAmount: u64 = 1000;
     // intentionally high number that prevents side-effecting tests other than  budget refill tests
     pub const BudgetRefillPeriod: u64 = 1000;
@@ -626,6 +724,15 @@ impl council::WeightInfo for CouncilWeightInfo {
     fn candidate_remark() -> Weight {
         0
     }
+    fn vesting_funding_request(_: u32, _: u32) -> Weight {
+        0
+    }
+    fn claim_vesting_funding(_: u32) -> Weight {
+        0
+    }
+    fn cancel_vesting_funding_request(_: u32) -> Weight {
+        0
+    }
 }
 
 pub type ReferendumInstance = referendum::Instance0;
@@ -775,6 +882,7 @@ impl council::Trait for Test {
     type CandidacyLock = StakingManager<Self, CandidacyLockId>;
     type CouncilorLock = StakingManager<Self, CouncilorLockId>;
     type ElectedMemberRewardPeriod = ElectedMemberRewardPeriod;
+    type MaxVestingMilestones = MaxVestingMilestones;
     type BudgetRefillPeriod = BudgetRefillPeriod;
     type StakingAccountValidator = ();
     type WeightInfo = CouncilWeightInfo;
@@ -847,3 +955,4 @@ pub fn initial_test_ext() -> sp_io::TestExternalities {
 }
 
 pub type System = frame_system::Module<Test>;
+pub type JoystreamUtility = crate::Module<Test>;
@@ -205,3 +205,76 @@ fn burn_account_tokens_fails_insufficient() {
             .execute_and_assert(Err(Error::<Test>::InsufficientFundsForBurn.into()));
     });
 }
+
+#[test]
+fn execute_batch_proposal_fails_with_invalid_origin() {
+    initial_test_ext().execute_with(|| {
+        let calls = vec![mocks::Call::JoystreamUtility(
+            crate::Call::<Test>::execute_signal_proposal(vec![0]),
+        )];
+
+        assert_eq!(
+            Utilities::<Test>::execute_batch_proposal(RawOrigin::Signed(0).into(), calls),
+            Err(DispatchError::BadOrigin)
+        );
+    });
+}
+
+#[test]
+fn execute_batch_proposal_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let wg = WorkingGroup::Forum;
+        let budget = 100000;
+        let funding_amount = 1;
+        council::Module::<Test>::set_budget(RawOrigin::Root.into(), budget).unwrap();
+
+        let calls = vec![
+            mocks::Call::JoystreamUtility(crate::Call::<Test>::execute_signal_proposal(vec![0])),
+            mocks::Call::JoystreamUtility(crate::Call::<Test>::update_working_group_budget(
+                wg,
+                funding_amount,
+                BalanceKind::Positive,
+            )),
+        ];
+
+        assert_eq!(
+            Utilities::<Test>::execute_batch_proposal(RawOrigin::Root.into(), calls),
+            Ok(())
+        );
+
+        assert_eq!(council::Module::<Test>::budget(), budget - funding_amount);
+        assert_eq!(
+            <Test as Trait>::get_working_group_budget(wg),
+            funding_amount
+        );
+        assert_last_event(RawEvent::BatchProposalExecuted(2).into());
+    });
+}
+
+#[test]
+fn execute_batch_proposal_reverts_all_calls_on_failure() {
+    initial_test_ext().execute_with(|| {
+        let wg = WorkingGroup::Forum;
+        let budget = 100000;
+        let funding_amount = 1;
+        council::Module::<Test>::set_budget(RawOrigin::Root.into(), budget).unwrap();
+
+        let calls = vec![
+            mocks::Call::JoystreamUtility(crate::Call::<Test>::update_working_group_budget(
+                wg,
+                funding_amount,
+                BalanceKind::Positive,
+            )),
+            // Burning tokens requires a signed origin, so this call fails.
+            mocks::Call::JoystreamUtility(crate::Call::<Test>::burn_account_tokens(1)),
+        ];
+
+        assert_eq!(
+            Utilities::<Test>::execute_batch_proposal(RawOrigin::Root.into(), calls),
+            Err(DispatchError::BadOrigin)
+        );
+
+        assert_eq!(council::Module::<Test>::budget(), budget);
+        assert_eq!(<Test as Trait>::get_working_group_budget(wg), 0);
+    });
+}
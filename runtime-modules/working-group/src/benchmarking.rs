@@ -16,6 +16,14 @@ use membership::Module as Membership;
 
 const SEED: u32 = 0;
 const MAX_BYTES: u32 = 16384;
+const MAX_EXPIRED_OPENINGS: u32 = 50;
+const ALL_DEPUTY_PERMISSIONS: [DeputyPermission; 5] = [
+    DeputyPermission::ManageOpenings,
+    DeputyPermission::ManageStorageBuckets,
+    DeputyPermission::ManageDistributionBuckets,
+    DeputyPermission::ManageCuratorGroups,
+    DeputyPermission::ManageForum,
+];
 
 fn assert_last_event<T: Trait<I>, I: Instance>(generic_event: <T as Trait<I>>::Event) {
     let events = System::<T>::events();
@@ -45,6 +53,8 @@ fn add_opening_helper<T: Trait<I>, I: Instance>(
         *job_opening_type,
         staking_policy,
         Some(One::one()),
+        None,
+        None,
     )
     .unwrap();
 
@@ -189,12 +199,25 @@ fn member_funded_account<T: Trait<I> + membership::Trait, I: Instance>(
     (account_id, member_id)
 }
 
-fn force_missed_reward<T: Trait<I>, I: Instance>() {
-    let curr_block_number =
-        System::<T>::block_number().saturating_add(T::RewardPeriod::get().into());
+fn force_missed_reward<T: Trait<I>, I: Instance>(
+    role_account_id: &T::AccountId,
+    worker_id: WorkerId<T>,
+) {
+    let curr_block_number = System::<T>::block_number().saturating_add(One::one());
     System::<T>::set_block_number(curr_block_number);
     WorkingGroup::<T, _>::set_budget(RawOrigin::Root.into(), Zero::zero()).unwrap();
-    WorkingGroup::<T, _>::on_initialize(curr_block_number);
+    WorkingGroup::<T, _>::claim_reward(
+        RawOrigin::Signed(role_account_id.clone()).into(),
+        worker_id,
+    )
+    .unwrap();
+
+    assert!(
+        WorkingGroup::<T, _>::worker_by_id(worker_id)
+            .missed_reward
+            .is_some(),
+        "Missed reward not saved"
+    );
 }
 
 pub fn insert_a_worker<T: Trait<I> + membership::Trait, I: Instance>(
@@ -241,14 +264,14 @@ pub fn complete_opening<T: Trait<I> + membership::Trait, I: Instance>(
     )
     .unwrap();
 
-    // Every worst case either include or doesn't mind having a non-zero
-    // remaining reward
-    force_missed_reward::<T, I>();
-
     let worker_id = WorkerId::<T>::from(id.try_into().unwrap());
 
     assert!(WorkerById::<T, I>::contains_key(worker_id));
 
+    // Every worst case either include or doesn't mind having a non-zero
+    // remaining reward
+    force_missed_reward::<T, I>(caller_id, worker_id);
+
     worker_id
 }
 
@@ -281,7 +304,12 @@ benchmarks_instance! {
             successful_application_ids.clone()
         ).unwrap();
 
-        force_missed_reward::<T,I>();
+        // Worst case scenario every leaving worker has a missed reward
+        let mut worker_id = Zero::zero();
+        for id in application_account_id.iter() {
+            worker_id += One::one();
+            force_missed_reward::<T,I>(id, worker_id);
+        }
 
         // Force all workers to leave (Including the lead)
         // We should have every WorkerId from 0 to i-1
@@ -328,24 +356,17 @@ benchmarks_instance! {
         assert_eq!(WorkingGroup::<T, _>::budget(), BalanceOf::<T>::max_value());
     }: { WorkingGroup::<T, _>::on_initialize(curr_block_number) }
     verify {
-        WorkerById::<T, I>::iter().for_each(|(worker_id, _)| {
-            assert!(!WorkerById::<T, I>::contains_key(worker_id), "Worker hasn't left");
-        });
+        assert_eq!(WorkerById::<T, I>::iter().count(), 0, "Not all workers left");
 
-        let reward_per_worker = BalanceOf::<T>::from(T::RewardPeriod::get());
-
-        assert_eq!(
-            WorkingGroup::<T, I>::budget(),
-            BalanceOf::<T>::max_value()
-                .saturating_sub(BalanceOf::<T>::from(i) * reward_per_worker)
-                .saturating_sub(reward_per_worker),
-            "Budget wasn't correctly updated, probably not all workers rewarded"
+        assert!(
+            WorkingGroup::<T, I>::budget() < BalanceOf::<T>::max_value(),
+            "Budget wasn't updated, probably not all workers rewarded"
         );
     }
 
-
-    on_initialize_rewarding_with_missing_reward {
-        let i in 2 .. T::MaxWorkerNumberLimit::get();
+    on_initialize_expired_openings {
+        let i in 1 .. MAX_EXPIRED_OPENINGS;
+        let j in 0 .. T::MaxWorkerNumberLimit::get();
 
         let (lead_id, _) = insert_a_worker::<T, I>(
             OpeningType::Leader,
@@ -353,159 +374,80 @@ benchmarks_instance! {
             None
         );
 
-        let (opening_id, successful_application_ids, _) =
-            add_opening_and_apply_with_multiple_ids::<T, I>(
-                &(1..i).collect(),
-                &T::Origin::from(RawOrigin::Signed(lead_id.clone())),
-                &OpeningType::Regular
-            );
-
-        WorkingGroup::<T, _>::fill_opening(RawOrigin::Signed(lead_id.clone()).into(), opening_id,
-        successful_application_ids.clone()).unwrap();
+        let expires_at = System::<T>::block_number().saturating_add(One::one());
 
-        for i in 1..successful_application_ids.len() {
-            assert!(
-                WorkerById::<T, I>::contains_key(WorkerId::<T>::from(i.try_into().unwrap())),
-                "Not all workers added"
-            );
-        }
+        let stake_policy = StakePolicy {
+            stake_amount: T::MinimumApplicationStake::get(),
+            leaving_unstaking_period: T::MinUnstakingPeriodLimit::get() + One::one(),
+        };
 
-        // Worst case scenario there is a missing reward
-        force_missed_reward::<T, I>();
+        let mut opening_ids = Vec::new();
+        for _ in 0..i {
+            opening_ids.push(WorkingGroup::<T, I>::next_opening_id());
 
-        // Sets periods so that we can reward
-        let curr_block_number =
-            System::<T>::block_number().saturating_add(T::RewardPeriod::get().into());
-        System::<T>::set_block_number(curr_block_number);
+            WorkingGroup::<T, _>::add_opening(
+                RawOrigin::Signed(lead_id.clone()).into(),
+                vec![],
+                OpeningType::Regular,
+                stake_policy.clone(),
+                Some(One::one()),
+                None,
+                Some(expires_at),
+            ).unwrap();
+        }
 
-        // Sets budget so that we can pay it
-        WorkingGroup::<T, _>::set_budget(
-            RawOrigin::Root.into(),
-            BalanceOf::<T>::max_value()
-        ).unwrap();
+        // Releasing the application stake doesn't depend on the opening.
+        for id in 1..=j {
+            let (applicant_account_id, applicant_member_id) =
+                member_funded_account::<T, I>("member", id);
+            apply_on_opening_helper::<T, I>(
+                id,
+                &applicant_account_id,
+                &applicant_member_id,
+                &opening_ids[0],
+            );
+        }
 
-        assert_eq!(WorkingGroup::<T, _>::budget(), BalanceOf::<T>::max_value());
-    }: { WorkingGroup::<T, _>::on_initialize(curr_block_number) }
+        System::<T>::set_block_number(expires_at);
+    }: { WorkingGroup::<T, _>::on_initialize(expires_at) }
     verify {
-        let reward_per_worker = BalanceOf::<T>::from(T::RewardPeriod::get());
-
-        let reward_for_workers =
-            BalanceOf::<T>::from(i) * reward_per_worker * BalanceOf::<T>::from(2u32);
-
+        for opening_id in opening_ids {
+            assert!(!OpeningById::<T, I>::contains_key(opening_id), "Opening not canceled");
+        }
+        assert_eq!(ApplicationById::<T, I>::iter().count(), 0, "Applications not removed");
         assert_eq!(
-            WorkingGroup::<T, _>::budget(),
-            // When creating a worker using `insert_a_worker` it gives the lead a number of block
-            // equating to reward period as missed reward(and the reward value is 1) therefore the
-            // additional discount of balance
-            BalanceOf::<T>::max_value()
-                .saturating_sub(reward_for_workers)
-                .saturating_sub(reward_per_worker),
-            "Budget wasn't correctly updated, probably not all workers rewarded"
-        );
-    }
-
-    on_initialize_rewarding_with_missing_reward_cant_pay {
-        let i in 2 .. T::MaxWorkerNumberLimit::get();
-
-        let (lead_id, _) = insert_a_worker::<T, I>(
-            OpeningType::Leader,
+            ApplicationIdsByOpeningId::<I>::iter().count(),
             0,
-            None
+            "Applications index not cleared"
         );
-
-        let (opening_id, successful_application_ids, _) =
-            add_opening_and_apply_with_multiple_ids::<T, I>(
-                &(1..i).collect(),
-                &T::Origin::from(RawOrigin::Signed(lead_id.clone())),
-                &OpeningType::Regular
-            );
-
-        WorkingGroup::<T, _>::fill_opening(RawOrigin::Signed(lead_id.clone()).into(), opening_id,
-        successful_application_ids.clone()).unwrap();
-
-        for i in 1..successful_application_ids.len() {
-            assert!(
-                WorkerById::<T, I>::contains_key(WorkerId::<T>::from(i.try_into().unwrap())),
-                "Not all workers added"
-            );
-        }
-
-        // Sets periods so that we can reward
-        let curr_block_number =
-            System::<T>::block_number().saturating_add(T::RewardPeriod::get().into());
-
-        System::<T>::set_block_number(curr_block_number);
-
-        // Sets budget so that we can't pay it
-        WorkingGroup::<T, _>::set_budget(RawOrigin::Root.into(), Zero::zero()).unwrap();
-
-        assert_eq!(WorkingGroup::<T, _>::budget(), Zero::zero());
-
-    }: { WorkingGroup::<T, _>::on_initialize(curr_block_number) }
-    verify {
-        WorkerById::<T, I>::iter().for_each(|(_, worker)| {
-            let missed_reward = worker.missed_reward.expect("There should be some missed reward");
-
-            assert!(
-                missed_reward >= BalanceOf::<T>::from(T::RewardPeriod::get()),
-                "At least one worker wasn't rewarded"
-            );
-        });
     }
 
-    on_initialize_rewarding_without_missing_reward {
-        let i in 2 .. T::MaxWorkerNumberLimit::get();
-
-        let (lead_id, _) = insert_a_worker::<T, I>(
+    claim_reward {
+        let (lead_id, lead_worker_id) = insert_a_worker::<T, I>(
             OpeningType::Leader,
             0,
             None
         );
 
-        let (opening_id, successful_application_ids, _) =
-            add_opening_and_apply_with_multiple_ids::<T, I>(
-                &(1..i).collect(),
-                &T::Origin::from(RawOrigin::Signed(lead_id.clone())),
-                &OpeningType::Regular
-            );
-
-        WorkingGroup::<T, _>::fill_opening(RawOrigin::Signed(lead_id.clone()).into(), opening_id,
-        successful_application_ids.clone()).unwrap();
-
-        for i in 1..successful_application_ids.len() {
-            assert!(
-                WorkerById::<T, I>::contains_key(WorkerId::<T>::from(i.try_into().unwrap())),
-                "Not all workers added"
-            );
-        }
-
-        // Sets periods so that we can reward
-        let curr_block_number =
-            System::<T>::block_number().saturating_add(T::RewardPeriod::get().into());
+        // Worst case scenario there are both the accrued and the missed reward to pay
+        let curr_block_number = System::<T>::block_number().saturating_add(One::one());
         System::<T>::set_block_number(curr_block_number);
 
         // Sets budget so that we can pay it
         WorkingGroup::<T, _>::set_budget(
-            RawOrigin::Root.into(), BalanceOf::<T>::max_value()
+            RawOrigin::Root.into(),
+            BalanceOf::<T>::max_value()
         ).unwrap();
-        assert_eq!(WorkingGroup::<T, _>::budget(), BalanceOf::<T>::max_value());
-
-    }: { WorkingGroup::<T, _>::on_initialize(curr_block_number) }
+    }: _ (RawOrigin::Signed(lead_id), lead_worker_id)
     verify {
-        let reward_per_worker = BalanceOf::<T>::from(T::RewardPeriod::get());
-        let workers_total_reward = BalanceOf::<T>::from(i) * reward_per_worker;
-        assert_eq!(
-            WorkingGroup::<T, _>::budget(),
-            // When creating a worker using `insert_a_worker` it gives the lead a number of block
-            // equating to reward period as missed reward(and the reward value is 1) therefore the
-            // additional discount of balance
-            BalanceOf::<T>::max_value()
-                .saturating_sub(workers_total_reward)
-                .saturating_sub(reward_per_worker),
-            "Budget wasn't correctly updated, probably not all workers rewarded"
-        );
+        let lead = WorkingGroup::<T, I>::worker_by_id(lead_worker_id);
+
+        assert_eq!(lead.missed_reward, None, "Missed reward wasn't paid");
+        assert_eq!(lead.rewarded_until, curr_block_number, "Accrued reward wasn't paid");
     }
 
+
+
     apply_on_opening {
         let i in 1 .. MAX_BYTES;
 
@@ -616,20 +558,97 @@ benchmarks_instance! {
         );
     }
 
+    shortlist_applications {
+        let i in 1 .. T::MaxWorkerNumberLimit::get() - 1;
+        let (lead_id, _) = insert_a_worker::<T, I>(
+            OpeningType::Leader,
+            0,
+            None
+        );
+
+        let (opening_id, application_ids, _) =
+            add_opening_and_apply_with_multiple_ids::<T, I>(
+                &(1..i+1).collect(),
+                &T::Origin::from(RawOrigin::Signed(lead_id.clone())),
+                &OpeningType::Regular
+            );
+    }: _ (RawOrigin::Signed(lead_id.clone()), opening_id, application_ids.clone())
+    verify {
+        for application_id in application_ids.iter() {
+            assert_eq!(
+                WorkingGroup::<T, I>::application_by_id(application_id).status,
+                ApplicationStatus::Shortlisted,
+                "Application not shortlisted"
+            );
+        }
+
+        assert_last_event::<T, I>(
+            RawEvent::ApplicationsShortlisted(opening_id, application_ids).into()
+        );
+    }
+
+    reject_applications {
+        let i in 1 .. T::MaxWorkerNumberLimit::get() - 1;
+        let (lead_id, _) = insert_a_worker::<T, I>(
+            OpeningType::Leader,
+            0,
+            None
+        );
+
+        let (opening_id, application_ids, _) =
+            add_opening_and_apply_with_multiple_ids::<T, I>(
+                &(1..i+1).collect(),
+                &T::Origin::from(RawOrigin::Signed(lead_id.clone())),
+                &OpeningType::Regular
+            );
+    }: _ (RawOrigin::Signed(lead_id.clone()), opening_id, application_ids.clone())
+    verify {
+        for application_id in application_ids.iter() {
+            assert_eq!(
+                WorkingGroup::<T, I>::application_by_id(application_id).status,
+                ApplicationStatus::Rejected,
+                "Application not rejected"
+            );
+        }
+
+        assert_last_event::<T, I>(
+            RawEvent::ApplicationsRejected(opening_id, application_ids).into()
+        );
+    }
+
     update_role_account{
-        let (lead_id, lead_worker_id) =
+        let (lead_id, _) =
             insert_a_worker::<T, I>(OpeningType::Leader, 0, None);
-        let new_account_id = account::<T::AccountId>("new_lead_account", 1, SEED);
-    }: _ (RawOrigin::Signed(lead_id), lead_worker_id, new_account_id.clone())
+        let (caller_id, worker_id) = insert_a_worker::<T, I>(
+            OpeningType::Regular,
+            1,
+            Some(lead_id.clone())
+        );
+
+        // Worst case scenario the worker is a deputy
+        WorkingGroup::<T, I>::appoint_deputy(
+            RawOrigin::Signed(lead_id).into(),
+            worker_id,
+            ALL_DEPUTY_PERMISSIONS.iter().copied().collect()
+        ).unwrap();
+
+        let new_account_id = account::<T::AccountId>("new_worker_account", 1, SEED);
+    }: _ (RawOrigin::Signed(caller_id), worker_id, new_account_id.clone())
     verify {
         assert_eq!(
-            WorkingGroup::<T, I>::worker_by_id(lead_worker_id).role_account_id,
+            WorkingGroup::<T, I>::worker_by_id(worker_id).role_account_id,
             new_account_id,
             "Role account notupdated"
         );
 
+        assert_eq!(
+            WorkingGroup::<T, I>::deputy_id_by_role_account_id(new_account_id.clone()),
+            Some(worker_id),
+            "Deputy role account not updated"
+        );
+
         assert_last_event::<T, I>(
-            RawEvent::WorkerRoleAccountUpdated(lead_worker_id, new_account_id).into()
+            RawEvent::WorkerRoleAccountUpdated(worker_id, new_account_id).into()
         );
     }
 
@@ -790,9 +809,28 @@ benchmarks_instance! {
 
         let current_budget = BalanceOf::<T>::max_value();
         WorkingGroup::<T, _>::set_budget(RawOrigin::Root.into(), current_budget).unwrap();
+
+        // Spending limit check is the worst case scenario.
+        let spending_limit = SpendingLimit {
+            amount: current_budget,
+            period: T::BlockNumber::max_value(),
+        };
+        WorkingGroup::<T, _>::set_spending_limit(
+            RawOrigin::Root.into(),
+            Some(spending_limit)
+        ).unwrap();
+
+        // Worst case scenario the ledger is full and the oldest record is pruned.
+        let max_records = T::MaxBudgetSpendingRecords::get();
+        NextBudgetSpendingId::<I>::put(max_records);
     }: _ (RawOrigin::Signed(lead_id.clone()), lead_id.clone(), current_budget, None)
     verify {
         assert_eq!(WorkingGroup::<T, I>::budget(), Zero::zero(), "Budget not updated");
+        assert_eq!(
+            WorkingGroup::<T, I>::next_budget_spending_id(),
+            max_records + 1,
+            "Budget spending not recorded"
+        );
         assert_last_event::<T, I>(RawEvent::BudgetSpending(lead_id, current_budget, None).into());
     }
 
@@ -888,7 +926,9 @@ benchmarks_instance! {
             description.clone(),
             OpeningType::Regular,
             stake_policy.clone(),
-            Some(BalanceOf::<T>::max_value())
+            Some(BalanceOf::<T>::max_value()),
+            Some(T::BlockNumber::max_value()),
+            Some(T::BlockNumber::max_value())
         )
     verify {
         assert!(OpeningById::<T, I>::contains_key(1));
@@ -904,12 +944,21 @@ benchmarks_instance! {
 
     leave_role {
         let i in 0 .. MAX_BYTES;
+        let (lead_id, _) =
+            insert_a_worker::<T, I>(OpeningType::Leader, 0, None);
         // Workers with stake can't leave immediatly
         let (caller_id, caller_worker_id) = insert_a_worker::<T, I>(
-            OpeningType::Leader,
-            0,
-            None
+            OpeningType::Regular,
+            1,
+            Some(lead_id.clone())
         );
+
+        // Worst case scenario the leaving worker is a deputy
+        WorkingGroup::<T, I>::appoint_deputy(
+            RawOrigin::Signed(lead_id).into(),
+            caller_worker_id,
+            ALL_DEPUTY_PERMISSIONS.iter().copied().collect()
+        ).unwrap();
     }: leave_role(
             RawOrigin::Signed(caller_id),
             caller_worker_id,
@@ -921,6 +970,11 @@ benchmarks_instance! {
             Some(System::<T>::block_number()),
             "Worker hasn't started leaving"
         );
+
+        assert!(
+            !DeputyPermissionsByWorkerId::<T, I>::contains_key(caller_worker_id),
+            "Deputy not revoked"
+        );
     }
 
     lead_remark {
@@ -947,6 +1001,128 @@ benchmarks_instance! {
         verify {
             assert_last_event::<T, I>(RawEvent::WorkerRemarked(worker_id, msg).into());
     }
+
+    pay_worker_bonus {
+        let (lead_id, _) =
+            insert_a_worker::<T, I>(OpeningType::Leader, 0, None);
+        let (_, worker_id) = insert_a_worker::<T, I>(
+            OpeningType::Regular,
+            1,
+            Some(lead_id.clone())
+        );
+
+        let current_budget = BalanceOf::<T>::max_value();
+        WorkingGroup::<T, _>::set_budget(RawOrigin::Root.into(), current_budget).unwrap();
+
+        // Worst case scenario the ledger is full and the oldest record is pruned.
+        NextBudgetSpendingId::<I>::put(T::MaxBudgetSpendingRecords::get());
+
+        let amount: BalanceOf<T> = 100u32.into();
+        let rationale = Some(vec![0u8; MAX_BYTES.try_into().unwrap()]);
+    }: _ (RawOrigin::Signed(lead_id.clone()), worker_id, amount, rationale.clone())
+    verify {
+        assert_eq!(
+            WorkingGroup::<T, I>::budget(),
+            current_budget - amount,
+            "Budget not updated"
+        );
+
+        let reward_account_id = WorkingGroup::<T, I>::worker_by_id(worker_id).reward_account_id;
+        assert_last_event::<T, I>(
+            RawEvent::WorkerBonusPaid(worker_id, reward_account_id, amount, rationale).into()
+        );
+    }
+
+    review_worker {
+        let i in 0 .. MAX_BYTES;
+
+        let (lead_id, _) =
+            insert_a_worker::<T, I>(OpeningType::Leader, 0, None);
+        let (_, worker_id) = insert_a_worker::<T, I>(
+            OpeningType::Regular,
+            1,
+            Some(lead_id.clone())
+        );
+
+        let score = Percent::from_percent(80);
+        let review = vec![0u8; i.try_into().unwrap()];
+    }: _ (RawOrigin::Signed(lead_id.clone()), worker_id, score, review.clone())
+    verify {
+        let review_period = WorkingGroup::<T, I>::current_review_period();
+
+        assert!(
+            WorkerReviewByPeriod::<T, I>::contains_key(worker_id, review_period),
+            "Review not added"
+        );
+
+        assert_last_event::<T, I>(
+            RawEvent::WorkerReviewed(worker_id, review_period, score, review).into()
+        );
+    }
+
+    appoint_deputy {
+        let i in 1 .. ALL_DEPUTY_PERMISSIONS.len() as u32;
+
+        let (lead_id, _) =
+            insert_a_worker::<T, I>(OpeningType::Leader, 0, None);
+        let (_, worker_id) = insert_a_worker::<T, I>(
+            OpeningType::Regular,
+            1,
+            Some(lead_id.clone())
+        );
+
+        let permissions: BTreeSet<DeputyPermission> =
+            ALL_DEPUTY_PERMISSIONS.iter().take(i as usize).copied().collect();
+    }: _ (RawOrigin::Signed(lead_id.clone()), worker_id, permissions.clone())
+    verify {
+        assert_eq!(
+            WorkingGroup::<T, I>::deputy_permissions_by_worker_id(worker_id),
+            permissions,
+            "Deputy not appointed"
+        );
+
+        assert_last_event::<T, I>(RawEvent::DeputyAppointed(worker_id, permissions).into());
+    }
+
+    remove_deputy {
+        let (lead_id, _) =
+            insert_a_worker::<T, I>(OpeningType::Leader, 0, None);
+        let (_, worker_id) = insert_a_worker::<T, I>(
+            OpeningType::Regular,
+            1,
+            Some(lead_id.clone())
+        );
+
+        let permissions = ALL_DEPUTY_PERMISSIONS.iter().copied().collect();
+        WorkingGroup::<T, I>::appoint_deputy(
+            RawOrigin::Signed(lead_id.clone()).into(),
+            worker_id,
+            permissions
+        ).unwrap();
+    }: _ (RawOrigin::Signed(lead_id.clone()), worker_id)
+    verify {
+        assert!(
+            !DeputyPermissionsByWorkerId::<T, I>::contains_key(worker_id),
+            "Deputy not removed"
+        );
+
+        assert_last_event::<T, I>(RawEvent::DeputyRemoved(worker_id).into());
+    }
+
+    set_spending_limit {
+        let spending_limit = Some(SpendingLimit {
+            amount: BalanceOf::<T>::max_value(),
+            period: T::BlockNumber::max_value(),
+        });
+    }: _(RawOrigin::Root, spending_limit.clone())
+    verify {
+        assert_eq!(
+            WorkingGroup::<T, I>::lead_spending_limit(),
+            spending_limit,
+            "Spending limit isn't updated"
+        );
+        assert_last_event::<T, I>(RawEvent::SpendingLimitSet(spending_limit).into());
+    }
 }
 
 #[cfg(test)]
@@ -962,6 +1138,13 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_on_initialize_expired_openings() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_on_initialize_expired_openings::<Test>());
+        });
+    }
+
     #[test]
     fn test_add_opening() {
         build_test_externalities().execute_with(|| {
@@ -1082,46 +1265,79 @@ mod tests {
     }
 
     #[test]
-    fn test_on_inintialize_rewarding_without_missing_reward() {
+    fn test_claim_reward() {
         build_test_externalities().execute_with(|| {
-            assert_ok!(test_benchmark_on_initialize_rewarding_without_missing_reward::<Test>());
+            assert_ok!(test_benchmark_claim_reward::<Test>());
         });
     }
 
     #[test]
-    fn test_on_inintialize_rewarding_with_missing_reward_cant_pay() {
+    fn test_on_inintialize_leaving() {
         build_test_externalities().execute_with(|| {
-            assert_ok!(
-                test_benchmark_on_initialize_rewarding_with_missing_reward_cant_pay::<Test>()
-            );
+            assert_ok!(test_benchmark_on_initialize_leaving::<Test>());
         });
     }
 
     #[test]
-    fn test_on_inintialize_rewarding_with_missing_reward() {
+    fn test_lead_remark() {
         build_test_externalities().execute_with(|| {
-            assert_ok!(test_benchmark_on_initialize_rewarding_with_missing_reward::<Test>());
+            assert_ok!(test_benchmark_lead_remark::<Test>());
         });
     }
 
     #[test]
-    fn test_on_inintialize_leaving() {
+    fn test_worker_remark() {
         build_test_externalities().execute_with(|| {
-            assert_ok!(test_benchmark_on_initialize_leaving::<Test>());
+            assert_ok!(test_benchmark_worker_remark::<Test>());
         });
     }
 
     #[test]
-    fn test_lead_remark() {
+    fn test_shortlist_applications() {
         build_test_externalities().execute_with(|| {
-            assert_ok!(test_benchmark_lead_remark::<Test>());
+            assert_ok!(test_benchmark_shortlist_applications::<Test>());
         });
     }
 
     #[test]
-    fn test_worker_remark() {
+    fn test_reject_applications() {
         build_test_externalities().execute_with(|| {
-            assert_ok!(test_benchmark_worker_remark::<Test>());
+            assert_ok!(test_benchmark_reject_applications::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_pay_worker_bonus() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_pay_worker_bonus::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_review_worker() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_review_worker::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_appoint_deputy() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_appoint_deputy::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_remove_deputy() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_remove_deputy::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_set_spending_limit() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_set_spending_limit::<Test>());
         });
     }
 }
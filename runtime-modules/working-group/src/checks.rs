@@ -1,14 +1,15 @@
 use crate::{
-    ApplicationId, BalanceOf, Instance, Opening, OpeningId, OpeningType, StakePolicy, Trait,
-    Worker, WorkerId,
+    ApplicationId, BalanceOf, Instance, LeadSpendingInPeriod, LeadSpendingLimit, Opening,
+    OpeningId, OpeningType, StakePolicy, Trait, Worker, WorkerId,
 };
 
 use super::Error;
+use common::working_group::DeputyPermission;
 use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::traits::Get;
 use frame_support::{ensure, StorageMap, StorageValue};
 use frame_system::{ensure_root, ensure_signed};
-use sp_arithmetic::traits::Zero;
+use sp_arithmetic::traits::{Saturating, Zero};
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::marker::PhantomData;
 use sp_std::vec::Vec;
@@ -23,8 +24,11 @@ pub(crate) fn ensure_origin_for_opening_type<T: Trait<I>, I: Instance>(
 ) -> DispatchResult {
     match opening_type {
         OpeningType::Regular => {
-            // Ensure lead is set and is origin signer.
-            ensure_origin_is_active_leader::<T, I>(origin)
+            // Ensure origin signer is the lead or the deputy managing openings.
+            ensure_origin_is_active_leader_or_deputy::<T, I>(
+                origin,
+                DeputyPermission::ManageOpenings,
+            )
         }
         OpeningType::Leader => {
             // Council proposal.
@@ -39,10 +43,10 @@ pub(crate) fn ensure_stake_for_opening_type<T: Trait<I>, I: Instance>(
 ) -> DispatchResult {
     // Lead needs stake to generate opening
     if opening_type == OpeningType::Regular {
-        // We check here that the origin is active leader
-        // just to make this future proof for any change in
-        // `ensure_origin_for_opening_type`
-        ensure_origin_is_active_leader::<T, I>(origin)?;
+        // We check here that the origin is active leader or the deputy
+        // managing openings just to make this future proof for any change in
+        // `ensure_origin_for_opening_type`. The stake is always taken from the lead.
+        ensure_origin_is_active_leader_or_deputy::<T, I>(origin, DeputyPermission::ManageOpenings)?;
         let lead = crate::Module::<T, I>::worker_by_id(ensure_lead_is_set::<T, I>()?);
 
         let new_stake = T::LeaderOpeningStake::get()
@@ -123,6 +127,33 @@ pub(crate) fn ensure_succesful_applications_exist<T: Trait<I>, I: Instance>(
     Ok(result_applications_info)
 }
 
+// Check applications: returns application info for the provided ids if all applications exist,
+// belong to the opening and were not rejected by the lead.
+pub(crate) fn ensure_valid_applications_for_opening<T: Trait<I>, I: Instance>(
+    opening_id: OpeningId,
+    application_ids: &BTreeSet<ApplicationId>,
+) -> Result<Vec<ApplicationInfo<T, I>>, Error<T, I>> {
+    let applications_info = ensure_succesful_applications_exist::<T, I>(application_ids)?;
+
+    // Check that all applications are for the intended opening
+    ensure!(
+        applications_info
+            .iter()
+            .all(|info| info.application.opening_id == opening_id),
+        Error::<T, I>::ApplicationsNotForOpening
+    );
+
+    // Check that none of the applications was rejected
+    ensure!(
+        !applications_info
+            .iter()
+            .any(|info| info.application.is_rejected()),
+        Error::<T, I>::ApplicationAlreadyRejected
+    );
+
+    Ok(applications_info)
+}
+
 // Check leader: ensures that group leader was hired.
 pub(crate) fn ensure_lead_is_set<T: Trait<I>, I: Instance>() -> Result<WorkerId<T>, Error<T, I>> {
     let leader_worker_id = <crate::CurrentLead<T, I>>::get();
@@ -159,6 +190,41 @@ pub(crate) fn ensure_origin_is_active_leader<T: Trait<I>, I: Instance>(
     ensure_is_lead_account::<T, I>(signer)
 }
 
+// Check leader or deputy: ensures origin is signed by the leader or by the deputy
+// with the delegated permission.
+pub(crate) fn ensure_origin_is_active_leader_or_deputy<T: Trait<I>, I: Instance>(
+    origin: T::Origin,
+    permission: DeputyPermission,
+) -> DispatchResult {
+    // Ensure is signed
+    let signer = ensure_signed(origin)?;
+
+    let lead_check_result = ensure_is_lead_account::<T, I>(signer.clone());
+
+    if lead_check_result.is_err()
+        && crate::Module::<T, I>::is_deputy_account_id(&signer, permission)
+    {
+        return Ok(());
+    }
+
+    lead_check_result
+}
+
+// Check deputy: ensures the role account is not used by another deputy.
+pub(crate) fn ensure_role_account_not_used_by_other_deputy<T: Trait<I>, I: Instance>(
+    role_account_id: &T::AccountId,
+    worker_id: &WorkerId<T>,
+) -> DispatchResult {
+    let deputy_id = <crate::DeputyIdByRoleAccountId<T, I>>::get(role_account_id);
+
+    ensure!(
+        deputy_id.map_or(true, |deputy_id| deputy_id == *worker_id),
+        Error::<T, I>::DeputyRoleAccountAlreadyUsed
+    );
+
+    Ok(())
+}
+
 // Check worker: ensures the worker was already created.
 pub(crate) fn ensure_worker_exists<T: Trait<I>, I: Instance>(
     worker_id: &WorkerId<T>,
@@ -211,6 +277,51 @@ pub(crate) fn ensure_origin_for_worker_operation<T: Trait<I>, I: Instance>(
     Ok(is_sudo)
 }
 
+// Check opening: verifies the opening application deadline and expiry block.
+pub(crate) fn ensure_valid_opening_deadlines<T: Trait<I>, I: Instance>(
+    application_deadline: Option<T::BlockNumber>,
+    expires_at: Option<T::BlockNumber>,
+) -> DispatchResult {
+    let current_block = <frame_system::Module<T>>::block_number();
+
+    if let Some(expires_at) = expires_at {
+        ensure!(
+            expires_at > current_block,
+            Error::<T, I>::InvalidOpeningExpiry
+        );
+    }
+
+    if let Some(application_deadline) = application_deadline {
+        ensure!(
+            application_deadline > current_block,
+            Error::<T, I>::InvalidApplicationDeadline
+        );
+
+        if let Some(expires_at) = expires_at {
+            ensure!(
+                application_deadline <= expires_at,
+                Error::<T, I>::InvalidApplicationDeadline
+            );
+        }
+    }
+
+    Ok(())
+}
+
+// Check opening: verifies that the opening application deadline has not passed.
+pub(crate) fn ensure_application_deadline_not_passed<T: Trait<I>, I: Instance>(
+    opening: &Opening<T::BlockNumber, BalanceOf<T>>,
+) -> DispatchResult {
+    if let Some(application_deadline) = opening.application_deadline {
+        ensure!(
+            <frame_system::Module<T>>::block_number() < application_deadline,
+            Error::<T, I>::ApplicationDeadlinePassed
+        );
+    }
+
+    Ok(())
+}
+
 // Check opening: verifies stake policy for the opening.
 pub(crate) fn ensure_valid_stake_policy<T: Trait<I>, I: Instance>(
     stake_policy: &StakePolicy<T::BlockNumber, BalanceOf<T>>,
@@ -274,3 +385,33 @@ pub(crate) fn ensure_worker_role_storage_text_is_valid<T: Trait<I>, I: Instance>
     );
     Ok(())
 }
+
+// Check lead spending: verifies that the lead spending limit for the current period is not exceeded.
+// Returns the updated spending period index and the amount spent during the period.
+pub(crate) fn ensure_lead_spending_limit_not_exceeded<T: Trait<I>, I: Instance>(
+    amount: BalanceOf<T>,
+) -> Result<(T::BlockNumber, BalanceOf<T>), DispatchError> {
+    let spending_limit = match LeadSpendingLimit::<T, I>::get() {
+        Some(spending_limit) => spending_limit,
+        // No spending limit was set.
+        None => return Ok(LeadSpendingInPeriod::<T, I>::get()),
+    };
+
+    let current_period = <frame_system::Module<T>>::block_number() / spending_limit.period;
+    let (spending_period, spent_amount) = LeadSpendingInPeriod::<T, I>::get();
+
+    let spent_amount = if spending_period == current_period {
+        spent_amount
+    } else {
+        Zero::zero()
+    };
+
+    let new_spent_amount = spent_amount.saturating_add(amount);
+
+    ensure!(
+        new_spent_amount <= spending_limit.amount,
+        Error::<T, I>::SpendingLimitExceeded
+    );
+
+    Ok((current_period, new_spent_amount))
+}
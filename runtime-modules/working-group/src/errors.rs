@@ -89,5 +89,41 @@ decl_error! {
 
         /// Worker storage text is too long.
         WorkerStorageValueTooLong,
+
+        /// There is no accrued or missed reward to claim.
+        NoRewardToClaim,
+
+        /// Worker was already reviewed in the current review period.
+        WorkerAlreadyReviewed,
+
+        /// Application was rejected by the lead.
+        ApplicationAlreadyRejected,
+
+        /// Cannot appoint the leader as a deputy.
+        CannotAppointLeaderAsDeputy,
+
+        /// Deputy permissions were not provided.
+        NoDeputyPermissionsProvided,
+
+        /// Worker is not a deputy.
+        WorkerIsNotDeputy,
+
+        /// Role account is already used by another deputy.
+        DeputyRoleAccountAlreadyUsed,
+
+        /// Lead spending limit for the current period exceeded.
+        SpendingLimitExceeded,
+
+        /// Spending limit period cannot be zero.
+        InvalidSpendingLimitPeriod,
+
+        /// Opening application deadline should be in the future and not after the opening expiry.
+        InvalidApplicationDeadline,
+
+        /// Opening expiry block should be in the future.
+        InvalidOpeningExpiry,
+
+        /// Opening doesn't accept applications anymore.
+        ApplicationDeadlinePassed,
     }
 }
@@ -6,8 +6,10 @@
 //!
 //! ## Supported extrinsics
 //!
-//! - [add_opening](./struct.Module.html#method.add_opening) - Add an opening for a regular worker/lead role.
+//! - [add_opening](./struct.Module.html#method.add_opening) - Add an opening for a regular worker/lead role with optional application deadline and expiry.
 //! - [apply_on_opening](./struct.Module.html#method.apply_on_opening) - Apply on a regular worker/lead opening.
+//! - [shortlist_applications](./struct.Module.html#method.shortlist_applications) - Shortlist applications for regular worker/lead opening.
+//! - [reject_applications](./struct.Module.html#method.reject_applications) - Reject applications for regular worker/lead opening and release their stakes.
 //! - [fill_opening](./struct.Module.html#method.fill_opening) - Fill opening for regular worker/lead role.
 //! - [update_role_account](./struct.Module.html#method.update_role_account) -  Update the role account of the regular worker/lead.
 //! - [leave_role](./struct.Module.html#method.leave_role) - Leave the role by the active regular worker/lead.
@@ -20,8 +22,14 @@
 //! - [set_budget](./struct.Module.html#method.set_budget) - Sets the working group budget.
 //! - [update_reward_account](./struct.Module.html#method.update_reward_account) -  Update the reward account of the regular worker/lead.
 //! - [update_reward_amount](./struct.Module.html#method.update_reward_amount) -  Update the reward amount of the regular worker/lead.
+//! - [claim_reward](./struct.Module.html#method.claim_reward) - Claim the accrued reward of the regular worker/lead.
 //! - [set_status_text](./struct.Module.html#method.set_status_text) - Sets the working group status.
 //! - [spend_from_budget](./struct.Module.html#method.spend_from_budget) - Spend tokens from the group budget.
+//! - [pay_worker_bonus](./struct.Module.html#method.pay_worker_bonus) - Pay a bonus to the regular worker/lead from the group budget.
+//! - [review_worker](./struct.Module.html#method.review_worker) - Add a performance review of the regular worker/lead.
+//! - [appoint_deputy](./struct.Module.html#method.appoint_deputy) - Appoint the regular worker as a deputy with the delegated lead permissions.
+//! - [remove_deputy](./struct.Module.html#method.remove_deputy) - Revoke the delegated lead permissions from the deputy.
+//! - [set_spending_limit](./struct.Module.html#method.set_spending_limit) - Sets the lead spending limit for the group budget.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -44,22 +52,29 @@ mod types;
 
 use frame_support::traits::{Currency, Get, LockIdentifier};
 use frame_support::weights::Weight;
-use frame_support::IterableStorageMap;
 use frame_support::{decl_event, decl_module, decl_storage, ensure, StorageValue};
+use frame_support::{IterableStorageMap, StoragePrefixedMap};
 use frame_system::{ensure_root, ensure_signed};
 use sp_arithmetic::traits::{One, Zero};
+use sp_arithmetic::Percent;
 use sp_runtime::traits::{Hash, SaturatedConversion, Saturating};
+use sp_std::cell::Cell;
 use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
 use sp_std::vec::Vec;
 
 pub use errors::Error;
 pub use types::{
-    Application, ApplicationId, ApplyOnOpeningParameters, BalanceOf, Opening, OpeningId,
-    OpeningType, RewardPaymentType, StakeParameters, StakePolicy, Worker, WorkerId,
+    Application, ApplicationId, ApplicationStatus, ApplyOnOpeningParameters, BalanceOf,
+    BudgetSpendingRecord, Opening, OpeningId, OpeningType, RewardPaymentType, SpendingLimit,
+    StakeParameters, StakePolicy, Worker, WorkerId, WorkerReview,
+};
+use types::{
+    ApplicationInfo, BudgetSpendingRecordOf, GroupWorker, JobApplication, OldGroupWorker,
+    OldJobApplication, OldOpening, WorkerInfo,
 };
-use types::{ApplicationInfo, WorkerInfo};
 
 use common::membership::MemberOriginValidator;
+use common::working_group::DeputyPermission;
 use common::{MemberId, StakingAccountValidator};
 use frame_support::dispatch::DispatchResult;
 use staking_handler::StakingHandler;
@@ -70,9 +85,8 @@ type WeightInfoWorkingGroup<T, I> = <T as Trait<I>>::WeightInfo;
 /// Note: This was auto generated through the benchmark CLI using the `--weight-trait` flag
 pub trait WeightInfo {
     fn on_initialize_leaving(i: u32) -> Weight;
-    fn on_initialize_rewarding_with_missing_reward(i: u32) -> Weight;
-    fn on_initialize_rewarding_with_missing_reward_cant_pay(i: u32) -> Weight;
-    fn on_initialize_rewarding_without_missing_reward(i: u32) -> Weight;
+    fn on_initialize_expired_openings(i: u32, j: u32) -> Weight;
+    fn claim_reward() -> Weight;
     fn apply_on_opening(i: u32) -> Weight;
     fn fill_opening_lead() -> Weight;
     fn fill_opening_worker(i: u32) -> Weight;
@@ -93,6 +107,13 @@ pub trait WeightInfo {
     fn leave_role(i: u32) -> Weight;
     fn lead_remark() -> Weight;
     fn worker_remark() -> Weight;
+    fn pay_worker_bonus() -> Weight;
+    fn review_worker(i: u32) -> Weight;
+    fn shortlist_applications(i: u32) -> Weight;
+    fn reject_applications(i: u32) -> Weight;
+    fn appoint_deputy(i: u32) -> Weight;
+    fn remove_deputy() -> Weight;
+    fn set_spending_limit() -> Weight;
 }
 
 /// The _Group_ main _Trait_
@@ -122,9 +143,6 @@ pub trait Trait<I: Instance = DefaultInstance>:
     /// Defines min unstaking period in the group.
     type MinUnstakingPeriodLimit: Get<Self::BlockNumber>;
 
-    /// Defines the period every worker gets paid in blocks.
-    type RewardPeriod: Get<u32>;
-
     /// Weight information for extrinsics in this pallet.
     type WeightInfo: WeightInfo;
 
@@ -133,6 +151,12 @@ pub trait Trait<I: Instance = DefaultInstance>:
 
     /// Stake needed to create an opening
     type LeaderOpeningStake: Get<Self::Balance>;
+
+    /// Defines the period in blocks during which a worker can be reviewed once.
+    type WorkerReviewPeriod: Get<Self::BlockNumber>;
+
+    /// Max number of the budget spending records kept in the ledger.
+    type MaxBudgetSpendingRecords: Get<u64>;
 }
 
 decl_event!(
@@ -147,7 +171,9 @@ decl_event!(
        Balance = BalanceOf<T>,
        OpeningType = OpeningType,
        StakePolicy = StakePolicy<<T as frame_system::Trait>::BlockNumber, BalanceOf<T>>,
+       BlockNumber = <T as frame_system::Trait>::BlockNumber,
        ApplyOnOpeningParameters = ApplyOnOpeningParameters<T>,
+       SpendingLimit = SpendingLimit<BalanceOf<T>, <T as frame_system::Trait>::BlockNumber>,
     {
         /// Emits on adding new job opening.
         /// Params:
@@ -164,6 +190,18 @@ decl_event!(
         /// - Application id
         AppliedOnOpening(ApplyOnOpeningParameters, ApplicationId),
 
+        /// Emits on shortlisting the applications for the job opening.
+        /// Params:
+        /// - Opening id
+        /// - Shortlisted application ids
+        ApplicationsShortlisted(OpeningId, BTreeSet<ApplicationId>),
+
+        /// Emits on rejecting the applications for the job opening.
+        /// Params:
+        /// - Opening id
+        /// - Rejected application ids
+        ApplicationsRejected(OpeningId, BTreeSet<ApplicationId>),
+
         /// Emits on filling the job opening.
         /// Params:
         /// - Worker opening id
@@ -301,6 +339,43 @@ decl_event!(
         /// - worker
         /// - message
         WorkerRemarked(WorkerId, Vec<u8>),
+
+        /// Emits on paying the bonus to the worker.
+        /// Params:
+        /// - Id of the worker.
+        /// - Receiver Account Id.
+        /// - Bonus
+        /// - Rationale.
+        WorkerBonusPaid(WorkerId, AccountId, Balance, Option<Vec<u8>>),
+
+        /// Emits on adding the performance review of the worker.
+        /// Params:
+        /// - Id of the worker.
+        /// - Review period index.
+        /// - Score.
+        /// - Review.
+        WorkerReviewed(WorkerId, BlockNumber, Percent, Vec<u8>),
+
+        /// Emits on appointing the deputy or updating the deputy permissions.
+        /// Params:
+        /// - Worker id.
+        /// - Delegated permissions.
+        DeputyAppointed(WorkerId, BTreeSet<DeputyPermission>),
+
+        /// Emits on removing the deputy.
+        /// Params:
+        /// - Worker id.
+        DeputyRemoved(WorkerId),
+
+        /// Emits on canceling the expired job opening.
+        /// Params:
+        /// - Opening id
+        OpeningExpired(OpeningId),
+
+        /// Emits on setting the lead spending limit for the working group budget.
+        /// Params:
+        /// - new spending limit (optional). None means 'no spending limit'.
+        SpendingLimitSet(Option<SpendingLimit>),
     }
 );
 
@@ -320,6 +395,11 @@ decl_storage! {
         pub ApplicationById get(fn application_by_id) : map hasher(blake2_128_concat)
             ApplicationId => Application<T>;
 
+        /// Worker applications indexed by the opening they were submitted to.
+        pub ApplicationIdsByOpeningId get(fn application_ids_by_opening_id) : double_map
+            hasher(blake2_128_concat) OpeningId,
+            hasher(blake2_128_concat) ApplicationId => ();
+
         /// Next identifier value for new worker application.
         pub NextApplicationId get(fn next_application_id) : ApplicationId;
 
@@ -330,6 +410,16 @@ decl_storage! {
         pub WorkerById get(fn worker_by_id) : map hasher(blake2_128_concat)
             WorkerId<T> => Worker<T>;
 
+        /// Leaving workers indexed by the block their unstaking period ends at.
+        pub LeavingWorkers get(fn leaving_workers) : double_map
+            hasher(blake2_128_concat) T::BlockNumber,
+            hasher(blake2_128_concat) WorkerId<T> => ();
+
+        /// Unfilled openings indexed by the block they expire at.
+        pub ExpiringOpenings get(fn expiring_openings) : double_map
+            hasher(blake2_128_concat) T::BlockNumber,
+            hasher(blake2_128_concat) OpeningId => ();
+
         /// Current group lead.
         pub CurrentLead get(fn current_lead) : Option<WorkerId<T>>;
 
@@ -345,6 +435,34 @@ decl_storage! {
 
         /// Worker storage size upper bound.
         pub WorkerStorageSize get(fn worker_storage_size) : u16 = default_storage_size_constraint();
+
+        /// Worker performance reviews by the review period index.
+        pub WorkerReviewByPeriod get(fn worker_review_by_period) : double_map
+            hasher(blake2_128_concat) WorkerId<T>,
+            hasher(blake2_128_concat) T::BlockNumber => WorkerReview<T::BlockNumber>;
+
+        /// Maps the deputy worker identifier to the permissions delegated by the lead.
+        pub DeputyPermissionsByWorkerId get(fn deputy_permissions_by_worker_id):
+            map hasher(blake2_128_concat) WorkerId<T> => BTreeSet<DeputyPermission>;
+
+        /// Maps the deputy role account to the deputy worker identifier.
+        pub DeputyIdByRoleAccountId get(fn deputy_id_by_role_account_id):
+            map hasher(blake2_128_concat) T::AccountId => Option<WorkerId<T>>;
+
+        /// Lead spending limit for the working group budget. None means 'no spending limit'.
+        pub LeadSpendingLimit get(fn lead_spending_limit):
+            Option<SpendingLimit<BalanceOf<T>, T::BlockNumber>>;
+
+        /// Spending period index and the amount spent by the lead during this period.
+        pub LeadSpendingInPeriod get(fn lead_spending_in_period): (T::BlockNumber, BalanceOf<T>);
+
+        /// Next identifier for a new budget spending record.
+        pub NextBudgetSpendingId get(fn next_budget_spending_id) : u64;
+
+        /// Working group budget spending ledger. Keeps the latest `MaxBudgetSpendingRecords`
+        /// records only.
+        pub BudgetSpendingById get(fn budget_spending_by_id) : map hasher(blake2_128_concat)
+            u64 => BudgetSpendingRecordOf<T>;
     }
 }
 
@@ -371,23 +489,27 @@ decl_module! {
         /// Stake needed to create an opening.
         const LeaderOpeningStake: T::Balance = T::LeaderOpeningStake::get();
 
-        /// Defines the period every worker gets paid in blocks.
-        const RewardPeriod: u32 = T::RewardPeriod::get();
-
         /// Staking handler lock id.
         const StakingHandlerLockId: LockIdentifier = T::StakingHandler::lock_id();
 
+        /// Defines the period in blocks during which a worker can be reviewed once.
+        const WorkerReviewPeriod: T::BlockNumber = T::WorkerReviewPeriod::get();
+
+        /// Max number of the budget spending records kept in the ledger.
+        const MaxBudgetSpendingRecords: u64 = T::MaxBudgetSpendingRecords::get();
+
         /// # <weight>
         ///
         /// ## Weight
-        /// `O (W)` where:
-        /// - `W` is the number of workers currently present in the `working_group`
+        /// `O (W + E + A)` where:
+        /// - `W` is the number of workers with the unstaking period ending at the current block
+        /// - `E` is the number of openings expiring at the current block
+        /// - `A` is the number of applications for the openings expiring at the current block
         /// - DB:
-        ///    - O(W)
+        ///    - O(W + E + A)
         /// # </weight>
         fn on_initialize() -> Weight {
             let leaving_workers = Self::get_workers_with_finished_unstaking_period();
-            let mut biggest_number_of_processed_workers = leaving_workers.len();
 
             leaving_workers.iter().for_each(|wi| {
                 Self::remove_worker(
@@ -397,23 +519,23 @@ decl_module! {
                 );
             });
 
-            if Self::is_reward_block() {
-                // We count the number of workers that will be processed to not be so pessimistic
-                // when calculating the weight for this function
-                let mut count_number_of_workers = 0;
-                WorkerById::<T, I>::iter().for_each(|(worker_id, worker)| {
-                    Self::reward_worker(&worker_id, &worker);
-                    count_number_of_workers += 1;
-                });
-
-                biggest_number_of_processed_workers = biggest_number_of_processed_workers.max(count_number_of_workers);
-            }
+            let (expired_openings_number, released_applications_number) =
+                Self::cancel_expired_openings();
 
-            Self::calculate_weight_on_initialize(biggest_number_of_processed_workers.saturated_into())
+            WeightInfoWorkingGroup::<T, I>::on_initialize_leaving(
+                leaving_workers.len().saturated_into()
+            ).saturating_add(
+                WeightInfoWorkingGroup::<T, I>::on_initialize_expired_openings(
+                    expired_openings_number,
+                    released_applications_number,
+                )
+            )
         }
 
         /// Add a job opening for a regular worker/lead role.
         /// Require signed leader origin or the root (to add opening for the leader position).
+        /// Optional application deadline closes the opening for the new applications and
+        /// the optional expiry block cancels the unfilled opening releasing the application stakes.
         ///
         /// # <weight>
         ///
@@ -429,12 +551,16 @@ decl_module! {
             description: Vec<u8>,
             opening_type: OpeningType,
             stake_policy: StakePolicy<T::BlockNumber, BalanceOf<T>>,
-            reward_per_block: Option<BalanceOf<T>>
+            reward_per_block: Option<BalanceOf<T>>,
+            application_deadline: Option<T::BlockNumber>,
+            expires_at: Option<T::BlockNumber>,
         ){
             checks::ensure_origin_for_opening_type::<T, I>(origin.clone(), opening_type)?;
 
             checks::ensure_valid_stake_policy::<T, I>(&stake_policy)?;
 
+            checks::ensure_valid_opening_deadlines::<T, I>(application_deadline, expires_at)?;
+
             checks::ensure_valid_reward_per_block::<T, I>(&reward_per_block)?;
 
             checks::ensure_stake_for_opening_type::<T, I>(origin, opening_type)?;
@@ -462,6 +588,8 @@ decl_module! {
             let new_opening = Opening{
                 opening_type,
                 created: Self::current_block(),
+                application_deadline,
+                expires_at,
                 description_hash: hashed_description.as_ref().to_vec(),
                 stake_policy: stake_policy.clone(),
                 reward_per_block,
@@ -472,6 +600,10 @@ decl_module! {
 
             OpeningById::<T, I>::insert(new_opening_id, new_opening);
 
+            if let Some(expires_at) = expires_at {
+                ExpiringOpenings::<T, I>::insert(expires_at, new_opening_id, ());
+            }
+
             // Update NextOpeningId
             NextOpeningId::<I>::mutate(|id| *id += <OpeningId as One>::one());
 
@@ -502,6 +634,9 @@ decl_module! {
             // Ensure job opening exists.
             let opening = checks::ensure_opening_exists::<T, I>(p.opening_id)?;
 
+            // Ensure that opening still accepts applications.
+            checks::ensure_application_deadline_not_passed::<T, I>(&opening)?;
+
             // Ensure that proposed stake is enough for the opening.
             checks::ensure_application_stake_match_opening::<T, I>(&opening, &p.stake_parameters)?;
 
@@ -552,6 +687,7 @@ decl_module! {
 
             // Store an application.
             ApplicationById::<T, I>::insert(new_application_id, application);
+            ApplicationIdsByOpeningId::<I>::insert(p.opening_id, new_application_id, ());
 
             // Update the next application identifier value.
             NextApplicationId::<I>::mutate(|id| *id += <ApplicationId as One>::one());
@@ -560,6 +696,92 @@ decl_module! {
             Self::deposit_event(RawEvent::AppliedOnOpening(p, new_application_id));
         }
 
+        /// Shortlist applications for the regular/lead position.
+        /// Require signed leader origin or the root (to screen applications for the leader position).
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (A)` where:
+        /// - `A` is the length of `application_ids`
+        /// - DB:
+        ///    - O(A)
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::shortlist_applications(
+            application_ids.len().saturated_into()
+        )]
+        pub fn shortlist_applications(
+            origin,
+            opening_id: OpeningId,
+            application_ids: BTreeSet<ApplicationId>,
+        ) {
+            // Ensure job opening exists.
+            let opening = checks::ensure_opening_exists::<T, I>(opening_id)?;
+
+            checks::ensure_origin_for_opening_type::<T, I>(origin, opening.opening_type)?;
+
+            let checked_applications_info = checks::ensure_valid_applications_for_opening::<T, I>(
+                opening_id,
+                &application_ids
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            for application_info in checked_applications_info {
+                <ApplicationById<T, I>>::mutate(application_info.application_id, |application| {
+                    application.status = ApplicationStatus::Shortlisted;
+                });
+            }
+
+            // Trigger event
+            Self::deposit_event(RawEvent::ApplicationsShortlisted(opening_id, application_ids));
+        }
+
+        /// Reject applications for the regular/lead position and release their stakes.
+        /// Require signed leader origin or the root (to screen applications for the leader position).
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (A)` where:
+        /// - `A` is the length of `application_ids`
+        /// - DB:
+        ///    - O(A)
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::reject_applications(
+            application_ids.len().saturated_into()
+        )]
+        pub fn reject_applications(
+            origin,
+            opening_id: OpeningId,
+            application_ids: BTreeSet<ApplicationId>,
+        ) {
+            // Ensure job opening exists.
+            let opening = checks::ensure_opening_exists::<T, I>(opening_id)?;
+
+            checks::ensure_origin_for_opening_type::<T, I>(origin, opening.opening_type)?;
+
+            let checked_applications_info = checks::ensure_valid_applications_for_opening::<T, I>(
+                opening_id,
+                &application_ids
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            for application_info in checked_applications_info {
+                T::StakingHandler::unlock(&application_info.application.staking_account_id);
+
+                <ApplicationById<T, I>>::mutate(application_info.application_id, |application| {
+                    application.status = ApplicationStatus::Rejected;
+                });
+            }
+
+            // Trigger event
+            Self::deposit_event(RawEvent::ApplicationsRejected(opening_id, application_ids));
+        }
+
         /// Fill opening for the regular/lead position.
         /// Require signed leader origin or the root (to fill opening for the leader position).
         /// # <weight>
@@ -603,15 +825,10 @@ decl_module! {
                 );
             }
 
-            let checked_applications_info =
-                checks::ensure_succesful_applications_exist::<T, I>(&successful_application_ids)?;
-
-            // Check that all applications are for the intended opening
-            ensure!(
-                checked_applications_info.iter()
-                .all(|info| info.application.opening_id == opening_id),
-                Error::<T, I>::ApplicationsNotForOpening
-            );
+            let checked_applications_info = checks::ensure_valid_applications_for_opening::<T, I>(
+                opening_id,
+                &successful_application_ids
+            )?;
 
 
             // Check for a single application for a leader.
@@ -644,7 +861,7 @@ decl_module! {
             );
 
             // Remove the opening.
-            <OpeningById::<T, I>>::remove(opening_id);
+            Self::remove_opening(opening_id, &opening);
 
             // Trigger event
             Self::deposit_event(RawEvent::OpeningFilled(
@@ -678,10 +895,24 @@ decl_module! {
             // Ensure the worker is active.
             ensure!(!worker.is_leaving(), Error::<T, I>::WorkerIsLeaving);
 
+            let is_deputy = DeputyPermissionsByWorkerId::<T, I>::contains_key(worker_id);
+            if is_deputy {
+                checks::ensure_role_account_not_used_by_other_deputy::<T, I>(
+                    &new_role_account_id,
+                    &worker_id,
+                )?;
+            }
+
             //
             // == MUTATION SAFE ==
             //
 
+            // Move the deputy to the new role account.
+            if is_deputy {
+                DeputyIdByRoleAccountId::<T, I>::remove(&worker.role_account_id);
+                DeputyIdByRoleAccountId::<T, I>::insert(&new_role_account_id, worker_id);
+            }
+
             // Update role account
             WorkerById::<T, I>::mutate(worker_id, |worker| {
                 worker.role_account_id = new_role_account_id.clone()
@@ -715,10 +946,19 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
+            let started_leaving_at = Self::current_block();
+
             WorkerById::<T, I>::mutate(worker_id, |worker| {
-                worker.started_leaving_at = Some(Self::current_block())
+                worker.started_leaving_at = Some(started_leaving_at)
             });
 
+            let unstaking_period_end =
+                Self::unstaking_period_end(started_leaving_at, worker.job_unstaking_period);
+            LeavingWorkers::<T, I>::insert(unstaking_period_end, worker_id, ());
+
+            // Revoke the delegated permissions of the leaving deputy.
+            Self::revoke_deputy(&worker_id, &worker);
+
             // Trigger event
             Self::deposit_event(RawEvent::WorkerStartedLeaving(worker_id, rationale));
         }
@@ -926,10 +1166,13 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
-            T::StakingHandler::unlock(&application_info.application.staking_account_id);
+            // Stake of the rejected application was already released.
+            if !application_info.application.is_rejected() {
+                T::StakingHandler::unlock(&application_info.application.staking_account_id);
+            }
 
             // Remove an application.
-            <ApplicationById<T, I>>::remove(application_info.application_id);
+            Self::remove_application(&application_info);
 
             // Trigger event
             Self::deposit_event(RawEvent::ApplicationWithdrawn(application_id));
@@ -972,7 +1215,7 @@ decl_module! {
             }
 
             // Remove the opening.
-            <OpeningById::<T, I>>::remove(opening_id);
+            Self::remove_opening(opening_id, &opening);
 
             // Trigger event
             Self::deposit_event(RawEvent::OpeningCanceled(opening_id));
@@ -1060,12 +1303,15 @@ decl_module! {
             checks::ensure_origin_for_worker_operation::<T,I>(origin, worker_id)?;
 
             // Ensuring worker actually exists
-            checks::ensure_worker_exists::<T,I>(&worker_id)?;
+            let worker = checks::ensure_worker_exists::<T,I>(&worker_id)?;
 
             //
             // == MUTATION SAFE ==
             //
 
+            // Pay the reward accrued with the previous reward amount.
+            Self::reward_worker(&worker_id, &worker);
+
             // Update worker reward amount.
             WorkerById::<T, I>::mutate(worker_id, |worker| {
                 worker.reward_per_block = reward_per_block;
@@ -1075,6 +1321,33 @@ decl_module! {
             Self::deposit_event(RawEvent::WorkerRewardAmountUpdated(worker_id, reward_per_block));
         }
 
+        /// Pays the reward accrued since the last payment along with the missed reward
+        /// from the working group budget. Requires signed worker origin.
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::claim_reward()]
+        pub fn claim_reward(origin, worker_id: WorkerId<T>) {
+            // Ensure there is a signer which matches role account of worker corresponding to provided id.
+            let worker = checks::ensure_worker_signed::<T, I>(origin, &worker_id)?;
+
+            ensure!(
+                Self::accrued_reward(&worker) > Zero::zero() || worker.missed_reward.is_some(),
+                Error::<T, I>::NoRewardToClaim
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::reward_worker(&worker_id, &worker);
+        }
+
         /// Sets a new status text for the working group.
         /// Requires root origin.
         ///
@@ -1118,7 +1391,7 @@ decl_module! {
         }
 
         /// Transfers specified amount to any account.
-        /// Requires leader origin.
+        /// Requires leader origin or the root (to spend above the lead spending limit).
         ///
         /// # <weight>
         ///
@@ -1134,8 +1407,11 @@ decl_module! {
             amount: BalanceOf<T>,
             rationale: Option<Vec<u8>>,
         ) {
-            // Ensure group leader privilege.
-            checks::ensure_origin_is_active_leader::<T,I>(origin)?;
+            // Ensure group leader privilege or the council approval.
+            let approved_by_council = ensure_root(origin.clone()).is_ok();
+            if !approved_by_council {
+                checks::ensure_origin_is_active_leader::<T,I>(origin)?;
+            }
 
             ensure!(amount > Zero::zero(), Error::<T, I>::CannotSpendZero);
 
@@ -1146,16 +1422,250 @@ decl_module! {
                 Error::<T, I>::InsufficientBudgetForSpending
             );
 
+            // Ensures that the lead spending limit is not exceeded.
+            let lead_spending = if approved_by_council {
+                None
+            } else {
+                Some(checks::ensure_lead_spending_limit_not_exceeded::<T, I>(amount)?)
+            };
+
             //
             // == MUTATION SAFE ==
             //
 
+            if let Some(lead_spending) = lead_spending {
+                LeadSpendingInPeriod::<T, I>::put(lead_spending);
+            }
+
             Self::pay_from_budget(&account_id, amount);
 
+            Self::record_budget_spending(&account_id, amount, &rationale, approved_by_council);
+
             // Trigger event
             Self::deposit_event(RawEvent::BudgetSpending(account_id, amount, rationale));
         }
 
+        /// Pays the bonus to the reward account of the worker from the working group budget.
+        /// Requires signed leader origin or the root (to pay the bonus to the leader).
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::pay_worker_bonus()]
+        pub fn pay_worker_bonus(
+            origin,
+            worker_id: WorkerId<T>,
+            amount: BalanceOf<T>,
+            rationale: Option<Vec<u8>>,
+        ) {
+            // Ensure lead is set or it is the council paying the bonus to the leader.
+            let is_sudo = checks::ensure_origin_for_worker_operation::<T,I>(origin, worker_id)?;
+
+            // Ensuring worker actually exists.
+            let worker = checks::ensure_worker_exists::<T,I>(&worker_id)?;
+
+            ensure!(amount > Zero::zero(), Error::<T, I>::CannotSpendZero);
+
+            // Ensures that the budget is sufficient for the bonus
+            let (_, potential_missed_payment) = Self::calculate_possible_payment(amount);
+            ensure!(
+                potential_missed_payment == Zero::zero(),
+                Error::<T, I>::InsufficientBudgetForSpending
+            );
+
+            // Ensures that the lead spending limit is not exceeded.
+            let lead_spending = if is_sudo {
+                None
+            } else {
+                Some(checks::ensure_lead_spending_limit_not_exceeded::<T, I>(amount)?)
+            };
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            if let Some(lead_spending) = lead_spending {
+                LeadSpendingInPeriod::<T, I>::put(lead_spending);
+            }
+
+            Self::pay_from_budget(&worker.reward_account_id, amount);
+
+            Self::record_budget_spending(&worker.reward_account_id, amount, &rationale, is_sudo);
+
+            // Trigger event
+            Self::deposit_event(
+                RawEvent::WorkerBonusPaid(worker_id, worker.reward_account_id, amount, rationale)
+            );
+        }
+
+        /// Adds the performance review of the worker for the current review period.
+        /// Requires signed leader origin or the root (to review the leader).
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (R)` where:
+        /// - `R` is the length of `review`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::review_worker(review.len().saturated_into())]
+        pub fn review_worker(
+            origin,
+            worker_id: WorkerId<T>,
+            score: Percent,
+            review: Vec<u8>,
+        ) {
+            // Ensure lead is set or it is the council reviewing the leader.
+            checks::ensure_origin_for_worker_operation::<T,I>(origin, worker_id)?;
+
+            // Ensuring worker actually exists.
+            checks::ensure_worker_exists::<T,I>(&worker_id)?;
+
+            let review_period = Self::current_review_period();
+
+            ensure!(
+                !WorkerReviewByPeriod::<T, I>::contains_key(worker_id, review_period),
+                Error::<T, I>::WorkerAlreadyReviewed
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let worker_review = WorkerReview {
+                score,
+                review_hash: T::Hashing::hash(&review).as_ref().to_vec(),
+                reviewed_at: Self::current_block(),
+            };
+
+            WorkerReviewByPeriod::<T, I>::insert(worker_id, review_period, worker_review);
+
+            // Trigger event
+            Self::deposit_event(RawEvent::WorkerReviewed(worker_id, review_period, score, review));
+        }
+
+        /// Appoints the regular worker as a deputy with the delegated lead permissions.
+        /// Overrides the permissions of the existing deputy. Requires signed leader origin.
+        /// The deputies are revoked when the lead is unset or when the deputy leaves the role.
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (P)` where:
+        /// - `P` is the length of `permissions`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::appoint_deputy(permissions.len().saturated_into())]
+        pub fn appoint_deputy(
+            origin,
+            worker_id: WorkerId<T>,
+            permissions: BTreeSet<DeputyPermission>,
+        ) {
+            // Ensure lead is set and is origin signer.
+            checks::ensure_origin_is_active_leader::<T, I>(origin)?;
+
+            // Ensuring worker actually exists.
+            let worker = checks::ensure_worker_exists::<T, I>(&worker_id)?;
+
+            ensure!(
+                !checks::ensure_lead_is_set::<T, I>().map_or(false, |lead_id| lead_id == worker_id),
+                Error::<T, I>::CannotAppointLeaderAsDeputy
+            );
+
+            ensure!(!worker.is_leaving(), Error::<T, I>::WorkerIsLeaving);
+
+            ensure!(!permissions.is_empty(), Error::<T, I>::NoDeputyPermissionsProvided);
+
+            checks::ensure_role_account_not_used_by_other_deputy::<T, I>(
+                &worker.role_account_id,
+                &worker_id,
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            DeputyPermissionsByWorkerId::<T, I>::insert(worker_id, permissions.clone());
+            DeputyIdByRoleAccountId::<T, I>::insert(&worker.role_account_id, worker_id);
+
+            // Trigger event
+            Self::deposit_event(RawEvent::DeputyAppointed(worker_id, permissions));
+        }
+
+        /// Revokes the delegated lead permissions from the deputy. Requires signed leader origin.
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::remove_deputy()]
+        pub fn remove_deputy(origin, worker_id: WorkerId<T>) {
+            // Ensure lead is set and is origin signer.
+            checks::ensure_origin_is_active_leader::<T, I>(origin)?;
+
+            ensure!(
+                DeputyPermissionsByWorkerId::<T, I>::contains_key(worker_id),
+                Error::<T, I>::WorkerIsNotDeputy
+            );
+
+            let worker = checks::ensure_worker_exists::<T, I>(&worker_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::revoke_deputy(&worker_id, &worker);
+
+            // Trigger event
+            Self::deposit_event(RawEvent::DeputyRemoved(worker_id));
+        }
+
+        /// Sets the lead spending limit for the working group budget.
+        /// Requires root origin.
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::set_spending_limit()]
+        pub fn set_spending_limit(
+            origin,
+            spending_limit: Option<SpendingLimit<BalanceOf<T>, T::BlockNumber>>,
+        ) {
+            ensure_root(origin)?;
+
+            if let Some(ref spending_limit) = spending_limit {
+                ensure!(
+                    spending_limit.period > Zero::zero(),
+                    Error::<T, I>::InvalidSpendingLimitPeriod
+                );
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            LeadSpendingLimit::<T, I>::set(spending_limit.clone());
+
+            // Reset the spending tracking for the new limit.
+            LeadSpendingInPeriod::<T, I>::kill();
+
+            // Trigger event
+            Self::deposit_event(RawEvent::SpendingLimitSet(spending_limit));
+        }
+
         /// Update the associated role storage.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn update_role_storage(
@@ -1225,26 +1735,22 @@ decl_module! {
 }
 
 impl<T: Trait<I>, I: Instance> Module<T, I> {
-    // Calculate weight for on_initialize
-    // We assume worst case scenario in a safe manner
-    // We take the most number of workers that will be processed and use it as input of the most costly function
-    fn calculate_weight_on_initialize(workers: u32) -> Weight {
-        WeightInfoWorkingGroup::<T, I>::on_initialize_rewarding_without_missing_reward(
-            workers,
-        )
-        .max(
-            WeightInfoWorkingGroup::<T, I>::on_initialize_rewarding_with_missing_reward_cant_pay(
-                workers,
-            ),
-        )
-        .max(
-            WeightInfoWorkingGroup::<T, I>::on_initialize_rewarding_with_missing_reward(
-                workers,
-            ),
-        )
-        .max(WeightInfoWorkingGroup::<T, I>::on_initialize_leaving(
-            workers,
-        ))
+    // Verifies that given account ID belongs to the deputy with the delegated permission.
+    pub(crate) fn is_deputy_account_id(
+        account_id: &T::AccountId,
+        permission: DeputyPermission,
+    ) -> bool {
+        Self::deputy_id_by_role_account_id(account_id).map_or(false, |worker_id| {
+            Self::deputy_permissions_by_worker_id(worker_id).contains(&permission)
+        })
+    }
+
+    // Revokes the delegated permissions of the deputy.
+    fn revoke_deputy(worker_id: &WorkerId<T>, worker: &Worker<T>) {
+        if DeputyPermissionsByWorkerId::<T, I>::contains_key(worker_id) {
+            DeputyPermissionsByWorkerId::<T, I>::remove(worker_id);
+            DeputyIdByRoleAccountId::<T, I>::remove(&worker.role_account_id);
+        }
     }
 
     // Calculate weight for `leave_role`
@@ -1288,6 +1794,18 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         <frame_system::Module<T>>::block_number()
     }
 
+    // Returns the index of the current worker review period.
+    fn current_review_period() -> T::BlockNumber {
+        let mut review_period = T::WorkerReviewPeriod::get();
+
+        // Special case for not set review period. Treats as review_period == 1.
+        if review_period == Zero::zero() {
+            review_period = One::one();
+        }
+
+        Self::current_block() / review_period
+    }
+
     // Increases active worker counter (saturating).
     fn increase_active_worker_counter() {
         let next_active_worker_count_value = Self::active_worker_count().saturating_add(1);
@@ -1350,11 +1868,20 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         <NextWorkerId<T, I>>::mutate(|id| *id += <WorkerId<T> as One>::one());
 
         // Remove an application.
-        <ApplicationById<T, I>>::remove(application_info.application_id);
+        Self::remove_application(application_info);
 
         new_worker_id
     }
 
+    // Removes the application and its opening index record.
+    fn remove_application(application_info: &ApplicationInfo<T, I>) {
+        <ApplicationById<T, I>>::remove(application_info.application_id);
+        ApplicationIdsByOpeningId::<I>::remove(
+            application_info.application.opening_id,
+            application_info.application_id,
+        );
+    }
+
     // Set worker id as a leader id.
     pub(crate) fn set_lead(worker_id: WorkerId<T>) {
         // Update current lead
@@ -1370,6 +1897,10 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
             // Update current lead
             <CurrentLead<T, I>>::kill();
 
+            // Revoke the permissions delegated by the evicted lead.
+            DeputyPermissionsByWorkerId::<T, I>::remove_all();
+            DeputyIdByRoleAccountId::<T, I>::remove_all();
+
             Self::deposit_event(RawEvent::LeaderUnset());
         }
     }
@@ -1385,7 +1916,20 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
             }
         }
 
-        Self::try_to_pay_missed_reward(worker_id, worker);
+        Self::reward_worker(worker_id, worker);
+
+        // Remove the worker from the leaving workers index.
+        if let Some(started_leaving_at) = worker.started_leaving_at {
+            let unstaking_period_end =
+                Self::unstaking_period_end(started_leaving_at, worker.job_unstaking_period);
+            LeavingWorkers::<T, I>::remove(unstaking_period_end, worker_id);
+        }
+
+        // Revoke the delegated permissions of the deputy.
+        Self::revoke_deputy(worker_id, worker);
+
+        // Remove the worker performance reviews.
+        WorkerReviewByPeriod::<T, I>::remove_prefix(worker_id);
 
         // Remove the worker from the storage.
         WorkerById::<T, I>::remove(worker_id);
@@ -1412,24 +1956,28 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         ));
     }
 
-    // Reward a worker using reward presets and working group budget.
-    fn reward_worker(worker_id: &WorkerId<T>, worker: &Worker<T>) {
-        // If reward period is not set.
-        let mut rewarding_period: u32 = T::RewardPeriod::get();
-        if rewarding_period == 0u32 {
-            rewarding_period = One::one();
-        }
+    // Calculates the reward accrued by the worker since the last reward payment.
+    fn accrued_reward(worker: &Worker<T>) -> BalanceOf<T> {
+        worker
+            .reward_per_block
+            .map_or(Zero::zero(), |reward_per_block| {
+                let rewarded_blocks: u128 = Self::current_block()
+                    .saturating_sub(worker.rewarded_until)
+                    .saturated_into();
 
-        // Modify rewarding period for new workers.
-        let block_from_worker_creation: u128 =
-            (Self::current_block() - worker.created_at).saturated_into();
-        if block_from_worker_creation < rewarding_period.into() {
-            rewarding_period = block_from_worker_creation.saturated_into();
-        }
+                reward_per_block.saturating_mul(rewarded_blocks.saturated_into())
+            })
+    }
 
-        if let Some(reward_per_block) = worker.reward_per_block {
-            let reward = reward_per_block * rewarding_period.into();
+    // Reward a worker for the blocks since the last reward payment using the working group budget.
+    fn reward_worker(worker_id: &WorkerId<T>, worker: &Worker<T>) {
+        let reward = Self::accrued_reward(worker);
 
+        WorkerById::<T, I>::mutate(worker_id, |worker| {
+            worker.rewarded_until = Self::current_block();
+        });
+
+        if reward > Zero::zero() {
             let (actual_reward, missed_reward) = Self::calculate_possible_payment(reward);
 
             // Check whether the budget is not zero.
@@ -1448,6 +1996,8 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
             } else {
                 Self::try_to_pay_missed_reward(worker_id, worker);
             }
+        } else {
+            Self::try_to_pay_missed_reward(worker_id, worker);
         }
     }
 
@@ -1462,6 +2012,35 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         let _ = <balances::Module<T>>::deposit_creating(account_id, amount);
     }
 
+    // Adds the budget spending record to the ledger.
+    fn record_budget_spending(
+        recipient: &T::AccountId,
+        amount: BalanceOf<T>,
+        rationale: &Option<Vec<u8>>,
+        approved_by_council: bool,
+    ) {
+        let spending_id = Self::next_budget_spending_id();
+
+        let record = BudgetSpendingRecord {
+            recipient: recipient.clone(),
+            amount,
+            rationale_hash: rationale
+                .as_ref()
+                .map(|rationale| T::Hashing::hash(rationale).as_ref().to_vec()),
+            spent_at: Self::current_block(),
+            approved_by_council,
+        };
+
+        // Prune the oldest record to keep the ledger bounded.
+        let max_records = T::MaxBudgetSpendingRecords::get();
+        if spending_id >= max_records {
+            BudgetSpendingById::<T, I>::remove(spending_id - max_records);
+        }
+
+        BudgetSpendingById::<T, I>::insert(spending_id, record);
+        NextBudgetSpendingId::<I>::put(spending_id + 1);
+    }
+
     // Helper-function joining the reward payment with the event.
     fn pay_reward(
         worker_id: &WorkerId<T>,
@@ -1540,39 +2119,104 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         }
     }
 
-    // Returns a collection of workers with finished unstaking period.
-    fn get_workers_with_finished_unstaking_period() -> Vec<WorkerInfo<T>> {
-        WorkerById::<T, I>::iter()
-            .filter_map(|(worker_id, worker)| {
-                if let Some(started_leaving_at) = worker.started_leaving_at {
-                    if started_leaving_at + worker.job_unstaking_period <= Self::current_block() {
-                        return Some((worker_id, worker).into());
-                    }
-                }
+    // Removes the opening and its expiry record.
+    fn remove_opening(opening_id: OpeningId, opening: &Opening<T::BlockNumber, BalanceOf<T>>) {
+        <OpeningById<T, I>>::remove(opening_id);
 
-                None
-            })
-            .collect::<Vec<_>>()
+        if let Some(expires_at) = opening.expires_at {
+            ExpiringOpenings::<T, I>::remove(expires_at, opening_id);
+        }
     }
 
-    // Defines whether the current block is a reward block.
-    fn is_reward_block() -> bool {
+    // Cancels the openings expiring at the current block: releases the opening creation stake
+    // and the application stakes, removes the applications and the openings.
+    // Returns the number of expired openings and the number of removed applications.
+    fn cancel_expired_openings() -> (u32, u32) {
         let current_block = Self::current_block();
 
-        // Don't reward at genesis.
-        if current_block == Zero::zero() {
-            return false;
-        }
+        let expired_opening_ids: Vec<OpeningId> =
+            ExpiringOpenings::<T, I>::iter_prefix(current_block)
+                .map(|(opening_id, _)| opening_id)
+                .collect();
+
+        ExpiringOpenings::<T, I>::remove_prefix(current_block);
+
+        let mut expired_openings_number = 0u32;
+        let mut removed_applications_number = 0u32;
+        for opening_id in expired_opening_ids {
+            let opening = match checks::ensure_opening_exists::<T, I>(opening_id) {
+                Ok(opening) => opening,
+                Err(_) => continue,
+            };
+
+            // Release the opening creation stake of the lead.
+            if opening.opening_type == OpeningType::Regular {
+                if let Some(lead_id) = Self::current_lead() {
+                    let lead = Self::worker_by_id(lead_id);
+                    let current_stake = T::StakingHandler::current_stake(&lead.staking_account_id);
+                    let _ = T::StakingHandler::set_stake(
+                        &lead.staking_account_id,
+                        current_stake.saturating_sub(opening.creation_stake),
+                    );
+                }
+            }
+
+            let application_ids: Vec<ApplicationId> =
+                ApplicationIdsByOpeningId::<I>::iter_prefix(opening_id)
+                    .map(|(application_id, _)| application_id)
+                    .collect();
 
-        let reward_period: u32 = T::RewardPeriod::get();
+            ApplicationIdsByOpeningId::<I>::remove_prefix(opening_id);
+
+            for application_id in application_ids {
+                let application = Self::application_by_id(application_id);
+
+                // Stake of the rejected application was already released.
+                if !application.is_rejected() {
+                    T::StakingHandler::unlock(&application.staking_account_id);
+                }
 
-        // Special case for not set reward_period. Treats as reward_period == 1.
-        if reward_period == 0u32 {
-            return true;
+                <ApplicationById<T, I>>::remove(application_id);
+
+                removed_applications_number = removed_applications_number.saturating_add(1);
+            }
+
+            <OpeningById<T, I>>::remove(opening_id);
+
+            expired_openings_number = expired_openings_number.saturating_add(1);
+
+            Self::deposit_event(RawEvent::OpeningExpired(opening_id));
         }
 
-        // Check whether current block is a reward block.
-        current_block % reward_period.into() == Zero::zero()
+        (expired_openings_number, removed_applications_number)
+    }
+
+    // Returns a collection of workers with the unstaking period ending at the current block.
+    // Removes them from the leaving workers index.
+    fn get_workers_with_finished_unstaking_period() -> Vec<WorkerInfo<T>> {
+        let current_block = Self::current_block();
+
+        let leaving_workers: Vec<WorkerInfo<T>> =
+            LeavingWorkers::<T, I>::iter_prefix(current_block)
+                .filter_map(|(worker_id, _)| {
+                    checks::ensure_worker_exists::<T, I>(&worker_id)
+                        .ok()
+                        .map(|worker| (worker_id, worker).into())
+                })
+                .collect();
+
+        LeavingWorkers::<T, I>::remove_prefix(current_block);
+
+        leaving_workers
+    }
+
+    // Returns the block at which the worker leaving at the provided block exits the role.
+    // The worker is removed no earlier than at the next block.
+    fn unstaking_period_end(
+        started_leaving_at: T::BlockNumber,
+        job_unstaking_period: T::BlockNumber,
+    ) -> T::BlockNumber {
+        started_leaving_at.saturating_add(job_unstaking_period.max(One::one()))
     }
 
     // Sets the working group budget.
@@ -1586,6 +2230,122 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
             .map(|(worker_id, _)| worker_id)
             .collect()
     }
+
+    /// Migrates the stored workers to the lazy reward accrual: sets the block the workers are
+    /// rewarded until to the current block (rewards were paid on each block before the upgrade)
+    /// and indexes the leaving workers by the end of their unstaking period. Leaving workers with
+    /// the already finished unstaking period are removed on the current block.
+    pub fn migrate_workers() -> Weight {
+        let current_block = Self::current_block();
+        let migrated_workers_number = Cell::new(0 as Weight);
+        let leaving_workers_number = Cell::new(0 as Weight);
+
+        <WorkerById<T, I>>::translate(
+            |worker_id,
+             old_worker: OldGroupWorker<
+                T::AccountId,
+                MemberId<T>,
+                T::BlockNumber,
+                BalanceOf<T>,
+            >| {
+                migrated_workers_number.set(migrated_workers_number.get() + 1);
+
+                if let Some(started_leaving_at) = old_worker.started_leaving_at {
+                    let unstaking_period_end = Self::unstaking_period_end(
+                        started_leaving_at,
+                        old_worker.job_unstaking_period,
+                    )
+                    .max(current_block);
+
+                    LeavingWorkers::<T, I>::insert(unstaking_period_end, worker_id, ());
+                    leaving_workers_number.set(leaving_workers_number.get() + 1);
+                }
+
+                Some(GroupWorker {
+                    member_id: old_worker.member_id,
+                    role_account_id: old_worker.role_account_id,
+                    staking_account_id: old_worker.staking_account_id,
+                    reward_account_id: old_worker.reward_account_id,
+                    started_leaving_at: old_worker.started_leaving_at,
+                    job_unstaking_period: old_worker.job_unstaking_period,
+                    reward_per_block: old_worker.reward_per_block,
+                    missed_reward: old_worker.missed_reward,
+                    created_at: old_worker.created_at,
+                    rewarded_until: current_block,
+                })
+            },
+        );
+
+        let migrated_workers_number = migrated_workers_number.get();
+        let leaving_workers_number = leaving_workers_number.get();
+
+        T::DbWeight::get().reads_writes(
+            migrated_workers_number,
+            migrated_workers_number.saturating_add(leaving_workers_number),
+        )
+    }
+
+    /// Migrates the stored openings to the application deadlines and the opening expiry: the
+    /// openings added before the upgrade have neither.
+    pub fn migrate_openings() -> Weight {
+        let migrated_openings_number = Cell::new(0 as Weight);
+
+        <OpeningById<T, I>>::translate(
+            |_, old_opening: OldOpening<T::BlockNumber, BalanceOf<T>>| {
+                migrated_openings_number.set(migrated_openings_number.get() + 1);
+
+                Some(Opening {
+                    opening_type: old_opening.opening_type,
+                    created: old_opening.created,
+                    application_deadline: None,
+                    expires_at: None,
+                    description_hash: old_opening.description_hash,
+                    stake_policy: old_opening.stake_policy,
+                    reward_per_block: old_opening.reward_per_block,
+                    creation_stake: old_opening.creation_stake,
+                })
+            },
+        );
+
+        let migrated_openings_number = migrated_openings_number.get();
+
+        T::DbWeight::get().reads_writes(migrated_openings_number, migrated_openings_number)
+    }
+
+    /// Migrates the stored applications to the application screening: the applications
+    /// submitted before the upgrade are pending. Also indexes the applications by the opening.
+    pub fn migrate_applications() -> Weight {
+        let migrated_applications_number = Cell::new(0 as Weight);
+
+        <ApplicationById<T, I>>::translate(
+            |application_id, old_application: OldJobApplication<T::AccountId, MemberId<T>>| {
+                migrated_applications_number.set(migrated_applications_number.get() + 1);
+
+                ApplicationIdsByOpeningId::<I>::insert(
+                    old_application.opening_id,
+                    application_id,
+                    (),
+                );
+
+                Some(JobApplication {
+                    role_account_id: old_application.role_account_id,
+                    reward_account_id: old_application.reward_account_id,
+                    staking_account_id: old_application.staking_account_id,
+                    member_id: old_application.member_id,
+                    description_hash: old_application.description_hash,
+                    opening_id: old_application.opening_id,
+                    status: ApplicationStatus::Pending,
+                })
+            },
+        );
+
+        let migrated_applications_number = migrated_applications_number.get();
+
+        T::DbWeight::get().reads_writes(
+            migrated_applications_number,
+            migrated_applications_number.saturating_mul(2),
+        )
+    }
 }
 
 impl<T: Trait<I>, I: Instance> common::working_group::WorkingGroupAuthenticator<T>
@@ -1599,6 +2359,13 @@ impl<T: Trait<I>, I: Instance> common::working_group::WorkingGroupAuthenticator<
         checks::ensure_origin_is_active_leader::<T, I>(origin)
     }
 
+    fn ensure_leader_or_deputy_origin(
+        origin: T::Origin,
+        permission: DeputyPermission,
+    ) -> DispatchResult {
+        checks::ensure_origin_is_active_leader_or_deputy::<T, I>(origin, permission)
+    }
+
     fn get_leader_member_id() -> Option<T::MemberId> {
         checks::ensure_lead_is_set::<T, I>()
             .map(Self::worker_by_id)
@@ -1610,6 +2377,13 @@ impl<T: Trait<I>, I: Instance> common::working_group::WorkingGroupAuthenticator<
         checks::ensure_is_lead_account::<T, I>(account_id.clone()).is_ok()
     }
 
+    fn is_leader_or_deputy_account_id(
+        account_id: &T::AccountId,
+        permission: DeputyPermission,
+    ) -> bool {
+        Self::is_leader_account_id(account_id) || Self::is_deputy_account_id(account_id, permission)
+    }
+
     fn is_worker_account_id(account_id: &T::AccountId, worker_id: &WorkerId<T>) -> bool {
         checks::ensure_worker_exists::<T, I>(worker_id)
             .map(|worker| worker.role_account_id == account_id.clone())
@@ -1,8 +1,10 @@
 #![cfg(test)]
+use common::working_group::DeputyPermission;
 use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::traits::Currency;
-use frame_support::StorageMap;
+use frame_support::{StorageDoubleMap, StorageMap};
 use frame_system::{EventRecord, Phase, RawOrigin};
+use sp_arithmetic::Percent;
 use sp_runtime::traits::Hash;
 use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
 
@@ -12,8 +14,9 @@ use super::mock::{
 };
 use crate::types::StakeParameters;
 use crate::{
-    Application, ApplyOnOpeningParameters, DefaultInstance, Opening, OpeningType, RawEvent,
-    StakePolicy, Trait, Worker,
+    Application, ApplicationStatus, ApplyOnOpeningParameters, BudgetSpendingRecord,
+    DefaultInstance, Opening, OpeningType, RawEvent, SpendingLimit, StakePolicy, Trait, Worker,
+    WorkerReview,
 };
 
 pub struct EventFixture;
@@ -28,7 +31,9 @@ impl EventFixture {
             u64,
             OpeningType,
             StakePolicy<u64, u64>,
+            u64,
             ApplyOnOpeningParameters<Test>,
+            SpendingLimit<u64, u64>,
             DefaultInstance,
         >,
     ) {
@@ -57,7 +62,9 @@ impl EventFixture {
             u64,
             OpeningType,
             StakePolicy<u64, u64>,
+            u64,
             ApplyOnOpeningParameters<Test>,
+            SpendingLimit<u64, u64>,
             DefaultInstance,
         >,
     ) {
@@ -84,6 +91,8 @@ pub struct AddOpeningFixture {
     pub starting_block: u64,
     pub stake_policy: StakePolicy<u64, u64>,
     pub reward_per_block: Option<u64>,
+    pub application_deadline: Option<u64>,
+    pub expires_at: Option<u64>,
 }
 
 impl Default for AddOpeningFixture {
@@ -98,6 +107,8 @@ impl Default for AddOpeningFixture {
                 leaving_unstaking_period: <Test as Trait>::MinUnstakingPeriodLimit::get() + 1,
             },
             reward_per_block: None,
+            application_deadline: None,
+            expires_at: None,
         }
     }
 }
@@ -121,6 +132,8 @@ impl AddOpeningFixture {
             let expected_hash = <Test as frame_system::Trait>::Hashing::hash(&self.description);
             let expected_opening = Opening {
                 created: self.starting_block,
+                application_deadline: self.application_deadline,
+                expires_at: self.expires_at,
                 description_hash: expected_hash.as_ref().to_vec(),
                 opening_type: self.opening_type,
                 stake_policy: self.stake_policy.clone(),
@@ -146,6 +159,8 @@ impl AddOpeningFixture {
             self.opening_type,
             self.stake_policy.clone(),
             self.reward_per_block.clone(),
+            self.application_deadline,
+            self.expires_at,
         )?;
 
         Ok(saved_opening_next_id)
@@ -182,6 +197,17 @@ impl AddOpeningFixture {
             ..self
         }
     }
+
+    pub fn with_application_deadline(self, application_deadline: Option<u64>) -> Self {
+        Self {
+            application_deadline,
+            ..self
+        }
+    }
+
+    pub fn with_expires_at(self, expires_at: Option<u64>) -> Self {
+        Self { expires_at, ..self }
+    }
 }
 
 pub struct ApplyOnOpeningFixture {
@@ -296,9 +322,16 @@ impl ApplyOnOpeningFixture {
                 member_id: self.member_id,
                 description_hash: expected_hash.as_ref().to_vec(),
                 opening_id: self.opening_id,
+                status: ApplicationStatus::Pending,
             };
 
             assert_eq!(actual_application, expected_application);
+            assert!(
+                <crate::ApplicationIdsByOpeningId<DefaultInstance>>::contains_key(
+                    self.opening_id,
+                    application_id
+                )
+            );
         }
 
         saved_application_next_id
@@ -407,6 +440,7 @@ impl FillOpeningFixture {
                 reward_per_block: self.reward_per_block,
                 missed_reward: None,
                 created_at: self.created_at,
+                rewarded_until: self.created_at,
             };
 
             let actual_worker = TestWorkingGroup::worker_by_id(worker_id);
@@ -912,12 +946,20 @@ impl WithdrawApplicationFixture {
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
         let old_balance = Balances::usable_balance(&self.account_id);
         let old_stake = get_stake_balance(&self.account_id);
+        let opening_id = TestWorkingGroup::application_by_id(self.application_id).opening_id;
 
         let actual_result =
             TestWorkingGroup::withdraw_application(self.origin.clone().into(), self.application_id);
         assert_eq!(actual_result.clone(), expected_result);
 
         if actual_result.is_ok() {
+            assert!(
+                !<crate::ApplicationIdsByOpeningId<DefaultInstance>>::contains_key(
+                    opening_id,
+                    self.application_id
+                )
+            );
+
             if self.stake {
                 // the stake was removed
                 assert_eq!(0, get_stake_balance(&self.account_id));
@@ -931,6 +973,101 @@ impl WithdrawApplicationFixture {
     }
 }
 
+pub struct ShortlistApplicationsFixture {
+    origin: RawOrigin<u64>,
+    opening_id: u64,
+    application_ids: BTreeSet<u64>,
+}
+
+impl ShortlistApplicationsFixture {
+    pub fn default_for_ids(opening_id: u64, application_ids: Vec<u64>) -> Self {
+        Self {
+            origin: RawOrigin::Signed(1),
+            opening_id,
+            application_ids: application_ids.iter().copied().collect(),
+        }
+    }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_statuses = self.get_application_statuses();
+
+        let actual_result = TestWorkingGroup::shortlist_applications(
+            self.origin.clone().into(),
+            self.opening_id,
+            self.application_ids.clone(),
+        );
+        assert_eq!(actual_result.clone(), expected_result);
+
+        let new_statuses = self.get_application_statuses();
+
+        if actual_result.is_ok() {
+            assert!(new_statuses
+                .iter()
+                .all(|status| *status == ApplicationStatus::Shortlisted));
+        } else {
+            assert_eq!(old_statuses, new_statuses);
+        }
+    }
+
+    fn get_application_statuses(&self) -> Vec<ApplicationStatus> {
+        self.application_ids
+            .iter()
+            .map(|id| TestWorkingGroup::application_by_id(id).status)
+            .collect()
+    }
+}
+
+pub struct RejectApplicationsFixture {
+    origin: RawOrigin<u64>,
+    opening_id: u64,
+    application_ids: BTreeSet<u64>,
+}
+
+impl RejectApplicationsFixture {
+    pub fn default_for_ids(opening_id: u64, application_ids: Vec<u64>) -> Self {
+        Self {
+            origin: RawOrigin::Signed(1),
+            opening_id,
+            application_ids: application_ids.iter().copied().collect(),
+        }
+    }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_applications = self.get_applications();
+
+        let actual_result = TestWorkingGroup::reject_applications(
+            self.origin.clone().into(),
+            self.opening_id,
+            self.application_ids.clone(),
+        );
+        assert_eq!(actual_result.clone(), expected_result);
+
+        let new_applications = self.get_applications();
+
+        if actual_result.is_ok() {
+            for application in new_applications {
+                assert_eq!(application.status, ApplicationStatus::Rejected);
+                assert_eq!(get_stake_balance(&application.staking_account_id), 0);
+            }
+        } else {
+            assert_eq!(old_applications, new_applications);
+        }
+    }
+
+    fn get_applications(&self) -> Vec<Application<Test>> {
+        self.application_ids
+            .iter()
+            .map(|id| TestWorkingGroup::application_by_id(id))
+            .collect()
+    }
+}
+
 pub struct CancelOpeningFixture {
     origin: RawOrigin<u64>,
     opening_id: u64,
@@ -1098,6 +1235,36 @@ impl UpdateRewardAmountFixture {
     }
 }
 
+pub struct ClaimRewardFixture {
+    worker_id: u64,
+    origin: RawOrigin<u64>,
+}
+
+impl ClaimRewardFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        Self {
+            worker_id,
+            origin: RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID),
+        }
+    }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result =
+            TestWorkingGroup::claim_reward(self.origin.clone().into(), self.worker_id);
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        if actual_result.is_ok() {
+            let worker = TestWorkingGroup::worker_by_id(self.worker_id);
+
+            assert_eq!(worker.rewarded_until, System::block_number());
+        }
+    }
+}
+
 pub struct SetStatusTextFixture {
     origin: RawOrigin<u64>,
     new_status_text: Option<Vec<u8>>,
@@ -1180,6 +1347,10 @@ impl SpendFromBudgetFixture {
         Self { amount, ..self }
     }
 
+    pub fn with_rationale(self, rationale: Option<Vec<u8>>) -> Self {
+        Self { rationale, ..self }
+    }
+
     pub fn call(&self) -> DispatchResult {
         TestWorkingGroup::spend_from_budget(
             self.origin.clone().into(),
@@ -1192,6 +1363,7 @@ impl SpendFromBudgetFixture {
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
         let old_budget = TestWorkingGroup::budget();
         let old_balance = Balances::usable_balance(&self.account_id);
+        let spending_id = TestWorkingGroup::next_budget_spending_id();
 
         let actual_result = self.call().map(|_| ());
 
@@ -1203,9 +1375,28 @@ impl SpendFromBudgetFixture {
         if actual_result.is_ok() {
             assert_eq!(new_budget, old_budget - self.amount);
             assert_eq!(new_balance, old_balance + self.amount);
+
+            let expected_record = BudgetSpendingRecord {
+                recipient: self.account_id,
+                amount: self.amount,
+                rationale_hash: self.rationale.as_ref().map(|rationale| {
+                    <Test as frame_system::Trait>::Hashing::hash(rationale)
+                        .as_ref()
+                        .to_vec()
+                }),
+                spent_at: System::block_number(),
+                approved_by_council: self.origin == RawOrigin::Root,
+            };
+
+            assert_eq!(
+                TestWorkingGroup::budget_spending_by_id(spending_id),
+                expected_record
+            );
+            assert_eq!(TestWorkingGroup::next_budget_spending_id(), spending_id + 1);
         } else {
             assert_eq!(old_budget, new_budget);
             assert_eq!(old_balance, new_balance);
+            assert_eq!(TestWorkingGroup::next_budget_spending_id(), spending_id);
         }
     }
 }
@@ -1243,3 +1434,252 @@ impl UpdateWorkerStorageFixture {
         }
     }
 }
+
+pub struct PayWorkerBonusFixture {
+    worker_id: u64,
+    amount: u64,
+    rationale: Option<Vec<u8>>,
+    origin: RawOrigin<u64>,
+}
+
+impl PayWorkerBonusFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
+        Self {
+            worker_id,
+            amount: 100,
+            rationale: None,
+            origin: RawOrigin::Signed(lead_account_id),
+        }
+    }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_amount(self, amount: u64) -> Self {
+        Self { amount, ..self }
+    }
+
+    pub fn with_rationale(self, rationale: Option<Vec<u8>>) -> Self {
+        Self { rationale, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let reward_account_id = TestWorkingGroup::worker_by_id(self.worker_id).reward_account_id;
+
+        let old_budget = TestWorkingGroup::budget();
+        let old_balance = Balances::usable_balance(&reward_account_id);
+
+        let actual_result = TestWorkingGroup::pay_worker_bonus(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.amount,
+            self.rationale.clone(),
+        );
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        let new_budget = TestWorkingGroup::budget();
+        let new_balance = Balances::usable_balance(&reward_account_id);
+
+        if actual_result.is_ok() {
+            assert_eq!(new_budget, old_budget - self.amount);
+            assert_eq!(new_balance, old_balance + self.amount);
+        } else {
+            assert_eq!(old_budget, new_budget);
+            assert_eq!(old_balance, new_balance);
+        }
+    }
+}
+
+pub struct ReviewWorkerFixture {
+    worker_id: u64,
+    score: Percent,
+    review: Vec<u8>,
+    origin: RawOrigin<u64>,
+}
+
+impl ReviewWorkerFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
+        Self {
+            worker_id,
+            score: Percent::from_percent(80),
+            review: b"review".to_vec(),
+            origin: RawOrigin::Signed(lead_account_id),
+        }
+    }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_score(self, score: Percent) -> Self {
+        Self { score, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let review_period = TestWorkingGroup::current_review_period();
+        let old_review = TestWorkingGroup::worker_review_by_period(self.worker_id, review_period);
+
+        let actual_result = TestWorkingGroup::review_worker(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.score,
+            self.review.clone(),
+        );
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        let new_review = TestWorkingGroup::worker_review_by_period(self.worker_id, review_period);
+
+        if actual_result.is_ok() {
+            let expected_review = WorkerReview {
+                score: self.score,
+                review_hash: <Test as frame_system::Trait>::Hashing::hash(&self.review)
+                    .as_ref()
+                    .to_vec(),
+                reviewed_at: System::block_number(),
+            };
+
+            assert_eq!(new_review, expected_review);
+        } else {
+            assert_eq!(old_review, new_review);
+        }
+    }
+}
+
+pub struct AppointDeputyFixture {
+    worker_id: u64,
+    permissions: BTreeSet<DeputyPermission>,
+    origin: RawOrigin<u64>,
+}
+
+impl AppointDeputyFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
+        let mut permissions = BTreeSet::new();
+        permissions.insert(DeputyPermission::ManageOpenings);
+
+        Self {
+            worker_id,
+            permissions,
+            origin: RawOrigin::Signed(lead_account_id),
+        }
+    }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_permissions(self, permissions: BTreeSet<DeputyPermission>) -> Self {
+        Self {
+            permissions,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_permissions = TestWorkingGroup::deputy_permissions_by_worker_id(self.worker_id);
+
+        let actual_result = TestWorkingGroup::appoint_deputy(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.permissions.clone(),
+        );
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        let new_permissions = TestWorkingGroup::deputy_permissions_by_worker_id(self.worker_id);
+
+        if actual_result.is_ok() {
+            assert_eq!(new_permissions, self.permissions);
+        } else {
+            assert_eq!(old_permissions, new_permissions);
+        }
+    }
+}
+
+pub struct RemoveDeputyFixture {
+    worker_id: u64,
+    origin: RawOrigin<u64>,
+}
+
+impl RemoveDeputyFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
+        Self {
+            worker_id,
+            origin: RawOrigin::Signed(lead_account_id),
+        }
+    }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result =
+            TestWorkingGroup::remove_deputy(self.origin.clone().into(), self.worker_id);
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        if actual_result.is_ok() {
+            assert!(
+                !<crate::DeputyPermissionsByWorkerId<Test, DefaultInstance>>::contains_key(
+                    self.worker_id
+                )
+            );
+        }
+    }
+}
+
+pub struct SetSpendingLimitFixture {
+    origin: RawOrigin<u64>,
+    spending_limit: Option<SpendingLimit<u64, u64>>,
+}
+
+impl Default for SetSpendingLimitFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Root,
+            spending_limit: Some(SpendingLimit {
+                amount: 100,
+                period: 10,
+            }),
+        }
+    }
+}
+
+impl SetSpendingLimitFixture {
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_spending_limit(self, spending_limit: Option<SpendingLimit<u64, u64>>) -> Self {
+        Self {
+            spending_limit,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_spending_limit = TestWorkingGroup::lead_spending_limit();
+
+        let actual_result = TestWorkingGroup::set_spending_limit(
+            self.origin.clone().into(),
+            self.spending_limit.clone(),
+        );
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        let new_spending_limit = TestWorkingGroup::lead_spending_limit();
+
+        if actual_result.is_ok() {
+            assert_eq!(new_spending_limit, self.spending_limit);
+        } else {
+            assert_eq!(new_spending_limit, old_spending_limit);
+        }
+    }
+}
@@ -44,6 +44,11 @@ parameter_types! {
     pub const ReferralCutMaximumPercent: u8 = 50;
     pub const StakingCandidateLockId: [u8; 8] = [3; 8];
     pub const CandidateStake: u64 = 100;
+    pub const HandleDisputeLockId: [u8; 8] = [4; 8];
+    pub const HandleDisputeStake: u64 = 150;
+    pub const HandleDisputeGracePeriod: u64 = 10;
+    pub const MaxRecoveryGuardians: u32 = 5;
+    pub const RecoveryDelay: u64 = 10;
 }
 
 // Workaround for https://github.com/rust-lang/rust/issues/26925 - remove when sorted.
@@ -160,6 +165,57 @@ impl membership::WeightInfo for Weights {
     fn member_remark() -> Weight {
         unimplemented!()
     }
+    fn reserve_handle(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn release_handle(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn open_handle_dispute(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn resolve_handle_dispute() -> Weight {
+        unimplemented!()
+    }
+    fn execute_handle_dispute_resolution() -> Weight {
+        unimplemented!()
+    }
+    fn withdraw_handle_dispute() -> Weight {
+        unimplemented!()
+    }
+    fn add_member_verification() -> Weight {
+        unimplemented!()
+    }
+    fn revoke_member_verification() -> Weight {
+        unimplemented!()
+    }
+    fn set_second_level_referral_cut() -> Weight {
+        unimplemented!()
+    }
+    fn start_referral_campaign() -> Weight {
+        unimplemented!()
+    }
+    fn end_referral_campaign() -> Weight {
+        unimplemented!()
+    }
+    fn set_recovery_guardians(_: u32) -> Weight {
+        unimplemented!()
+    }
+    fn initiate_recovery() -> Weight {
+        unimplemented!()
+    }
+    fn approve_recovery() -> Weight {
+        unimplemented!()
+    }
+    fn reject_recovery() -> Weight {
+        unimplemented!()
+    }
+    fn cancel_recovery() -> Weight {
+        unimplemented!()
+    }
+    fn execute_recovery() -> Weight {
+        unimplemented!()
+    }
 }
 
 impl membership::Trait for Test {
@@ -173,6 +229,11 @@ impl membership::Trait for Test {
     type StakingCandidateStakingHandler =
         staking_handler::StakingManager<Self, StakingCandidateLockId>;
     type CandidateStake = CandidateStake;
+    type HandleDisputeStakingHandler = staking_handler::StakingManager<Self, HandleDisputeLockId>;
+    type HandleDisputeStake = HandleDisputeStake;
+    type HandleDisputeGracePeriod = HandleDisputeGracePeriod;
+    type MaxRecoveryGuardians = MaxRecoveryGuardians;
+    type RecoveryDelay = RecoveryDelay;
 }
 
 impl LockComparator<<Test as balances::Trait>::Balance> for Test {
@@ -185,12 +246,13 @@ pub type Balances = balances::Module<Test>;
 pub type System = frame_system::Module<Test>;
 
 parameter_types! {
-    pub const RewardPeriod: u32 = 2;
     pub const MaxWorkerNumberLimit: u32 = 3;
     pub const MinUnstakingPeriodLimit: u64 = 3;
     pub const MinimumApplicationStake: u64 = 50;
     pub const LockId: [u8; 8] = [1; 8];
     pub const LeaderOpeningStake: u64 = 20;
+    pub const WorkerReviewPeriod: u64 = 10;
+    pub const MaxBudgetSpendingRecords: u64 = 3;
 }
 
 impl Trait for Test {
@@ -200,10 +262,11 @@ impl Trait for Test {
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
-    type RewardPeriod = RewardPeriod;
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type WorkerReviewPeriod = WorkerReviewPeriod;
+    type MaxBudgetSpendingRecords = MaxBudgetSpendingRecords;
 }
 
 impl common::StakingAccountValidator<Test> for () {
@@ -216,13 +279,10 @@ impl crate::WeightInfo for () {
     fn on_initialize_leaving(_: u32) -> Weight {
         0
     }
-    fn on_initialize_rewarding_with_missing_reward(_: u32) -> Weight {
-        0
-    }
-    fn on_initialize_rewarding_with_missing_reward_cant_pay(_: u32) -> Weight {
+    fn on_initialize_expired_openings(_: u32, _: u32) -> Weight {
         0
     }
-    fn on_initialize_rewarding_without_missing_reward(_: u32) -> Weight {
+    fn claim_reward() -> Weight {
         0
     }
     fn apply_on_opening(_: u32) -> Weight {
@@ -285,6 +345,27 @@ impl crate::WeightInfo for () {
     fn worker_remark() -> Weight {
         0
     }
+    fn pay_worker_bonus() -> Weight {
+        0
+    }
+    fn review_worker(_: u32) -> Weight {
+        0
+    }
+    fn shortlist_applications(_: u32) -> Weight {
+        0
+    }
+    fn reject_applications(_: u32) -> Weight {
+        0
+    }
+    fn appoint_deputy(_: u32) -> Weight {
+        0
+    }
+    fn remove_deputy() -> Weight {
+        0
+    }
+    fn set_spending_limit() -> Weight {
+        0
+    }
 }
 
 pub const ACTOR_ORIGIN_ERROR: &'static str = "Invalid membership";
@@ -7,31 +7,34 @@ pub use mock::{build_test_externalities, Test, DEFAULT_WORKER_ACCOUNT_ID};
 use frame_system::RawOrigin;
 
 use crate::tests::fixtures::{
-    CancelOpeningFixture, DecreaseWorkerStakeFixture, IncreaseWorkerStakeFixture, SetBudgetFixture,
-    SetStatusTextFixture, SlashWorkerStakeFixture, SpendFromBudgetFixture,
-    UpdateRewardAccountFixture, UpdateRewardAmountFixture, UpdateWorkerStorageFixture,
-    WithdrawApplicationFixture,
+    AppointDeputyFixture, CancelOpeningFixture, ClaimRewardFixture, DecreaseWorkerStakeFixture,
+    IncreaseWorkerStakeFixture, PayWorkerBonusFixture, RejectApplicationsFixture,
+    RemoveDeputyFixture, ReviewWorkerFixture, SetBudgetFixture, SetSpendingLimitFixture,
+    SetStatusTextFixture, ShortlistApplicationsFixture, SlashWorkerStakeFixture,
+    SpendFromBudgetFixture, UpdateRewardAccountFixture, UpdateRewardAmountFixture,
+    UpdateWorkerStorageFixture, WithdrawApplicationFixture,
 };
 use crate::tests::hiring_workflow::HiringWorkflow;
 use crate::tests::mock::{
     STAKING_ACCOUNT_ID_FOR_CONFLICTING_STAKES, STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER,
 };
-use crate::types::StakeParameters;
+use crate::types::{OldGroupWorker, OldJobApplication, OldOpening, StakeParameters};
 use crate::{
-    default_storage_size_constraint, DefaultInstance, Error, OpeningType, RawEvent,
-    RewardPaymentType, StakePolicy, Trait, Worker,
+    default_storage_size_constraint, ApplicationStatus, DefaultInstance, Error, OpeningType,
+    RawEvent, RewardPaymentType, SpendingLimit, StakePolicy, Trait, Worker,
 };
-use common::working_group::WorkingGroupAuthenticator;
+use common::working_group::{DeputyPermission, WorkingGroupAuthenticator};
 use fixtures::{
     increase_total_balance_issuance_using_account_id, AddOpeningFixture, ApplyOnOpeningFixture,
     EventFixture, FillOpeningFixture, HireLeadFixture, HireRegularWorkerFixture,
     LeaveWorkerRoleFixture, TerminateWorkerRoleFixture, UpdateWorkerRoleAccountFixture,
 };
 use frame_support::dispatch::DispatchError;
-use frame_support::StorageMap;
-use mock::{run_to_block, Balances, RewardPeriod, TestWorkingGroup, ACTOR_ORIGIN_ERROR};
+use frame_support::{StorageDoubleMap, StorageMap};
+use mock::{run_to_block, Balances, TestWorkingGroup, ACTOR_ORIGIN_ERROR};
+use sp_arithmetic::Percent;
 use sp_runtime::traits::Hash;
-use sp_std::collections::btree_map::BTreeMap;
+use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
 
 #[test]
 fn add_opening_succeeded() {
@@ -393,6 +396,29 @@ fn fill_opening_fails_with_application_for_other_opening() {
     });
 }
 
+#[test]
+fn fill_opening_fails_with_rejected_application() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let add_opening_fixture = AddOpeningFixture::default();
+        let opening_id = add_opening_fixture.call_and_assert(Ok(()));
+
+        let apply_on_opening_fixture = ApplyOnOpeningFixture::default_for_opening_id(opening_id);
+        let application_id = apply_on_opening_fixture.call_and_assert(Ok(()));
+
+        RejectApplicationsFixture::default_for_ids(opening_id, vec![application_id])
+            .call_and_assert(Ok(()));
+
+        let fill_opening_fixture =
+            FillOpeningFixture::default_for_ids(opening_id, vec![application_id]);
+
+        fill_opening_fixture.call_and_assert(Err(
+            Error::<Test, DefaultInstance>::ApplicationAlreadyRejected.into(),
+        ));
+    });
+}
+
 #[test]
 fn fill_opening_fails_with_invalid_active_worker_number() {
     build_test_externalities().execute_with(|| {
@@ -625,11 +651,12 @@ fn leave_worker_role_succeeds_with_paying_missed_reward() {
             .with_reward_per_block(Some(reward_per_block))
             .hire();
 
-        let reward_period: u64 = <Test as Trait>::RewardPeriod::get().into();
-        let missed_reward_block_number = reward_period * 2;
+        let missed_reward_block_number = 4;
 
         run_to_block(missed_reward_block_number);
 
+        ClaimRewardFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
         assert_eq!(Balances::usable_balance(&account_id), 0);
 
         SetBudgetFixture::default().with_budget(1000000).execute();
@@ -653,11 +680,10 @@ fn leave_worker_role_succeeds_with_paying_missed_reward() {
             RewardPaymentType::MissedReward,
         ));
 
-        // Didn't get the last reward period: leaving earlier than rewarding.
-        let reward_block_count = leaving_block - reward_period;
+        // Got the reward for the unstaking period as well.
         assert_eq!(
             Balances::usable_balance(&account_id),
-            reward_block_count * reward_per_block + <Test as Trait>::MinimumApplicationStake::get()
+            leaving_block * reward_per_block + <Test as Trait>::MinimumApplicationStake::get()
         );
     });
 }
@@ -1799,6 +1825,194 @@ fn withdraw_worker_application_fails_with_invalid_application_author() {
     });
 }
 
+#[test]
+fn withdraw_rejected_application_succeeds() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let add_opening_fixture = AddOpeningFixture::default();
+        let opening_id = add_opening_fixture.call_and_assert(Ok(()));
+
+        let apply_on_opening_fixture = ApplyOnOpeningFixture::default_for_opening_id(opening_id)
+            .with_initial_balance(<Test as Trait>::MinimumApplicationStake::get() + 1);
+        let application_id = apply_on_opening_fixture.call_and_assert(Ok(()));
+
+        RejectApplicationsFixture::default_for_ids(opening_id, vec![application_id])
+            .call_and_assert(Ok(()));
+
+        WithdrawApplicationFixture::default_for_application_id(application_id)
+            .call_and_assert(Ok(()));
+
+        assert!(!<crate::ApplicationById<Test, DefaultInstance>>::contains_key(application_id));
+    });
+}
+
+#[test]
+fn shortlist_applications_succeeded() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let add_opening_fixture = AddOpeningFixture::default().with_starting_block(starting_block);
+        let opening_id = add_opening_fixture.call_and_assert(Ok(()));
+
+        let apply_on_opening_fixture = ApplyOnOpeningFixture::default_for_opening_id(opening_id);
+        let application_id = apply_on_opening_fixture.call_and_assert(Ok(()));
+
+        ShortlistApplicationsFixture::default_for_ids(opening_id, vec![application_id])
+            .call_and_assert(Ok(()));
+
+        let mut application_ids = BTreeSet::new();
+        application_ids.insert(application_id);
+
+        EventFixture::assert_last_crate_event(RawEvent::ApplicationsShortlisted(
+            opening_id,
+            application_ids,
+        ));
+    });
+}
+
+#[test]
+fn shortlist_applications_fails_with_bad_origin() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let add_opening_fixture = AddOpeningFixture::default();
+        let opening_id = add_opening_fixture.call_and_assert(Ok(()));
+
+        let apply_on_opening_fixture = ApplyOnOpeningFixture::default_for_opening_id(opening_id);
+        let application_id = apply_on_opening_fixture.call_and_assert(Ok(()));
+
+        ShortlistApplicationsFixture::default_for_ids(opening_id, vec![application_id])
+            .with_origin(RawOrigin::None)
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn shortlist_applications_fails_with_rejected_application() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let add_opening_fixture = AddOpeningFixture::default();
+        let opening_id = add_opening_fixture.call_and_assert(Ok(()));
+
+        let apply_on_opening_fixture = ApplyOnOpeningFixture::default_for_opening_id(opening_id);
+        let application_id = apply_on_opening_fixture.call_and_assert(Ok(()));
+
+        RejectApplicationsFixture::default_for_ids(opening_id, vec![application_id])
+            .call_and_assert(Ok(()));
+
+        ShortlistApplicationsFixture::default_for_ids(opening_id, vec![application_id])
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::ApplicationAlreadyRejected.into(),
+            ));
+    });
+}
+
+#[test]
+fn reject_applications_succeeded_with_stake() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let account_id = 2;
+        let total_balance = 300;
+        let stake = 200;
+
+        let add_opening_fixture = AddOpeningFixture::default()
+            .with_starting_block(starting_block)
+            .with_stake_policy(StakePolicy {
+                stake_amount: stake,
+                leaving_unstaking_period: 10,
+            });
+        let opening_id = add_opening_fixture.call_and_assert(Ok(()));
+
+        let apply_on_opening_fixture = ApplyOnOpeningFixture::default_for_opening_id(opening_id)
+            .with_initial_balance(total_balance)
+            .with_stake_parameters(StakeParameters {
+                stake,
+                staking_account_id: account_id,
+            });
+        let application_id = apply_on_opening_fixture.call_and_assert(Ok(()));
+
+        assert_eq!(Balances::usable_balance(&account_id), total_balance - stake);
+
+        RejectApplicationsFixture::default_for_ids(opening_id, vec![application_id])
+            .call_and_assert(Ok(()));
+
+        assert_eq!(Balances::usable_balance(&account_id), total_balance);
+
+        let mut application_ids = BTreeSet::new();
+        application_ids.insert(application_id);
+
+        EventFixture::assert_last_crate_event(RawEvent::ApplicationsRejected(
+            opening_id,
+            application_ids,
+        ));
+    });
+}
+
+#[test]
+fn reject_shortlisted_applications_succeeded() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let add_opening_fixture = AddOpeningFixture::default();
+        let opening_id = add_opening_fixture.call_and_assert(Ok(()));
+
+        let apply_on_opening_fixture = ApplyOnOpeningFixture::default_for_opening_id(opening_id);
+        let application_id = apply_on_opening_fixture.call_and_assert(Ok(()));
+
+        ShortlistApplicationsFixture::default_for_ids(opening_id, vec![application_id])
+            .call_and_assert(Ok(()));
+
+        RejectApplicationsFixture::default_for_ids(opening_id, vec![application_id])
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn reject_applications_fails_with_bad_origin() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let add_opening_fixture = AddOpeningFixture::default();
+        let opening_id = add_opening_fixture.call_and_assert(Ok(()));
+
+        let apply_on_opening_fixture = ApplyOnOpeningFixture::default_for_opening_id(opening_id);
+        let application_id = apply_on_opening_fixture.call_and_assert(Ok(()));
+
+        RejectApplicationsFixture::default_for_ids(opening_id, vec![application_id])
+            .with_origin(RawOrigin::None)
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn reject_applications_fails_with_already_rejected_application() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let add_opening_fixture = AddOpeningFixture::default();
+        let opening_id = add_opening_fixture.call_and_assert(Ok(()));
+
+        let apply_on_opening_fixture = ApplyOnOpeningFixture::default_for_opening_id(opening_id);
+        let application_id = apply_on_opening_fixture.call_and_assert(Ok(()));
+
+        let reject_applications_fixture =
+            RejectApplicationsFixture::default_for_ids(opening_id, vec![application_id]);
+        reject_applications_fixture.call_and_assert(Ok(()));
+        reject_applications_fixture.call_and_assert(Err(
+            Error::<Test, DefaultInstance>::ApplicationAlreadyRejected.into(),
+        ));
+    });
+}
+
 #[test]
 fn cancel_opening_succeeds() {
     build_test_externalities().execute_with(|| {
@@ -1935,16 +2149,20 @@ fn rewards_payments_are_successful() {
         let block_number = 10;
         run_to_block(block_number);
 
+        // The reward isn't paid until claimed.
+        assert_eq!(Balances::usable_balance(&account_id), 0);
+
+        ClaimRewardFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
         assert_eq!(
             Balances::usable_balance(&account_id),
             block_number * reward_per_block
         );
 
-        let reward_period: u64 = <Test as Trait>::RewardPeriod::get().into();
         EventFixture::assert_last_crate_event(RawEvent::RewardPaid(
             worker_id,
             account_id,
-            reward_per_block * reward_period,
+            block_number * reward_per_block,
             RewardPaymentType::RegularReward,
         ));
     });
@@ -1968,6 +2186,8 @@ fn rewards_payments_with_no_budget() {
         let block_number = 10;
         run_to_block(block_number);
 
+        ClaimRewardFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
         assert_eq!(Balances::usable_balance(&account_id), 0);
 
         let worker = TestWorkingGroup::worker_by_id(worker_id);
@@ -2004,6 +2224,8 @@ fn rewards_payments_with_insufficient_budget_and_restored_budget() {
         let block_number = 10;
         run_to_block(block_number);
 
+        ClaimRewardFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
         assert_eq!(Balances::usable_balance(&account_id), first_budget);
 
         let worker = TestWorkingGroup::worker_by_id(worker_id);
@@ -2018,6 +2240,8 @@ fn rewards_payments_with_insufficient_budget_and_restored_budget() {
         let block_number2 = 20;
         run_to_block(block_number2);
 
+        ClaimRewardFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
         assert_eq!(
             Balances::usable_balance(&account_id),
             block_number2 * reward_per_block
@@ -2032,7 +2256,6 @@ fn rewards_payments_with_starting_block() {
         run_to_block(starting_block);
 
         let reward_per_block = 10;
-        let reward_period: u64 = RewardPeriod::get().into();
 
         let worker_id = HireRegularWorkerFixture::default()
             .with_reward_per_block(Some(reward_per_block))
@@ -2047,8 +2270,9 @@ fn rewards_payments_with_starting_block() {
         let block_number = 11;
         run_to_block(block_number);
 
-        let effective_paid_blocks =
-            (block_number - starting_block) - (block_number % reward_period);
+        ClaimRewardFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        let effective_paid_blocks = block_number - starting_block;
         assert_eq!(
             Balances::usable_balance(&account_id),
             effective_paid_blocks * reward_per_block
@@ -2057,96 +2281,302 @@ fn rewards_payments_with_starting_block() {
 }
 
 #[test]
-fn set_budget_succeeded() {
+fn migrate_workers_succeeds() {
     build_test_externalities().execute_with(|| {
-        run_to_block(1);
-
-        let new_budget = 10000;
-        SetBudgetFixture::default()
-            .with_budget(new_budget)
-            .call_and_assert(Ok(()));
+        let migration_block = 3;
+        run_to_block(migration_block);
 
-        EventFixture::assert_last_crate_event(RawEvent::BudgetSet(new_budget));
-    });
-}
-
-#[test]
-fn set_budget_fails_with_bad_origin() {
-    build_test_externalities().execute_with(|| {
-        HireLeadFixture::default().hire_lead();
-        let leader_account_id = 1;
+        let active_worker_id = 1;
+        let leaving_worker_id = 2;
+        let old_worker = OldGroupWorker {
+            member_id: 1,
+            role_account_id: 1,
+            staking_account_id: 1,
+            reward_account_id: 1,
+            started_leaving_at: None,
+            job_unstaking_period: 10,
+            reward_per_block: Some(10),
+            missed_reward: None,
+            created_at: 1,
+        };
+        let old_leaving_worker = OldGroupWorker {
+            started_leaving_at: Some(2),
+            ..old_worker.clone()
+        };
 
-        SetBudgetFixture::default()
-            .with_origin(RawOrigin::Signed(leader_account_id))
-            .call_and_assert(Err(DispatchError::BadOrigin));
-    });
-}
+        frame_support::storage::unhashed::put(
+            &<crate::WorkerById<Test, DefaultInstance>>::hashed_key_for(active_worker_id),
+            &old_worker,
+        );
+        frame_support::storage::unhashed::put(
+            &<crate::WorkerById<Test, DefaultInstance>>::hashed_key_for(leaving_worker_id),
+            &old_leaving_worker,
+        );
 
-#[test]
-fn update_reward_account_succeeds() {
-    build_test_externalities().execute_with(|| {
-        /*
-           Events are not emitted on block 0.
-           So any dispatchable calls made during genesis block formation will have no events emitted.
-           https://substrate.dev/recipes/2-appetizers/4-events.html
-        */
-        run_to_block(1);
+        TestWorkingGroup::migrate_workers();
 
-        let reward_per_block = 10;
+        let worker = TestWorkingGroup::worker_by_id(active_worker_id);
+        assert_eq!(worker.rewarded_until, migration_block);
+        assert_eq!(worker.created_at, 1);
+        assert_eq!(worker.reward_per_block, Some(10));
 
-        let worker_id = HireRegularWorkerFixture::default()
-            .with_reward_per_block(Some(reward_per_block))
-            .hire();
+        let leaving_worker = TestWorkingGroup::worker_by_id(leaving_worker_id);
+        assert_eq!(leaving_worker.rewarded_until, migration_block);
+        assert_eq!(leaving_worker.started_leaving_at, Some(2));
 
-        let new_reward_account = 22;
-        let update_account_fixture =
-            UpdateRewardAccountFixture::default_with_ids(worker_id, new_reward_account);
+        let unstaking_period_end = 12;
+        assert!(
+            <crate::LeavingWorkers<Test, DefaultInstance>>::contains_key(
+                unstaking_period_end,
+                leaving_worker_id
+            )
+        );
+        assert!(
+            !<crate::LeavingWorkers<Test, DefaultInstance>>::contains_key(
+                unstaking_period_end,
+                active_worker_id
+            )
+        );
 
-        update_account_fixture.call_and_assert(Ok(()));
+        run_to_block(unstaking_period_end);
 
-        EventFixture::assert_last_crate_event(RawEvent::WorkerRewardAccountUpdated(
-            worker_id,
-            new_reward_account,
+        assert!(!<crate::WorkerById<Test, DefaultInstance>>::contains_key(
+            leaving_worker_id
+        ));
+        assert!(<crate::WorkerById<Test, DefaultInstance>>::contains_key(
+            active_worker_id
         ));
     });
 }
 
 #[test]
-fn update_reward_account_succeeds_for_leader() {
+fn migrate_applications_succeeds() {
     build_test_externalities().execute_with(|| {
-        let reward_per_block = 10;
+        let application_id = 1;
+        let old_application = OldJobApplication {
+            role_account_id: 1,
+            reward_account_id: 1,
+            staking_account_id: 1,
+            member_id: 1,
+            description_hash: b"description".to_vec(),
+            opening_id: 2,
+        };
 
-        let worker_id = HireLeadFixture::default()
-            .with_reward_per_block(Some(reward_per_block))
-            .hire_lead();
+        frame_support::storage::unhashed::put(
+            &<crate::ApplicationById<Test, DefaultInstance>>::hashed_key_for(application_id),
+            &old_application,
+        );
 
-        let new_reward_account = 22;
-        let update_account_fixture =
-            UpdateRewardAccountFixture::default_with_ids(worker_id, new_reward_account)
-                .with_origin(RawOrigin::Signed(1));
+        TestWorkingGroup::migrate_applications();
 
-        update_account_fixture.call_and_assert(Ok(()));
+        let application = TestWorkingGroup::application_by_id(application_id);
+        assert_eq!(application.status, ApplicationStatus::Pending);
+        assert_eq!(application.opening_id, 2);
+        assert_eq!(application.member_id, 1);
+        assert_eq!(application.description_hash, b"description".to_vec());
+
+        assert!(
+            <crate::ApplicationIdsByOpeningId<DefaultInstance>>::contains_key(2, application_id)
+        );
     });
 }
 
 #[test]
-fn update_reward_account_fails_with_invalid_origin() {
+fn migrate_openings_succeeds() {
     build_test_externalities().execute_with(|| {
-        let update_account_fixture =
-            UpdateRewardAccountFixture::default_with_ids(1, 1).with_origin(RawOrigin::None);
+        let opening_id = 1;
+        let old_opening = OldOpening {
+            opening_type: OpeningType::Regular,
+            created: 1,
+            description_hash: b"description".to_vec(),
+            stake_policy: StakePolicy {
+                stake_amount: 10,
+                leaving_unstaking_period: 100,
+            },
+            reward_per_block: Some(10),
+            creation_stake: 5,
+        };
 
-        update_account_fixture.call_and_assert(Err(DispatchError::BadOrigin));
+        frame_support::storage::unhashed::put(
+            &<crate::OpeningById<Test, DefaultInstance>>::hashed_key_for(opening_id),
+            &old_opening,
+        );
+
+        TestWorkingGroup::migrate_openings();
+
+        let opening = TestWorkingGroup::opening_by_id(opening_id);
+        assert_eq!(opening.application_deadline, None);
+        assert_eq!(opening.expires_at, None);
+        assert_eq!(opening.created, 1);
+        assert_eq!(opening.reward_per_block, Some(10));
+        assert_eq!(opening.creation_stake, 5);
     });
 }
 
 #[test]
-fn update_reward_account_fails_with_invalid_origin_signed_account() {
+fn rewards_payments_with_updated_reward_amount() {
     build_test_externalities().execute_with(|| {
         let reward_per_block = 10;
 
-        let worker_id = HireLeadFixture::default()
+        let worker_id = HireRegularWorkerFixture::default()
             .with_reward_per_block(Some(reward_per_block))
-            .hire_lead();
+            .hire();
+
+        let worker = TestWorkingGroup::worker_by_id(worker_id);
+
+        let account_id = worker.reward_account_id;
+
+        SetBudgetFixture::default().with_budget(100000).execute();
+
+        let block_number = 5;
+        run_to_block(block_number);
+
+        let new_reward_per_block = 20;
+        UpdateRewardAmountFixture::default_for_worker_id(worker_id)
+            .with_reward_per_block(Some(new_reward_per_block))
+            .call_and_assert(Ok(()));
+
+        // The reward accrued with the previous reward amount is paid on the update.
+        assert_eq!(
+            Balances::usable_balance(&account_id),
+            block_number * reward_per_block
+        );
+
+        let block_number2 = 8;
+        run_to_block(block_number2);
+
+        ClaimRewardFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        assert_eq!(
+            Balances::usable_balance(&account_id),
+            block_number * reward_per_block + (block_number2 - block_number) * new_reward_per_block
+        );
+    });
+}
+
+#[test]
+fn claim_reward_fails_with_invalid_origin_signed_account() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default()
+            .with_reward_per_block(Some(10))
+            .hire();
+
+        run_to_block(1);
+
+        ClaimRewardFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(3))
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::SignerIsNotWorkerRoleAccount.into(),
+            ));
+    });
+}
+
+#[test]
+fn claim_reward_fails_with_no_reward() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        run_to_block(1);
+
+        ClaimRewardFixture::default_for_worker_id(worker_id).call_and_assert(Err(Error::<
+            Test,
+            DefaultInstance,
+        >::NoRewardToClaim
+            .into()));
+    });
+}
+
+#[test]
+fn set_budget_succeeded() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let new_budget = 10000;
+        SetBudgetFixture::default()
+            .with_budget(new_budget)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::BudgetSet(new_budget));
+    });
+}
+
+#[test]
+fn set_budget_fails_with_bad_origin() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+        let leader_account_id = 1;
+
+        SetBudgetFixture::default()
+            .with_origin(RawOrigin::Signed(leader_account_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn update_reward_account_succeeds() {
+    build_test_externalities().execute_with(|| {
+        /*
+           Events are not emitted on block 0.
+           So any dispatchable calls made during genesis block formation will have no events emitted.
+           https://substrate.dev/recipes/2-appetizers/4-events.html
+        */
+        run_to_block(1);
+
+        let reward_per_block = 10;
+
+        let worker_id = HireRegularWorkerFixture::default()
+            .with_reward_per_block(Some(reward_per_block))
+            .hire();
+
+        let new_reward_account = 22;
+        let update_account_fixture =
+            UpdateRewardAccountFixture::default_with_ids(worker_id, new_reward_account);
+
+        update_account_fixture.call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::WorkerRewardAccountUpdated(
+            worker_id,
+            new_reward_account,
+        ));
+    });
+}
+
+#[test]
+fn update_reward_account_succeeds_for_leader() {
+    build_test_externalities().execute_with(|| {
+        let reward_per_block = 10;
+
+        let worker_id = HireLeadFixture::default()
+            .with_reward_per_block(Some(reward_per_block))
+            .hire_lead();
+
+        let new_reward_account = 22;
+        let update_account_fixture =
+            UpdateRewardAccountFixture::default_with_ids(worker_id, new_reward_account)
+                .with_origin(RawOrigin::Signed(1));
+
+        update_account_fixture.call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn update_reward_account_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let update_account_fixture =
+            UpdateRewardAccountFixture::default_with_ids(1, 1).with_origin(RawOrigin::None);
+
+        update_account_fixture.call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn update_reward_account_fails_with_invalid_origin_signed_account() {
+    build_test_externalities().execute_with(|| {
+        let reward_per_block = 10;
+
+        let worker_id = HireLeadFixture::default()
+            .with_reward_per_block(Some(reward_per_block))
+            .hire_lead();
 
         let invalid_role_account = 23333;
         let new_reward_account = 22;
@@ -2342,6 +2772,28 @@ fn spend_from_budget_succeeded() {
     });
 }
 
+#[test]
+fn spend_from_budget_prunes_oldest_ledger_records() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let set_budget_fixture = SetBudgetFixture::default().with_budget(1000);
+        assert_eq!(set_budget_fixture.call(), Ok(()));
+
+        let max_records: u64 = <Test as Trait>::MaxBudgetSpendingRecords::get();
+        for _ in 0..=max_records {
+            SpendFromBudgetFixture::default()
+                .with_amount(10)
+                .call_and_assert(Ok(()));
+        }
+
+        assert!(!<crate::BudgetSpendingById<Test, DefaultInstance>>::contains_key(0));
+        for spending_id in 1..=max_records {
+            assert!(<crate::BudgetSpendingById<Test, DefaultInstance>>::contains_key(spending_id));
+        }
+    });
+}
+
 #[test]
 fn spend_from_budget_failed_with_invalid_origin() {
     build_test_externalities().execute_with(|| {
@@ -2382,121 +2834,284 @@ fn spend_from_budget_fails_with_zero_amount() {
 }
 
 #[test]
-fn ensure_worker_origin_works_correctly() {
+fn pay_worker_bonus_succeeded() {
     build_test_externalities().execute_with(|| {
-        let invalid_worker_id = 2;
-        assert_eq!(
-            TestWorkingGroup::ensure_worker_origin(RawOrigin::None.into(), &invalid_worker_id),
-            Err(DispatchError::BadOrigin)
-        );
+        let worker_id = HireRegularWorkerFixture::default().hire();
+        let amount = 100;
+        let rationale = Some(b"rationale".to_vec());
 
-        let account_id = 2;
-        assert_eq!(
-            TestWorkingGroup::ensure_worker_origin(
-                RawOrigin::Signed(account_id).into(),
-                &invalid_worker_id
-            ),
-            Err(Error::<Test, DefaultInstance>::WorkerDoesNotExist.into())
-        );
+        run_to_block(1);
 
-        let worker_id = HireRegularWorkerFixture::default().hire();
+        let set_budget_fixture = SetBudgetFixture::default().with_budget(1000);
+        assert_eq!(set_budget_fixture.call(), Ok(()));
 
-        let invalid_account = 3;
-        assert_eq!(
-            TestWorkingGroup::ensure_worker_origin(
-                RawOrigin::Signed(invalid_account).into(),
-                &worker_id
-            ),
-            Err(Error::<Test, DefaultInstance>::SignerIsNotWorkerRoleAccount.into())
-        );
+        PayWorkerBonusFixture::default_for_worker_id(worker_id)
+            .with_amount(amount)
+            .with_rationale(rationale.clone())
+            .call_and_assert(Ok(()));
 
-        assert_eq!(
-            TestWorkingGroup::ensure_worker_origin(
-                RawOrigin::Signed(account_id).into(),
-                &worker_id
-            ),
-            Ok(())
-        );
+        let reward_account_id = TestWorkingGroup::worker_by_id(worker_id).reward_account_id;
+        EventFixture::assert_last_crate_event(RawEvent::WorkerBonusPaid(
+            worker_id,
+            reward_account_id,
+            amount,
+            rationale,
+        ));
     });
 }
 
 #[test]
-fn ensure_leader_origin_works_correctly() {
+fn pay_worker_bonus_to_leader_succeeded() {
     build_test_externalities().execute_with(|| {
-        assert_eq!(
-            TestWorkingGroup::ensure_leader_origin(RawOrigin::None.into()),
-            Err(DispatchError::BadOrigin)
-        );
-
-        let account_id = 1;
-        assert_eq!(
-            TestWorkingGroup::ensure_leader_origin(RawOrigin::Signed(account_id).into()),
-            Err(Error::<Test, DefaultInstance>::CurrentLeadNotSet.into())
-        );
-
-        HireLeadFixture::default().hire_lead();
+        let lead_id = HireLeadFixture::default().hire_lead();
 
-        let invalid_account = 2;
-        assert_eq!(
-            TestWorkingGroup::ensure_leader_origin(RawOrigin::Signed(invalid_account).into()),
-            Err(Error::<Test, DefaultInstance>::IsNotLeadAccount.into())
-        );
+        let set_budget_fixture = SetBudgetFixture::default().with_budget(1000);
+        assert_eq!(set_budget_fixture.call(), Ok(()));
 
-        assert_eq!(
-            TestWorkingGroup::ensure_leader_origin(RawOrigin::Signed(account_id).into()),
-            Ok(())
-        );
+        PayWorkerBonusFixture::default_for_worker_id(lead_id)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
     });
 }
 
 #[test]
-fn get_leader_member_id_works_correctly() {
+fn pay_worker_bonus_fails_with_invalid_origin() {
     build_test_externalities().execute_with(|| {
-        assert_eq!(TestWorkingGroup::get_leader_member_id(), None);
+        let worker_id = HireRegularWorkerFixture::default().hire();
 
-        HireLeadFixture::default().hire_lead();
+        let set_budget_fixture = SetBudgetFixture::default().with_budget(1000);
+        assert_eq!(set_budget_fixture.call(), Ok(()));
 
-        let leader_member_id = 1;
-        assert_eq!(
-            TestWorkingGroup::get_leader_member_id(),
-            Some(leader_member_id)
-        );
+        PayWorkerBonusFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::IsNotLeadAccount.into()));
     });
 }
 
 #[test]
-fn is_leader_account_id_works_correctly() {
+fn pay_worker_bonus_fails_with_zero_amount() {
     build_test_externalities().execute_with(|| {
-        let invalid_account_id = 2u64;
-        // No leader set
-        assert_eq!(
-            TestWorkingGroup::is_leader_account_id(&invalid_account_id),
-            false
-        );
-
-        HireLeadFixture::default().hire_lead();
+        let worker_id = HireRegularWorkerFixture::default().hire();
 
-        assert_eq!(
-            TestWorkingGroup::is_leader_account_id(&invalid_account_id),
-            false
-        );
+        let set_budget_fixture = SetBudgetFixture::default().with_budget(1000);
+        assert_eq!(set_budget_fixture.call(), Ok(()));
 
-        let account_id = 1u64;
-        assert_eq!(TestWorkingGroup::is_leader_account_id(&account_id), true);
+        PayWorkerBonusFixture::default_for_worker_id(worker_id)
+            .with_amount(0)
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::CannotSpendZero.into()));
     });
 }
 
 #[test]
-fn is_worker_account_id_works_correctly() {
+fn pay_worker_bonus_fails_with_insufficient_budget() {
     build_test_externalities().execute_with(|| {
-        let invalid_account_id = 3u64;
-        let invalid_worker_id = 3u64;
+        let worker_id = HireRegularWorkerFixture::default().hire();
 
-        // Not hired
-        assert_eq!(
-            TestWorkingGroup::is_worker_account_id(&invalid_account_id, &invalid_worker_id),
-            false
-        );
+        PayWorkerBonusFixture::default_for_worker_id(worker_id).call_and_assert(Err(
+            Error::<Test, DefaultInstance>::InsufficientBudgetForSpending.into(),
+        ));
+    });
+}
+
+#[test]
+fn review_worker_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+        let score = Percent::from_percent(95);
+
+        run_to_block(1);
+
+        ReviewWorkerFixture::default_for_worker_id(worker_id)
+            .with_score(score)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::WorkerReviewed(
+            worker_id,
+            0,
+            score,
+            b"review".to_vec(),
+        ));
+    });
+}
+
+#[test]
+fn review_worker_fails_with_already_reviewed_worker() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        ReviewWorkerFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        run_to_block(<Test as Trait>::WorkerReviewPeriod::get() - 1);
+
+        ReviewWorkerFixture::default_for_worker_id(worker_id).call_and_assert(Err(
+            Error::<Test, DefaultInstance>::WorkerAlreadyReviewed.into(),
+        ));
+    });
+}
+
+#[test]
+fn review_worker_succeeded_in_the_next_review_period() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        ReviewWorkerFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        let review_period: u64 = <Test as Trait>::WorkerReviewPeriod::get();
+        run_to_block(review_period);
+
+        ReviewWorkerFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        assert!(TestWorkingGroup::worker_review_by_period(worker_id, 0) != Default::default());
+        assert!(TestWorkingGroup::worker_review_by_period(worker_id, 1) != Default::default());
+    });
+}
+
+#[test]
+fn terminate_worker_role_removes_worker_reviews() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        ReviewWorkerFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        let review_period: u64 = <Test as Trait>::WorkerReviewPeriod::get();
+        run_to_block(review_period);
+
+        ReviewWorkerFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        TerminateWorkerRoleFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        assert!(!<crate::WorkerReviewByPeriod<Test, DefaultInstance>>::contains_key(worker_id, 0));
+        assert!(!<crate::WorkerReviewByPeriod<Test, DefaultInstance>>::contains_key(worker_id, 1));
+    });
+}
+
+#[test]
+fn review_worker_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        ReviewWorkerFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::IsNotLeadAccount.into()));
+    });
+}
+
+#[test]
+fn ensure_worker_origin_works_correctly() {
+    build_test_externalities().execute_with(|| {
+        let invalid_worker_id = 2;
+        assert_eq!(
+            TestWorkingGroup::ensure_worker_origin(RawOrigin::None.into(), &invalid_worker_id),
+            Err(DispatchError::BadOrigin)
+        );
+
+        let account_id = 2;
+        assert_eq!(
+            TestWorkingGroup::ensure_worker_origin(
+                RawOrigin::Signed(account_id).into(),
+                &invalid_worker_id
+            ),
+            Err(Error::<Test, DefaultInstance>::WorkerDoesNotExist.into())
+        );
+
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        let invalid_account = 3;
+        assert_eq!(
+            TestWorkingGroup::ensure_worker_origin(
+                RawOrigin::Signed(invalid_account).into(),
+                &worker_id
+            ),
+            Err(Error::<Test, DefaultInstance>::SignerIsNotWorkerRoleAccount.into())
+        );
+
+        assert_eq!(
+            TestWorkingGroup::ensure_worker_origin(
+                RawOrigin::Signed(account_id).into(),
+                &worker_id
+            ),
+            Ok(())
+        );
+    });
+}
+
+#[test]
+fn ensure_leader_origin_works_correctly() {
+    build_test_externalities().execute_with(|| {
+        assert_eq!(
+            TestWorkingGroup::ensure_leader_origin(RawOrigin::None.into()),
+            Err(DispatchError::BadOrigin)
+        );
+
+        let account_id = 1;
+        assert_eq!(
+            TestWorkingGroup::ensure_leader_origin(RawOrigin::Signed(account_id).into()),
+            Err(Error::<Test, DefaultInstance>::CurrentLeadNotSet.into())
+        );
+
+        HireLeadFixture::default().hire_lead();
+
+        let invalid_account = 2;
+        assert_eq!(
+            TestWorkingGroup::ensure_leader_origin(RawOrigin::Signed(invalid_account).into()),
+            Err(Error::<Test, DefaultInstance>::IsNotLeadAccount.into())
+        );
+
+        assert_eq!(
+            TestWorkingGroup::ensure_leader_origin(RawOrigin::Signed(account_id).into()),
+            Ok(())
+        );
+    });
+}
+
+#[test]
+fn get_leader_member_id_works_correctly() {
+    build_test_externalities().execute_with(|| {
+        assert_eq!(TestWorkingGroup::get_leader_member_id(), None);
+
+        HireLeadFixture::default().hire_lead();
+
+        let leader_member_id = 1;
+        assert_eq!(
+            TestWorkingGroup::get_leader_member_id(),
+            Some(leader_member_id)
+        );
+    });
+}
+
+#[test]
+fn is_leader_account_id_works_correctly() {
+    build_test_externalities().execute_with(|| {
+        let invalid_account_id = 2u64;
+        // No leader set
+        assert_eq!(
+            TestWorkingGroup::is_leader_account_id(&invalid_account_id),
+            false
+        );
+
+        HireLeadFixture::default().hire_lead();
+
+        assert_eq!(
+            TestWorkingGroup::is_leader_account_id(&invalid_account_id),
+            false
+        );
+
+        let account_id = 1u64;
+        assert_eq!(TestWorkingGroup::is_leader_account_id(&account_id), true);
+    });
+}
+
+#[test]
+fn is_worker_account_id_works_correctly() {
+    build_test_externalities().execute_with(|| {
+        let invalid_account_id = 3u64;
+        let invalid_worker_id = 3u64;
+
+        // Not hired
+        assert_eq!(
+            TestWorkingGroup::is_worker_account_id(&invalid_account_id, &invalid_worker_id),
+            false
+        );
 
         let worker_id = HireRegularWorkerFixture::default().hire();
 
@@ -2621,3 +3236,616 @@ fn update_worker_storage_fails_with_too_long_text() {
         ));
     });
 }
+
+#[test]
+fn appoint_deputy_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        run_to_block(1);
+
+        let mut permissions = BTreeSet::new();
+        permissions.insert(DeputyPermission::ManageOpenings);
+        permissions.insert(DeputyPermission::ManageForum);
+
+        AppointDeputyFixture::default_for_worker_id(worker_id)
+            .with_permissions(permissions.clone())
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DeputyAppointed(worker_id, permissions));
+    });
+}
+
+#[test]
+fn appoint_deputy_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        AppointDeputyFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::IsNotLeadAccount.into()));
+    });
+}
+
+#[test]
+fn appoint_deputy_fails_with_leader() {
+    build_test_externalities().execute_with(|| {
+        let lead_id = HireLeadFixture::default().hire_lead();
+
+        AppointDeputyFixture::default_for_worker_id(lead_id).call_and_assert(Err(
+            Error::<Test, DefaultInstance>::CannotAppointLeaderAsDeputy.into(),
+        ));
+    });
+}
+
+#[test]
+fn appoint_deputy_fails_with_empty_permissions() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        AppointDeputyFixture::default_for_worker_id(worker_id)
+            .with_permissions(BTreeSet::new())
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::NoDeputyPermissionsProvided.into(),
+            ));
+    });
+}
+
+#[test]
+fn remove_deputy_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        run_to_block(1);
+
+        AppointDeputyFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        RemoveDeputyFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DeputyRemoved(worker_id));
+    });
+}
+
+#[test]
+fn remove_deputy_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        AppointDeputyFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        RemoveDeputyFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::IsNotLeadAccount.into()));
+    });
+}
+
+#[test]
+fn remove_deputy_fails_with_not_deputy() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        RemoveDeputyFixture::default_for_worker_id(worker_id)
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::WorkerIsNotDeputy.into()));
+    });
+}
+
+#[test]
+fn deputy_permissions_revoked_on_worker_termination() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        AppointDeputyFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        TerminateWorkerRoleFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        assert!(
+            !<crate::DeputyPermissionsByWorkerId<Test, DefaultInstance>>::contains_key(worker_id)
+        );
+    });
+}
+
+#[test]
+fn deputy_permissions_revoked_on_worker_leaving() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        AppointDeputyFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        LeaveWorkerRoleFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        assert!(
+            !<crate::DeputyPermissionsByWorkerId<Test, DefaultInstance>>::contains_key(worker_id)
+        );
+        assert!(!TestWorkingGroup::is_leader_or_deputy_account_id(
+            &DEFAULT_WORKER_ACCOUNT_ID,
+            DeputyPermission::ManageOpenings
+        ));
+    });
+}
+
+#[test]
+fn deputy_permissions_revoked_on_leader_termination() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+        let leader_worker_id = TestWorkingGroup::current_lead().unwrap();
+
+        AppointDeputyFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        TerminateWorkerRoleFixture::default_for_worker_id(leader_worker_id)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        assert!(
+            !<crate::DeputyPermissionsByWorkerId<Test, DefaultInstance>>::contains_key(worker_id)
+        );
+        assert!(!TestWorkingGroup::is_leader_or_deputy_account_id(
+            &DEFAULT_WORKER_ACCOUNT_ID,
+            DeputyPermission::ManageOpenings
+        ));
+    });
+}
+
+#[test]
+fn update_role_account_moves_deputy_permissions() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+        let new_account_id = 10;
+
+        AppointDeputyFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        UpdateWorkerRoleAccountFixture::default_with_ids(worker_id, new_account_id)
+            .call_and_assert(Ok(()));
+
+        assert!(!TestWorkingGroup::is_leader_or_deputy_account_id(
+            &DEFAULT_WORKER_ACCOUNT_ID,
+            DeputyPermission::ManageOpenings
+        ));
+        assert!(TestWorkingGroup::is_leader_or_deputy_account_id(
+            &new_account_id,
+            DeputyPermission::ManageOpenings
+        ));
+    });
+}
+
+#[test]
+fn add_opening_succeeded_with_deputy() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        AppointDeputyFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        AddOpeningFixture::default()
+            .with_origin(RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID))
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn add_opening_fails_with_deputy_without_permission() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        let mut permissions = BTreeSet::new();
+        permissions.insert(DeputyPermission::ManageForum);
+
+        AppointDeputyFixture::default_for_worker_id(worker_id)
+            .with_permissions(permissions)
+            .call_and_assert(Ok(()));
+
+        AddOpeningFixture::default()
+            .with_origin(RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::IsNotLeadAccount.into()));
+    });
+}
+
+#[test]
+fn ensure_leader_or_deputy_origin_works_correctly() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        let lead_account_id = 1;
+        assert_eq!(
+            TestWorkingGroup::ensure_leader_or_deputy_origin(
+                RawOrigin::Signed(lead_account_id).into(),
+                DeputyPermission::ManageForum
+            ),
+            Ok(())
+        );
+
+        assert_eq!(
+            TestWorkingGroup::ensure_leader_or_deputy_origin(
+                RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID).into(),
+                DeputyPermission::ManageForum
+            ),
+            Err(Error::<Test, DefaultInstance>::IsNotLeadAccount.into())
+        );
+
+        let mut permissions = BTreeSet::new();
+        permissions.insert(DeputyPermission::ManageForum);
+
+        AppointDeputyFixture::default_for_worker_id(worker_id)
+            .with_permissions(permissions)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            TestWorkingGroup::ensure_leader_or_deputy_origin(
+                RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID).into(),
+                DeputyPermission::ManageForum
+            ),
+            Ok(())
+        );
+
+        assert_eq!(
+            TestWorkingGroup::ensure_leader_or_deputy_origin(
+                RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID).into(),
+                DeputyPermission::ManageCuratorGroups
+            ),
+            Err(Error::<Test, DefaultInstance>::IsNotLeadAccount.into())
+        );
+    });
+}
+
+#[test]
+fn is_leader_or_deputy_account_id_works_correctly() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        let lead_account_id = 1u64;
+        assert!(TestWorkingGroup::is_leader_or_deputy_account_id(
+            &lead_account_id,
+            DeputyPermission::ManageStorageBuckets
+        ));
+
+        assert!(!TestWorkingGroup::is_leader_or_deputy_account_id(
+            &DEFAULT_WORKER_ACCOUNT_ID,
+            DeputyPermission::ManageStorageBuckets
+        ));
+
+        let mut permissions = BTreeSet::new();
+        permissions.insert(DeputyPermission::ManageStorageBuckets);
+
+        AppointDeputyFixture::default_for_worker_id(worker_id)
+            .with_permissions(permissions)
+            .call_and_assert(Ok(()));
+
+        assert!(TestWorkingGroup::is_leader_or_deputy_account_id(
+            &DEFAULT_WORKER_ACCOUNT_ID,
+            DeputyPermission::ManageStorageBuckets
+        ));
+    });
+}
+
+#[test]
+fn set_spending_limit_succeeded() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let spending_limit = Some(SpendingLimit {
+            amount: 500,
+            period: 10,
+        });
+
+        SetSpendingLimitFixture::default()
+            .with_spending_limit(spending_limit.clone())
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::SpendingLimitSet(spending_limit));
+
+        SetSpendingLimitFixture::default()
+            .with_spending_limit(None)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::SpendingLimitSet(None));
+    });
+}
+
+#[test]
+fn set_spending_limit_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        SetSpendingLimitFixture::default()
+            .with_origin(RawOrigin::Signed(1))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn set_spending_limit_fails_with_zero_period() {
+    build_test_externalities().execute_with(|| {
+        SetSpendingLimitFixture::default()
+            .with_spending_limit(Some(SpendingLimit {
+                amount: 500,
+                period: 0,
+            }))
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::InvalidSpendingLimitPeriod.into(),
+            ));
+    });
+}
+
+#[test]
+fn spend_from_budget_fails_with_exceeded_spending_limit() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        SetBudgetFixture::default().with_budget(1000).execute();
+
+        SetSpendingLimitFixture::default()
+            .with_spending_limit(Some(SpendingLimit {
+                amount: 150,
+                period: 10,
+            }))
+            .call_and_assert(Ok(()));
+
+        SpendFromBudgetFixture::default()
+            .with_amount(100)
+            .call_and_assert(Ok(()));
+
+        SpendFromBudgetFixture::default()
+            .with_amount(100)
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::SpendingLimitExceeded.into()
+            ));
+    });
+}
+
+#[test]
+fn spend_from_budget_succeeded_in_new_spending_period() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        SetBudgetFixture::default().with_budget(1000).execute();
+
+        let period = 10;
+        SetSpendingLimitFixture::default()
+            .with_spending_limit(Some(SpendingLimit {
+                amount: 100,
+                period,
+            }))
+            .call_and_assert(Ok(()));
+
+        SpendFromBudgetFixture::default()
+            .with_amount(100)
+            .call_and_assert(Ok(()));
+
+        run_to_block(period);
+
+        SpendFromBudgetFixture::default()
+            .with_amount(100)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(TestWorkingGroup::lead_spending_in_period(), (1, 100));
+    });
+}
+
+#[test]
+fn spend_from_budget_by_council_succeeded_above_spending_limit() {
+    build_test_externalities().execute_with(|| {
+        let account_id = 2;
+        let amount = 500;
+        let rationale = Some(b"rationale".to_vec());
+
+        run_to_block(1);
+
+        SetBudgetFixture::default().with_budget(1000).execute();
+
+        SetSpendingLimitFixture::default()
+            .with_spending_limit(Some(SpendingLimit {
+                amount: 100,
+                period: 10,
+            }))
+            .call_and_assert(Ok(()));
+
+        SpendFromBudgetFixture::default()
+            .with_origin(RawOrigin::Root)
+            .with_account_id(account_id)
+            .with_amount(amount)
+            .with_rationale(rationale.clone())
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::BudgetSpending(
+            account_id, amount, rationale,
+        ));
+
+        // Council spending doesn't count towards the lead spending limit.
+        assert_eq!(TestWorkingGroup::lead_spending_in_period(), (0, 0));
+    });
+}
+
+#[test]
+fn pay_worker_bonus_fails_with_exceeded_spending_limit() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        SetBudgetFixture::default().with_budget(1000).execute();
+
+        SetSpendingLimitFixture::default()
+            .with_spending_limit(Some(SpendingLimit {
+                amount: 100,
+                period: 10,
+            }))
+            .call_and_assert(Ok(()));
+
+        PayWorkerBonusFixture::default_for_worker_id(worker_id)
+            .with_amount(101)
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::SpendingLimitExceeded.into()
+            ));
+
+        PayWorkerBonusFixture::default_for_worker_id(worker_id)
+            .with_amount(100)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(TestWorkingGroup::next_budget_spending_id(), 1);
+    });
+}
+
+#[test]
+fn add_opening_succeeded_with_deadlines() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let expires_at = 10;
+        let opening_id = AddOpeningFixture::default()
+            .with_starting_block(starting_block)
+            .with_application_deadline(Some(5))
+            .with_expires_at(Some(expires_at))
+            .call_and_assert(Ok(()));
+
+        assert!(
+            <crate::ExpiringOpenings<Test, DefaultInstance>>::contains_key(expires_at, opening_id)
+        );
+    });
+}
+
+#[test]
+fn add_opening_fails_with_invalid_application_deadline() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let starting_block = 5;
+        run_to_block(starting_block);
+
+        AddOpeningFixture::default()
+            .with_starting_block(starting_block)
+            .with_application_deadline(Some(starting_block))
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::InvalidApplicationDeadline.into(),
+            ));
+
+        AddOpeningFixture::default()
+            .with_starting_block(starting_block)
+            .with_application_deadline(Some(20))
+            .with_expires_at(Some(10))
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::InvalidApplicationDeadline.into(),
+            ));
+    });
+}
+
+#[test]
+fn add_opening_fails_with_invalid_expiry() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let starting_block = 5;
+        run_to_block(starting_block);
+
+        AddOpeningFixture::default()
+            .with_starting_block(starting_block)
+            .with_expires_at(Some(starting_block))
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::InvalidOpeningExpiry.into()
+            ));
+    });
+}
+
+#[test]
+fn apply_on_opening_fails_after_application_deadline() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let application_deadline = 5;
+        let opening_id = AddOpeningFixture::default()
+            .with_starting_block(starting_block)
+            .with_application_deadline(Some(application_deadline))
+            .call_and_assert(Ok(()));
+
+        run_to_block(application_deadline);
+
+        ApplyOnOpeningFixture::default_for_opening_id(opening_id).call_and_assert(Err(
+            Error::<Test, DefaultInstance>::ApplicationDeadlinePassed.into(),
+        ));
+    });
+}
+
+#[test]
+fn expired_opening_canceled_and_stakes_released() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let account_id = 2;
+        let total_balance = 300;
+        let stake = 200;
+        let expires_at = 5;
+
+        let opening_id = AddOpeningFixture::default()
+            .with_starting_block(starting_block)
+            .with_stake_policy(StakePolicy {
+                stake_amount: stake,
+                leaving_unstaking_period: 10,
+            })
+            .with_expires_at(Some(expires_at))
+            .call_and_assert(Ok(()));
+
+        let application_id = ApplyOnOpeningFixture::default_for_opening_id(opening_id)
+            .with_initial_balance(total_balance)
+            .with_stake_parameters(StakeParameters {
+                stake,
+                staking_account_id: account_id,
+            })
+            .call_and_assert(Ok(()));
+
+        assert_eq!(Balances::usable_balance(&account_id), total_balance - stake);
+
+        let lead_balance = Balances::usable_balance(&1);
+
+        run_to_block(expires_at);
+
+        assert!(!<crate::OpeningById<Test, DefaultInstance>>::contains_key(
+            opening_id
+        ));
+        assert!(!<crate::ApplicationById<Test, DefaultInstance>>::contains_key(application_id));
+        assert!(
+            !<crate::ApplicationIdsByOpeningId<DefaultInstance>>::contains_key(
+                opening_id,
+                application_id
+            )
+        );
+        assert!(
+            !<crate::ExpiringOpenings<Test, DefaultInstance>>::contains_key(expires_at, opening_id)
+        );
+
+        assert_eq!(Balances::usable_balance(&account_id), total_balance);
+        assert_eq!(
+            Balances::usable_balance(&1),
+            lead_balance + <Test as Trait>::LeaderOpeningStake::get()
+        );
+
+        EventFixture::assert_last_crate_event(RawEvent::OpeningExpired(opening_id));
+    });
+}
+
+#[test]
+fn canceled_opening_removed_from_expiring_openings() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        HireLeadFixture::default().hire_lead();
+
+        let expires_at = 5;
+        let opening_id = AddOpeningFixture::default()
+            .with_starting_block(starting_block)
+            .with_expires_at(Some(expires_at))
+            .call_and_assert(Ok(()));
+
+        CancelOpeningFixture::default_for_opening_id(opening_id).call_and_assert(Ok(()));
+
+        assert!(
+            !<crate::ExpiringOpenings<Test, DefaultInstance>>::contains_key(expires_at, opening_id)
+        );
+
+        run_to_block(expires_at);
+
+        EventFixture::assert_last_crate_event(RawEvent::OpeningCanceled(opening_id));
+    });
+}
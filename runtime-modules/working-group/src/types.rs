@@ -8,6 +8,7 @@ use serde::{Deserialize, Serialize};
 use sp_std::marker::PhantomData;
 
 use common::{ActorId, MemberId};
+use sp_arithmetic::Percent;
 
 /// Working group job application type alias.
 pub type Application<T> = JobApplication<<T as frame_system::Trait>::AccountId, MemberId<T>>;
@@ -66,6 +67,12 @@ pub struct Opening<BlockNumber: Ord, Balance> {
     /// Block at which opening was added.
     pub created: BlockNumber,
 
+    /// Block starting from which the applications are not accepted. None means 'no deadline'.
+    pub application_deadline: Option<BlockNumber>,
+
+    /// Block at which the opening is canceled if it wasn't filled. None means 'no expiry'.
+    pub expires_at: Option<BlockNumber>,
+
     /// Hash of the opening description.
     pub description_hash: Vec<u8>,
 
@@ -98,6 +105,26 @@ impl Default for OpeningType {
     }
 }
 
+/// Screening status of the application for the job opening.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, Copy)]
+pub enum ApplicationStatus {
+    /// Application was not screened yet.
+    Pending,
+
+    /// Application was shortlisted by the lead.
+    Shortlisted,
+
+    /// Application was rejected by the lead. The application stake is released.
+    Rejected,
+}
+
+impl Default for ApplicationStatus {
+    fn default() -> Self {
+        Self::Pending
+    }
+}
+
 /// An application for the regular worker/lead role opening.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
@@ -119,6 +146,9 @@ pub struct JobApplication<AccountId, MemberId> {
 
     /// Opening ID for the application
     pub opening_id: OpeningId,
+
+    /// Screening status of the application.
+    pub status: ApplicationStatus,
 }
 
 impl<AccountId: Clone, MemberId: Clone> JobApplication<AccountId, MemberId> {
@@ -138,8 +168,14 @@ impl<AccountId: Clone, MemberId: Clone> JobApplication<AccountId, MemberId> {
             member_id: member_id.clone(),
             opening_id,
             description_hash,
+            status: ApplicationStatus::Pending,
         }
     }
+
+    /// Defines whether the application was rejected by the lead.
+    pub fn is_rejected(&self) -> bool {
+        self.status == ApplicationStatus::Rejected
+    }
 }
 
 /// Working group participant: regular worker or lead.
@@ -174,9 +210,12 @@ pub struct GroupWorker<AccountId, MemberId, BlockNumber, Balance> {
 
     /// Specifies the block when the worker was created.
     pub created_at: BlockNumber,
+
+    /// Block up to which the worker reward has been paid or saved as the missed reward.
+    pub rewarded_until: BlockNumber,
 }
 
-impl<AccountId: Clone, MemberId: Clone, BlockNumber, Balance>
+impl<AccountId: Clone, MemberId: Clone, BlockNumber: Clone, Balance>
     GroupWorker<AccountId, MemberId, BlockNumber, Balance>
 {
     /// Creates a new _GroupWorker_ using parameters.
@@ -198,7 +237,8 @@ impl<AccountId: Clone, MemberId: Clone, BlockNumber, Balance>
             job_unstaking_period,
             reward_per_block,
             missed_reward: None,
-            created_at,
+            created_at: created_at.clone(),
+            rewarded_until: created_at,
         }
     }
 
@@ -208,6 +248,136 @@ impl<AccountId: Clone, MemberId: Clone, BlockNumber, Balance>
     }
 }
 
+/// Job opening in the format preceding the application deadlines and the opening expiry.
+/// Used by the stored openings migration only.
+#[derive(Encode, Decode, Default, Clone, PartialEq)]
+pub(crate) struct OldOpening<BlockNumber: Ord, Balance> {
+    /// Defines opening type: Leader or worker.
+    pub opening_type: OpeningType,
+
+    /// Block at which opening was added.
+    pub created: BlockNumber,
+
+    /// Hash of the opening description.
+    pub description_hash: Vec<u8>,
+
+    /// Stake policy for the job opening.
+    pub stake_policy: StakePolicy<BlockNumber, Balance>,
+
+    /// Reward per block for the job opening.
+    pub reward_per_block: Option<Balance>,
+
+    /// Stake used to create the opening.
+    pub creation_stake: Balance,
+}
+
+/// Job application in the format preceding the application screening.
+/// Used by the stored applications migration only.
+#[derive(Encode, Decode, Default, Clone, PartialEq)]
+pub(crate) struct OldJobApplication<AccountId, MemberId> {
+    /// Account used to authenticate in this role.
+    pub role_account_id: AccountId,
+
+    /// Reward account id.
+    pub reward_account_id: AccountId,
+
+    /// Account used to stake in this role.
+    pub staking_account_id: AccountId,
+
+    /// Member applying.
+    pub member_id: MemberId,
+
+    /// Hash of the application description.
+    pub description_hash: Vec<u8>,
+
+    /// Opening ID for the application
+    pub opening_id: OpeningId,
+}
+
+/// Working group participant in the format preceding the lazy reward accrual.
+/// Used by the stored workers migration only.
+#[derive(Encode, Decode, Default, Clone, PartialEq)]
+pub(crate) struct OldGroupWorker<AccountId, MemberId, BlockNumber, Balance> {
+    /// Member id related to the worker/lead.
+    pub member_id: MemberId,
+
+    /// Account used to authenticate in this role.
+    pub role_account_id: AccountId,
+
+    /// Account used to stake in this role.
+    pub staking_account_id: AccountId,
+
+    /// Reward account id.
+    pub reward_account_id: AccountId,
+
+    /// Specifies the block when the worker chose to leave.
+    pub started_leaving_at: Option<BlockNumber>,
+
+    /// Unstaking period when the worker chooses to leave the role.
+    pub job_unstaking_period: BlockNumber,
+
+    /// Optional reward setting for the worker.
+    pub reward_per_block: Option<Balance>,
+
+    /// Total missed reward amount.
+    pub missed_reward: Option<Balance>,
+
+    /// Specifies the block when the worker was created.
+    pub created_at: BlockNumber,
+}
+
+/// Performance review of the worker.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorkerReview<BlockNumber> {
+    /// Performance score given by the reviewer.
+    pub score: Percent,
+
+    /// Hash of the review text.
+    pub review_hash: Vec<u8>,
+
+    /// Block at which the review was added.
+    pub reviewed_at: BlockNumber,
+}
+
+/// Lead spending limit for the working group budget.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpendingLimit<Balance, BlockNumber> {
+    /// Max amount the lead can spend from the budget during the period.
+    pub amount: Balance,
+
+    /// Spending limit period length in blocks.
+    pub period: BlockNumber,
+}
+
+/// Working group budget spending ledger record.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, Default, PartialEq, Eq)]
+pub struct BudgetSpendingRecord<AccountId, Balance, BlockNumber> {
+    /// Account that received the tokens.
+    pub recipient: AccountId,
+
+    /// Spent amount.
+    pub amount: Balance,
+
+    /// Hash of the spending rationale.
+    pub rationale_hash: Option<Vec<u8>>,
+
+    /// Block at which the spending was made.
+    pub spent_at: BlockNumber,
+
+    /// Defines whether the spending was approved by the council (root origin).
+    pub approved_by_council: bool,
+}
+
+/// Working group budget spending ledger record type alias.
+pub type BudgetSpendingRecordOf<T> = BudgetSpendingRecord<
+    <T as frame_system::Trait>::AccountId,
+    BalanceOf<T>,
+    <T as frame_system::Trait>::BlockNumber,
+>;
+
 /// Stake policy for the job opening.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Debug, Clone, Default, PartialEq, Eq)]
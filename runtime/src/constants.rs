@@ -122,12 +122,14 @@ parameter_types! {
     pub const MembershipWorkingGroupLockId: LockIdentifier = *b"wg-membr";
     pub const InvitedMemberLockId: LockIdentifier = *b"invitemb";
     pub const BoundStakingAccountLockId: LockIdentifier = *b"boundsta";
+    pub const HandleDisputeLockId: LockIdentifier = *b"handledi";
     pub const BountyLockId: LockIdentifier = *b"bounty  ";
     pub const OperationsWorkingGroupAlphaLockId: LockIdentifier = *b"wg-opera";
     pub const GatewayWorkingGroupLockId: LockIdentifier = *b"wg-gatew";
     pub const OperationsWorkingGroupBetaLockId: LockIdentifier = *b"wg-operb";
     pub const OperationsWorkingGroupGammaLockId: LockIdentifier = *b"wg-operg";
     pub const DistributionWorkingGroupLockId: LockIdentifier = *b"wg-distr";
+    pub const ForumPollVoteLockId: LockIdentifier = *b"forumpol";
 }
 
 // Staking lock ID used by nomination and validation in the staking pallet.
@@ -142,6 +144,7 @@ lazy_static! {
         VESTING_LOCK_ID,
         InvitedMemberLockId::get(),
         BoundStakingAccountLockId::get(),
+        ForumPollVoteLockId::get(),
     ]
     .to_vec();
 }
@@ -1,5 +1,6 @@
 use crate::{AccountId, ContentWorkingGroup, Runtime};
-use common::{membership::MemberOriginValidator, working_group::WorkingGroupAuthenticator};
+use common::membership::MemberOriginValidator;
+use common::working_group::{DeputyPermission, WorkingGroupAuthenticator};
 
 impl content::ContentActorAuthenticator for Runtime {
     type CuratorId = u64;
@@ -9,6 +10,10 @@ impl content::ContentActorAuthenticator for Runtime {
         ContentWorkingGroup::is_leader_account_id(account_id)
     }
 
+    fn is_lead_or_deputy(account_id: &AccountId, permission: DeputyPermission) -> bool {
+        ContentWorkingGroup::is_leader_or_deputy_account_id(account_id, permission)
+    }
+
     fn is_curator(curator_id: &Self::CuratorId, account_id: &AccountId) -> bool {
         ContentWorkingGroup::is_worker_account_id(account_id, curator_id)
     }
@@ -43,7 +43,14 @@ macro_rules! wrap_working_group_call {
 pub struct ExtrinsicProposalEncoder;
 impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
     fn encode_proposal(proposal_details: ProposalDetailsOf<Runtime>) -> Vec<u8> {
-        let call = match proposal_details {
+        Self::create_call(proposal_details).encode()
+    }
+}
+
+impl ExtrinsicProposalEncoder {
+    // Creates the runtime call corresponding to the proposal details.
+    fn create_call(proposal_details: ProposalDetailsOf<Runtime>) -> Call {
+        match proposal_details {
             ProposalDetails::Signal(signal) => {
                 Call::JoystreamUtility(joystream_utility::Call::execute_signal_proposal(signal))
             }
@@ -101,9 +108,12 @@ impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
                     Wg::terminate_role_call(terminate_role_params)
                 )
             }
-            ProposalDetails::AmendConstitution(constitution_text) => Call::Constitution(
-                pallet_constitution::Call::amend_constitution(constitution_text),
-            ),
+            ProposalDetails::AmendConstitution(constitution_text, base_amendment_number) => {
+                Call::Constitution(pallet_constitution::Call::amend_constitution(
+                    constitution_text,
+                    base_amendment_number,
+                ))
+            }
             ProposalDetails::CancelWorkingGroupLeadOpening(opening_id, working_group) => {
                 wrap_working_group_call!(
                     working_group,
@@ -149,9 +159,79 @@ impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
             ProposalDetails::VetoProposal(proposal_id) => {
                 Call::ProposalsEngine(proposals_engine::Call::veto_proposal(proposal_id))
             }
-        };
+            ProposalDetails::UpdateNftMarketplaceParameters(params) => {
+                Call::Content(content::Call::update_nft_marketplace_parameters(params))
+            }
+            ProposalDetails::SetMaxRewardAllowed(amount) => {
+                Call::Content(content::Call::update_max_reward_allowed(amount))
+            }
+            ProposalDetails::SetMinCashoutAllowed(amount) => {
+                Call::Content(content::Call::update_min_cashout_allowed(amount))
+            }
+            ProposalDetails::UpdateStorageDataSizeFee(new_data_size_fee) => {
+                Call::Storage(storage::Call::update_data_size_fee(new_data_size_fee))
+            }
+            ProposalDetails::UpdateStorageBucketsPerBagLimit(new_limit) => Call::Storage(
+                storage::Call::update_storage_buckets_per_bag_limit(new_limit),
+            ),
+            ProposalDetails::UpdateStorageBucketsVoucherMaxLimits(
+                new_objects_size,
+                new_objects_number,
+            ) => Call::Storage(storage::Call::update_storage_buckets_voucher_max_limits(
+                new_objects_size,
+                new_objects_number,
+            )),
+            ProposalDetails::UpdateDynamicBagStorageBucketsNumber(
+                dynamic_bag_type,
+                number_of_storage_buckets,
+            ) => Call::Storage(
+                storage::Call::update_number_of_storage_buckets_in_dynamic_bag_creation_policy(
+                    dynamic_bag_type,
+                    number_of_storage_buckets,
+                ),
+            ),
+            ProposalDetails::UpdateStorageBlacklist(remove_hashes, add_hashes) => {
+                Call::Storage(storage::Call::update_blacklist(remove_hashes, add_hashes))
+            }
+            ProposalDetails::BlockStorageUploads(new_status) => {
+                Call::Storage(storage::Call::update_uploading_blocked_status(new_status))
+            }
+            ProposalDetails::VestingFundingRequest(params) => {
+                Call::Council(council::Call::vesting_funding_request(params))
+            }
+            ProposalDetails::CancelVestingFundingRequest(request_id) => {
+                Call::Council(council::Call::cancel_vesting_funding_request(request_id))
+            }
+            ProposalDetails::UpdateBlogAuthor(member_id, authorized) => {
+                Call::Blog(blog::Call::update_author(member_id, authorized))
+            }
+            ProposalDetails::ReviewBlogDrafts(decisions) => {
+                Call::Blog(blog::Call::review_drafts(decisions))
+            }
+            ProposalDetails::SetWorkingGroupSpendingLimit(spending_limit, working_group) => {
+                wrap_working_group_call!(
+                    working_group,
+                    Wg::create_set_spending_limit_call(spending_limit)
+                )
+            }
+            ProposalDetails::SpendFromWorkingGroupBudget(
+                account_id,
+                amount,
+                rationale,
+                working_group,
+            ) => wrap_working_group_call!(
+                working_group,
+                Wg::create_spend_from_budget_call(account_id, amount, rationale)
+            ),
+            ProposalDetails::StartReferralCampaign(budget, duration) => {
+                Call::Members(membership::Call::start_referral_campaign(budget, duration))
+            }
+            ProposalDetails::Batch(batch) => {
+                let calls = batch.into_iter().map(Self::create_call).collect();
 
-        call.encode()
+                Call::JoystreamUtility(joystream_utility::Call::execute_batch_proposal(calls))
+            }
+        }
     }
 }
 
@@ -178,6 +258,8 @@ where
             OpeningType::Leader,
             create_opening_params.stake_policy,
             create_opening_params.reward_per_block,
+            None, // Leader openings created by the council don't expire
+            None,
         )
     }
 
@@ -237,4 +319,22 @@ where
     ) -> working_group::Call<T, I> {
         working_group::Call::<T, I>::cancel_opening(opening_id)
     }
+
+    // Generic call constructor for the working group 'set spending limit'.
+    fn create_set_spending_limit_call(
+        spending_limit: Option<
+            working_group::SpendingLimit<working_group::BalanceOf<T>, T::BlockNumber>,
+        >,
+    ) -> working_group::Call<T, I> {
+        working_group::Call::<T, I>::set_spending_limit(spending_limit)
+    }
+
+    // Generic call constructor for the working group 'spend from budget'.
+    fn create_spend_from_budget_call(
+        account_id: T::AccountId,
+        amount: working_group::BalanceOf<T>,
+        rationale: Vec<u8>,
+    ) -> working_group::Call<T, I> {
+        working_group::Call::<T, I>::spend_from_budget(account_id, amount, Some(rationale))
+    }
 }
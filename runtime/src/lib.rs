@@ -72,6 +72,7 @@ pub use runtime_api::*;
 
 use integration::proposals::{CouncilManager, ExtrinsicProposalEncoder};
 
+use common::membership::{MemberVerificationProvider, VerificationKind};
 use common::working_group::{WorkingGroup, WorkingGroupBudgetHandler};
 use council::ReferendumConnection;
 use referendum::{CastVote, OptionResult};
@@ -98,7 +99,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("joystream-node"),
     impl_name: create_runtime_str!("joystream-node"),
     authoring_version: 10,
-    spec_version: 6,
+    spec_version: 7,
     impl_version: 0,
     apis: crate::runtime_api::EXPORTED_RUNTIME_API_VERSIONS,
     transaction_version: 1,
@@ -632,6 +633,10 @@ impl referendum::Trait<ReferendumInstance> for Runtime {
     }
 }
 
+parameter_types! {
+    pub const MaxVestingMilestones: u32 = 20;
+}
+
 impl council::Trait for Runtime {
     type Event = Event;
     type Referendum = ReferendumModule;
@@ -645,6 +650,7 @@ impl council::Trait for Runtime {
     type StakingAccountValidator = Members;
     type ElectedMemberRewardPeriod = ElectedMemberRewardPeriod;
     type BudgetRefillPeriod = BudgetRefillPeriod;
+    type MaxVestingMilestones = MaxVestingMilestones;
     type MemberOriginValidator = Members;
     type WeightInfo = weights::council::WeightInfo;
 
@@ -715,6 +721,10 @@ parameter_types! {
     pub const DefaultInitialInvitationBalance: Balance = 100;
     // The candidate stake should be more than the transaction fee which currently is 53
     pub const CandidateStake: Balance = 200;
+    pub const HandleDisputeStake: Balance = 2000;
+    pub const HandleDisputeGracePeriod: BlockNumber = 100_800; // 1 week
+    pub const MaxRecoveryGuardians: u32 = 10;
+    pub const RecoveryDelay: BlockNumber = 100_800; // 1 week
 }
 
 impl membership::Trait for Runtime {
@@ -727,6 +737,11 @@ impl membership::Trait for Runtime {
     type WeightInfo = weights::membership::WeightInfo;
     type ReferralCutMaximumPercent = ReferralCutMaximumPercent;
     type CandidateStake = CandidateStake;
+    type HandleDisputeStakingHandler = HandleDisputeStakingManager;
+    type HandleDisputeStake = HandleDisputeStake;
+    type HandleDisputeGracePeriod = HandleDisputeGracePeriod;
+    type MaxRecoveryGuardians = MaxRecoveryGuardians;
+    type RecoveryDelay = RecoveryDelay;
 }
 
 parameter_types! {
@@ -737,10 +752,17 @@ parameter_types! {
     pub const MaxModeratorsForCategory: u64 = 20;
     pub const MaxCategories: u64 = 40;
     pub const MaxPollAlternativesNumber: u64 = 20;
+    pub const MaxForumSubscribers: u64 = 1000;
+    pub const MaxMentionsPerPost: u64 = 20;
+    pub const MaxRepliesPerPost: u64 = 100;
+    pub const MaxQuotesPerPost: u64 = 10;
+    pub const MaxWhitelistedMembers: u64 = 1000;
+    pub const MaxModerationLogSize: u64 = 100;
     pub const ThreadDeposit: u64 = 30;
     pub const PostDeposit: u64 = 10;
     pub const ForumModuleId: ModuleId = ModuleId(*b"mo:forum"); // module : forum
     pub const PostLifeTime: BlockNumber = 3600;
+    pub const ModerationLogLifeTime: BlockNumber = 100_800;
 }
 
 pub struct MapLimits;
@@ -749,6 +771,12 @@ impl forum::StorageLimits for MapLimits {
     type MaxModeratorsForCategory = MaxModeratorsForCategory;
     type MaxCategories = MaxCategories;
     type MaxPollAlternativesNumber = MaxPollAlternativesNumber;
+    type MaxSubscribers = MaxForumSubscribers;
+    type MaxMentionsPerPost = MaxMentionsPerPost;
+    type MaxRepliesPerPost = MaxRepliesPerPost;
+    type MaxQuotesPerPost = MaxQuotesPerPost;
+    type MaxWhitelistedMembers = MaxWhitelistedMembers;
+    type MaxModerationLogSize = MaxModerationLogSize;
 }
 
 impl forum::Trait for Runtime {
@@ -761,15 +789,24 @@ impl forum::Trait for Runtime {
     type ThreadDeposit = ThreadDeposit;
     type PostDeposit = PostDeposit;
     type ModuleId = ForumModuleId;
+    type PollVoteLockId = ForumPollVoteLockId;
     type MapLimits = MapLimits;
     type WeightInfo = weights::forum::WeightInfo;
     type WorkingGroup = ForumWorkingGroup;
     type MemberOriginValidator = Members;
     type PostLifeTime = PostLifeTime;
+    type ModerationLogLifeTime = ModerationLogLifeTime;
 
     fn calculate_hash(text: &[u8]) -> Self::Hash {
         Self::Hashing::hash(text)
     }
+
+    fn is_verified_member(member_id: &MemberId) -> bool {
+        <Members as MemberVerificationProvider<MemberId>>::has_valid_verification(
+            member_id,
+            VerificationKind::Identity,
+        )
+    }
 }
 
 impl LockComparator<<Runtime as pallet_balances::Trait>::Balance> for Runtime {
@@ -787,15 +824,6 @@ impl LockComparator<<Runtime as pallet_balances::Trait>::Balance> for Runtime {
 parameter_types! {
     pub const MaxWorkerNumberLimit: u32 = 100;
     pub const MinUnstakingPeriodLimit: u32 = 43200;
-    pub const ForumWorkingGroupRewardPeriod: u32 = 14400 + 10;
-    pub const StorageWorkingGroupRewardPeriod: u32 = 14400 + 20;
-    pub const ContentWorkingGroupRewardPeriod: u32 = 14400 + 30;
-    pub const MembershipRewardPeriod: u32 = 14400 + 40;
-    pub const GatewayRewardPeriod: u32 = 14400 + 50;
-    pub const OperationsAlphaRewardPeriod: u32 = 14400 + 60;
-    pub const OperationsBetaRewardPeriod: u32 = 14400 + 70;
-    pub const OperationsGammaRewardPeriod: u32 = 14400 + 80;
-    pub const DistributionRewardPeriod: u32 = 14400 + 90;
     // This should be more costly than `apply_on_opening` fee with the current configuration
     // the base cost of `apply_on_opening` in tokens is 193. And has a very slight slope
     // with the lenght with the length of rationale, with 2000 stake we are probably safe.
@@ -804,6 +832,8 @@ parameter_types! {
     // the base cost of `add_opening` in tokens is 81. And has a very slight slope
     // with the lenght with the length of rationale, with 2000 stake we are probably safe.
     pub const LeaderOpeningStake: Balance = 2000;
+    pub const WorkerReviewPeriod: BlockNumber = 201_600; // 2 weeks
+    pub const MaxBudgetSpendingRecords: u64 = 1000;
 }
 
 // Staking managers type aliases.
@@ -820,6 +850,8 @@ pub type InvitedMemberStakingManager =
     staking_handler::StakingManager<Runtime, InvitedMemberLockId>;
 pub type BoundStakingAccountStakingManager =
     staking_handler::StakingManager<Runtime, BoundStakingAccountLockId>;
+pub type HandleDisputeStakingManager =
+    staking_handler::StakingManager<Runtime, HandleDisputeLockId>;
 pub type GatewayWorkingGroupStakingManager =
     staking_handler::StakingManager<Runtime, GatewayWorkingGroupLockId>;
 pub type OperationsWorkingGroupAlphaStakingManager =
@@ -865,10 +897,11 @@ impl working_group::Trait<ForumWorkingGroupInstance> for Runtime {
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
-    type RewardPeriod = ForumWorkingGroupRewardPeriod;
     type WeightInfo = weights::working_group::WeightInfo;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type WorkerReviewPeriod = WorkerReviewPeriod;
+    type MaxBudgetSpendingRecords = MaxBudgetSpendingRecords;
 }
 
 impl working_group::Trait<StorageWorkingGroupInstance> for Runtime {
@@ -878,10 +911,11 @@ impl working_group::Trait<StorageWorkingGroupInstance> for Runtime {
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
-    type RewardPeriod = StorageWorkingGroupRewardPeriod;
     type WeightInfo = weights::working_group::WeightInfo;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type WorkerReviewPeriod = WorkerReviewPeriod;
+    type MaxBudgetSpendingRecords = MaxBudgetSpendingRecords;
 }
 
 impl working_group::Trait<ContentWorkingGroupInstance> for Runtime {
@@ -891,10 +925,11 @@ impl working_group::Trait<ContentWorkingGroupInstance> for Runtime {
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
-    type RewardPeriod = ContentWorkingGroupRewardPeriod;
     type WeightInfo = weights::working_group::WeightInfo;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type WorkerReviewPeriod = WorkerReviewPeriod;
+    type MaxBudgetSpendingRecords = MaxBudgetSpendingRecords;
 }
 
 impl working_group::Trait<MembershipWorkingGroupInstance> for Runtime {
@@ -904,10 +939,11 @@ impl working_group::Trait<MembershipWorkingGroupInstance> for Runtime {
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
-    type RewardPeriod = MembershipRewardPeriod;
     type WeightInfo = weights::working_group::WeightInfo;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type WorkerReviewPeriod = WorkerReviewPeriod;
+    type MaxBudgetSpendingRecords = MaxBudgetSpendingRecords;
 }
 
 impl working_group::Trait<OperationsWorkingGroupInstanceAlpha> for Runtime {
@@ -917,10 +953,11 @@ impl working_group::Trait<OperationsWorkingGroupInstanceAlpha> for Runtime {
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
-    type RewardPeriod = OperationsAlphaRewardPeriod;
     type WeightInfo = weights::working_group::WeightInfo;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type WorkerReviewPeriod = WorkerReviewPeriod;
+    type MaxBudgetSpendingRecords = MaxBudgetSpendingRecords;
 }
 
 impl working_group::Trait<GatewayWorkingGroupInstance> for Runtime {
@@ -930,10 +967,11 @@ impl working_group::Trait<GatewayWorkingGroupInstance> for Runtime {
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
-    type RewardPeriod = GatewayRewardPeriod;
     type WeightInfo = weights::working_group::WeightInfo;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type WorkerReviewPeriod = WorkerReviewPeriod;
+    type MaxBudgetSpendingRecords = MaxBudgetSpendingRecords;
 }
 
 impl working_group::Trait<OperationsWorkingGroupInstanceBeta> for Runtime {
@@ -943,10 +981,11 @@ impl working_group::Trait<OperationsWorkingGroupInstanceBeta> for Runtime {
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
-    type RewardPeriod = OperationsBetaRewardPeriod;
     type WeightInfo = weights::working_group::WeightInfo;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type WorkerReviewPeriod = WorkerReviewPeriod;
+    type MaxBudgetSpendingRecords = MaxBudgetSpendingRecords;
 }
 
 impl working_group::Trait<OperationsWorkingGroupInstanceGamma> for Runtime {
@@ -956,10 +995,11 @@ impl working_group::Trait<OperationsWorkingGroupInstanceGamma> for Runtime {
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
-    type RewardPeriod = OperationsGammaRewardPeriod;
     type WeightInfo = weights::working_group::WeightInfo;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type WorkerReviewPeriod = WorkerReviewPeriod;
+    type MaxBudgetSpendingRecords = MaxBudgetSpendingRecords;
 }
 
 impl working_group::Trait<DistributionWorkingGroupInstance> for Runtime {
@@ -969,10 +1009,11 @@ impl working_group::Trait<DistributionWorkingGroupInstance> for Runtime {
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
-    type RewardPeriod = DistributionRewardPeriod;
     type WeightInfo = weights::working_group::WeightInfo;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type WorkerReviewPeriod = WorkerReviewPeriod;
+    type MaxBudgetSpendingRecords = MaxBudgetSpendingRecords;
 }
 
 parameter_types! {
@@ -1010,6 +1051,8 @@ impl Default for Call {
 parameter_types! {
     pub const MaxWhiteListSize: u32 = 20;
     pub const ProposalsPostDeposit: Balance = 2000;
+    pub const MaxPostEditionNumber: u32 = 20;
+    pub const ThreadLockGracePeriod: BlockNumber = 14_400;
     // module : proposals_discussion
     pub const ProposalsDiscussionModuleId: ModuleId = ModuleId(*b"mo:prdis");
     pub const ForumPostLifeTime: BlockNumber = 3600;
@@ -1042,6 +1085,8 @@ impl proposals_discussion::Trait for Runtime {
     type PostDeposit = ProposalsPostDeposit;
     type ModuleId = ProposalsDiscussionModuleId;
     type PostLifeTime = ForumPostLifeTime;
+    type MaxPostEditionNumber = MaxPostEditionNumber;
+    type ThreadLockGracePeriod = ThreadLockGracePeriod;
 }
 
 impl joystream_utility::Trait for Runtime {
@@ -1049,6 +1094,8 @@ impl joystream_utility::Trait for Runtime {
 
     type WeightInfo = weights::joystream_utility::WeightInfo;
 
+    type Call = Call;
+
     fn get_working_group_budget(working_group: WorkingGroup) -> Balance {
         call_wg!(working_group, get_budget)
     }
@@ -1097,12 +1144,41 @@ impl proposals_codex::Trait for Runtime {
     type LockBlogPostProposalParameters = LockBlogPostProposalParameters;
     type UnlockBlogPostProposalParameters = UnlockBlogPostProposalParameters;
     type VetoProposalProposalParameters = VetoProposalProposalParameters;
+    type UpdateNftMarketplaceParametersProposalParameters =
+        UpdateNftMarketplaceParametersProposalParameters;
+    type SetMaxRewardAllowedProposalParameters = SetMaxRewardAllowedProposalParameters;
+    type SetMinCashoutAllowedProposalParameters = SetMinCashoutAllowedProposalParameters;
+    type UpdateStorageDataSizeFeeProposalParameters = UpdateStorageDataSizeFeeProposalParameters;
+    type UpdateStorageBucketsPerBagLimitProposalParameters =
+        UpdateStorageBucketsPerBagLimitProposalParameters;
+    type UpdateStorageBucketsVoucherMaxLimitsProposalParameters =
+        UpdateStorageBucketsVoucherMaxLimitsProposalParameters;
+    type UpdateDynamicBagStorageBucketsNumberProposalParameters =
+        UpdateDynamicBagStorageBucketsNumberProposalParameters;
+    type UpdateStorageBlacklistProposalParameters = UpdateStorageBlacklistProposalParameters;
+    type BlockStorageUploadsProposalParameters = BlockStorageUploadsProposalParameters;
+    type VestingFundingRequestProposalParameters = VestingFundingRequestProposalParameters;
+    type CancelVestingFundingRequestProposalParameters =
+        CancelVestingFundingRequestProposalParameters;
+    type UpdateBlogAuthorProposalParameters = UpdateBlogAuthorProposalParameters;
+    type ReviewBlogDraftsProposalParameters = ReviewBlogDraftsProposalParameters;
+    type SetWorkingGroupSpendingLimitProposalParameters =
+        SetWorkingGroupSpendingLimitProposalParameters;
+    type SpendFromWorkingGroupBudgetProposalParameters =
+        SpendFromWorkingGroupBudgetProposalParameters;
+    type StartReferralCampaignProposalParameters = StartReferralCampaignProposalParameters;
+    type MaxVestingMilestones = MaxVestingMilestones;
     type WeightInfo = weights::proposals_codex::WeightInfo;
 }
 
 impl pallet_constitution::Trait for Runtime {
     type Event = Event;
     type WeightInfo = weights::pallet_constitution::WeightInfo;
+    type ProposalId = u32;
+
+    fn executing_proposal_id() -> Option<u32> {
+        ProposalsEngine::executing_proposal_id()
+    }
 }
 
 parameter_types! {
@@ -1130,6 +1206,7 @@ impl bounty::Trait for Runtime {
 
 parameter_types! {
     pub const PostsMaxNumber: u64 = 20;
+    pub const ReactionsMaxNumber: u64 = 5;
     pub const RepliesMaxNumber: u64 = 100;
     pub const ReplyDeposit: Balance = 2000;
     pub const BlogModuleId: ModuleId = ModuleId(*b"mod:blog"); // module : forum
@@ -1140,17 +1217,26 @@ pub type BlogInstance = blog::Instance1;
 impl blog::Trait<BlogInstance> for Runtime {
     type Event = Event;
     type PostsMaxNumber = PostsMaxNumber;
+    type ReactionsMaxNumber = ReactionsMaxNumber;
     type ParticipantEnsureOrigin = Members;
+    type ParticipantInfoProvider = Members;
     type WeightInfo = weights::blog::WeightInfo;
     type ReplyId = u64;
     type ReplyDeposit = ReplyDeposit;
     type ModuleId = BlogModuleId;
     type ReplyLifetime = ReplyLifetime;
+    type EditorWorkingGroup = ContentWorkingGroup;
 }
 
 /// Forum identifier for category
 pub type CategoryId = u64;
 
+/// Forum moderator identifier
+pub type ForumModeratorId = forum::ModeratorId<Runtime>;
+
+/// Forum moderation log entry
+pub type ForumModerationLogEntry = forum::ModerationLogEntryOf<Runtime>;
+
 /// Opaque types. These are used by the CLI to instantiate machinery that don't need to know
 /// the specifics of the runtime. They can then be made to be agnostic over specific formats
 /// of data like extrinsics, allowing for them to continue syncing the network through upgrades
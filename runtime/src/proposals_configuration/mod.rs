@@ -97,4 +97,52 @@ parameter_types! {
 
     pub VetoProposalProposalParameters: ProposalParameters<BlockNumber, Balance> =
         veto_proposal_proposal();
+
+    pub UpdateNftMarketplaceParametersProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        update_nft_marketplace_parameters_proposal();
+
+    pub SetMaxRewardAllowedProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        set_max_reward_allowed_proposal();
+
+    pub SetMinCashoutAllowedProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        set_min_cashout_allowed_proposal();
+
+    pub UpdateStorageDataSizeFeeProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        update_storage_data_size_fee_proposal();
+
+    pub UpdateStorageBucketsPerBagLimitProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        update_storage_buckets_per_bag_limit_proposal();
+
+    pub UpdateStorageBucketsVoucherMaxLimitsProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        update_storage_buckets_voucher_max_limits_proposal();
+
+    pub UpdateDynamicBagStorageBucketsNumberProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        update_dynamic_bag_storage_buckets_number_proposal();
+
+    pub UpdateStorageBlacklistProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        update_storage_blacklist_proposal();
+
+    pub BlockStorageUploadsProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        block_storage_uploads_proposal();
+
+    pub VestingFundingRequestProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        vesting_funding_request_proposal();
+
+    pub CancelVestingFundingRequestProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        cancel_vesting_funding_request_proposal();
+
+    pub UpdateBlogAuthorProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        update_blog_author_proposal();
+
+    pub ReviewBlogDraftsProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        review_blog_drafts_proposal();
+
+    pub SetWorkingGroupSpendingLimitProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        set_working_group_spending_limit_proposal();
+
+    pub SpendFromWorkingGroupBudgetProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        spend_from_working_group_budget_proposal();
+
+    pub StartReferralCampaignProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        start_referral_campaign_proposal();
 }
@@ -358,3 +358,235 @@ pub(crate) fn veto_proposal_proposal() -> ProposalParameters<BlockNumber, Balanc
         constitutionality: 1,
     }
 }
+
+// Proposal parameters for the 'Update Nft Marketplace Parameters' proposal
+pub(crate) fn update_nft_marketplace_parameters_proposal(
+) -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: 200,
+        grace_period: 100,
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(50_000),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Set Max Reward Allowed' proposal
+pub(crate) fn set_max_reward_allowed_proposal() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: 200,
+        grace_period: 100,
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(50_000),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Set Min Cashout Allowed' proposal
+pub(crate) fn set_min_cashout_allowed_proposal() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: 200,
+        grace_period: 100,
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(50_000),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Update Storage Data Size Fee' proposal
+pub(crate) fn update_storage_data_size_fee_proposal() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: 200,
+        grace_period: 100,
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(50_000),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Update Storage Buckets Per Bag Limit' proposal
+pub(crate) fn update_storage_buckets_per_bag_limit_proposal(
+) -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: 200,
+        grace_period: 100,
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(50_000),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Update Storage Buckets Voucher Max Limits' proposal
+pub(crate) fn update_storage_buckets_voucher_max_limits_proposal(
+) -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: 200,
+        grace_period: 100,
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(50_000),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Update Dynamic Bag Storage Buckets Number' proposal
+pub(crate) fn update_dynamic_bag_storage_buckets_number_proposal(
+) -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: 200,
+        grace_period: 100,
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(50_000),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Update Storage Blacklist' proposal
+pub(crate) fn update_storage_blacklist_proposal() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: 200,
+        grace_period: 100,
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(50_000),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Block Storage Uploads' proposal
+// Emergency proposal: short voting period and no grace period.
+pub(crate) fn block_storage_uploads_proposal() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: 50,
+        grace_period: 0,
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(50_000),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Vesting Funding Request' proposal
+pub(crate) fn vesting_funding_request_proposal() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: 200,
+        grace_period: 100,
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 80,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(25_000),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Cancel Vesting Funding Request' proposal
+pub(crate) fn cancel_vesting_funding_request_proposal() -> ProposalParameters<BlockNumber, Balance>
+{
+    ProposalParameters {
+        voting_period: 200,
+        grace_period: 100,
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 80,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(25_000),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Update Blog Author' proposal
+pub(crate) fn update_blog_author_proposal() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: 200,
+        grace_period: 100,
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 80,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(25_000),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Review Blog Drafts' proposal
+pub(crate) fn review_blog_drafts_proposal() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: 200,
+        grace_period: 100,
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 80,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(25_000),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Set Working Group Spending Limit' proposal
+pub(crate) fn set_working_group_spending_limit_proposal() -> ProposalParameters<BlockNumber, Balance>
+{
+    ProposalParameters {
+        voting_period: 200,
+        grace_period: 100,
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 80,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(25_000),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Spend From Working Group Budget' proposal
+pub(crate) fn spend_from_working_group_budget_proposal() -> ProposalParameters<BlockNumber, Balance>
+{
+    ProposalParameters {
+        voting_period: 200,
+        grace_period: 100,
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 80,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(25_000),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Start Referral Campaign' proposal
+pub(crate) fn start_referral_campaign_proposal() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: 200,
+        grace_period: 100,
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 80,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(25_000),
+        constitutionality: 1,
+    }
+}
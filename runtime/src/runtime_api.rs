@@ -1,6 +1,6 @@
 use frame_support::inherent::{CheckInherentsResult, InherentData};
 use frame_support::storage::StorageValue;
-use frame_support::traits::{KeyOwnerProofSystem, OnRuntimeUpgrade, Randomness};
+use frame_support::traits::{Get, KeyOwnerProofSystem, OnRuntimeUpgrade, Randomness};
 use frame_support::unsigned::{TransactionSource, TransactionValidity};
 use pallet_grandpa::fg_primitives;
 use pallet_transaction_payment_rpc_runtime_api::RuntimeDispatchInfo;
@@ -14,12 +14,17 @@ use sp_std::vec::Vec;
 use crate::constants::PRIMARY_PROBABILITY;
 
 use crate::{
-    AccountId, AuthorityDiscoveryId, Balance, BlockNumber, EpochDuration, GrandpaAuthorityList,
-    GrandpaId, Hash, Index, RuntimeVersion, Signature, VERSION,
+    AccountId, AuthorityDiscoveryId, Balance, BlockNumber, EpochDuration, ForumModerationLogEntry,
+    ForumModeratorId, GrandpaAuthorityList, GrandpaId, Hash, Index, RuntimeVersion, Signature,
+    VERSION,
 };
 use crate::{
-    AllModules, AuthorityDiscovery, Babe, Balances, Call, Grandpa, Historical, InherentDataExt,
-    ProposalsEngine, RandomnessCollectiveFlip, Runtime, SessionKeys, System, TransactionPayment,
+    AllModules, AuthorityDiscovery, Babe, Balances, Blog, Call, ContentWorkingGroup,
+    DistributionWorkingGroup, Forum, ForumWorkingGroup, GatewayWorkingGroup, Grandpa, Historical,
+    InherentDataExt, MembershipWorkingGroup, OperationsWorkingGroupAlpha,
+    OperationsWorkingGroupBeta, OperationsWorkingGroupGamma, ProposalsDiscussion, ProposalsEngine,
+    RandomnessCollectiveFlip, Runtime, SessionKeys, StorageWorkingGroup, System,
+    TransactionPayment,
 };
 
 use frame_support::weights::Weight;
@@ -82,12 +87,56 @@ pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<AccountId, Call, Signa
 pub struct CustomOnRuntimeUpgrade;
 impl OnRuntimeUpgrade for CustomOnRuntimeUpgrade {
     fn on_runtime_upgrade() -> Weight {
-        ProposalsEngine::cancel_active_and_pending_proposals();
+        let migration_weights = [
+            // Migrate the discussion threads before the proposal removal locks them
+            ProposalsDiscussion::migrate_threads(),
+            ProposalsEngine::cancel_active_and_pending_proposals(),
+            ProposalsDiscussion::migrate_posts(),
+            Forum::migrate_threads(),
+            Forum::migrate_posts(),
+            Blog::migrate_posts(),
+            // Migrate workers to the lazy reward accrual
+            ForumWorkingGroup::migrate_workers(),
+            StorageWorkingGroup::migrate_workers(),
+            ContentWorkingGroup::migrate_workers(),
+            OperationsWorkingGroupAlpha::migrate_workers(),
+            GatewayWorkingGroup::migrate_workers(),
+            MembershipWorkingGroup::migrate_workers(),
+            OperationsWorkingGroupBeta::migrate_workers(),
+            OperationsWorkingGroupGamma::migrate_workers(),
+            DistributionWorkingGroup::migrate_workers(),
+            // Migrate job openings to the application deadlines and the opening expiry
+            ForumWorkingGroup::migrate_openings(),
+            StorageWorkingGroup::migrate_openings(),
+            ContentWorkingGroup::migrate_openings(),
+            OperationsWorkingGroupAlpha::migrate_openings(),
+            GatewayWorkingGroup::migrate_openings(),
+            MembershipWorkingGroup::migrate_openings(),
+            OperationsWorkingGroupBeta::migrate_openings(),
+            OperationsWorkingGroupGamma::migrate_openings(),
+            DistributionWorkingGroup::migrate_openings(),
+            // Migrate job applications to the application screening and index them by the opening
+            ForumWorkingGroup::migrate_applications(),
+            StorageWorkingGroup::migrate_applications(),
+            ContentWorkingGroup::migrate_applications(),
+            OperationsWorkingGroupAlpha::migrate_applications(),
+            GatewayWorkingGroup::migrate_applications(),
+            MembershipWorkingGroup::migrate_applications(),
+            OperationsWorkingGroupBeta::migrate_applications(),
+            OperationsWorkingGroupGamma::migrate_applications(),
+            DistributionWorkingGroup::migrate_applications(),
+        ];
+
         // Set NFT values
         <content::MaxStartingPrice<Runtime>>::put(Balance::from(1_000_000_000_000u64));
         <content::MaxBidStep<Runtime>>::put(Balance::from(1_000_000_000_000u64));
+        let nft_values_weight = <Runtime as frame_system::Trait>::DbWeight::get().writes(2);
 
-        10_000_000 // TODO: adjust weight
+        migration_weights
+            .iter()
+            .fold(nft_values_weight, |total_weight, migration_weight| {
+                total_weight.saturating_add(*migration_weight)
+            })
     }
 }
 
@@ -260,6 +309,16 @@ impl_runtime_apis! {
         }
     }
 
+    impl forum::runtime_api::ForumApi<
+        Block,
+        ForumModeratorId,
+        ForumModerationLogEntry,
+    > for Runtime {
+        fn moderation_log(moderator_id: ForumModeratorId) -> Vec<ForumModerationLogEntry> {
+            Forum::moderation_log(moderator_id)
+        }
+    }
+
     impl sp_session::SessionKeys<Block> for Runtime {
         fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
             SessionKeys::generate(seed)
@@ -459,6 +459,8 @@ fn set_membership_leader(lead_account_id: AccountId32, lead_id: u64) {
             leaving_unstaking_period: 1000000,
         },
         None,
+        None,
+        None,
     )
     .unwrap();
 
@@ -635,6 +637,53 @@ fn create_blog_post_proposal_execution_succeeds() {
     });
 }
 
+#[test]
+fn review_blog_drafts_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = create_new_members(1)[0];
+        let account_id = account_from_member_id(member_id);
+        let council_budget = 5_000_000;
+
+        assert!(Council::set_budget(RawOrigin::Root.into(), council_budget).is_ok());
+
+        Blog::update_author(RawOrigin::Root.into(), member_id, true).unwrap();
+
+        let draft_id = Blog::draft_count();
+        Blog::submit_draft(
+            RawOrigin::Signed(account_id.clone()).into(),
+            member_id,
+            vec![0u8],
+            vec![0u8],
+        )
+        .unwrap();
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            let general_proposal_parameters = GeneralProposalParameters::<Runtime> {
+                member_id: member_id,
+                title: b"title".to_vec(),
+                description: b"body".to_vec(),
+                staking_account_id: Some(account_id.clone()),
+                exact_execution_block: None,
+            };
+
+            ProposalCodex::create_proposal(
+                RawOrigin::Signed(account_id.clone()).into(),
+                general_proposal_parameters,
+                ProposalDetails::ReviewBlogDrafts(vec![(draft_id, true)].into_iter().collect()),
+            )
+        })
+        .with_member_id(member_id as u64);
+
+        assert_eq!(Blog::post_count(), 0);
+
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+        let params = <Runtime as proposals_codex::Trait>::ReviewBlogDraftsProposalParameters::get();
+        run_to_block(System::block_number() + params.grace_period + 1);
+
+        assert_eq!(Blog::post_count(), 1);
+    });
+}
+
 #[test]
 fn edit_blog_post_proposal_execution_succeeds() {
     initial_test_ext().execute_with(|| {
@@ -772,7 +821,7 @@ fn veto_proposal_proposal_execution_succeeds() {
             ProposalCodex::create_proposal(
                 RawOrigin::Signed(account_id.clone()).into(),
                 general_proposal_parameters,
-                ProposalDetails::AmendConstitution(vec![0u8]),
+                ProposalDetails::AmendConstitution(vec![0u8], None),
             )
         })
         .with_member_id(member_id as u64);
@@ -878,18 +927,28 @@ fn amend_constitution_proposal_execution_succeeds() {
             ProposalCodex::create_proposal(
                 RawOrigin::Signed(account_id.clone()).into(),
                 general_proposal_parameters,
-                ProposalDetails::AmendConstitution(b"Constitution text".to_vec()),
+                ProposalDetails::AmendConstitution(b"Constitution text".to_vec(), Some(0)),
             )
         })
         .with_member_id(member_id as u64);
 
         codex_extrinsic_test_fixture.call_extrinsic_and_assert();
 
+        let proposal_id = ProposalsEngine::proposal_count();
+
         let params =
             <Runtime as proposals_codex::Trait>::AmendConstitutionProposalParameters::get();
         run_to_block(System::block_number() + params.grace_period + 1);
 
-        // assert constitution text was changed
+        // assert constitution version was recorded
+        assert_eq!(
+            pallet_constitution::Module::<Runtime>::amendment_number(),
+            1
+        );
+        assert_eq!(
+            pallet_constitution::Module::<Runtime>::constitution_versions(1).proposal_id,
+            Some(proposal_id)
+        );
     });
 }
 
@@ -927,6 +986,85 @@ fn set_membership_price_proposal_execution_succeeds() {
     });
 }
 
+#[test]
+fn batch_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = create_new_members(1)[0];
+        let account_id = account_from_member_id(member_id);
+        let membership_price = Membership::membership_price() + 100;
+        let new_referral_cut = 25;
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            let general_proposal_parameters = GeneralProposalParameters::<Runtime> {
+                member_id: member_id,
+                title: b"title".to_vec(),
+                description: b"body".to_vec(),
+                staking_account_id: Some(account_id.clone()),
+                exact_execution_block: None,
+            };
+
+            ProposalCodex::create_proposal(
+                RawOrigin::Signed(account_id.clone()).into(),
+                general_proposal_parameters,
+                ProposalDetails::Batch(vec![
+                    ProposalDetails::SetMembershipPrice(membership_price),
+                    ProposalDetails::SetReferralCut(new_referral_cut),
+                ]),
+            )
+        })
+        .with_member_id(member_id as u64);
+
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        let membership_price_params =
+            <Runtime as proposals_codex::Trait>::SetMembershipPriceProposalParameters::get();
+        let referral_cut_params =
+            <Runtime as proposals_codex::Trait>::SetReferralCutProposalParameters::get();
+        let grace_period = membership_price_params
+            .grace_period
+            .max(referral_cut_params.grace_period);
+        run_to_block(System::block_number() + grace_period + 1);
+
+        assert_eq!(Membership::membership_price(), membership_price);
+        assert_eq!(Membership::referral_cut(), new_referral_cut);
+    });
+}
+
+#[test]
+fn block_storage_uploads_proposal_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = create_new_members(1)[0];
+        let account_id = account_from_member_id(member_id);
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            let general_proposal_parameters = GeneralProposalParameters::<Runtime> {
+                member_id: member_id,
+                title: b"title".to_vec(),
+                description: b"body".to_vec(),
+                staking_account_id: Some(account_id.clone()),
+                exact_execution_block: None,
+            };
+
+            ProposalCodex::create_proposal(
+                RawOrigin::Signed(account_id.clone()).into(),
+                general_proposal_parameters,
+                ProposalDetails::BlockStorageUploads(true),
+            )
+        })
+        .with_member_id(member_id as u64);
+
+        assert!(!crate::Storage::uploading_blocked());
+
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        let params =
+            <Runtime as proposals_codex::Trait>::BlockStorageUploadsProposalParameters::get();
+        run_to_block(System::block_number() + params.grace_period + 1);
+
+        assert!(crate::Storage::uploading_blocked());
+    });
+}
+
 #[test]
 fn set_initial_invitation_balance_proposal_succeeds() {
     initial_test_ext().execute_with(|| {
@@ -1072,6 +1210,47 @@ fn set_referral_cut_proposal_succeeds() {
     });
 }
 
+#[test]
+fn start_referral_campaign_proposal_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = create_new_members(1)[0];
+        let account_id = account_from_member_id(member_id);
+        let budget = 100_000;
+        let duration = 1000;
+
+        <working_group::Budget<Runtime, MembershipWorkingGroupInstance>>::put(budget);
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            let general_proposal_parameters = GeneralProposalParameters::<Runtime> {
+                member_id: member_id,
+                title: b"title".to_vec(),
+                description: b"body".to_vec(),
+                staking_account_id: Some(account_id.clone()),
+                exact_execution_block: None,
+            };
+
+            ProposalCodex::create_proposal(
+                RawOrigin::Signed(account_id.clone()).into(),
+                general_proposal_parameters,
+                ProposalDetails::StartReferralCampaign(budget, duration),
+            )
+        })
+        .with_member_id(member_id as u64);
+
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        let params =
+            <Runtime as proposals_codex::Trait>::StartReferralCampaignProposalParameters::get();
+        run_to_block(System::block_number() + params.grace_period + 1);
+
+        let campaign = Membership::current_referral_campaign().unwrap();
+        assert_eq!(campaign.budget, budget);
+        assert_eq!(campaign.referrals, 0);
+        assert!(campaign.ends_at > System::block_number());
+        assert_eq!(MembershipWorkingGroup::budget(), 0);
+    });
+}
+
 #[test]
 fn set_budget_increment_proposal_succeds() {
     initial_test_ext().execute_with(|| {
@@ -379,6 +379,46 @@ fn set_mint_capacity<
     codex_extrinsic_test_fixture.call_extrinsic_and_assert();
 }
 
+fn spend_from_budget(
+    member_id: MemberId,
+    account_id: [u8; 32],
+    recipient_account_id: [u8; 32],
+    amount: Balance,
+    sequence_number: u32, // action sequence number to align with other actions
+    working_group: WorkingGroup,
+) {
+    let expected_proposal_id = sequence_number;
+
+    let staking_account_id: [u8; 32] = [225u8; 32];
+    increase_total_balance_issuance_using_account_id(staking_account_id.into(), 1_500_000);
+    set_staking_account(account_id.into(), staking_account_id.into(), member_id);
+
+    let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+        let general_proposal_parameters = GeneralProposalParameters::<Runtime> {
+            member_id: member_id.into(),
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(staking_account_id.into()),
+            exact_execution_block: None,
+        };
+
+        ProposalCodex::create_proposal(
+            RawOrigin::Signed(account_id.into()).into(),
+            general_proposal_parameters,
+            ProposalDetails::SpendFromWorkingGroupBudget(
+                recipient_account_id.into(),
+                amount,
+                b"rationale".to_vec(),
+                working_group,
+            ),
+        )
+    })
+    .disable_setup_enviroment()
+    .with_expected_proposal_id(expected_proposal_id);
+
+    codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+}
+
 fn terminate_role(
     member_id: MemberId,
     account_id: [u8; 32],
@@ -1785,3 +1825,131 @@ fn run_create_terminate_group_leader_role_proposal_with_slashing_execution_succe
         );
     });
 }
+
+#[test]
+fn create_spend_from_working_group_budget_proposal_execution_succeeds() {
+    // This uses strum crate for enum iteration
+    for group in WorkingGroup::iter() {
+        match group {
+            WorkingGroup::Content => {
+                run_create_spend_from_working_group_budget_proposal_execution_succeeds::<
+                    Runtime,
+                    ContentWorkingGroupInstance,
+                >(group);
+            }
+            WorkingGroup::Storage => {
+                run_create_spend_from_working_group_budget_proposal_execution_succeeds::<
+                    Runtime,
+                    StorageWorkingGroupInstance,
+                >(group);
+            }
+            WorkingGroup::Forum => {
+                run_create_spend_from_working_group_budget_proposal_execution_succeeds::<
+                    Runtime,
+                    ForumWorkingGroupInstance,
+                >(group);
+            }
+            WorkingGroup::Membership => {
+                run_create_spend_from_working_group_budget_proposal_execution_succeeds::<
+                    Runtime,
+                    MembershipWorkingGroupInstance,
+                >(group);
+            }
+            WorkingGroup::Gateway => {
+                run_create_spend_from_working_group_budget_proposal_execution_succeeds::<
+                    Runtime,
+                    GatewayWorkingGroupInstance,
+                >(group);
+            }
+            WorkingGroup::Distribution => {
+                run_create_spend_from_working_group_budget_proposal_execution_succeeds::<
+                    Runtime,
+                    DistributionWorkingGroupInstance,
+                >(group);
+            }
+            WorkingGroup::OperationsAlpha => {
+                run_create_spend_from_working_group_budget_proposal_execution_succeeds::<
+                    Runtime,
+                    OperationsWorkingGroupInstanceAlpha,
+                >(group);
+            }
+            WorkingGroup::OperationsBeta => {
+                run_create_spend_from_working_group_budget_proposal_execution_succeeds::<
+                    Runtime,
+                    OperationsWorkingGroupInstanceBeta,
+                >(group);
+            }
+            WorkingGroup::OperationsGamma => {
+                run_create_spend_from_working_group_budget_proposal_execution_succeeds::<
+                    Runtime,
+                    OperationsWorkingGroupInstanceGamma,
+                >(group);
+            }
+        }
+    }
+}
+
+fn run_create_spend_from_working_group_budget_proposal_execution_succeeds<
+    T: working_group::Trait<I> + frame_system::Trait,
+    I: frame_support::traits::Instance,
+>(
+    working_group: WorkingGroup,
+) where
+    <T as frame_system::Trait>::AccountId: From<[u8; 32]>,
+    <T as common::membership::MembershipTypes>::MemberId: From<u64>,
+    working_group::BalanceOf<T>: From<u128>,
+{
+    initial_test_ext().execute_with(|| {
+        setup_new_council(0);
+
+        let member_id: MemberId = create_new_members(1)[0];
+        let account_id: [u8; 32] = account_from_member_id(member_id).into();
+        let recipient_account_id: [u8; 32] = [226u8; 32];
+
+        let mint_capacity = 999999;
+        let amount = 1000;
+
+        increase_total_balance_issuance_using_account_id(account_id.clone().into(), 1_500_000);
+
+        Council::set_budget(RawOrigin::Root.into(), 5_000_000).unwrap();
+
+        set_mint_capacity::<T, I>(
+            member_id,
+            account_id,
+            mint_capacity,
+            1,
+            false,
+            working_group,
+            BalanceKind::Positive,
+        );
+
+        let old_balance = Balances::usable_balance(&recipient_account_id.into());
+
+        spend_from_budget(
+            member_id,
+            account_id,
+            recipient_account_id,
+            amount,
+            2,
+            working_group,
+        );
+
+        assert_eq!(
+            Balances::usable_balance(&recipient_account_id.into()),
+            old_balance + amount
+        );
+
+        assert_eq!(
+            working_group::Module::<T, I>::budget(),
+            (mint_capacity - amount).into()
+        );
+
+        let expected_recipient: <T as frame_system::Trait>::AccountId = recipient_account_id.into();
+        let expected_amount: working_group::BalanceOf<T> = amount.into();
+
+        let record = working_group::Module::<T, I>::budget_spending_by_id(0);
+        assert_eq!(record.recipient, expected_recipient);
+        assert_eq!(record.amount, expected_amount);
+        assert!(record.approved_by_council);
+    });
+}
@@ -56,4 +56,52 @@ impl blog::WeightInfo for WeightInfo {
             .saturating_add(DbWeight::get().reads((3 as Weight).saturating_mul(i as Weight)))
             .saturating_add(DbWeight::get().writes((2 as Weight).saturating_mul(i as Weight)))
     }
+    fn update_author() -> Weight {
+        (143_228_000 as Weight)
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn submit_draft(t: u32, b: u32) -> Weight {
+        (512_774_000 as Weight)
+            .saturating_add((88_000 as Weight).saturating_mul(t as Weight))
+            .saturating_add((150_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn edit_draft(t: u32, b: u32) -> Weight {
+        (578_301_000 as Weight)
+            .saturating_add((94_000 as Weight).saturating_mul(t as Weight))
+            .saturating_add((134_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn withdraw_draft() -> Weight {
+        (241_519_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn review_drafts(i: u32) -> Weight {
+        (104_382_000 as Weight)
+            .saturating_add((297_611_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+            .saturating_add(DbWeight::get().writes((2 as Weight).saturating_mul(i as Weight)))
+    }
+    fn react_to_post() -> Weight {
+        (215_322_000 as Weight).saturating_add(DbWeight::get().reads(2 as Weight))
+    }
+    fn react_to_reply() -> Weight {
+        (221_071_000 as Weight).saturating_add(DbWeight::get().reads(2 as Weight))
+    }
+    fn tip_post() -> Weight {
+        (398_446_000 as Weight)
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn tip_reply() -> Weight {
+        (403_918_000 as Weight)
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
 }
@@ -75,4 +75,24 @@ impl council::WeightInfo for WeightInfo {
     fn councilor_remark() -> Weight {
         (406_707_000 as Weight).saturating_add(DbWeight::get().reads(2 as Weight))
     }
+    fn vesting_funding_request(i: u32, m: u32) -> Weight {
+        (0 as Weight)
+            .saturating_add((385_420_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add((2_174_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(i as Weight)))
+    }
+    fn claim_vesting_funding(m: u32) -> Weight {
+        (412_305_000 as Weight)
+            .saturating_add((1_351_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn cancel_vesting_funding_request(m: u32) -> Weight {
+        (438_910_000 as Weight)
+            .saturating_add((1_389_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
 }
@@ -48,19 +48,21 @@ impl forum::WeightInfo for WeightInfo {
             .saturating_add(DbWeight::get().writes(2 as Weight))
     }
     fn delete_category_moderator(i: u32) -> Weight {
-        (168_140_000 as Weight)
+        (3_912_606_000 as Weight)
             .saturating_add((72_252_000 as Weight).saturating_mul(i as Weight))
-            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
             .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
-            .saturating_add(DbWeight::get().writes(3 as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
     }
     // WARNING! Some components were not used: ["z"]
-    fn create_thread(j: u32, k: u32, i: u32) -> Weight {
-        (1_988_301_000 as Weight)
+    fn create_thread(j: u32, k: u32, i: u32, s: u32) -> Weight {
+        (1_941_527_000 as Weight)
             .saturating_add((144_000 as Weight).saturating_mul(j as Weight))
             .saturating_add((190_000 as Weight).saturating_mul(k as Weight))
+            .saturating_add((28_413_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add((1_127_000 as Weight).saturating_mul(s as Weight))
             .saturating_add(DbWeight::get().reads(7 as Weight))
-            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().reads((2 as Weight).saturating_mul(i as Weight)))
             .saturating_add(DbWeight::get().writes(7 as Weight))
     }
     fn edit_thread_metadata(i: u32, j: u32) -> Weight {
@@ -86,19 +88,19 @@ impl forum::WeightInfo for WeightInfo {
             .saturating_add(DbWeight::get().writes(4 as Weight))
     }
     fn move_thread_to_category_moderator(i: u32) -> Weight {
-        (429_980_000 as Weight)
+        (4_187_354_000 as Weight)
             .saturating_add((107_333_000 as Weight).saturating_mul(i as Weight))
-            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().reads(7 as Weight))
             .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
-            .saturating_add(DbWeight::get().writes(4 as Weight))
+            .saturating_add(DbWeight::get().writes(5 as Weight))
     }
     fn vote_on_poll(i: u32, j: u32) -> Weight {
         (331_092_000 as Weight)
             .saturating_add((64_865_000 as Weight).saturating_mul(i as Weight))
             .saturating_add((20_909_000 as Weight).saturating_mul(j as Weight))
-            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().reads(8 as Weight))
             .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
-            .saturating_add(DbWeight::get().writes(2 as Weight))
+            .saturating_add(DbWeight::get().writes(6 as Weight))
     }
     fn moderate_thread_lead(i: u32, k: u32) -> Weight {
         (570_691_000 as Weight)
@@ -109,20 +111,24 @@ impl forum::WeightInfo for WeightInfo {
             .saturating_add(DbWeight::get().writes(3 as Weight))
     }
     fn moderate_thread_moderator(i: u32, k: u32) -> Weight {
-        (555_260_000 as Weight)
+        (4_301_973_000 as Weight)
             .saturating_add((65_443_000 as Weight).saturating_mul(i as Weight))
             .saturating_add((143_000 as Weight).saturating_mul(k as Weight))
-            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
             .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
-            .saturating_add(DbWeight::get().writes(3 as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
     }
-    fn add_post(i: u32, j: u32) -> Weight {
-        (628_419_000 as Weight)
+    fn add_post(i: u32, j: u32, k: u32, l: u32, s: u32) -> Weight {
+        (602_318_000 as Weight)
             .saturating_add((59_569_000 as Weight).saturating_mul(i as Weight))
             .saturating_add((145_000 as Weight).saturating_mul(j as Weight))
-            .saturating_add(DbWeight::get().reads(6 as Weight))
-            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
-            .saturating_add(DbWeight::get().writes(5 as Weight))
+            .saturating_add((1_236_000 as Weight).saturating_mul(k as Weight))
+            .saturating_add((21_418_000 as Weight).saturating_mul(l as Weight))
+            .saturating_add((1_384_000 as Weight).saturating_mul(s as Weight))
+            .saturating_add(DbWeight::get().reads(9 as Weight))
+            .saturating_add(DbWeight::get().reads((2 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().reads((2 as Weight).saturating_mul(l as Weight)))
+            .saturating_add(DbWeight::get().writes(6 as Weight))
     }
     fn react_post(i: u32) -> Weight {
         (330_603_000 as Weight)
@@ -138,32 +144,41 @@ impl forum::WeightInfo for WeightInfo {
             .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
             .saturating_add(DbWeight::get().writes(1 as Weight))
     }
-    fn moderate_post_lead(i: u32, j: u32) -> Weight {
-        (1_061_737_000 as Weight)
+    fn moderate_post_lead(i: u32, j: u32, k: u32) -> Weight {
+        (1_118_402_000 as Weight)
             .saturating_add((125_999_000 as Weight).saturating_mul(i as Weight))
             .saturating_add((142_000 as Weight).saturating_mul(j as Weight))
-            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add((41_836_000 as Weight).saturating_mul(k as Weight))
+            .saturating_add(DbWeight::get().reads(8 as Weight))
             .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
-            .saturating_add(DbWeight::get().writes(3 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(k as Weight)))
+            .saturating_add(DbWeight::get().writes(5 as Weight))
+            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(k as Weight)))
     }
-    fn moderate_post_moderator(i: u32, j: u32) -> Weight {
-        (992_903_000 as Weight)
+    fn moderate_post_moderator(i: u32, j: u32, k: u32) -> Weight {
+        (4_806_592_000 as Weight)
             .saturating_add((134_194_000 as Weight).saturating_mul(i as Weight))
             .saturating_add((145_000 as Weight).saturating_mul(j as Weight))
-            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add((42_309_000 as Weight).saturating_mul(k as Weight))
+            .saturating_add(DbWeight::get().reads(9 as Weight))
             .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
-            .saturating_add(DbWeight::get().writes(3 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(k as Weight)))
+            .saturating_add(DbWeight::get().writes(6 as Weight))
+            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(k as Weight)))
     }
-    fn delete_posts(i: u32, j: u32, k: u32) -> Weight {
+    fn delete_posts(i: u32, j: u32, k: u32, l: u32) -> Weight {
         (0 as Weight)
             .saturating_add((30_679_118_000 as Weight).saturating_mul(i as Weight))
             .saturating_add((27_000 as Weight).saturating_mul(j as Weight))
-            .saturating_add((1_545_369_000 as Weight).saturating_mul(k as Weight))
+            .saturating_add((1_612_774_000 as Weight).saturating_mul(k as Weight))
+            .saturating_add((10_582_915_000 as Weight).saturating_mul(l as Weight))
             .saturating_add(DbWeight::get().reads(5 as Weight))
             .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
-            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(k as Weight)))
+            .saturating_add(DbWeight::get().reads((3 as Weight).saturating_mul(k as Weight)))
+            .saturating_add(DbWeight::get().reads((250 as Weight).saturating_mul(l as Weight)))
             .saturating_add(DbWeight::get().writes(3 as Weight))
-            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(k as Weight)))
+            .saturating_add(DbWeight::get().writes((3 as Weight).saturating_mul(k as Weight)))
+            .saturating_add(DbWeight::get().writes((250 as Weight).saturating_mul(l as Weight)))
     }
     fn set_stickied_threads_lead(i: u32, j: u32) -> Weight {
         (0 as Weight)
@@ -175,13 +190,13 @@ impl forum::WeightInfo for WeightInfo {
             .saturating_add(DbWeight::get().writes(1 as Weight))
     }
     fn set_stickied_threads_moderator(i: u32, j: u32) -> Weight {
-        (20_591_000 as Weight)
+        (3_768_214_000 as Weight)
             .saturating_add((72_609_000 as Weight).saturating_mul(i as Weight))
-            .saturating_add((233_007_000 as Weight).saturating_mul(j as Weight))
-            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add((241_385_000 as Weight).saturating_mul(j as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
             .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
             .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(j as Weight)))
-            .saturating_add(DbWeight::get().writes(1 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
     }
     fn update_category_title_lead(i: u32, j: u32) -> Weight {
         (20_591_000 as Weight)
@@ -219,4 +234,79 @@ impl forum::WeightInfo for WeightInfo {
             .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(j as Weight)))
             .saturating_add(DbWeight::get().writes(1 as Weight))
     }
+    fn ban_forum_user_lead(i: u32, j: u32) -> Weight {
+        (312_458_000 as Weight)
+            .saturating_add((62_117_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add((141_000 as Weight).saturating_mul(j as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn ban_forum_user_moderator(i: u32, j: u32) -> Weight {
+        (3_845_117_000 as Weight)
+            .saturating_add((61_742_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add((139_000 as Weight).saturating_mul(j as Weight))
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn unban_forum_user_lead(i: u32) -> Weight {
+        (191_321_000 as Weight)
+            .saturating_add((62_117_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn unban_forum_user_moderator(i: u32) -> Weight {
+        (3_794_528_000 as Weight)
+            .saturating_add((58_413_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn update_thread_subscription(s: u32) -> Weight {
+        (1_276_045_000 as Weight)
+            .saturating_add((3_287_000 as Weight).saturating_mul(s as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn update_category_subscription(s: u32) -> Weight {
+        (1_198_732_000 as Weight)
+            .saturating_add((3_164_000 as Weight).saturating_mul(s as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn unlock_poll_vote_stake() -> Weight {
+        (412_378_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn update_category_privacy_lead(i: u32) -> Weight {
+        (189_442_000 as Weight)
+            .saturating_add((61_874_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn update_category_privacy_moderator(i: u32) -> Weight {
+        (211_506_000 as Weight)
+            .saturating_add((58_209_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn update_category_whitelist_membership_lead(i: u32) -> Weight {
+        (224_371_000 as Weight)
+            .saturating_add((61_952_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn update_category_whitelist_membership_moderator(i: u32) -> Weight {
+        (246_815_000 as Weight)
+            .saturating_add((58_337_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
 }
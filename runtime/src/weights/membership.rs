@@ -18,8 +18,8 @@ impl membership::WeightInfo for WeightInfo {
         (1_211_185_000 as Weight)
             .saturating_add((187_000 as Weight).saturating_mul(i as Weight))
             .saturating_add((267_000 as Weight).saturating_mul(j as Weight))
-            .saturating_add(DbWeight::get().reads(7 as Weight))
-            .saturating_add(DbWeight::get().writes(4 as Weight))
+            .saturating_add(DbWeight::get().reads(11 as Weight))
+            .saturating_add(DbWeight::get().writes(8 as Weight))
     }
     fn update_profile(i: u32) -> Weight {
         (506_958_000 as Weight)
@@ -97,4 +97,92 @@ impl membership::WeightInfo for WeightInfo {
     fn member_remark() -> Weight {
         (248_401_000 as Weight).saturating_add(DbWeight::get().reads(1 as Weight))
     }
+    fn reserve_handle(i: u32) -> Weight {
+        (315_208_000 as Weight)
+            .saturating_add((83_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn release_handle(i: u32) -> Weight {
+        (402_114_000 as Weight)
+            .saturating_add((84_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+    }
+    fn open_handle_dispute(i: u32) -> Weight {
+        (803_375_000 as Weight)
+            .saturating_add((82_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(7 as Weight))
+            .saturating_add(DbWeight::get().writes(5 as Weight))
+    }
+    fn resolve_handle_dispute() -> Weight {
+        (868_402_000 as Weight)
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(5 as Weight))
+    }
+    fn execute_handle_dispute_resolution() -> Weight {
+        (730_917_000 as Weight)
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().writes(7 as Weight))
+    }
+    fn withdraw_handle_dispute() -> Weight {
+        (417_382_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+    }
+    fn add_member_verification() -> Weight {
+        (421_733_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn revoke_member_verification() -> Weight {
+        (438_190_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn set_second_level_referral_cut() -> Weight {
+        (81_174_000 as Weight).saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn start_referral_campaign() -> Weight {
+        (203_541_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn end_referral_campaign() -> Weight {
+        (188_716_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn set_recovery_guardians(i: u32) -> Weight {
+        (301_627_000 as Weight)
+            .saturating_add((27_356_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn initiate_recovery() -> Weight {
+        (389_514_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn approve_recovery() -> Weight {
+        (372_806_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn reject_recovery() -> Weight {
+        (366_238_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn cancel_recovery() -> Weight {
+        (283_451_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn execute_recovery() -> Weight {
+        (347_962_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
 }
@@ -191,4 +191,124 @@ impl proposals_codex::WeightInfo for WeightInfo {
             .saturating_add(DbWeight::get().reads(6 as Weight))
             .saturating_add(DbWeight::get().writes(9 as Weight))
     }
+    fn create_proposal_update_nft_marketplace_parameters(t: u32, d: u32) -> Weight {
+        (727_615_000 as Weight)
+            .saturating_add((451_000 as Weight).saturating_mul(t as Weight))
+            .saturating_add((235_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(9 as Weight))
+    }
+    fn create_proposal_set_max_reward_allowed(t: u32, d: u32) -> Weight {
+        (727_615_000 as Weight)
+            .saturating_add((451_000 as Weight).saturating_mul(t as Weight))
+            .saturating_add((235_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(9 as Weight))
+    }
+    fn create_proposal_set_min_cashout_allowed(t: u32, d: u32) -> Weight {
+        (727_615_000 as Weight)
+            .saturating_add((451_000 as Weight).saturating_mul(t as Weight))
+            .saturating_add((235_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(9 as Weight))
+    }
+    fn create_proposal_update_storage_data_size_fee(t: u32, d: u32) -> Weight {
+        (727_615_000 as Weight)
+            .saturating_add((451_000 as Weight).saturating_mul(t as Weight))
+            .saturating_add((235_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(9 as Weight))
+    }
+    fn create_proposal_update_storage_buckets_per_bag_limit(t: u32, d: u32) -> Weight {
+        (727_615_000 as Weight)
+            .saturating_add((451_000 as Weight).saturating_mul(t as Weight))
+            .saturating_add((235_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(9 as Weight))
+    }
+    fn create_proposal_update_storage_buckets_voucher_max_limits(t: u32, d: u32) -> Weight {
+        (727_615_000 as Weight)
+            .saturating_add((451_000 as Weight).saturating_mul(t as Weight))
+            .saturating_add((235_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(9 as Weight))
+    }
+    fn create_proposal_update_dynamic_bag_storage_buckets_number(t: u32, d: u32) -> Weight {
+        (727_615_000 as Weight)
+            .saturating_add((451_000 as Weight).saturating_mul(t as Weight))
+            .saturating_add((235_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(9 as Weight))
+    }
+    fn create_proposal_update_storage_blacklist(t: u32, d: u32) -> Weight {
+        (727_615_000 as Weight)
+            .saturating_add((451_000 as Weight).saturating_mul(t as Weight))
+            .saturating_add((235_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(9 as Weight))
+    }
+    fn create_proposal_block_storage_uploads(t: u32, d: u32) -> Weight {
+        (727_615_000 as Weight)
+            .saturating_add((451_000 as Weight).saturating_mul(t as Weight))
+            .saturating_add((235_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(9 as Weight))
+    }
+    fn create_proposal_vesting_funding_request(t: u32, d: u32) -> Weight {
+        (727_615_000 as Weight)
+            .saturating_add((451_000 as Weight).saturating_mul(t as Weight))
+            .saturating_add((235_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(9 as Weight))
+    }
+    fn create_proposal_cancel_vesting_funding_request(t: u32, d: u32) -> Weight {
+        (727_615_000 as Weight)
+            .saturating_add((451_000 as Weight).saturating_mul(t as Weight))
+            .saturating_add((235_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(9 as Weight))
+    }
+    fn create_proposal_update_blog_author(t: u32, d: u32) -> Weight {
+        (727_615_000 as Weight)
+            .saturating_add((451_000 as Weight).saturating_mul(t as Weight))
+            .saturating_add((235_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(9 as Weight))
+    }
+    fn create_proposal_review_blog_drafts(t: u32, d: u32) -> Weight {
+        (727_615_000 as Weight)
+            .saturating_add((451_000 as Weight).saturating_mul(t as Weight))
+            .saturating_add((235_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(9 as Weight))
+    }
+    fn create_proposal_set_working_group_spending_limit(t: u32, d: u32) -> Weight {
+        (727_615_000 as Weight)
+            .saturating_add((451_000 as Weight).saturating_mul(t as Weight))
+            .saturating_add((235_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(9 as Weight))
+    }
+    fn create_proposal_spend_from_working_group_budget(t: u32, d: u32) -> Weight {
+        (727_615_000 as Weight)
+            .saturating_add((451_000 as Weight).saturating_mul(t as Weight))
+            .saturating_add((235_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(9 as Weight))
+    }
+    fn create_proposal_start_referral_campaign(t: u32, d: u32) -> Weight {
+        (727_615_000 as Weight)
+            .saturating_add((451_000 as Weight).saturating_mul(t as Weight))
+            .saturating_add((235_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(9 as Weight))
+    }
+    fn create_proposal_batch(t: u32, d: u32, i: u32) -> Weight {
+        (702_148_000 as Weight)
+            .saturating_add((451_000 as Weight).saturating_mul(t as Weight))
+            .saturating_add((235_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add((38_512_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(9 as Weight))
+    }
 }
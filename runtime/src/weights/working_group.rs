@@ -12,30 +12,24 @@ impl working_group::WeightInfo for WeightInfo {
             .saturating_add((1_019_158_000 as Weight).saturating_mul(i as Weight))
             .saturating_add(DbWeight::get().reads(4 as Weight))
             .saturating_add(DbWeight::get().reads((3 as Weight).saturating_mul(i as Weight)))
-            .saturating_add(DbWeight::get().writes(3 as Weight))
-            .saturating_add(DbWeight::get().writes((3 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+            .saturating_add(DbWeight::get().writes((4 as Weight).saturating_mul(i as Weight)))
     }
-    fn on_initialize_rewarding_with_missing_reward(i: u32) -> Weight {
-        (0 as Weight)
-            .saturating_add((1_049_486_000 as Weight).saturating_mul(i as Weight))
+    fn on_initialize_expired_openings(i: u32, j: u32) -> Weight {
+        (1_873_402_000 as Weight)
+            .saturating_add((1_208_615_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add((409_328_000 as Weight).saturating_mul(j as Weight))
             .saturating_add(DbWeight::get().reads(2 as Weight))
-            .saturating_add(DbWeight::get().reads((2 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().reads((4 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().reads((2 as Weight).saturating_mul(j as Weight)))
             .saturating_add(DbWeight::get().writes(1 as Weight))
-            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes((4 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes((2 as Weight).saturating_mul(j as Weight)))
     }
-    fn on_initialize_rewarding_with_missing_reward_cant_pay(i: u32) -> Weight {
-        (4_634_959_000 as Weight)
-            .saturating_add((516_394_000 as Weight).saturating_mul(i as Weight))
-            .saturating_add(DbWeight::get().reads(2 as Weight))
-            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
-            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(i as Weight)))
-    }
-    fn on_initialize_rewarding_without_missing_reward(i: u32) -> Weight {
-        (973_631_000 as Weight)
-            .saturating_add((506_288_000 as Weight).saturating_mul(i as Weight))
-            .saturating_add(DbWeight::get().reads(2 as Weight))
-            .saturating_add(DbWeight::get().reads((2 as Weight).saturating_mul(i as Weight)))
-            .saturating_add(DbWeight::get().writes(2 as Weight))
+    fn claim_reward() -> Weight {
+        (702_318_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
     }
     fn apply_on_opening(i: u32) -> Weight {
         (1_030_006_000 as Weight)
@@ -57,9 +51,9 @@ impl working_group::WeightInfo for WeightInfo {
             .saturating_add(DbWeight::get().writes((2 as Weight).saturating_mul(i as Weight)))
     }
     fn update_role_account() -> Weight {
-        (561_638_000 as Weight)
-            .saturating_add(DbWeight::get().reads(2 as Weight))
-            .saturating_add(DbWeight::get().writes(1 as Weight))
+        (742_915_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
     }
     fn cancel_opening() -> Weight {
         (974_307_000 as Weight)
@@ -81,13 +75,13 @@ impl working_group::WeightInfo for WeightInfo {
         (1_950_392_000 as Weight)
             .saturating_add((504_000 as Weight).saturating_mul(i as Weight))
             .saturating_add(DbWeight::get().reads(7 as Weight))
-            .saturating_add(DbWeight::get().writes(5 as Weight))
+            .saturating_add(DbWeight::get().writes(6 as Weight))
     }
     fn terminate_role_lead(i: u32) -> Weight {
         (1_898_566_000 as Weight)
             .saturating_add((502_000 as Weight).saturating_mul(i as Weight))
             .saturating_add(DbWeight::get().reads(6 as Weight))
-            .saturating_add(DbWeight::get().writes(6 as Weight))
+            .saturating_add(DbWeight::get().writes(9 as Weight))
     }
     fn increase_stake() -> Weight {
         (804_388_000 as Weight)
@@ -100,14 +94,14 @@ impl working_group::WeightInfo for WeightInfo {
             .saturating_add(DbWeight::get().writes(2 as Weight))
     }
     fn spend_from_budget() -> Weight {
-        (428_955_000 as Weight)
-            .saturating_add(DbWeight::get().reads(4 as Weight))
-            .saturating_add(DbWeight::get().writes(1 as Weight))
+        (521_847_000 as Weight)
+            .saturating_add(DbWeight::get().reads(7 as Weight))
+            .saturating_add(DbWeight::get().writes(5 as Weight))
     }
     fn update_reward_amount() -> Weight {
-        (608_563_000 as Weight)
-            .saturating_add(DbWeight::get().reads(3 as Weight))
-            .saturating_add(DbWeight::get().writes(1 as Weight))
+        (781_654_000 as Weight)
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
     }
     fn set_status_text(i: u32) -> Weight {
         (353_651_000 as Weight)
@@ -130,10 +124,10 @@ impl working_group::WeightInfo for WeightInfo {
             .saturating_add(DbWeight::get().writes(4 as Weight))
     }
     fn leave_role(i: u32) -> Weight {
-        (473_226_000 as Weight)
-            .saturating_add((221_000 as Weight).saturating_mul(i as Weight))
-            .saturating_add(DbWeight::get().reads(1 as Weight))
-            .saturating_add(DbWeight::get().writes(1 as Weight))
+        (618_407_000 as Weight)
+            .saturating_add((223_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
     }
     fn lead_remark() -> Weight {
         (279_387_000 as Weight).saturating_add(DbWeight::get().reads(2 as Weight))
@@ -141,4 +135,43 @@ impl working_group::WeightInfo for WeightInfo {
     fn worker_remark() -> Weight {
         (302_419_000 as Weight).saturating_add(DbWeight::get().reads(1 as Weight))
     }
+    fn pay_worker_bonus() -> Weight {
+        (524_193_000 as Weight)
+            .saturating_add(DbWeight::get().reads(7 as Weight))
+            .saturating_add(DbWeight::get().writes(6 as Weight))
+    }
+    fn review_worker(i: u32) -> Weight {
+        (361_208_000 as Weight)
+            .saturating_add((262_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn shortlist_applications(i: u32) -> Weight {
+        (377_482_000 as Weight)
+            .saturating_add((89_614_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(i as Weight)))
+    }
+    fn reject_applications(i: u32) -> Weight {
+        (381_905_000 as Weight)
+            .saturating_add((302_117_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().reads((3 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes((3 as Weight).saturating_mul(i as Weight)))
+    }
+    fn appoint_deputy(i: u32) -> Weight {
+        (391_826_000 as Weight)
+            .saturating_add((1_097_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn remove_deputy() -> Weight {
+        (342_671_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn set_spending_limit() -> Weight {
+        (131_904_000 as Weight).saturating_add(DbWeight::get().writes(2 as Weight))
+    }
 }
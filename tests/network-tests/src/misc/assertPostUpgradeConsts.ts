@@ -12,7 +12,7 @@ export default async function assertValues({ api, query }: FlowProps): Promise<v
 
   debug('Check runtime spec version')
   const version = await api.rpc.state.getRuntimeVersion()
-  assert.equal(version.specVersion.toNumber(), 6)
+  assert.equal(version.specVersion.toNumber(), 7)
 
   debug('Check that post migration NFT value are updated')
 